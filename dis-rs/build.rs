@@ -21,7 +21,7 @@ use proc_macro2::{Ident, Literal, TokenStream};
 ///
 /// Finally, some enums have variants that result in empty names (`""`) or duplicate names (such as 'Emitter Name').
 /// The bool flag will append `"_value"` to the name of the variant to make it unique
-const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
+const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 164] = [
     (3, Some("ProtocolVersion"), None, false),   // Protocol Version
     (4, Some("PduType"), None, false),           // PDU Type
     (5, Some("ProtocolFamily"), None, false),    // PDU Family
@@ -42,7 +42,9 @@ const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
     (60, None, None, false), // Munition Descriptor-Warhead
     (61, None, None, true), // Munition Descriptor-Fuse
     (62, None, None, false), // Detonation result
-    // 63-74, // All kinds of stuff for lesser priority PDUs
+    (63, None, None, false), // Service Request-Service Type Requested
+    (64, None, None, false), // Repair Complete-Repair
+    (65, None, None, false), // Repair Response-Repair Result
     (66, Some("VariableRecordType"), None, true), // Variable Record Types
     (67, None, None, false), // Stop/Freeze Reason
     (69, Some("AcknowledgeFlag"), None, false), // Acknowledge-Acknowledge Flag
@@ -50,6 +52,7 @@ const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
     (71, Some("ActionId"), None, false), // Action Request-Action ID
     (72, Some("RequestStatus"), None, false), // Action Request-Request Status
     (73, Some("EventType"), None, false), // Event Report-Event Type
+    (74, None, None, false), // Required Reliability Service
     (75, None, None, true), // Emitter Name
     (76, None, None, true), // Emitter System Function
     (77, None, None, false), // Electromagnetic Emission-State Update Indicator
@@ -62,6 +65,12 @@ const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
     (84, Some("IffSystemMode"), None, false), // IFF-System Mode
     // 87, 96-98 // IFF stuff
     // 100-106, // Subcategories
+    (143, Some("UAStateChangeUpdateIndicator"), None, false), // UA-State/Change Update Indicator
+    (144, Some("UAAcousticSystemName"), None, false), // UA-Acoustic System Name
+    (145, Some("UAAcousticEmitterSystemFunction"), None, false), // UA-Acoustic Emitter System Function
+    (146, Some("UAActiveEmissionParameterIndex"), None, false), // UA-Active Emission Parameter Index
+    (147, Some("UAScanPattern"), None, false), // UA-Scan Pattern
+    (148, Some("UAPassiveParameterIndex"), None, false), // UA-Passive Parameter Index
     (155, None, None, false), // Transmitter Major Modulation
     (156, None, None, false), // Transmitter-Detail-Amplitude Modulation
     (157, Some("TransmitterDetailAmplitudeAngleModulation"), None, false), // Transmitter-Detail-Amplitude and Angle Modulation
@@ -79,12 +88,36 @@ const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
     (177, None, None, false), // Signal User Protocol Identification Number
     (178, Some("SignalTdlType"), None, true), // Signal TDL Type
     (179, Some("ReceiverState"), None, false), // Receiver Receiver State
+    (180, Some("IntercomControlControlType"), None, false), // Intercom Control-Control Type
+    (181, Some("IntercomControlCommunicationsType"), None, false), // Intercom Control-Communications Type
+    (182, Some("IntercomControlCommand"), None, false), // Intercom Control-Command
+    (183, Some("IntercomControlTransmitLineState"), None, false), // Intercom Control-Transmit Line State
+    (185, Some("IntercomControlRecordType"), None, false), // Intercom Control-Record Type
     (189, None, None, false), // Collision Type
+    (204, Some("AggregateStateAggregateState"), None, false), // Aggregate State-Aggregate State
+    (205, Some("AggregateStateFormation"), None, false), // Aggregate State-Formation
+    (206, Some("AggregateStateAggregateKind"), None, false), // Aggregate State-Aggregate Kind
+    (210, Some("IsPartOfNature"), None, false), // IsPartOf-Nature
+    (211, Some("IsPartOfPosition"), None, false), // IsPartOf-Position
     (212, Some("StationName"), None, false), // IsPartOf-Station Name
+    (213, Some("IsGroupOfGroupedEntityCategory"), None, false), // IsGroupOf-Grouped Entity Category
+    (214, Some("IsGroupOfRestStatus"), None, false), // IsGroupOf-Rest Status
+    (224, Some("TransferControlTransferType"), None, false), // Transfer Control-Transfer Type
+    (225, Some("ObjectKind"), None, false), // Object Kind
     (270, None, Some(16), false), // Signal Encoding Class
     (271, None, Some(16), true), // Signal Encoding Type
     (282, Some("SeparationReasonForSeparation"), None, false), // Separation VP-Reason for Separation
     (283, Some("SeparationPreEntityIndicator"), None, false), // Separation VP-Pre-Entity Indicator
+    (285, Some("IOActionIOWarfareType"), None, false), // IO Action-IO Warfare Type
+    (286, Some("IOActionIOSimulationSource"), None, false), // IO Action-IO Simulation Source
+    (287, Some("IOActionIOActionType"), None, false), // IO Action-IO Action Type
+    (288, Some("IOActionIOActionPhase"), None, false), // IO Action-IO Action Phase
+    (289, Some("IOReportIOReportType"), None, false), // IO Report-IO Report Type
+    (290, Some("IOEffectsRecordIOStatus"), None, false), // IO Effects Record-IO Status
+    (291, Some("IOEffectsRecordIOLinkType"), None, false), // IO Effects Record-IO Link Type
+    (292, Some("IOEffectsRecordIOEffect"), None, false), // IO Effects Record-IO Effect
+    (293, Some("IOEffectsRecordIOProcess"), None, false), // IO Effects Record-IO Process
+    (294, Some("IOCommsNodeRecordCommsNodeType"), None, false), // IO Comms Node Record-Comms Node Type
     (295, Some("AttributeActionCode"), None, false), // Attribute Action Code
     (296, Some("DrParametersType"), None, false), // Dead Reckoning Parameters Type
     (301, Some("TransferredEntityIndicator"), None, false), // DIS-PDU Status-Transferred Entity Indicator (TEI)
@@ -102,6 +135,8 @@ const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 128] = [
     (321, None, None, false), // Entity Association-Group Member Type
     (323, None, None, false), // Entity Association-Physical Association Type
     (324, None, None, false), // Entity Association-Physical Connection Type
+    (333, Some("RecordREventType"), None, false), // Record R-Event Type
+    (334, Some("RecordQueryREventType"), None, false), // Record Query-R-Event Type
     (339, Some("IffApplicableModes"), None, false), // IFF Applicable Modes
     (346, Some("Mode5IffMission"), None, false), // IFF Mission
     (347, Some("ModeSTransmitState"), Some(8), false), // Mode S Interrogator Status Transmit State
@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::{be_u16, be_u32};
 use crate::common::acknowledge::model::Acknowledge;
 use crate::common::parser::entity_id;
@@ -6,11 +7,11 @@ use crate::common::model::PduBody;
 use crate::enumerations::{AcknowledgeFlag, ResponseFlag};
 
 pub fn acknowledge_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, acknowledge_flag) = be_u16(input)?;
-    let (input, response_flag) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, acknowledge_flag) = context("Acknowledge Flag", be_u16)(input)?;
+    let (input, response_flag) = context("Response Flag", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
 
     let acknowledge_flag = AcknowledgeFlag::from(acknowledge_flag);
     let response_flag = ResponseFlag::from(response_flag);
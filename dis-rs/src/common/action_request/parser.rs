@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::parser::{datum_specification, entity_id};
 use crate::enumerations::{ActionId};
@@ -6,12 +7,12 @@ use crate::common::action_request::model::ActionRequest;
 use crate::common::model::PduBody;
 
 pub fn action_request_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, action_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, action_id) = context("Action ID", be_u32)(input)?;
     let action_id = ActionId::from(action_id);
-    let (input, datums) = datum_specification(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
 
     let body = ActionRequest::builder()
         .with_origination_id(originating_id)
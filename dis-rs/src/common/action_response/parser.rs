@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::action_response::model::ActionResponse;
 use crate::common::parser::{datum_specification, entity_id};
@@ -6,12 +7,12 @@ use crate::enumerations::{RequestStatus};
 use crate::common::model::PduBody;
 
 pub fn action_response_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, request_status) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, request_status) = context("Request Status", be_u32)(input)?;
     let request_status = RequestStatus::from(request_status);
-    let (input, datums) = datum_specification(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
 
     let body = ActionResponse::builder()
         .with_origination_id(originating_id)
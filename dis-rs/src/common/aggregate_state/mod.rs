@@ -0,0 +1,71 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::aggregate_state::model::{AggregateMarking, AggregateState, AggregateType, SilentAggregateSystem, SilentEntitySystem};
+    use crate::common::entity_state::model::EntityAppearance;
+    use crate::common::model::{DisTimeStamp, EntityId, EntityType, Location, Orientation, Pdu, PduHeader, VariableDatum, VectorF32};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{AggregateStateAggregateKind, AggregateStateAggregateState, AggregateStateFormation, Country, EntityKind, EntityMarkingCharacterSet, ForceId, LandPlatformAppearance, PduType, PlatformDomain, VariableRecordType};
+
+    #[test]
+    fn aggregate_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::AggregateState);
+
+        let tank = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land)
+            .with_country(Country::Netherlands_NLD_)
+            .with_category(1);
+        let body = AggregateState::builder()
+            .with_aggregate_id(EntityId::new(1, 1, 100))
+            .with_force_id(ForceId::Friendly)
+            .with_aggregate_state(AggregateStateAggregateState::from(1))
+            .with_aggregate_type(AggregateType::default()
+                .with_aggregate_kind(AggregateStateAggregateKind::from(1))
+                .with_domain(PlatformDomain::Land)
+                .with_country(Country::Netherlands_NLD_)
+                .with_category(2))
+            .with_formation(AggregateStateFormation::from(3))
+            .with_aggregate_marking(AggregateMarking::new("A COY 1 TANK BN".to_string(), EntityMarkingCharacterSet::ASCII))
+            .with_dimensions(VectorF32::new(500.0, 300.0, 10.0))
+            .with_orientation(Orientation::new(1.0, 0.0, 0.0))
+            .with_center_of_mass(Location::new(3_900_000.0, 350_000.0, 5_000_000.0))
+            .with_velocity(VectorF32::new(5.0, 0.0, 0.0))
+            .with_aggregate(EntityId::new(1, 1, 101))
+            .with_entity(EntityId::new(1, 1, 1))
+            .with_entity(EntityId::new(1, 1, 2))
+            .with_silent_aggregate_system(SilentAggregateSystem::new(2, AggregateType::default()
+                .with_aggregate_kind(AggregateStateAggregateKind::from(1))
+                .with_category(3)))
+            .with_silent_entity_system(SilentEntitySystem::new(4, tank)
+                .with_appearance(EntityAppearance::LandPlatform(LandPlatformAppearance::from(0u32))))
+            .with_variable_datum(VariableDatum::new(VariableRecordType::Azimuth_52340, vec![1,2,3,4,5,6,7]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
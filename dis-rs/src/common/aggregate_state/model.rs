@@ -0,0 +1,196 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::aggregate_state::builder::AggregateStateBuilder;
+use crate::common::entity_state::model::EntityAppearance;
+use crate::common::model::{BASE_VARIABLE_DATUM_LENGTH, EntityId, EntityType, length_padded_to_num_bytes, Location, Orientation, PduBody, VariableDatum, VectorF32};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS};
+use crate::enumerations::{AggregateStateAggregateKind, AggregateStateAggregateState, AggregateStateFormation, Country, EntityMarkingCharacterSet, ForceId, PduType, PlatformDomain};
+
+pub const BASE_AGGREGATE_STATE_BODY_LENGTH: u16 = 124;
+pub const AGGREGATE_ID_RECORD_LENGTH: u16 = 6;
+pub const SILENT_AGGREGATE_SYSTEM_RECORD_LENGTH: u16 = 12;
+pub const BASE_SILENT_ENTITY_SYSTEM_RECORD_LENGTH: u16 = 12;
+pub const ENTITY_APPEARANCE_RECORD_LENGTH: u16 = 4;
+pub const AGGREGATE_MARKING_STRING_LENGTH: usize = 31;
+
+/// 5.9.2 Aggregate State PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct AggregateState {
+    pub aggregate_id: EntityId,
+    pub force_id: ForceId,
+    pub aggregate_state: AggregateStateAggregateState,
+    pub aggregate_type: AggregateType,
+    pub formation: AggregateStateFormation,
+    pub aggregate_marking: AggregateMarking,
+    pub dimensions: VectorF32,
+    pub orientation: Orientation,
+    pub center_of_mass: Location,
+    pub velocity: VectorF32,
+    pub aggregates: Vec<EntityId>,
+    pub entities: Vec<EntityId>,
+    pub silent_aggregate_systems: Vec<SilentAggregateSystem>,
+    pub silent_entity_systems: Vec<SilentEntitySystem>,
+    pub variable_datums: Vec<VariableDatum>,
+}
+
+impl AggregateState {
+    pub fn builder() -> AggregateStateBuilder {
+        AggregateStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> AggregateStateBuilder {
+        AggregateStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::AggregateState(self)
+    }
+
+    /// The number of octets of padding after the aggregate and entity ID lists, to align the body to 32 bits.
+    pub fn padding_length(&self) -> u16 {
+        let ids_length = (self.aggregates.len() + self.entities.len()) * AGGREGATE_ID_RECORD_LENGTH as usize;
+        ((FOUR_OCTETS - ids_length % FOUR_OCTETS) % FOUR_OCTETS) as u16
+    }
+}
+
+impl BodyInfo for AggregateState {
+    fn body_length(&self) -> u16 {
+        BASE_AGGREGATE_STATE_BODY_LENGTH
+            + ((self.aggregates.len() + self.entities.len()) as u16 * AGGREGATE_ID_RECORD_LENGTH)
+            + self.padding_length()
+            + (self.silent_aggregate_systems.len() as u16 * SILENT_AGGREGATE_SYSTEM_RECORD_LENGTH)
+            + self.silent_entity_systems.iter().map(|system| system.record_length()).sum::<u16>()
+            + (self.variable_datums.iter().map(|datum| {
+                let padded_record = length_padded_to_num_bytes(
+                    BASE_VARIABLE_DATUM_LENGTH as usize + datum.datum_value.len(),
+                    EIGHT_OCTETS);
+                padded_record.record_length_bytes as u16
+            } ).sum::<u16>())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::AggregateState
+    }
+}
+
+impl Interaction for AggregateState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.aggregate_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.5 Aggregate Type record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AggregateType {
+    pub aggregate_kind: AggregateStateAggregateKind,
+    pub domain: PlatformDomain,
+    pub country: Country,
+    pub category: u8,
+    pub subcategory: u8,
+    pub specific: u8,
+    pub extra: u8,
+}
+
+impl AggregateType {
+    pub fn with_aggregate_kind(mut self, aggregate_kind: AggregateStateAggregateKind) -> Self {
+        self.aggregate_kind = aggregate_kind;
+        self
+    }
+
+    pub fn with_domain(mut self, domain: PlatformDomain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    pub fn with_country(mut self, country: Country) -> Self {
+        self.country = country;
+        self
+    }
+
+    pub fn with_category(mut self, category: u8) -> Self {
+        self.category = category;
+        self
+    }
+
+    pub fn with_subcategory(mut self, subcategory: u8) -> Self {
+        self.subcategory = subcategory;
+        self
+    }
+
+    pub fn with_specific(mut self, specific: u8) -> Self {
+        self.specific = specific;
+        self
+    }
+
+    pub fn with_extra(mut self, extra: u8) -> Self {
+        self.extra = extra;
+        self
+    }
+}
+
+/// 6.2.4 Aggregate Marking record
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AggregateMarking {
+    pub marking_character_set : EntityMarkingCharacterSet,
+    pub marking_string : String, // 31 byte String
+}
+
+impl AggregateMarking {
+    pub fn new(marking: String, character_set: EntityMarkingCharacterSet) -> Self {
+        Self {
+            marking_character_set: character_set,
+            marking_string: marking
+        }
+    }
+
+    pub fn with_marking(mut self, marking: String) -> Self {
+        self.marking_string = marking;
+        self
+    }
+}
+
+/// 6.2.79 Silent Aggregate System record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SilentAggregateSystem {
+    pub number_of_aggregates: u16,
+    pub aggregate_type: AggregateType,
+}
+
+impl SilentAggregateSystem {
+    pub fn new(number_of_aggregates: u16, aggregate_type: AggregateType) -> Self {
+        Self {
+            number_of_aggregates,
+            aggregate_type,
+        }
+    }
+}
+
+/// 6.2.80 Silent Entity System record
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SilentEntitySystem {
+    pub number_of_entities: u16,
+    pub entity_type: EntityType,
+    pub appearances: Vec<EntityAppearance>,
+}
+
+impl SilentEntitySystem {
+    pub fn new(number_of_entities: u16, entity_type: EntityType) -> Self {
+        Self {
+            number_of_entities,
+            entity_type,
+            appearances: vec![],
+        }
+    }
+
+    pub fn with_appearance(mut self, appearance: EntityAppearance) -> Self {
+        self.appearances.push(appearance);
+        self
+    }
+
+    pub fn record_length(&self) -> u16 {
+        BASE_SILENT_ENTITY_SYSTEM_RECORD_LENGTH + (self.appearances.len() as u16 * ENTITY_APPEARANCE_RECORD_LENGTH)
+    }
+}
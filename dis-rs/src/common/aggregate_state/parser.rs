@@ -0,0 +1,111 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::aggregate_state::model::{AGGREGATE_ID_RECORD_LENGTH, AGGREGATE_MARKING_STRING_LENGTH, AggregateMarking, AggregateState, AggregateType, SilentAggregateSystem, SilentEntitySystem};
+use crate::common::entity_state::parser::{entity_appearance, force_id};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, entity_type, location, orientation, variable_datum, vec3_f32};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::{AggregateStateAggregateKind, AggregateStateAggregateState, AggregateStateFormation, Country, EntityMarkingCharacterSet, PlatformDomain};
+
+pub fn aggregate_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, aggregate_id) = context("Aggregate ID", entity_id)(input)?;
+    let (input, force_id) = context("Force ID", force_id)(input)?;
+    let (input, aggregate_state) = context("Aggregate State", be_u8)(input)?;
+    let (input, aggregate_type) = context("Aggregate Type", aggregate_type)(input)?;
+    let (input, formation) = context("Formation", be_u32)(input)?;
+    let (input, aggregate_marking) = context("Aggregate Marking", aggregate_marking)(input)?;
+    let (input, dimensions) = context("Dimensions", vec3_f32)(input)?;
+    let (input, orientation) = context("Orientation", orientation)(input)?;
+    let (input, center_of_mass) = context("Center of Mass", location)(input)?;
+    let (input, velocity) = context("Velocity", vec3_f32)(input)?;
+    let (input, number_of_aggregates) = context("Number of Aggregates", be_u16)(input)?;
+    let (input, number_of_entities) = context("Number of Entities", be_u16)(input)?;
+    let (input, number_of_silent_aggregate_types) = context("Number of Silent Aggregate Types", be_u16)(input)?;
+    let (input, number_of_silent_entity_types) = context("Number of Silent Entity Types", be_u16)(input)?;
+    let (input, aggregates) = context("Aggregate ID List", count(entity_id, number_of_aggregates as usize))(input)?;
+    let (input, entities) = context("Entity ID List", count(entity_id, number_of_entities as usize))(input)?;
+    let ids_length = (number_of_aggregates as usize + number_of_entities as usize) * AGGREGATE_ID_RECORD_LENGTH as usize;
+    let (input, _padding) = context("Padding", take((FOUR_OCTETS - ids_length % FOUR_OCTETS) % FOUR_OCTETS))(input)?;
+    let (input, silent_aggregate_systems) = context("Silent Aggregate System List", count(silent_aggregate_system, number_of_silent_aggregate_types as usize))(input)?;
+    let (input, silent_entity_systems) = context("Silent Entity System List", count(silent_entity_system, number_of_silent_entity_types as usize))(input)?;
+    let (input, number_of_variable_datums) = context("Number of Variable Datum Records", be_u32)(input)?;
+    let (input, variable_datums) = context("Variable Datum Records", count(variable_datum, number_of_variable_datums as usize))(input)?;
+
+    let body = AggregateState::builder()
+        .with_aggregate_id(aggregate_id)
+        .with_force_id(force_id)
+        .with_aggregate_state(AggregateStateAggregateState::from(aggregate_state))
+        .with_aggregate_type(aggregate_type)
+        .with_formation(AggregateStateFormation::from(formation))
+        .with_aggregate_marking(aggregate_marking)
+        .with_dimensions(dimensions)
+        .with_orientation(orientation)
+        .with_center_of_mass(center_of_mass)
+        .with_velocity(velocity)
+        .with_aggregates(aggregates)
+        .with_entities(entities)
+        .with_silent_aggregate_systems(silent_aggregate_systems)
+        .with_silent_entity_systems(silent_entity_systems)
+        .with_variable_datums(variable_datums)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+pub fn aggregate_type(input: &[u8]) -> IResult<&[u8], AggregateType> {
+    let (input, aggregate_kind) = be_u8(input)?;
+    let (input, domain) = be_u8(input)?;
+    let (input, country) = be_u16(input)?;
+    let (input, category) = be_u8(input)?;
+    let (input, subcategory) = be_u8(input)?;
+    let (input, specific) = be_u8(input)?;
+    let (input, extra) = be_u8(input)?;
+
+    Ok((input, AggregateType {
+        aggregate_kind: AggregateStateAggregateKind::from(aggregate_kind),
+        domain: PlatformDomain::from(domain),
+        country: Country::from(country),
+        category,
+        subcategory,
+        specific,
+        extra,
+    }))
+}
+
+pub fn aggregate_marking(input: &[u8]) -> IResult<&[u8], AggregateMarking> {
+    let mut buf : [u8;AGGREGATE_MARKING_STRING_LENGTH] = [0;AGGREGATE_MARKING_STRING_LENGTH];
+    let (input, character_set) = be_u8(input)?;
+    let (input, _) = nom::multi::fill(be_u8, &mut buf)(input)?;
+
+    let mut marking = String::from_utf8_lossy(&buf[..]).into_owned();
+    marking.truncate(marking.trim_end().trim_end_matches(|c : char | !c.is_alphanumeric()).len());
+
+    Ok((input, AggregateMarking{
+        marking_character_set: EntityMarkingCharacterSet::from(character_set),
+        marking_string: marking,
+    }))
+}
+
+pub fn silent_aggregate_system(input: &[u8]) -> IResult<&[u8], SilentAggregateSystem> {
+    let (input, number_of_aggregates) = be_u16(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, aggregate_type) = aggregate_type(input)?;
+
+    Ok((input, SilentAggregateSystem::new(number_of_aggregates, aggregate_type)))
+}
+
+pub fn silent_entity_system(input: &[u8]) -> IResult<&[u8], SilentEntitySystem> {
+    let (input, number_of_entities) = be_u16(input)?;
+    let (input, number_of_appearance_records) = be_u16(input)?;
+    let (input, entity_type) = entity_type(input)?;
+    let (input, appearances) = count(entity_appearance(entity_type), number_of_appearance_records as usize)(input)?;
+
+    Ok((input, SilentEntitySystem {
+        number_of_entities,
+        entity_type,
+        appearances,
+    }))
+}
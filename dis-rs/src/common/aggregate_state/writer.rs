@@ -0,0 +1,92 @@
+use bytes::BufMut;
+use crate::common::aggregate_state::model::{AGGREGATE_MARKING_STRING_LENGTH, AggregateMarking, AggregateState, AggregateType, SilentAggregateSystem, SilentEntitySystem};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for AggregateState {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let aggregate_id_bytes = self.aggregate_id.serialize(buf);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8(self.aggregate_state.into());
+        let aggregate_type_bytes = self.aggregate_type.serialize(buf);
+        buf.put_u32(self.formation.into());
+        let marking_bytes = self.aggregate_marking.serialize(buf);
+        let dimensions_bytes = self.dimensions.serialize(buf);
+        let orientation_bytes = self.orientation.serialize(buf);
+        let center_of_mass_bytes = self.center_of_mass.serialize(buf);
+        let velocity_bytes = self.velocity.serialize(buf);
+        buf.put_u16(self.aggregates.len() as u16);
+        buf.put_u16(self.entities.len() as u16);
+        buf.put_u16(self.silent_aggregate_systems.len() as u16);
+        buf.put_u16(self.silent_entity_systems.len() as u16);
+        let aggregates_bytes = self.aggregates.iter()
+            .map(|aggregate| aggregate.serialize(buf))
+            .sum::<u16>();
+        let entities_bytes = self.entities.iter()
+            .map(|entity| entity.serialize(buf))
+            .sum::<u16>();
+        let padding_bytes = self.padding_length();
+        (0..padding_bytes).for_each(|_i| buf.put_u8(0u8));
+        let silent_aggregates_bytes = self.silent_aggregate_systems.iter()
+            .map(|system| system.serialize(buf))
+            .sum::<u16>();
+        let silent_entities_bytes = self.silent_entity_systems.iter()
+            .map(|system| system.serialize(buf))
+            .sum::<u16>();
+        buf.put_u32(self.variable_datums.len() as u32);
+        let variable_datum_bytes = self.variable_datums.iter()
+            .map(|datum| datum.serialize(buf))
+            .sum::<u16>();
+
+        aggregate_id_bytes + 2 + aggregate_type_bytes + 4 + marking_bytes
+            + dimensions_bytes + orientation_bytes + center_of_mass_bytes + velocity_bytes + 8
+            + aggregates_bytes + entities_bytes + padding_bytes
+            + silent_aggregates_bytes + silent_entities_bytes + 4 + variable_datum_bytes
+    }
+}
+
+impl Serialize for AggregateType {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.aggregate_kind.into());
+        buf.put_u8(self.domain.into());
+        buf.put_u16(self.country.into());
+        buf.put_u8(self.category);
+        buf.put_u8(self.subcategory);
+        buf.put_u8(self.specific);
+        buf.put_u8(self.extra);
+        8
+    }
+}
+
+impl Serialize for AggregateMarking {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.marking_character_set.into());
+        let marking = self.marking_string.as_bytes();
+        let marking = &marking[..marking.len().min(AGGREGATE_MARKING_STRING_LENGTH)];
+        let num_pad = AGGREGATE_MARKING_STRING_LENGTH - marking.len();
+
+        buf.put_slice(marking);
+        (0..num_pad).for_each( |_i| buf.put_u8(0x20) );
+        1 + AGGREGATE_MARKING_STRING_LENGTH as u16
+    }
+}
+
+impl Serialize for SilentAggregateSystem {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.number_of_aggregates);
+        buf.put_u16(0u16);
+        let aggregate_type_bytes = self.aggregate_type.serialize(buf);
+        4 + aggregate_type_bytes
+    }
+}
+
+impl Serialize for SilentEntitySystem {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.number_of_entities);
+        buf.put_u16(self.appearances.len() as u16);
+        let entity_type_bytes = self.entity_type.serialize(buf);
+        let appearances_bytes = self.appearances.iter()
+            .map(|appearance| appearance.serialize(buf))
+            .sum::<u16>();
+        4 + entity_type_bytes + appearances_bytes
+    }
+}
@@ -0,0 +1,57 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::areal_object_state::model::ArealObjectState;
+    use crate::common::model::{DisTimeStamp, EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Pdu, PduHeader, SimulationAddress};
+    use crate::enumerations::{ForceId, ObjectKind, PduType, PlatformDomain};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn areal_object_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::ArealObjectState);
+
+        let body = ArealObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 300))
+            .with_referenced_object_id(EntityId::new(1, 1, 301))
+            .with_update_number(1)
+            .with_force_id(ForceId::Neutral)
+            .with_modifications(ObjectStateModification::default()
+                .with_location(true))
+            .with_object_type(ObjectType::new(PlatformDomain::Land, ObjectKind::from(1), 1, 1))
+            .with_specific_object_appearance(0x0000_0010)
+            .with_general_object_appearance(GeneralObjectAppearance::default()
+                .with_percent_complete(50)
+                .with_flaming(true))
+            .with_requester_id(SimulationAddress::new(1, 1))
+            .with_receiving_id(SimulationAddress::new(2, 2))
+            .with_object_location(Location::new(10.0, 10.0, 0.0))
+            .with_object_location(Location::new(20.0, 10.0, 0.0))
+            .with_object_location(Location::new(20.0, 20.0, 0.0))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::areal_object_state::builder::ArealObjectStateBuilder;
+use crate::common::model::{EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, PduBody, SimulationAddress};
+use crate::enumerations::{ForceId, PduType};
+
+const BASE_AREAL_OBJECT_STATE_BODY_LENGTH: u16 = 36;
+const OBJECT_LOCATION_RECORD_LENGTH: u16 = 24;
+
+/// 5.11.3.4 Areal Object State PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct ArealObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    pub specific_object_appearance: u32,
+    pub general_object_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_locations: Vec<Location>,
+}
+
+impl ArealObjectState {
+    pub fn builder() -> ArealObjectStateBuilder {
+        ArealObjectStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ArealObjectStateBuilder {
+        ArealObjectStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ArealObjectState(self)
+    }
+}
+
+impl BodyInfo for ArealObjectState {
+    fn body_length(&self) -> u16 {
+        BASE_AREAL_OBJECT_STATE_BODY_LENGTH
+            + (self.object_locations.len() as u16 * OBJECT_LOCATION_RECORD_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArealObjectState
+    }
+}
+
+impl Interaction for ArealObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
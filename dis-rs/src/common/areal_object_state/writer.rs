@@ -0,0 +1,25 @@
+use bytes::BufMut;
+use crate::common::areal_object_state::model::ArealObjectState;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ArealObjectState {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let object_id_bytes = self.object_id.serialize(buf);
+        let referenced_object_id_bytes = self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        let modifications_bytes = self.modifications.serialize(buf);
+        let object_type_bytes = self.object_type.serialize(buf);
+        buf.put_u32(self.specific_object_appearance);
+        let general_appearance_bytes = self.general_object_appearance.serialize(buf);
+        buf.put_u16(self.object_locations.len() as u16);
+        let requester_id_bytes = self.requester_id.serialize(buf);
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        let locations_bytes = self.object_locations.iter()
+            .map(|location| location.serialize(buf))
+            .sum::<u16>();
+
+        object_id_bytes + referenced_object_id_bytes + 3 + modifications_bytes + object_type_bytes + 4
+            + general_appearance_bytes + 2 + requester_id_bytes + receiving_id_bytes + locations_bytes
+    }
+}
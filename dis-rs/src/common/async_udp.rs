@@ -0,0 +1,129 @@
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_core::Stream;
+use futures_sink::Sink;
+use tokio::net::UdpSocket;
+use tokio_util::udp::UdpFramed;
+use crate::common::codec::DisCodec;
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::udp::{bind_socket, stamp_header, UdpSettings};
+
+/// An asynchronous UDP socket for PDUs, for use with tokio.
+///
+/// The endpoint is a `Stream` of received PDUs that pass the exercise id and protocol version filters of the settings,
+/// and a `Sink` that sends PDUs to the remote address of the settings. Like `UdpEndpoint`, the PDU Length
+/// and the (absolute) timestamp in the header are filled in when sending a PDU.
+/// Each datagram can contain multiple PDUs, which are yielded in order.
+pub struct AsyncUdpEndpoint {
+    framed: UdpFramed<DisCodec>,
+    settings: UdpSettings,
+}
+
+impl AsyncUdpEndpoint {
+    /// Creates and binds the socket, and joins the multicast group when configured.
+    /// Must be called from within a tokio runtime. The read timeout of the settings is not used.
+    pub fn new(settings: UdpSettings) -> io::Result<Self> {
+        let socket = bind_socket(&settings)?;
+        socket.set_nonblocking(true)?;
+        let socket = UdpSocket::from_std(socket)?;
+
+        Ok(Self {
+            framed: UdpFramed::new(socket, DisCodec::new()),
+            settings,
+        })
+    }
+
+    pub fn settings(&self) -> &UdpSettings {
+        &self.settings
+    }
+
+    /// The local address the socket is bound to, e.g. to find out the port when binding to port 0.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.framed.get_ref().local_addr()
+    }
+}
+
+impl Stream for AsyncUdpEndpoint {
+    type Item = Result<Pdu, DisError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.framed).poll_next(cx) {
+                Poll::Ready(Some(Ok((pdu, _source)))) => {
+                    if this.settings.accepts(&pdu) {
+                        return Poll::Ready(Some(Ok(pdu)));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => { return Poll::Ready(Some(Err(err))); }
+                Poll::Ready(None) => { return Poll::Ready(None); }
+                Poll::Pending => { return Poll::Pending; }
+            }
+        }
+    }
+}
+
+impl Sink<Pdu> for AsyncUdpEndpoint {
+    type Error = DisError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Sink::<(Pdu, SocketAddr)>::poll_ready(Pin::new(&mut self.get_mut().framed), cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, mut item: Pdu) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        stamp_header(&mut item);
+        Pin::new(&mut this.framed).start_send((item, this.settings.remote_address))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Sink::<(Pdu, SocketAddr)>::poll_flush(Pin::new(&mut self.get_mut().framed), cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Sink::<(Pdu, SocketAddr)>::poll_close(Pin::new(&mut self.get_mut().framed), cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+    use futures_util::{SinkExt, StreamExt};
+    use crate::common::async_udp::AsyncUdpEndpoint;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::model::{EntityId, EntityType, Pdu, PduBody, PduHeader};
+    use crate::common::udp::UdpSettings;
+    use crate::enumerations::{EntityKind, ForceId, PduType};
+
+    fn loopback() -> SocketAddr {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)
+    }
+
+    fn entity_state(exercise_id: u8, entity_number: u16) -> Pdu {
+        let body = EntityState::new(EntityId::new(1, 1, entity_number), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v7(exercise_id, PduType::EntityState), body, 0)
+    }
+
+    #[tokio::test]
+    async fn send_and_receive_with_filter() {
+        let mut receiver = AsyncUdpEndpoint::new(UdpSettings::new_unicast(loopback(), loopback())
+            .with_exercise_id(1)).unwrap();
+        let mut sender = AsyncUdpEndpoint::new(
+            UdpSettings::new_unicast(loopback(), receiver.local_addr().unwrap())).unwrap();
+
+        sender.send(entity_state(2, 1)).await.unwrap();
+        sender.send(entity_state(1, 2)).await.unwrap();
+
+        let pdu = receiver.next().await.unwrap().unwrap();
+        assert_eq!(pdu.header.exercise_id, 1);
+        assert_eq!(pdu.header.time_stamp & 1, 1);
+        if let PduBody::EntityState(body) = pdu.body {
+            assert_eq!(body.entity_id.entity_id, 2);
+        } else { panic!("Expected an EntityState PDU") }
+    }
+}
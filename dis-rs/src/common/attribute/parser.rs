@@ -1,5 +1,5 @@
 use nom::bytes::complete::take;
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::multi::count;
 use nom::number::complete::{be_u16, be_u32, be_u8};
 use crate::common::parser::{entity_id, pdu_type, protocol_version, simulation_address};
@@ -1,11 +1,11 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::attribute::model::{Attribute, AttributeRecord, AttributeRecordSet, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::constants::EIGHT_OCTETS;
 use crate::common::model::length_padded_to_num_bytes;
 
 impl SerializePdu for Attribute {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let sim_address_bytes = self.originating_simulation_address.serialize(buf);
         buf.put_u32(0u32);
         buf.put_u16(0u16);
@@ -24,7 +24,7 @@ impl SerializePdu for Attribute {
 }
 
 impl Serialize for AttributeRecordSet {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let entity_id_bytes = self.entity_id.serialize(buf);
         buf.put_u16(self.attribute_records.len() as u16);
         let records_bytes = self.attribute_records.iter()
@@ -35,7 +35,7 @@ impl Serialize for AttributeRecordSet {
 }
 
 impl Serialize for AttributeRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let padded_record_lengths = length_padded_to_num_bytes(
             BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS as usize + self.specific_fields.len(),
             EIGHT_OCTETS);
@@ -0,0 +1,251 @@
+use crate::common::model::Pdu;
+use crate::common::errors::DisError;
+use crate::common::TrySerialize;
+use crate::constants::EIGHT_OCTETS;
+use crate::VariableParameters;
+
+/// Default time, in seconds, that a PDU may wait in a bundle before the bundle is flushed.
+const DEFAULT_MAX_BUNDLE_DELAY_SECONDS: f64 = 0.01;
+
+/// Packs multiple PDUs into datagrams of at most the MTU in size, preserving the order of the PDUs.
+///
+/// Following IEEE 1278.1-2012, 5.3.3.3, each PDU in a bundle starts at an 8-octet boundary,
+/// counted from the start of the datagram. The zero octets padding the preceding PDU are not part of its PDU Length.
+/// A datagram containing a single PDU is identical to that PDU sent on its own.
+///
+/// A bundle is flushed when the next PDU does not fit in it, or when its oldest PDU has waited for the maximum delay.
+/// The latter is checked when adding PDUs and when polling, so `poll` must be called regularly.
+/// The flushed datagrams are returned to the user for sending.
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time or time since start).
+pub struct Bundler {
+    mtu: usize,
+    max_delay: f64,
+    buffer: Vec<u8>,
+    pdu_count: usize,
+    deadline: Option<f64>,
+}
+
+impl Default for Bundler {
+    fn default() -> Self {
+        Self::new(&VariableParameters::default())
+    }
+}
+
+impl Bundler {
+    /// Creates a new bundler, taking the MTU from the provided `VariableParameters` (SMALLEST_MTU_OCTETS).
+    pub fn new(parameters: &VariableParameters) -> Self {
+        Self {
+            mtu: parameters.SMALLEST_MTU_OCTETS as usize,
+            max_delay: DEFAULT_MAX_BUNDLE_DELAY_SECONDS,
+            buffer: Vec::new(),
+            pdu_count: 0,
+            deadline: None,
+        }
+    }
+
+    /// Sets the maximum size, in octets, of a datagram.
+    pub fn with_mtu(mut self, mtu: usize) -> Self {
+        self.mtu = mtu;
+        self
+    }
+
+    /// Sets the maximum time, in seconds, a PDU may wait in a bundle before the bundle is flushed.
+    pub fn with_max_delay(mut self, max_delay: f64) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Adds a PDU to the current bundle, returning the datagrams that are complete as a result.
+    ///
+    /// The current bundle is flushed first when the PDU does not fit in it. The bundle including the PDU is flushed
+    /// when the bundle is full, or when its maximum delay has passed at the given time.
+    ///
+    /// The PDU must be complete, including the PDU Length in the header (see `TrySerialize`).
+    /// A PDU that is larger than the MTU results in `DisError::ExceedsMtu`. On errors the current bundle is left unchanged.
+    pub fn add(&mut self, pdu: &Pdu, time: f64) -> Result<Vec<Vec<u8>>, DisError> {
+        let pdu_length = pdu.header.pdu_length as usize;
+        if pdu_length > self.mtu {
+            return Err(DisError::ExceedsMtu(pdu_length, self.mtu));
+        }
+
+        let mut pdu_bytes = vec![0u8; pdu_length];
+        pdu.try_serialize_to_slice(&mut pdu_bytes)?;
+
+        let mut datagrams = Vec::new();
+        if self.padded_length() + pdu_length > self.mtu {
+            datagrams.extend(self.flush());
+        }
+
+        self.buffer.resize(self.padded_length(), 0);
+        self.buffer.extend_from_slice(&pdu_bytes);
+        self.pdu_count += 1;
+        if self.deadline.is_none() {
+            self.deadline = Some(time + self.max_delay);
+        }
+
+        if self.padded_length() >= self.mtu || self.is_due(time) {
+            datagrams.extend(self.flush());
+        }
+        Ok(datagrams)
+    }
+
+    /// Flushes the current bundle when its maximum delay has passed at the given time.
+    pub fn poll(&mut self, time: f64) -> Option<Vec<u8>> {
+        if self.is_due(time) {
+            self.flush()
+        } else { None }
+    }
+
+    /// Flushes the current bundle regardless of its size and deadline. Returns `None` when the bundle is empty.
+    pub fn flush(&mut self) -> Option<Vec<u8>> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        self.pdu_count = 0;
+        self.deadline = None;
+        Some(std::mem::take(&mut self.buffer))
+    }
+
+    /// The time at which the current bundle is flushed by `poll`, or `None` when the bundle is empty.
+    pub fn deadline(&self) -> Option<f64> {
+        self.deadline
+    }
+
+    /// The number of octets in the current bundle.
+    pub fn pending_octets(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// The number of PDUs in the current bundle.
+    pub fn len(&self) -> usize {
+        self.pdu_count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pdu_count == 0
+    }
+
+    fn is_due(&self, time: f64) -> bool {
+        self.deadline.is_some_and(|deadline| time >= deadline)
+    }
+
+    /// The length of the current bundle including the padding to the 8-octet boundary of the next PDU.
+    fn padded_length(&self) -> usize {
+        self.buffer.len().next_multiple_of(EIGHT_OCTETS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::bundler::Bundler;
+    use crate::common::create_entity::model::CreateEntity;
+    use crate::common::errors::DisError;
+    use crate::common::model::{EntityId, Pdu, PduHeader};
+    use crate::common::other::model::Other;
+    use crate::common::{parse, parse_per_pdu};
+    use crate::enumerations::PduType;
+
+    fn create_entity(request_id: u32) -> Pdu {
+        let body = CreateEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_request_id(request_id)
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::CreateEntity), body, 0)
+    }
+
+    fn other(body_length: usize) -> Pdu {
+        let body = Other::new(vec![1; body_length]).into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::Other), body, 0)
+    }
+
+    #[test]
+    fn bundle_pads_pdus_to_eight_octets() {
+        let mut bundler = Bundler::default().with_mtu(1400);
+
+        assert!(bundler.add(&create_entity(1), 0.0).unwrap().is_empty());
+        assert!(bundler.add(&create_entity(2), 0.0).unwrap().is_empty());
+        assert_eq!(bundler.len(), 2);
+        // 28 octets, padded to 32, followed by 28 octets
+        assert_eq!(bundler.pending_octets(), 60);
+
+        let datagram = bundler.flush().unwrap();
+        assert!(bundler.is_empty());
+        assert_eq!(datagram.len(), 60);
+        assert_eq!(&datagram[28..32], &[0u8; 4]);
+
+        let pdus = parse(&datagram).unwrap();
+        assert_eq!(pdus, vec![create_entity(1), create_entity(2)]);
+        let offsets: Vec<usize> = parse_per_pdu(&datagram).iter().map(|result| result.offset).collect();
+        assert_eq!(offsets, vec![0, 32]);
+    }
+
+    #[test]
+    fn flush_when_next_pdu_does_not_fit() {
+        let mut bundler = Bundler::default().with_mtu(64);
+
+        assert!(bundler.add(&create_entity(1), 0.0).unwrap().is_empty());
+        let datagrams = bundler.add(&other(28), 0.0).unwrap();
+
+        assert_eq!(datagrams.len(), 1);
+        assert_eq!(datagrams[0].len(), 28);
+        assert_eq!(bundler.len(), 1);
+        assert_eq!(bundler.pending_octets(), 40);
+    }
+
+    #[test]
+    fn flush_when_bundle_is_full() {
+        let mut bundler = Bundler::default().with_mtu(64);
+
+        assert!(bundler.add(&other(20), 0.0).unwrap().is_empty());
+        let datagrams = bundler.add(&other(20), 0.0).unwrap();
+
+        assert_eq!(datagrams.len(), 1);
+        assert_eq!(datagrams[0].len(), 64);
+        assert!(bundler.is_empty());
+    }
+
+    #[test]
+    fn flush_on_deadline() {
+        let mut bundler = Bundler::default().with_max_delay(0.5);
+
+        assert!(bundler.add(&create_entity(1), 10.0).unwrap().is_empty());
+        assert!(bundler.add(&create_entity(2), 10.2).unwrap().is_empty());
+        assert_eq!(bundler.deadline(), Some(10.5));
+        assert!(bundler.poll(10.4).is_none());
+
+        let datagram = bundler.poll(10.5).unwrap();
+        assert_eq!(parse(&datagram).unwrap().len(), 2);
+        assert_eq!(bundler.deadline(), None);
+        assert!(bundler.poll(11.0).is_none());
+
+        assert!(bundler.add(&create_entity(3), 11.0).unwrap().is_empty());
+        let datagrams = bundler.add(&create_entity(4), 11.5).unwrap();
+        assert_eq!(datagrams.len(), 1);
+        assert_eq!(parse(&datagrams[0]).unwrap(), vec![create_entity(3), create_entity(4)]);
+    }
+
+    #[test]
+    fn pdu_larger_than_mtu() {
+        let mut bundler = Bundler::default().with_mtu(64);
+        assert!(bundler.add(&create_entity(1), 0.0).unwrap().is_empty());
+
+        assert_eq!(bundler.add(&other(60), 0.0), Err(DisError::ExceedsMtu(72, 64)));
+        assert_eq!(bundler.len(), 1);
+        assert_eq!(bundler.pending_octets(), 28);
+    }
+
+    #[test]
+    fn invalid_pdu_leaves_bundle_unchanged() {
+        let mut bundler = Bundler::default();
+        assert!(bundler.add(&create_entity(1), 0.0).unwrap().is_empty());
+
+        let mut pdu = create_entity(2);
+        pdu.header.pdu_length += 4;
+        assert!(bundler.add(&pdu, 0.0).is_err());
+        assert_eq!(bundler.len(), 1);
+        assert_eq!(bundler.pending_octets(), 28);
+    }
+}
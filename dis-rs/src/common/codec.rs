@@ -0,0 +1,105 @@
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+use crate::common::decoder::decode_pdu;
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::TrySerialize;
+
+/// A `tokio_util` codec that frames PDUs in a byte stream, e.g. a TCP connection or Unix socket,
+/// or in datagrams when used with `UdpFramed`.
+///
+/// Decoding uses the PDU Length field of the header to find the end of each PDU, and discards invalid data
+/// in the same way as `PduDecoder`. Encoding validates the PDU like `TrySerialize`.
+#[derive(Debug, Default)]
+pub struct DisCodec;
+
+impl DisCodec {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Decoder for DisCodec {
+    type Item = Pdu;
+    type Error = DisError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        decode_pdu(src).transpose()
+    }
+}
+
+impl Encoder<Pdu> for DisCodec {
+    type Error = DisError;
+
+    fn encode(&mut self, item: Pdu, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&item, dst)
+    }
+}
+
+impl Encoder<&Pdu> for DisCodec {
+    type Error = DisError;
+
+    fn encode(&mut self, item: &Pdu, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.reserve(item.header.pdu_length as usize);
+        item.try_serialize(dst)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+    use crate::common::codec::DisCodec;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::model::{EntityId, EntityType, Pdu, PduBody, PduHeader};
+    use crate::enumerations::{EntityKind, ForceId, PduType};
+
+    fn entity_state(entity_number: u16) -> Pdu {
+        let body = EntityState::new(EntityId::new(1, 1, entity_number), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityState), body, 0)
+    }
+
+    fn entity_number(pdu: &Pdu) -> u16 {
+        if let PduBody::EntityState(body) = &pdu.body {
+            body.entity_id.entity_id
+        } else { panic!("Expected an EntityState PDU") }
+    }
+
+    #[test]
+    fn decode_partial_frames() {
+        let mut codec = DisCodec::new();
+        let mut encoded = BytesMut::new();
+        codec.encode(entity_state(1), &mut encoded).unwrap();
+        codec.encode(entity_state(2), &mut encoded).unwrap();
+
+        let mut src = BytesMut::from(&encoded[..20]);
+        assert!(codec.decode(&mut src).unwrap().is_none());
+        src.extend_from_slice(&encoded[20..]);
+        assert_eq!(entity_number(&codec.decode(&mut src).unwrap().unwrap()), 1);
+        assert_eq!(entity_number(&codec.decode(&mut src).unwrap().unwrap()), 2);
+        assert!(codec.decode(&mut src).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn framed_over_duplex_stream() {
+        let (client, server) = tokio::io::duplex(64);
+        let mut sink = FramedWrite::new(client, DisCodec::new());
+        let mut stream = FramedRead::new(server, DisCodec::new());
+
+        let writer = tokio::spawn(async move {
+            for entity_number in 1..=3 {
+                sink.send(entity_state(entity_number)).await.unwrap();
+            }
+        });
+
+        for expected in 1..=3 {
+            let pdu = stream.next().await.unwrap().unwrap();
+            assert_eq!(entity_number(&pdu), expected);
+        }
+        writer.await.unwrap();
+    }
+}
@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::common::collision_elastic::model::CollisionElastic;
 
 impl SerializePdu for CollisionElastic {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let issuing_id_bytes = self.issuing_entity_id.serialize(buf);
         let colliding_id_bytes = self.colliding_entity_id.serialize(buf);
         let event_id_bytes = self.event_id.serialize(buf);
@@ -1,12 +1,13 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use crate::common::parser::{datum_specification, entity_id};
 use crate::common::comment::model::Comment;
 use crate::common::model::PduBody;
 
 pub fn comment_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
 
     let body = Comment::builder()
         .with_origination_id(originating_id)
@@ -0,0 +1,352 @@
+use crate::common::dead_reckoning::{add, location_to_vector, mat_mul, mat_vec, matrix_to_orientation, transpose, world_to_body_matrix, Matrix3, Vector3};
+use crate::common::model::{Location, Orientation};
+
+/// WGS-84 semi-major axis (equatorial radius), in meters
+pub const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+/// WGS-84 flattening
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+/// WGS-84 semi-minor axis (polar radius), in meters
+pub const WGS84_SEMI_MINOR_AXIS: f64 = WGS84_SEMI_MAJOR_AXIS * (1.0 - WGS84_FLATTENING);
+/// WGS-84 first eccentricity squared
+const WGS84_ECCENTRICITY_SQUARED: f64 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+/// WGS-84 second eccentricity squared
+const WGS84_SECOND_ECCENTRICITY_SQUARED: f64 = WGS84_ECCENTRICITY_SQUARED / (1.0 - WGS84_ECCENTRICITY_SQUARED);
+/// Number of iterations when converting geocentric to geodetic coordinates; sub-millimeter accurate for terrestrial heights
+const GEODETIC_ITERATIONS: usize = 3;
+
+/// A location in WGS-84 geodetic coordinates.
+/// Latitude and longitude are in degrees, altitude is the height above the ellipsoid in meters.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GeodeticLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+}
+
+impl GeodeticLocation {
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+}
+
+/// An orientation with respect to the local North-East-Down frame at a location.
+/// Heading, pitch and roll are in degrees.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LocalOrientation {
+    pub heading: f64,
+    pub pitch: f64,
+    pub roll: f64,
+}
+
+impl LocalOrientation {
+    pub fn new(heading: f64, pitch: f64, roll: f64) -> Self {
+        Self {
+            heading,
+            pitch,
+            roll,
+        }
+    }
+}
+
+/// Coordinates in a local East-North-Up frame, in meters.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Enu {
+    pub east: f64,
+    pub north: f64,
+    pub up: f64,
+}
+
+/// Coordinates in a local North-East-Down frame, in meters.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ned {
+    pub north: f64,
+    pub east: f64,
+    pub down: f64,
+}
+
+impl From<Enu> for Ned {
+    fn from(value: Enu) -> Self {
+        Self {
+            north: value.north,
+            east: value.east,
+            down: -value.up,
+        }
+    }
+}
+
+impl From<Ned> for Enu {
+    fn from(value: Ned) -> Self {
+        Self {
+            east: value.east,
+            north: value.north,
+            up: -value.down,
+        }
+    }
+}
+
+impl Location {
+    /// Converts this geocentric (ECEF) location to WGS-84 geodetic coordinates,
+    /// using Bowring's method.
+    pub fn to_geodetic(&self) -> GeodeticLocation {
+        let (x, y, z) = (self.x_coordinate, self.y_coordinate, self.z_coordinate);
+        let a = WGS84_SEMI_MAJOR_AXIS;
+        let b = WGS84_SEMI_MINOR_AXIS;
+        let p = (x * x + y * y).sqrt();
+
+        let longitude = y.atan2(x);
+        // parametric (reduced) latitude as the initial estimate
+        let mut beta = (z * a).atan2(p * b);
+        let mut latitude = 0.0;
+        for _ in 0..GEODETIC_ITERATIONS {
+            let (sin_beta, cos_beta) = beta.sin_cos();
+            latitude = (z + WGS84_SECOND_ECCENTRICITY_SQUARED * b * sin_beta.powi(3))
+                .atan2(p - WGS84_ECCENTRICITY_SQUARED * a * cos_beta.powi(3));
+            beta = ((1.0 - WGS84_FLATTENING) * latitude.tan()).atan();
+        }
+
+        let (sin_latitude, cos_latitude) = f64::sin_cos(latitude);
+        let altitude = p * cos_latitude + z * sin_latitude - a * a / prime_vertical_radius(sin_latitude);
+
+        GeodeticLocation::new(latitude.to_degrees(), longitude.to_degrees(), altitude)
+    }
+
+    /// Constructs a geocentric (ECEF) location from WGS-84 geodetic coordinates.
+    pub fn from_geodetic(geodetic: &GeodeticLocation) -> Self {
+        let (sin_latitude, cos_latitude) = geodetic.latitude.to_radians().sin_cos();
+        let (sin_longitude, cos_longitude) = geodetic.longitude.to_radians().sin_cos();
+        let n = prime_vertical_radius(sin_latitude);
+
+        Location::new(
+            (n + geodetic.altitude) * cos_latitude * cos_longitude,
+            (n + geodetic.altitude) * cos_latitude * sin_longitude,
+            (n * (1.0 - WGS84_ECCENTRICITY_SQUARED) + geodetic.altitude) * sin_latitude)
+    }
+}
+
+impl From<GeodeticLocation> for Location {
+    fn from(value: GeodeticLocation) -> Self {
+        Location::from_geodetic(&value)
+    }
+}
+
+impl From<Location> for GeodeticLocation {
+    fn from(value: Location) -> Self {
+        value.to_geodetic()
+    }
+}
+
+impl Orientation {
+    /// Converts this orientation (Euler angles with respect to the geocentric frame) to
+    /// heading, pitch and roll with respect to the local North-East-Down frame at `location`.
+    pub fn to_local(&self, location: &Location) -> LocalOrientation {
+        let ecef_to_ned = ecef_to_ned_matrix(&location.to_geodetic());
+        let ecef_to_body = world_to_body_matrix(self);
+        let ned_to_body = mat_mul(&ecef_to_body, &transpose(&ecef_to_ned));
+        let local = matrix_to_orientation(&ned_to_body);
+
+        LocalOrientation::new(
+            (local.psi as f64).to_degrees(),
+            (local.theta as f64).to_degrees(),
+            (local.phi as f64).to_degrees())
+    }
+
+    /// Constructs an orientation (Euler angles with respect to the geocentric frame) from
+    /// heading, pitch and roll with respect to the local North-East-Down frame at `location`.
+    pub fn from_local(local: &LocalOrientation, location: &Location) -> Self {
+        let ecef_to_ned = ecef_to_ned_matrix(&location.to_geodetic());
+        let ned_to_body = world_to_body_matrix(&Orientation::new(
+            local.heading.to_radians() as f32,
+            local.pitch.to_radians() as f32,
+            local.roll.to_radians() as f32));
+        let ecef_to_body = mat_mul(&ned_to_body, &ecef_to_ned);
+
+        matrix_to_orientation(&ecef_to_body)
+    }
+}
+
+/// A local tangent plane, with its origin at a geocentric location,
+/// to convert between geocentric locations and local ENU or NED coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LocalFrame {
+    origin: Location,
+    ecef_to_ned: Matrix3,
+}
+
+impl LocalFrame {
+    pub fn new(origin: Location) -> Self {
+        Self {
+            origin,
+            ecef_to_ned: ecef_to_ned_matrix(&origin.to_geodetic()),
+        }
+    }
+
+    pub fn from_geodetic(origin: &GeodeticLocation) -> Self {
+        Self {
+            origin: Location::from_geodetic(origin),
+            ecef_to_ned: ecef_to_ned_matrix(origin),
+        }
+    }
+
+    pub fn origin(&self) -> &Location {
+        &self.origin
+    }
+
+    pub fn to_ned(&self, location: &Location) -> Ned {
+        let offset = add(location_to_vector(location), location_to_vector(&self.origin).map(|c| -c));
+        let ned = mat_vec(&self.ecef_to_ned, offset);
+
+        Ned {
+            north: ned[0],
+            east: ned[1],
+            down: ned[2],
+        }
+    }
+
+    pub fn from_ned(&self, ned: &Ned) -> Location {
+        let offset = mat_vec(&transpose(&self.ecef_to_ned), [ned.north, ned.east, ned.down]);
+        let location = add(location_to_vector(&self.origin), offset);
+
+        Location::new(location[0], location[1], location[2])
+    }
+
+    pub fn to_enu(&self, location: &Location) -> Enu {
+        Enu::from(self.to_ned(location))
+    }
+
+    pub fn from_enu(&self, enu: &Enu) -> Location {
+        self.from_ned(&Ned::from(*enu))
+    }
+}
+
+/// The radius of curvature in the prime vertical, N
+fn prime_vertical_radius(sin_latitude: f64) -> f64 {
+    WGS84_SEMI_MAJOR_AXIS / (1.0 - WGS84_ECCENTRICITY_SQUARED * sin_latitude * sin_latitude).sqrt()
+}
+
+/// Rotation matrix from the geocentric frame to the local North-East-Down frame at the given location.
+/// The rows are the north, east and down unit vectors expressed in geocentric coordinates.
+fn ecef_to_ned_matrix(geodetic: &GeodeticLocation) -> Matrix3 {
+    let (sin_latitude, cos_latitude) = geodetic.latitude.to_radians().sin_cos();
+    let (sin_longitude, cos_longitude) = geodetic.longitude.to_radians().sin_cos();
+
+    let north: Vector3 = [-sin_latitude * cos_longitude, -sin_latitude * sin_longitude, cos_latitude];
+    let east: Vector3 = [-sin_longitude, cos_longitude, 0.0];
+    let down: Vector3 = [-cos_latitude * cos_longitude, -cos_latitude * sin_longitude, -sin_latitude];
+
+    [north, east, down]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::coordinates::{Enu, GeodeticLocation, LocalFrame, LocalOrientation, WGS84_SEMI_MAJOR_AXIS, WGS84_SEMI_MINOR_AXIS};
+    use crate::common::model::{Location, Orientation};
+
+    const METER_EPSILON: f64 = 1.0e-3;
+    const DEGREE_EPSILON: f64 = 1.0e-8;
+    const ANGLE_EPSILON: f64 = 1.0e-4;
+
+    fn assert_location(actual: &Location, x: f64, y: f64, z: f64) {
+        assert!((actual.x_coordinate - x).abs() < METER_EPSILON, "x: {} != {}", actual.x_coordinate, x);
+        assert!((actual.y_coordinate - y).abs() < METER_EPSILON, "y: {} != {}", actual.y_coordinate, y);
+        assert!((actual.z_coordinate - z).abs() < METER_EPSILON, "z: {} != {}", actual.z_coordinate, z);
+    }
+
+    fn assert_geodetic(actual: &GeodeticLocation, latitude: f64, longitude: f64, altitude: f64) {
+        assert!((actual.latitude - latitude).abs() < DEGREE_EPSILON, "latitude: {} != {}", actual.latitude, latitude);
+        assert!((actual.longitude - longitude).abs() < DEGREE_EPSILON, "longitude: {} != {}", actual.longitude, longitude);
+        assert!((actual.altitude - altitude).abs() < METER_EPSILON, "altitude: {} != {}", actual.altitude, altitude);
+    }
+
+    fn assert_local_orientation(actual: &LocalOrientation, heading: f64, pitch: f64, roll: f64) {
+        assert!((actual.heading - heading).abs() < ANGLE_EPSILON, "heading: {} != {}", actual.heading, heading);
+        assert!((actual.pitch - pitch).abs() < ANGLE_EPSILON, "pitch: {} != {}", actual.pitch, pitch);
+        assert!((actual.roll - roll).abs() < ANGLE_EPSILON, "roll: {} != {}", actual.roll, roll);
+    }
+
+    #[test]
+    fn geodetic_to_geocentric_reference_points() {
+        // Defining points of the WGS-84 ellipsoid
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(0.0, 0.0, 0.0)), WGS84_SEMI_MAJOR_AXIS, 0.0, 0.0);
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(0.0, 90.0, 0.0)), 0.0, WGS84_SEMI_MAJOR_AXIS, 0.0);
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(0.0, 180.0, 1000.0)), -WGS84_SEMI_MAJOR_AXIS - 1000.0, 0.0, 0.0);
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(90.0, 0.0, 0.0)), 0.0, 0.0, 6_356_752.314_245);
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(-90.0, 0.0, 0.0)), 0.0, 0.0, -WGS84_SEMI_MINOR_AXIS);
+        // 45 degrees North, 45 degrees East on the ellipsoid
+        assert_location(&Location::from_geodetic(&GeodeticLocation::new(45.0, 45.0, 0.0)),
+                        3_194_419.145_061, 3_194_419.145_061, 4_487_348.408_866);
+    }
+
+    #[test]
+    fn geocentric_to_geodetic_reference_points() {
+        assert_geodetic(&Location::new(WGS84_SEMI_MAJOR_AXIS, 0.0, 0.0).to_geodetic(), 0.0, 0.0, 0.0);
+        assert_geodetic(&Location::new(0.0, 0.0, 6_356_752.314_245 + 500.0).to_geodetic(), 90.0, 0.0, 500.0);
+        assert_geodetic(&Location::new(3_194_419.145_061, 3_194_419.145_061, 4_487_348.408_866).to_geodetic(), 45.0, 45.0, 0.0);
+    }
+
+    #[test]
+    fn geodetic_round_trip() {
+        let points = [
+            GeodeticLocation::new(52.0, 4.5, 12.0),
+            GeodeticLocation::new(-33.9, 151.2, 58.0),
+            GeodeticLocation::new(36.1, -115.2, 10_000.0),
+            GeodeticLocation::new(-89.9, -179.9, 2_835.0),
+            GeodeticLocation::new(0.001, 0.001, -50.0),
+        ];
+        for point in points {
+            let location = Location::from_geodetic(&point);
+            assert_geodetic(&location.to_geodetic(), point.latitude, point.longitude, point.altitude);
+        }
+    }
+
+    #[test]
+    fn local_frame_enu() {
+        let origin = GeodeticLocation::new(52.0, 4.5, 0.0);
+        let frame = LocalFrame::from_geodetic(&origin);
+
+        let up = Location::from_geodetic(&GeodeticLocation::new(52.0, 4.5, 100.0));
+        let enu = frame.to_enu(&up);
+        assert!(enu.east.abs() < METER_EPSILON);
+        assert!(enu.north.abs() < METER_EPSILON);
+        assert!((enu.up - 100.0).abs() < METER_EPSILON);
+
+        let north = Location::from_geodetic(&GeodeticLocation::new(52.001, 4.5, 0.0));
+        let ned = frame.to_ned(&north);
+        assert!(ned.north > 111.0 && ned.north < 112.0);
+        assert!(ned.east.abs() < METER_EPSILON);
+
+        let target = Enu { east: 250.0, north: -120.0, up: 30.0 };
+        let location = frame.from_enu(&target);
+        let back = frame.to_enu(&location);
+        assert!((back.east - target.east).abs() < METER_EPSILON);
+        assert!((back.north - target.north).abs() < METER_EPSILON);
+        assert!((back.up - target.up).abs() < METER_EPSILON);
+    }
+
+    #[test]
+    fn local_orientation_at_equator() {
+        // At latitude 0, longitude 0: north is ECEF +z, east is ECEF +y and down is ECEF -x.
+        // Heading east, level flight: body x-axis along ECEF +y and body z-axis along ECEF -x.
+        let location = Location::from_geodetic(&GeodeticLocation::new(0.0, 0.0, 0.0));
+        let orientation = Orientation::new(std::f32::consts::FRAC_PI_2, 0.0, -std::f32::consts::FRAC_PI_2);
+
+        assert_local_orientation(&orientation.to_local(&location), 90.0, 0.0, 0.0);
+
+        let converted = Orientation::from_local(&LocalOrientation::new(90.0, 0.0, 0.0), &location);
+        assert!((converted.psi - orientation.psi).abs() < 1.0e-5);
+        assert!((converted.theta - orientation.theta).abs() < 1.0e-5);
+        assert!((converted.phi - orientation.phi).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn local_orientation_round_trip() {
+        let location = Location::from_geodetic(&GeodeticLocation::new(52.0, 4.5, 1000.0));
+        let local = LocalOrientation::new(135.0, 10.0, -20.0);
+
+        let orientation = Orientation::from_local(&local, &location);
+        assert_local_orientation(&orientation.to_local(&location), 135.0, 10.0, -20.0);
+    }
+}
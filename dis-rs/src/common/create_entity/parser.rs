@@ -1,13 +1,14 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::parser::entity_id;
 use crate::common::create_entity::model::CreateEntity;
 use crate::common::model::PduBody;
 
 pub fn create_entity_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
 
     let body = CreateEntity::builder()
         .with_origination_id(originating_id)
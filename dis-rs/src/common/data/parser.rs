@@ -1,15 +1,16 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::parser::{datum_specification, entity_id};
 use crate::common::data::model::Data;
 use crate::common::model::PduBody;
 
 pub fn data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, _padding) = be_u32(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
 
     let body = Data::builder()
         .with_origination_id(originating_id)
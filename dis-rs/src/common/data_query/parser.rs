@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::multi::count;
 use nom::number::complete::be_u32;
 use crate::common::data_query::model::DataQuery;
@@ -7,16 +8,16 @@ use crate::common::model::PduBody;
 use crate::enumerations::VariableRecordType;
 
 pub fn data_query_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, request_id) = be_u32(input)?;
-    let (input, time_interval) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, time_interval) = context("Time Interval", be_u32)(input)?;
 
-    let (input, num_of_fixed_datums) = be_u32(input)?;
-    let (input, num_of_variable_datums) = be_u32(input)?;
-    let (input, fixed_datum_ids) = count(be_u32, num_of_fixed_datums as usize)(input)?;
+    let (input, num_of_fixed_datums) = context("Number of Fixed Datum Records", be_u32)(input)?;
+    let (input, num_of_variable_datums) = context("Number of Variable Datum Records", be_u32)(input)?;
+    let (input, fixed_datum_ids) = context("Fixed Datum IDs", count(be_u32, num_of_fixed_datums as usize))(input)?;
     let fixed_datum_ids = fixed_datum_ids.iter().map(|id| VariableRecordType::from(*id)).collect();
-    let (input, variable_datum_ids) = count(be_u32, num_of_variable_datums as usize)(input)?;
+    let (input, variable_datum_ids) = context("Variable Datum IDs", count(be_u32, num_of_variable_datums as usize))(input)?;
     let variable_datum_ids = variable_datum_ids.iter().map(|id| VariableRecordType::from(*id)).collect();
 
     let body = DataQuery::builder()
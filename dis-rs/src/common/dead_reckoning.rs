@@ -0,0 +1,555 @@
+use crate::common::entity_state::model::EntityState;
+use crate::common::model::{Location, Orientation, VectorF32};
+use crate::enumerations::DeadReckoningAlgorithm;
+
+/// Angular velocities (in rad/s) below this magnitude are treated as no rotation,
+/// to avoid dividing by (almost) zero in the rotation matrices.
+const MIN_ANGULAR_VELOCITY: f64 = 1.0e-9;
+
+pub(crate) type Vector3 = [f64; 3];
+pub(crate) type Matrix3 = [[f64; 3]; 3];
+
+const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// The extrapolated state of an entity, as calculated by a dead reckoning algorithm.
+///
+/// The `location` is in world coordinates (ECEF), the `orientation` holds the Euler angles (psi, theta, phi)
+/// of the entity with respect to the world coordinate system and the `linear_velocity` is in world coordinates.
+#[derive(Debug, Default, PartialEq)]
+pub struct DrResult {
+    pub location: Location,
+    pub orientation: Orientation,
+    pub linear_velocity: VectorF32,
+}
+
+/// Extrapolates the location and orientation of the entity described by `entity_state`,
+/// using the dead reckoning algorithm and parameters in its `DrParameters` (IEEE 1278.1-2012 Annex E).
+///
+/// `time_delta` is the time in seconds that has passed since the moment the `EntityState` is valid for
+/// (typically the time stamp of the PDU). Negative values extrapolate back in time.
+///
+/// The `Other` and unspecified algorithms are treated as a static entity.
+pub fn extrapolate(entity_state: &EntityState, time_delta: f64) -> DrResult {
+    let dr_params = &entity_state.dead_reckoning_parameters;
+    let location = location_to_vector(&entity_state.entity_location);
+    let orientation = &entity_state.entity_orientation;
+    let velocity = vector_f32_to_vector(&entity_state.entity_linear_velocity);
+    let acceleration = vector_f32_to_vector(&dr_params.linear_acceleration);
+    let angular_velocity = vector_f32_to_vector(&dr_params.angular_velocity);
+
+    let world_to_body = world_to_body_matrix(orientation);
+
+    match dr_params.algorithm {
+        DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity => {
+            let location = add(location, scale(velocity, time_delta));
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation => {
+            let location = add(location, scale(velocity, time_delta));
+            let world_to_body = rotate_orientation(world_to_body, angular_velocity, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_RVW_HighSpeedorManeuveringEntitywithExtrapolationofOrientation => {
+            let (location, velocity) = world_linear_motion(location, velocity, acceleration, time_delta);
+            let world_to_body = rotate_orientation(world_to_body, angular_velocity, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_FVW_HighSpeedorManeuveringEntity => {
+            let (location, velocity) = world_linear_motion(location, velocity, acceleration, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_FPB_SimilartoFPWexceptinBodyCoordinates => {
+            // fixed orientation: the body axes do not rotate during the extrapolation
+            let (location, velocity) = body_linear_motion(location, velocity, [0.0; 3], [0.0; 3], world_to_body, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_RPB_SimilartoRPWexceptinBodyCoordinates => {
+            let (location, velocity) = body_linear_motion(location, velocity, [0.0; 3], angular_velocity, world_to_body, time_delta);
+            let world_to_body = rotate_orientation(world_to_body, angular_velocity, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_RVB_SimilartoRVWexceptinBodyCoordinates => {
+            let (location, velocity) = body_linear_motion(location, velocity, acceleration, angular_velocity, world_to_body, time_delta);
+            let world_to_body = rotate_orientation(world_to_body, angular_velocity, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        DeadReckoningAlgorithm::DRM_FVB_SimilartoFVWexceptinBodyCoordinates => {
+            let (location, velocity) = body_linear_motion(location, velocity, acceleration, [0.0; 3], world_to_body, time_delta);
+            dr_result(location, world_to_body, velocity)
+        }
+        // DeadReckoningAlgorithm::StaticNonmovingEntity, Other and unspecified values
+        _ => {
+            dr_result(location, world_to_body, [0.0; 3])
+        }
+    }
+}
+
+impl EntityState {
+    /// Extrapolates the location and orientation of this entity `time_delta` seconds ahead,
+    /// based on its dead reckoning parameters. See [`extrapolate`].
+    pub fn dead_reckon(&self, time_delta: f64) -> DrResult {
+        extrapolate(self, time_delta)
+    }
+}
+
+/// Calculates the angle (in radians) of the rotation between two orientations.
+/// This is the smallest angle over which one orientation must be rotated to match the other.
+pub fn orientation_difference(first: &Orientation, second: &Orientation) -> f64 {
+    let first = world_to_body_matrix(first);
+    let second = world_to_body_matrix(second);
+    let relative = mat_mul(&first, &transpose(&second));
+    let trace = relative[0][0] + relative[1][1] + relative[2][2];
+
+    ((trace - 1.0) / 2.0).clamp(-1.0, 1.0).acos()
+}
+
+/// Calculates the distance (in meters) between two locations.
+pub fn location_difference(first: &Location, second: &Location) -> f64 {
+    let first = location_to_vector(first);
+    let second = location_to_vector(second);
+
+    magnitude(add(first, scale(second, -1.0)))
+}
+
+fn dr_result(location: Vector3, world_to_body: Matrix3, velocity: Vector3) -> DrResult {
+    DrResult {
+        location: Location::new(location[0], location[1], location[2]),
+        orientation: matrix_to_orientation(&world_to_body),
+        linear_velocity: VectorF32::new(velocity[0] as f32, velocity[1] as f32, velocity[2] as f32),
+    }
+}
+
+/// Second order linear motion in world coordinates (E.5, DRM FVW and RVW):
+/// P = P0 + V0 * dt + 1/2 * A0 * dt^2
+fn world_linear_motion(location: Vector3, velocity: Vector3, acceleration: Vector3, time_delta: f64) -> (Vector3, Vector3) {
+    let location = add(add(location, scale(velocity, time_delta)), scale(acceleration, 0.5 * time_delta * time_delta));
+    let velocity = add(velocity, scale(acceleration, time_delta));
+    (location, velocity)
+}
+
+/// Linear motion in body coordinates (E.6, DRM FPB, RPB, RVB, FVB):
+/// P = P0 + [R w->b]^-1 * ([R1] * Vb + [R2] * Ab)
+///
+/// The velocity in the EntityState is in world coordinates, and is transformed to body coordinates first.
+/// The acceleration and angular velocity are in body coordinates.
+fn body_linear_motion(location: Vector3, velocity: Vector3, acceleration: Vector3, angular_velocity: Vector3, world_to_body: Matrix3, time_delta: f64) -> (Vector3, Vector3) {
+    let body_velocity = mat_vec(&world_to_body, velocity);
+    let (r1, r2) = body_integration_matrices(angular_velocity, time_delta);
+    let body_displacement = add(mat_vec(&r1, body_velocity), mat_vec(&r2, acceleration));
+    let body_to_world = transpose(&world_to_body);
+    let location = add(location, mat_vec(&body_to_world, body_displacement));
+
+    let world_to_body_at_t = rotate_orientation(world_to_body, angular_velocity, time_delta);
+    let body_velocity_at_t = add(body_velocity, scale(acceleration, time_delta));
+    let velocity = mat_vec(&transpose(&world_to_body_at_t), body_velocity_at_t);
+    (location, velocity)
+}
+
+/// Calculates the rotation matrices [R1] and [R2] used to integrate the body velocity and body acceleration
+/// over the elapsed time, while the body axes rotate with the angular velocity (E.6).
+fn body_integration_matrices(angular_velocity: Vector3, time_delta: f64) -> (Matrix3, Matrix3) {
+    let omega = magnitude(angular_velocity);
+    if omega < MIN_ANGULAR_VELOCITY {
+        return (scale_matrix(&IDENTITY, time_delta), scale_matrix(&IDENTITY, 0.5 * time_delta * time_delta));
+    }
+
+    let omega_dt = omega * time_delta;
+    let (sin_wt, cos_wt) = omega_dt.sin_cos();
+    let outer = outer_product(angular_velocity);
+    let skew = skew_symmetric(angular_velocity);
+
+    let r1 = add_matrices(&add_matrices(
+        &scale_matrix(&outer, (omega_dt - sin_wt) / (omega.powi(3))),
+        &scale_matrix(&IDENTITY, sin_wt / omega)),
+        &scale_matrix(&skew, (1.0 - cos_wt) / omega.powi(2)));
+
+    let r2 = add_matrices(&add_matrices(
+        &scale_matrix(&outer, (0.5 * omega_dt * omega_dt - cos_wt - omega_dt * sin_wt + 1.0) / omega.powi(4)),
+        &scale_matrix(&IDENTITY, (cos_wt + omega_dt * sin_wt - 1.0) / omega.powi(2))),
+        &scale_matrix(&skew, (sin_wt - omega_dt * cos_wt) / omega.powi(3)));
+
+    (r1, r2)
+}
+
+/// Extrapolates the orientation, expressed as the world-to-body rotation matrix,
+/// by applying the dead reckoning rotation matrix [DR] (E.4):
+/// [R w->b](t) = [DR] * [R w->b](t0)
+fn rotate_orientation(world_to_body: Matrix3, angular_velocity: Vector3, time_delta: f64) -> Matrix3 {
+    let omega = magnitude(angular_velocity);
+    if omega < MIN_ANGULAR_VELOCITY {
+        return world_to_body;
+    }
+
+    let omega_dt = omega * time_delta;
+    let (sin_wt, cos_wt) = omega_dt.sin_cos();
+    let outer = outer_product(angular_velocity);
+    let skew = skew_symmetric(angular_velocity);
+
+    let dr = add_matrices(&add_matrices(
+        &scale_matrix(&outer, (1.0 - cos_wt) / omega.powi(2)),
+        &scale_matrix(&IDENTITY, cos_wt)),
+        &scale_matrix(&skew, -sin_wt / omega));
+
+    mat_mul(&dr, &world_to_body)
+}
+
+/// Constructs the rotation matrix from world to body coordinates for the given Euler angles (psi, theta, phi)
+pub(crate) fn world_to_body_matrix(orientation: &Orientation) -> Matrix3 {
+    let (sin_psi, cos_psi) = (orientation.psi as f64).sin_cos();
+    let (sin_theta, cos_theta) = (orientation.theta as f64).sin_cos();
+    let (sin_phi, cos_phi) = (orientation.phi as f64).sin_cos();
+
+    [
+        [cos_theta * cos_psi, cos_theta * sin_psi, -sin_theta],
+        [sin_phi * sin_theta * cos_psi - cos_phi * sin_psi, sin_phi * sin_theta * sin_psi + cos_phi * cos_psi, sin_phi * cos_theta],
+        [cos_phi * sin_theta * cos_psi + sin_phi * sin_psi, cos_phi * sin_theta * sin_psi - sin_phi * cos_psi, cos_phi * cos_theta],
+    ]
+}
+
+/// Extracts the Euler angles (psi, theta, phi) from a world-to-body rotation matrix
+pub(crate) fn matrix_to_orientation(world_to_body: &Matrix3) -> Orientation {
+    let theta = (-world_to_body[0][2]).clamp(-1.0, 1.0).asin();
+    let psi = world_to_body[0][1].atan2(world_to_body[0][0]);
+    let phi = world_to_body[1][2].atan2(world_to_body[2][2]);
+
+    Orientation::new(psi as f32, theta as f32, phi as f32)
+}
+
+pub(crate) fn location_to_vector(location: &Location) -> Vector3 {
+    [location.x_coordinate, location.y_coordinate, location.z_coordinate]
+}
+
+fn vector_f32_to_vector(vector: &VectorF32) -> Vector3 {
+    [vector.first_vector_component as f64, vector.second_vector_component as f64, vector.third_vector_component as f64]
+}
+
+pub(crate) fn add(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn scale(v: Vector3, factor: f64) -> Vector3 {
+    [v[0] * factor, v[1] * factor, v[2] * factor]
+}
+
+pub(crate) fn magnitude(v: Vector3) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+/// The outer product of a vector with itself, w * w^T
+fn outer_product(v: Vector3) -> Matrix3 {
+    [
+        [v[0] * v[0], v[0] * v[1], v[0] * v[2]],
+        [v[1] * v[0], v[1] * v[1], v[1] * v[2]],
+        [v[2] * v[0], v[2] * v[1], v[2] * v[2]],
+    ]
+}
+
+/// The skew symmetric (cross product) matrix of a vector
+fn skew_symmetric(v: Vector3) -> Matrix3 {
+    [
+        [0.0, -v[2], v[1]],
+        [v[2], 0.0, -v[0]],
+        [-v[1], v[0], 0.0],
+    ]
+}
+
+fn scale_matrix(m: &Matrix3, factor: f64) -> Matrix3 {
+    let mut result = *m;
+    result.iter_mut().flatten().for_each(|value| *value *= factor);
+    result
+}
+
+fn add_matrices(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut result = *a;
+    for (row, b_row) in result.iter_mut().zip(b.iter()) {
+        for (value, b_value) in row.iter_mut().zip(b_row.iter()) {
+            *value += b_value;
+        }
+    }
+    result
+}
+
+pub(crate) fn transpose(m: &Matrix3) -> Matrix3 {
+    [
+        [m[0][0], m[1][0], m[2][0]],
+        [m[0][1], m[1][1], m[2][1]],
+        [m[0][2], m[1][2], m[2][2]],
+    ]
+}
+
+pub(crate) fn mat_vec(m: &Matrix3, v: Vector3) -> Vector3 {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) fn mat_mul(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::common::dead_reckoning::{extrapolate, orientation_difference};
+    use crate::common::entity_state::model::{DrParameters, EntityState};
+    use crate::common::model::{EntityId, EntityType, Location, Orientation, VectorF32};
+    use crate::enumerations::{DeadReckoningAlgorithm, ForceId};
+
+    const LOCATION_EPSILON: f64 = 1.0e-4;
+    const ORIENTATION_EPSILON: f32 = 1.0e-5;
+
+    fn entity(algorithm: DeadReckoningAlgorithm, orientation: Orientation, velocity: VectorF32, acceleration: VectorF32, angular_velocity: VectorF32) -> EntityState {
+        EntityState::new(EntityId::new(1, 1, 1), ForceId::Friendly, EntityType::default())
+            .with_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_orientation(orientation)
+            .with_velocity(velocity)
+            .with_dead_reckoning_parameters(DrParameters::default()
+                .with_algorithm(algorithm)
+                .with_linear_acceleration(acceleration)
+                .with_angular_velocity(angular_velocity))
+    }
+
+    fn assert_location(actual: &Location, x: f64, y: f64, z: f64) {
+        assert!((actual.x_coordinate - x).abs() < LOCATION_EPSILON, "x: {} != {}", actual.x_coordinate, x);
+        assert!((actual.y_coordinate - y).abs() < LOCATION_EPSILON, "y: {} != {}", actual.y_coordinate, y);
+        assert!((actual.z_coordinate - z).abs() < LOCATION_EPSILON, "z: {} != {}", actual.z_coordinate, z);
+    }
+
+    fn assert_orientation(actual: &Orientation, psi: f32, theta: f32, phi: f32) {
+        assert!((actual.psi - psi).abs() < ORIENTATION_EPSILON, "psi: {} != {}", actual.psi, psi);
+        assert!((actual.theta - theta).abs() < ORIENTATION_EPSILON, "theta: {} != {}", actual.theta, theta);
+        assert!((actual.phi - phi).abs() < ORIENTATION_EPSILON, "phi: {} != {}", actual.phi, phi);
+    }
+
+    #[test]
+    fn static_entity() {
+        let entity = entity(DeadReckoningAlgorithm::StaticNonmovingEntity,
+                            Orientation::new(0.5, 0.2, 0.1),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(1.0, 0.0, 0.0),
+                            VectorF32::new(0.1, 0.0, 0.0));
+        let result = extrapolate(&entity, 10.0);
+
+        assert_location(&result.location, 1000.0, 2000.0, 3000.0);
+        assert_orientation(&result.orientation, 0.5, 0.2, 0.1);
+    }
+
+    #[test]
+    fn fpw() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+                            Orientation::new(0.5, 0.2, 0.1),
+                            VectorF32::new(12.5, -3.0, 0.75),
+                            VectorF32::new(1.0, 1.0, 1.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+
+        // E.5 DRM FPW, P = P0 + V0 * dt; acceleration and angular velocity are ignored
+        // x = 1000 + 12.5 * 1.6 = 1020.0, y = 2000 - 3.0 * 1.6 = 1995.2, z = 3000 + 0.75 * 1.6 = 3001.2
+        let result = extrapolate(&entity, 1.6);
+        assert_location(&result.location, 1020.0, 1995.2, 3001.2);
+        assert_orientation(&result.orientation, 0.5, 0.2, 0.1);
+        assert_eq!(result.linear_velocity, VectorF32::new(12.5, -3.0, 0.75));
+
+        // x = 1000 - 12.5 * 0.4 = 995.0, y = 2000 + 3.0 * 0.4 = 2001.2, z = 3000 - 0.75 * 0.4 = 2999.7
+        let result = extrapolate(&entity, -0.4);
+        assert_location(&result.location, 995.0, 2001.2, 2999.7);
+    }
+
+    #[test]
+    fn rpw() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(1.0, 1.0, 1.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+        let result = extrapolate(&entity, 5.0);
+
+        // yaw rate of 0.1 rad/s for 5 seconds
+        assert_location(&result.location, 1050.0, 2000.0, 3000.0);
+        assert_orientation(&result.orientation, 0.5, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rvw() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_RVW_HighSpeedorManeuveringEntitywithExtrapolationofOrientation,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(2.0, -4.0, 0.0),
+                            VectorF32::new(0.05, 0.0, 0.0));
+        let result = extrapolate(&entity, 4.0);
+
+        // P = P0 + V0 * dt + 1/2 * A0 * dt^2; roll rate of 0.05 rad/s for 4 seconds
+        assert_location(&result.location, 1056.0, 1968.0, 3000.0);
+        assert_orientation(&result.orientation, 0.0, 0.0, 0.2);
+        assert_eq!(result.linear_velocity, VectorF32::new(18.0, -16.0, 0.0));
+    }
+
+    #[test]
+    fn fvw() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_FVW_HighSpeedorManeuveringEntity,
+                            Orientation::new(1.0, 0.0, 0.0),
+                            VectorF32::new(10.0, -5.0, 2.0),
+                            VectorF32::new(0.5, 2.0, -1.0),
+                            VectorF32::new(0.05, 0.0, 0.0));
+        let result = extrapolate(&entity, 3.0);
+
+        // E.5 DRM FVW, P = P0 + V0 * dt + 1/2 * A0 * dt^2, with dt^2 = 9
+        // x = 1000 + 10 * 3 + 0.5 * 0.5 * 9 = 1032.25
+        // y = 2000 - 5 * 3 + 0.5 * 2.0 * 9 = 1994.0
+        // z = 3000 + 2 * 3 - 0.5 * 1.0 * 9 = 3001.5
+        assert_location(&result.location, 1032.25, 1994.0, 3001.5);
+        // V = V0 + A0 * dt = (10 + 1.5, -5 + 6, 2 - 3)
+        assert_eq!(result.linear_velocity, VectorF32::new(11.5, 1.0, -1.0));
+        // the orientation is fixed, the angular velocity is ignored
+        assert_orientation(&result.orientation, 1.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rpw_rotation_about_oblique_axis() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.3, 0.0, 0.4));
+        let result = extrapolate(&entity, PI);
+
+        // E.4, [DR] = (1 - cos wt) / |w|^2 * ww^T + cos wt * I - sin wt / |w| * [W]
+        // |w| = 0.5 and wt = PI / 2, so [DR] = 4 * ww^T - 2 * [W], with
+        // ww^T = [[0.09, 0, 0.12], [0, 0, 0], [0.12, 0, 0.16]] and [W] = [[0, -0.4, 0], [0.4, 0, -0.3], [0, 0.3, 0]]:
+        // [DR] = [[0.36, 0.8, 0.48], [-0.8, 0, 0.6], [0.48, -0.6, 0.64]]
+        // Starting from the identity, [R w->b](t) = [DR]; the Euler angles follow from its elements:
+        // theta = asin(-0.48), psi = atan2(0.8, 0.36), phi = atan2(0.6, 0.64)
+        assert_orientation(&result.orientation,
+                           0.8f64.atan2(0.36) as f32,
+                           (-0.48f64).asin() as f32,
+                           0.6f64.atan2(0.64) as f32);
+        assert_location(&result.location, 1000.0, 2000.0, 3000.0);
+    }
+
+    #[test]
+    fn fpb() {
+        // heading of 90 degrees; the body x-axis points along the world y-axis
+        let entity = entity(DeadReckoningAlgorithm::DRM_FPB_SimilartoFPWexceptinBodyCoordinates,
+                            Orientation::new(std::f32::consts::FRAC_PI_2, 0.0, 0.0),
+                            VectorF32::new(0.0, 10.0, 0.0),
+                            VectorF32::new(1.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+        let result = extrapolate(&entity, 3.0);
+
+        assert_location(&result.location, 1000.0, 2030.0, 3000.0);
+        assert_orientation(&result.orientation, std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rpb_half_circle() {
+        // Constant turn with a radius of v / w = 100 m; after half a circle the entity is displaced 2 * r sideways.
+        let entity = entity(DeadReckoningAlgorithm::DRM_RPB_SimilartoRPWexceptinBodyCoordinates,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+        let result = extrapolate(&entity, PI / (0.1f32 as f64));
+
+        assert_location(&result.location, 1000.0, 2200.0, 3000.0);
+        // a heading of 180 degrees can be expressed as either +PI or -PI
+        assert!((result.orientation.psi.abs() - std::f32::consts::PI).abs() < ORIENTATION_EPSILON);
+        assert!((result.linear_velocity.first_vector_component + 10.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn rpb_quarter_circle() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_RPB_SimilartoRPWexceptinBodyCoordinates,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+        let result = extrapolate(&entity, PI / (2.0 * 0.1f32 as f64));
+
+        assert_location(&result.location, 1100.0, 2100.0, 3000.0);
+        assert_orientation(&result.orientation, std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rvb_without_rotation() {
+        // Without angular velocity the body-axis algorithm reduces to second order linear motion.
+        let entity = entity(DeadReckoningAlgorithm::DRM_RVB_SimilartoRVWexceptinBodyCoordinates,
+                            Orientation::new(std::f32::consts::FRAC_PI_2, 0.0, 0.0),
+                            VectorF32::new(0.0, 10.0, 0.0),
+                            VectorF32::new(2.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0));
+        let result = extrapolate(&entity, 4.0);
+
+        assert_location(&result.location, 1000.0, 2056.0, 3000.0);
+        assert_orientation(&result.orientation, std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rvb_turning() {
+        // Constant turn with a tangential body acceleration; compare against numerical integration of the motion.
+        let speed = 10.0f64;
+        let tangential_acceleration = 1.0f64;
+        let yaw_rate = 0.2f64;
+        let time_delta = 3.0f64;
+        let entity = entity(DeadReckoningAlgorithm::DRM_RVB_SimilartoRVWexceptinBodyCoordinates,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(speed as f32, 0.0, 0.0),
+                            VectorF32::new(tangential_acceleration as f32, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, yaw_rate as f32));
+        let result = extrapolate(&entity, time_delta);
+
+        let steps = 100_000;
+        let dt = time_delta / steps as f64;
+        let (mut x, mut y) = (1000.0f64, 2000.0f64);
+        for step in 0..steps {
+            let t = (step as f64 + 0.5) * dt;
+            let v = speed + tangential_acceleration * t;
+            x += v * (yaw_rate * t).cos() * dt;
+            y += v * (yaw_rate * t).sin() * dt;
+        }
+
+        assert!((result.location.x_coordinate - x).abs() < 1.0e-4);
+        assert!((result.location.y_coordinate - y).abs() < 1.0e-4);
+        assert_orientation(&result.orientation, 0.6, 0.0, 0.0);
+    }
+
+    #[test]
+    fn fvb() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_FVB_SimilartoFVWexceptinBodyCoordinates,
+                            Orientation::new(0.0, 0.0, 0.0),
+                            VectorF32::new(10.0, 0.0, 0.0),
+                            VectorF32::new(2.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.1));
+        let result = extrapolate(&entity, 4.0);
+
+        assert_location(&result.location, 1056.0, 2000.0, 3000.0);
+        assert_orientation(&result.orientation, 0.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn orientation_difference_single_axis() {
+        let first = Orientation::new(0.3, 0.0, 0.0);
+        let second = Orientation::new(0.1, 0.0, 0.0);
+
+        assert!((orientation_difference(&first, &second) - 0.2).abs() < 1.0e-6);
+        assert!(orientation_difference(&first, &first).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn orientation_round_trip() {
+        let entity = entity(DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation,
+                            Orientation::new(-2.5, 0.7, -1.2),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0),
+                            VectorF32::new(0.0, 0.0, 0.0));
+        let result = extrapolate(&entity, 1.0);
+
+        assert_orientation(&result.orientation, -2.5, 0.7, -1.2);
+    }
+}
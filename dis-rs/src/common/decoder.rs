@@ -0,0 +1,226 @@
+use bytes::{Buf, BytesMut};
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::parser::{bundle_padding, parse_pdu};
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::fixed_parameters::MAX_PDU_SIZE_OCTETS;
+
+const PROTOCOL_VERSION_INDEX: usize = 0;
+const PDU_LENGTH_INDEX: usize = 8;
+const MIN_PROTOCOL_VERSION: u8 = 1;
+const MAX_PROTOCOL_VERSION: u8 = 7;
+
+/// Incremental decoder for streams of PDUs, such as received over TCP or read from a file.
+///
+/// Chunks of bytes are pushed into the decoder as they arrive. A `Pdu` is produced as soon as
+/// the number of bytes indicated by the PDU Length field of its header is available; partial PDUs
+/// stay buffered until the rest of the data is pushed.
+///
+/// When the buffered data does not start with a plausible PDU header (unknown protocol version,
+/// or a PDU length smaller than a header or larger than MAX_PDU_SIZE_OCTETS), the decoder discards
+/// bytes until it finds one, and reports the discarded bytes as a single error.
+/// A PDU with a plausible header but a malformed body is consumed and reported as an error,
+/// after which decoding continues with the next PDU.
+/// Up to seven zero octets before a PDU are skipped silently, as these pad PDUs in a bundle to an 8-octet boundary.
+#[derive(Debug, Default)]
+pub struct PduDecoder {
+    buffer: BytesMut,
+}
+
+impl PduDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a chunk of received bytes to the internal buffer.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Number of bytes that are buffered but not yet decoded.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Discards all buffered data.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Decodes the next PDU from the buffered data.
+    ///
+    /// Returns `None` when more data is needed to complete the next PDU,
+    /// `Some(Ok(pdu))` when a PDU is decoded, and `Some(Err(..))` when invalid data is discarded.
+    pub fn next_pdu(&mut self) -> Option<Result<Pdu, DisError>> {
+        decode_pdu(&mut self.buffer)
+    }
+
+    /// Pushes a chunk of received bytes and decodes all PDUs that are complete.
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<Result<Pdu, DisError>> {
+        self.push(chunk);
+        std::iter::from_fn(|| self.next_pdu()).collect()
+    }
+}
+
+/// Decodes the next PDU from the start of `buffer`, removing the consumed bytes from the buffer.
+/// See `PduDecoder::next_pdu` for the returned values.
+pub(crate) fn decode_pdu(buffer: &mut BytesMut) -> Option<Result<Pdu, DisError>> {
+    if let Ok((_, padding)) = bundle_padding(buffer) {
+        buffer.advance(padding.len());
+    }
+    let discarded = resynchronise(buffer);
+    if discarded > 0 {
+        return Some(Err(DisError::ParseError(
+            format!("Discarded {discarded} bytes of invalid data while searching for the next PDU header."))));
+    }
+
+    if buffer.len() < PDU_HEADER_LEN_BYTES as usize {
+        return None;
+    }
+    let pdu_length = pdu_length(buffer);
+    if buffer.len() < pdu_length {
+        return None;
+    }
+
+    let pdu_bytes = buffer.split_to(pdu_length);
+    Some(parse_pdu(&pdu_bytes))
+}
+
+/// Discards bytes from the start of the buffer until it starts with a plausible PDU header,
+/// or the buffer is exhausted. Returns the number of discarded bytes.
+fn resynchronise(buffer: &mut BytesMut) -> usize {
+    let mut discarded = 0;
+    while !buffer.is_empty() && !is_plausible_header(buffer) {
+        buffer.advance(1);
+        discarded += 1;
+    }
+    discarded
+}
+
+fn pdu_length(buffer: &[u8]) -> usize {
+    u16::from_be_bytes([buffer[PDU_LENGTH_INDEX], buffer[PDU_LENGTH_INDEX + 1]]) as usize
+}
+
+/// Checks whether the (possibly incomplete) header at the start of the buffer can be the start of a PDU.
+fn is_plausible_header(buffer: &[u8]) -> bool {
+    let version = buffer[PROTOCOL_VERSION_INDEX];
+    if !(MIN_PROTOCOL_VERSION..=MAX_PROTOCOL_VERSION).contains(&version) {
+        return false;
+    }
+    if buffer.len() < PDU_LENGTH_INDEX + 2 {
+        return true;
+    }
+    let pdu_length = pdu_length(buffer);
+    (PDU_HEADER_LEN_BYTES as usize..=MAX_PDU_SIZE_OCTETS as usize).contains(&pdu_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::decoder::PduDecoder;
+    use crate::common::model::{EntityId, EntityType, Pdu, PduBody, PduHeader};
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::Serialize;
+    use crate::enumerations::{EntityKind, ForceId, PduType};
+
+    fn entity_state_bytes(entity_number: u16) -> Vec<u8> {
+        let body = EntityState::new(EntityId::new(1, 1, entity_number), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::EntityState), body, 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    fn entity_number(pdu: &Pdu) -> u16 {
+        if let PduBody::EntityState(body) = &pdu.body {
+            body.entity_id.entity_id
+        } else { panic!("Expected an EntityState PDU") }
+    }
+
+    #[test]
+    fn decode_chunked_pdu() {
+        let bytes = entity_state_bytes(1);
+        let mut decoder = PduDecoder::new();
+
+        for chunk in bytes[..bytes.len() - 1].chunks(7) {
+            assert!(decoder.decode(chunk).is_empty());
+        }
+        let pdus = decoder.decode(&bytes[bytes.len() - 1..]);
+        assert_eq!(pdus.len(), 1);
+        assert_eq!(entity_number(pdus[0].as_ref().unwrap()), 1);
+        assert_eq!(decoder.buffered_len(), 0);
+    }
+
+    #[test]
+    fn decode_multiple_and_trailing_fragment() {
+        let mut bytes = entity_state_bytes(1);
+        bytes.extend(entity_state_bytes(2));
+        let third = entity_state_bytes(3);
+        bytes.extend_from_slice(&third[..20]);
+
+        let mut decoder = PduDecoder::new();
+        let pdus = decoder.decode(&bytes);
+        assert_eq!(pdus.len(), 2);
+        assert_eq!(decoder.buffered_len(), 20);
+
+        let pdus = decoder.decode(&third[20..]);
+        assert_eq!(pdus.len(), 1);
+        assert_eq!(entity_number(pdus[0].as_ref().unwrap()), 3);
+    }
+
+    #[test]
+    fn resynchronise_after_garbage() {
+        let mut bytes = vec![0xFF, 0x00, 0xAB];
+        bytes.extend(entity_state_bytes(1));
+
+        let mut decoder = PduDecoder::new();
+        let results = decoder.decode(&bytes);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(entity_number(results[1].as_ref().unwrap()), 1);
+    }
+
+    #[test]
+    fn continue_after_corrupt_pdu() {
+        // EntityState header stating a total length of 14 bytes, i.e., a 2 byte body
+        let mut bytes = vec![0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0xf4];
+        bytes.extend(entity_state_bytes(2));
+
+        let mut decoder = PduDecoder::new();
+        let results = decoder.decode(&bytes);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(entity_number(results[1].as_ref().unwrap()), 2);
+    }
+
+    #[test]
+    fn skip_bundle_padding() {
+        let mut bytes = entity_state_bytes(1);
+        bytes.extend([0u8; 4]);
+        bytes.extend(entity_state_bytes(2));
+        bytes.extend([0u8; 7]);
+        bytes.extend(entity_state_bytes(3));
+
+        let mut decoder = PduDecoder::new();
+        let numbers: Vec<u16> = decoder.decode(&bytes).iter()
+            .map(|result| entity_number(result.as_ref().unwrap()))
+            .collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(decoder.buffered_len(), 0);
+    }
+
+    #[test]
+    fn padding_longer_than_seven_octets_is_discarded() {
+        let mut bytes = entity_state_bytes(1);
+        bytes.extend([0u8; 8]);
+        bytes.extend(entity_state_bytes(2));
+
+        let mut decoder = PduDecoder::new();
+        let results = decoder.decode(&bytes);
+        assert_eq!(results.len(), 3);
+        assert!(results[1].is_err());
+        assert_eq!(entity_number(results[2].as_ref().unwrap()), 2);
+    }
+}
@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::designator::model::Designator;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 
 impl SerializePdu for Designator {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let designating_id_bytes = self.designating_entity_id.serialize(buf);
         buf.put_u16(self.system_name.into());
         let designated_id_bytes = self.designated_entity_id.serialize(buf);
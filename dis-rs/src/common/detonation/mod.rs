@@ -1,6 +1,7 @@
 pub mod parser;
 pub mod model;
 pub mod writer;
+pub mod view;
 pub mod builder;
 
 #[cfg(test)]
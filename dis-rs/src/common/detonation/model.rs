@@ -4,7 +4,7 @@ use crate::enumerations::{DetonationResult, PduType};
 use crate::common::model::{DescriptorRecord, EntityId, EventId, Location, PduBody, VectorF32, VariableParameter};
 use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
 
-const BASE_DETONATION_BODY_LENGTH : u16 = 104;
+pub(crate) const BASE_DETONATION_BODY_LENGTH : u16 = 92;
 
 #[derive(Debug, Default, PartialEq)]
 pub struct Detonation {
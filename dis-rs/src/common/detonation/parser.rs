@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::multi::count;
 use nom::number::complete::{be_u16, be_u8};
 use crate::enumerations::{DetonationResult, DetonationTypeIndicator};
@@ -11,19 +12,19 @@ pub fn detonation_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], P
     move |input: &[u8]| {
         let dti = header.pdu_status.unwrap_or_default()
             .detonation_type_indicator.unwrap_or(DetonationTypeIndicator::Munition);
-        let (input, source_entity_id) = entity_id(input)?;
-        let (input, target_entity_id) = entity_id(input)?;
-        let (input, exploding_entity_id) = entity_id(input)?;
-        let (input, event_it) = event_id(input)?;
-        let (input, velocity) = vec3_f32(input)?;
-        let (input, world_location) = location(input)?;
-        let (input, descriptor) = descriptor_record_dti(dti)(input)?;
-        let (input, entity_location) = vec3_f32(input)?;
-        let (input, detonation_result) = be_u8(input)?;
-        let (input, variable_parameters_no) = be_u8(input)?;
-        let (input, _padding) = be_u16(input)?;
+        let (input, source_entity_id) = context("Source Entity ID", entity_id)(input)?;
+        let (input, target_entity_id) = context("Target Entity ID", entity_id)(input)?;
+        let (input, exploding_entity_id) = context("Exploding Entity ID", entity_id)(input)?;
+        let (input, event_it) = context("Event ID", event_id)(input)?;
+        let (input, velocity) = context("Velocity", vec3_f32)(input)?;
+        let (input, world_location) = context("Location in World Coordinates", location)(input)?;
+        let (input, descriptor) = context("Descriptor", descriptor_record_dti(dti))(input)?;
+        let (input, entity_location) = context("Location in Entity Coordinates", vec3_f32)(input)?;
+        let (input, detonation_result) = context("Detonation Result", be_u8)(input)?;
+        let (input, variable_parameters_no) = context("Number of Variable Parameter Records", be_u8)(input)?;
+        let (input, _padding) = context("Padding", be_u16)(input)?;
         let (input, articulation_parameters) = if variable_parameters_no > 0 {
-            count(variable_parameter, variable_parameters_no as usize)(input)?
+            context("Variable Parameter Records", count(variable_parameter, variable_parameters_no as usize))(input)?
         } else { (input, vec![]) };
 
         let body = Detonation::builder()
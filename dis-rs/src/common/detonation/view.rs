@@ -0,0 +1,128 @@
+use crate::common::detonation::model::BASE_DETONATION_BODY_LENGTH;
+use crate::common::errors::DisError;
+use crate::common::model::{DescriptorRecord, EntityId, EventId, Location, VariableParameter, VectorF32};
+use crate::common::parser::{descriptor_record_dti, entity_id, event_id, location, variable_parameter, vec3_f32};
+use crate::common::view::{check_body_length, decode};
+use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
+use crate::enumerations::{DetonationResult, DetonationTypeIndicator};
+
+const SOURCE_ENTITY_ID_OFFSET: usize = 0;
+const TARGET_ENTITY_ID_OFFSET: usize = 6;
+const EXPLODING_ENTITY_ID_OFFSET: usize = 12;
+const EVENT_ID_OFFSET: usize = 18;
+const VELOCITY_OFFSET: usize = 24;
+const LOCATION_IN_WORLD_OFFSET: usize = 36;
+const DESCRIPTOR_OFFSET: usize = 60;
+const LOCATION_IN_ENTITY_OFFSET: usize = 76;
+const DETONATION_RESULT_OFFSET: usize = 88;
+const NUMBER_OF_VARIABLE_PARAMETERS_OFFSET: usize = 89;
+
+/// A borrowed view over the body of a Detonation PDU (5.4.4), decoding fields when they are accessed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DetonationView<'a> {
+    body: &'a [u8],
+    detonation_type_indicator: DetonationTypeIndicator,
+}
+
+impl<'a> DetonationView<'a> {
+    /// Creates a view over a Detonation body, checking that the body holds the fixed fields and all variable parameters.
+    /// The detonation type indicator (from the PDU Status of the header) determines the type of the descriptor.
+    pub fn new(body: &'a [u8], detonation_type_indicator: DetonationTypeIndicator) -> Result<Self, DisError> {
+        check_body_length(body, BASE_DETONATION_BODY_LENGTH as usize)?;
+        let number_of_variable_parameters = body[NUMBER_OF_VARIABLE_PARAMETERS_OFFSET] as usize;
+        check_body_length(body, BASE_DETONATION_BODY_LENGTH as usize
+            + number_of_variable_parameters * VARIABLE_PARAMETER_RECORD_LENGTH as usize)?;
+
+        Ok(Self { body, detonation_type_indicator })
+    }
+
+    pub fn source_entity_id(&self) -> EntityId {
+        decode(self.body, SOURCE_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn target_entity_id(&self) -> EntityId {
+        decode(self.body, TARGET_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn exploding_entity_id(&self) -> EntityId {
+        decode(self.body, EXPLODING_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn event_id(&self) -> EventId {
+        decode(self.body, EVENT_ID_OFFSET, event_id)
+    }
+
+    pub fn velocity(&self) -> VectorF32 {
+        decode(self.body, VELOCITY_OFFSET, vec3_f32)
+    }
+
+    pub fn location_in_world_coordinates(&self) -> Location {
+        decode(self.body, LOCATION_IN_WORLD_OFFSET, location)
+    }
+
+    pub fn descriptor(&self) -> DescriptorRecord {
+        decode(self.body, DESCRIPTOR_OFFSET, descriptor_record_dti(self.detonation_type_indicator))
+    }
+
+    pub fn location_in_entity_coordinates(&self) -> VectorF32 {
+        decode(self.body, LOCATION_IN_ENTITY_OFFSET, vec3_f32)
+    }
+
+    pub fn detonation_result(&self) -> DetonationResult {
+        DetonationResult::from(self.body[DETONATION_RESULT_OFFSET])
+    }
+
+    pub fn number_of_variable_parameters(&self) -> u8 {
+        self.body[NUMBER_OF_VARIABLE_PARAMETERS_OFFSET]
+    }
+
+    /// Iterates over the variable parameter records, decoding each record when it is reached.
+    pub fn variable_parameters(&self) -> impl Iterator<Item = VariableParameter> + 'a {
+        let end = BASE_DETONATION_BODY_LENGTH as usize
+            + self.number_of_variable_parameters() as usize * VARIABLE_PARAMETER_RECORD_LENGTH as usize;
+        self.body[BASE_DETONATION_BODY_LENGTH as usize..end]
+            .chunks(VARIABLE_PARAMETER_RECORD_LENGTH as usize)
+            .map(|record| decode(record, 0, variable_parameter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::detonation::model::Detonation;
+    use crate::common::model::{EntityId, Location, Pdu, PduBody, PduHeader, VariableParameter, VectorF32};
+    use crate::common::Serialize;
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{DetonationResult, PduType};
+
+    #[test]
+    fn detonation_view_matches_parsed() {
+        let detonation = Detonation::builder()
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_target_entity_id(EntityId::new(2, 2, 2))
+            .with_velocity(VectorF32::new(1.0, 2.0, 3.0))
+            .with_world_location(Location::new(10.0, 20.0, 30.0))
+            .with_entity_location(VectorF32::new(0.5, 0.5, 0.0))
+            .with_detonation_result(DetonationResult::EntityImpact)
+            .with_variable_parameters(vec![VariableParameter::Unspecified(0, [1; 15])])
+            .build();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::Detonation), detonation.into_pdu_body(), 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+
+        let pdu_view = PduView::new(&buf).unwrap();
+        let view = if let Ok(BodyView::Detonation(view)) = pdu_view.body_view() { view } else { panic!("Expected a Detonation view") };
+        let detonation = if let PduBody::Detonation(detonation) = pdu_view.to_pdu().unwrap().body { detonation } else { panic!() };
+
+        assert_eq!(view.source_entity_id(), detonation.source_entity_id);
+        assert_eq!(view.target_entity_id(), detonation.target_entity_id);
+        assert_eq!(view.exploding_entity_id(), detonation.exploding_entity_id);
+        assert_eq!(view.event_id(), detonation.event_id);
+        assert_eq!(view.velocity(), detonation.velocity);
+        assert_eq!(view.location_in_world_coordinates(), detonation.location_in_world_coordinates);
+        assert_eq!(view.descriptor(), detonation.descriptor);
+        assert_eq!(view.location_in_entity_coordinates(), detonation.location_in_entity_coordinates);
+        assert_eq!(view.detonation_result(), detonation.detonation_result);
+        assert_eq!(view.variable_parameters().collect::<Vec<VariableParameter>>(), detonation.variable_parameters);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::common::directed_energy_fire::model::{DirectedEnergyFire, DirectedEnergyFireFlags, DirectedEnergyRecord};
+use crate::common::model::{ClockTime, EntityId, EntityType, EventId, VectorF32};
+
+pub struct DirectedEnergyFireBuilder(DirectedEnergyFire);
+
+impl DirectedEnergyFireBuilder {
+    pub fn new() -> Self {
+        DirectedEnergyFireBuilder(DirectedEnergyFire::default())
+    }
+
+    pub fn new_from_body(body: DirectedEnergyFire) -> Self {
+        DirectedEnergyFireBuilder(body)
+    }
+
+    pub fn build(self) -> DirectedEnergyFire {
+        self.0
+    }
+
+    pub fn with_firing_entity_id(mut self, firing_entity_id: EntityId) -> Self {
+        self.0.firing_entity_id = firing_entity_id;
+        self
+    }
+
+    pub fn with_event_id(mut self, event_id: EventId) -> Self {
+        self.0.event_id = event_id;
+        self
+    }
+
+    pub fn with_munition_type(mut self, munition_type: EntityType) -> Self {
+        self.0.munition_type = munition_type;
+        self
+    }
+
+    pub fn with_shot_start_time(mut self, shot_start_time: ClockTime) -> Self {
+        self.0.shot_start_time = shot_start_time;
+        self
+    }
+
+    pub fn with_cumulative_shot_time(mut self, cumulative_shot_time: f32) -> Self {
+        self.0.cumulative_shot_time = cumulative_shot_time;
+        self
+    }
+
+    pub fn with_aperture_emitter_location(mut self, aperture_emitter_location: VectorF32) -> Self {
+        self.0.aperture_emitter_location = aperture_emitter_location;
+        self
+    }
+
+    pub fn with_aperture_diameter(mut self, aperture_diameter: f32) -> Self {
+        self.0.aperture_diameter = aperture_diameter;
+        self
+    }
+
+    pub fn with_wavelength(mut self, wavelength: f32) -> Self {
+        self.0.wavelength = wavelength;
+        self
+    }
+
+    pub fn with_peak_irradiance(mut self, peak_irradiance: f32) -> Self {
+        self.0.peak_irradiance = peak_irradiance;
+        self
+    }
+
+    pub fn with_pulse_repetition_frequency(mut self, pulse_repetition_frequency: f32) -> Self {
+        self.0.pulse_repetition_frequency = pulse_repetition_frequency;
+        self
+    }
+
+    pub fn with_pulse_width(mut self, pulse_width: f32) -> Self {
+        self.0.pulse_width = pulse_width;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: DirectedEnergyFireFlags) -> Self {
+        self.0.flags = flags;
+        self
+    }
+
+    pub fn with_pulse_shape(mut self, pulse_shape: u8) -> Self {
+        self.0.pulse_shape = pulse_shape;
+        self
+    }
+
+    pub fn with_de_records(mut self, de_records: Vec<DirectedEnergyRecord>) -> Self {
+        self.0.de_records = de_records;
+        self
+    }
+
+    pub fn with_de_record(mut self, de_record: DirectedEnergyRecord) -> Self {
+        self.0.de_records.push(de_record);
+        self
+    }
+}
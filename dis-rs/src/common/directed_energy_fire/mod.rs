@@ -0,0 +1,67 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+pub mod publisher;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::directed_energy_fire::model::{DirectedEnergyAreaAiming, DirectedEnergyFire, DirectedEnergyFireFlags, DirectedEnergyPrecisionAiming, DirectedEnergyRecord, DirectedEnergyTargetEnergyDeposition};
+    use crate::common::model::{ClockTime, DisTimeStamp, EntityId, EntityType, EventId, Location, Orientation, Pdu, PduHeader, SimulationAddress, VectorF32};
+    use crate::common::transmitter::model::BeamAntennaPattern;
+    use crate::enumerations::PduType;
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn directed_energy_fire_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::DirectedEnergyFire);
+
+        let body = DirectedEnergyFire::builder()
+            .with_firing_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 1), 10))
+            .with_munition_type(EntityType::default().with_category(5))
+            .with_shot_start_time(ClockTime::new(10, 20))
+            .with_cumulative_shot_time(1.5)
+            .with_aperture_emitter_location(VectorF32::new(1.0, 0.0, -0.5))
+            .with_aperture_diameter(0.3)
+            .with_wavelength(1.064e-6)
+            .with_peak_irradiance(1000.0)
+            .with_pulse_repetition_frequency(10.0)
+            .with_pulse_width(0.01)
+            .with_flags(DirectedEnergyFireFlags::new(true, true))
+            .with_pulse_shape(1)
+            .with_de_record(DirectedEnergyRecord::PrecisionAiming(DirectedEnergyPrecisionAiming::default()
+                .with_target_spot_location(Location::new(100.0, 200.0, 300.0))
+                .with_target_entity_id(EntityId::new(1, 1, 2))
+                .with_beam_spot_type(1)
+                .with_peak_irradiance(500.0)))
+            .with_de_record(DirectedEnergyRecord::AreaAiming(DirectedEnergyAreaAiming::default()
+                .with_beam_antenna_pattern(BeamAntennaPattern::new()
+                    .with_beam_direction(Orientation::new(0.5, 0.0, 0.0))
+                    .with_azimuth_beamwidth(0.1))
+                .with_target_energy_deposition(DirectedEnergyTargetEnergyDeposition::new(EntityId::new(1, 1, 3), 20.0))))
+            .with_de_record(DirectedEnergyRecord::Unspecified(5000, vec![1, 2, 3, 4, 5, 6, 7, 8]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
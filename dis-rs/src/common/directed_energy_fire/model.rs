@@ -0,0 +1,275 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::directed_energy_fire::builder::DirectedEnergyFireBuilder;
+use crate::common::model::{ClockTime, EntityId, EntityType, EventId, Location, PduBody, VectorF32};
+use crate::common::transmitter::model::{BEAM_ANTENNA_PATTERN_OCTETS, BeamAntennaPattern};
+use crate::constants::EIGHT_OCTETS;
+use crate::enumerations::PduType;
+
+const BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH: u16 = 76;
+pub(crate) const DE_RECORD_HEADER_LENGTH: u16 = 8;
+pub(crate) const DE_PRECISION_AIMING_RECORD_LENGTH: u16 = 96;
+pub(crate) const BASE_DE_AREA_AIMING_RECORD_LENGTH: u16 = 12;
+pub(crate) const DE_TARGET_ENERGY_DEPOSITION_LENGTH: u16 = 12;
+
+pub(crate) const DE_PRECISION_AIMING_RECORD_TYPE: u32 = 4000;
+pub(crate) const DE_AREA_AIMING_RECORD_TYPE: u32 = 4001;
+
+pub(crate) const WEAPON_ON_FLAG: u16 = 0x0001;
+pub(crate) const STATE_UPDATE_FLAG: u16 = 0x0002;
+
+/// 5.4.7 Directed Energy Fire PDU
+///
+/// The pulse shape is kept as the raw value of the DE Fire Pulse Shape enumeration (SISO-REF-010).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectedEnergyFire {
+    pub firing_entity_id: EntityId,
+    pub event_id: EventId,
+    pub munition_type: EntityType,
+    pub shot_start_time: ClockTime,
+    pub cumulative_shot_time: f32,
+    pub aperture_emitter_location: VectorF32,
+    pub aperture_diameter: f32,
+    pub wavelength: f32,
+    pub peak_irradiance: f32,
+    pub pulse_repetition_frequency: f32,
+    pub pulse_width: f32,
+    pub flags: DirectedEnergyFireFlags,
+    pub pulse_shape: u8,
+    pub de_records: Vec<DirectedEnergyRecord>,
+}
+
+impl DirectedEnergyFire {
+    pub fn builder() -> DirectedEnergyFireBuilder {
+        DirectedEnergyFireBuilder::new()
+    }
+
+    pub fn into_builder(self) -> DirectedEnergyFireBuilder {
+        DirectedEnergyFireBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::DirectedEnergyFire(self)
+    }
+}
+
+impl BodyInfo for DirectedEnergyFire {
+    fn body_length(&self) -> u16 {
+        BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH
+            + self.de_records.iter().map(|record| record.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::DirectedEnergyFire
+    }
+}
+
+impl Interaction for DirectedEnergyFire {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.firing_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.25 DE Fire Flags record
+///
+/// `state_update` indicates that the PDU is issued because of a state change, rather than the heartbeat timer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirectedEnergyFireFlags {
+    pub weapon_on: bool,
+    pub state_update: bool,
+}
+
+impl DirectedEnergyFireFlags {
+    pub fn new(weapon_on: bool, state_update: bool) -> Self {
+        Self {
+            weapon_on,
+            state_update,
+        }
+    }
+
+    pub fn with_weapon_on(mut self, weapon_on: bool) -> Self {
+        self.weapon_on = weapon_on;
+        self
+    }
+
+    pub fn with_state_update(mut self, state_update: bool) -> Self {
+        self.state_update = state_update;
+        self
+    }
+}
+
+impl From<u16> for DirectedEnergyFireFlags {
+    fn from(value: u16) -> Self {
+        Self {
+            weapon_on: value & WEAPON_ON_FLAG != 0,
+            state_update: value & STATE_UPDATE_FLAG != 0,
+        }
+    }
+}
+
+impl From<&DirectedEnergyFireFlags> for u16 {
+    fn from(value: &DirectedEnergyFireFlags) -> Self {
+        let weapon_on = if value.weapon_on { WEAPON_ON_FLAG } else { 0 };
+        let state_update = if value.state_update { STATE_UPDATE_FLAG } else { 0 };
+        weapon_on | state_update
+    }
+}
+
+/// The DE records of a Directed Energy Fire PDU.
+/// Records of other types than DE Precision Aiming and DE Area Aiming are kept as the record type and the raw record data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DirectedEnergyRecord {
+    PrecisionAiming(DirectedEnergyPrecisionAiming),
+    AreaAiming(DirectedEnergyAreaAiming),
+    Unspecified(u32, Vec<u8>),
+}
+
+impl DirectedEnergyRecord {
+    pub fn record_type(&self) -> u32 {
+        match self {
+            DirectedEnergyRecord::PrecisionAiming(_) => { DE_PRECISION_AIMING_RECORD_TYPE }
+            DirectedEnergyRecord::AreaAiming(_) => { DE_AREA_AIMING_RECORD_TYPE }
+            DirectedEnergyRecord::Unspecified(record_type, _) => { *record_type }
+        }
+    }
+
+    /// The length of the record in octets, including the record type and length fields and any padding.
+    pub fn record_length(&self) -> u16 {
+        match self {
+            DirectedEnergyRecord::PrecisionAiming(_) => { DE_PRECISION_AIMING_RECORD_LENGTH }
+            DirectedEnergyRecord::AreaAiming(record) => { record.record_length() }
+            DirectedEnergyRecord::Unspecified(_, data) => { DE_RECORD_HEADER_LENGTH + data.len() as u16 }
+        }
+    }
+}
+
+/// 6.2.20.3 DE Precision Aiming record
+///
+/// The beam spot type is kept as the raw value of the DE Precision Aiming-Beam Spot Type enumeration (SISO-REF-010).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectedEnergyPrecisionAiming {
+    pub target_spot_location: Location,
+    pub target_spot_entity_location: VectorF32,
+    pub target_spot_velocity: VectorF32,
+    pub target_spot_acceleration: VectorF32,
+    pub target_entity_id: EntityId,
+    pub target_component_id: u8,
+    pub beam_spot_type: u8,
+    pub beam_spot_cross_section_semi_major_axis: f32,
+    pub beam_spot_cross_section_semi_minor_axis: f32,
+    pub beam_spot_cross_section_orientation_angle: f32,
+    pub peak_irradiance: f32,
+}
+
+impl DirectedEnergyPrecisionAiming {
+    pub fn with_target_spot_location(mut self, target_spot_location: Location) -> Self {
+        self.target_spot_location = target_spot_location;
+        self
+    }
+
+    pub fn with_target_spot_entity_location(mut self, target_spot_entity_location: VectorF32) -> Self {
+        self.target_spot_entity_location = target_spot_entity_location;
+        self
+    }
+
+    pub fn with_target_spot_velocity(mut self, target_spot_velocity: VectorF32) -> Self {
+        self.target_spot_velocity = target_spot_velocity;
+        self
+    }
+
+    pub fn with_target_spot_acceleration(mut self, target_spot_acceleration: VectorF32) -> Self {
+        self.target_spot_acceleration = target_spot_acceleration;
+        self
+    }
+
+    pub fn with_target_entity_id(mut self, target_entity_id: EntityId) -> Self {
+        self.target_entity_id = target_entity_id;
+        self
+    }
+
+    pub fn with_target_component_id(mut self, target_component_id: u8) -> Self {
+        self.target_component_id = target_component_id;
+        self
+    }
+
+    pub fn with_beam_spot_type(mut self, beam_spot_type: u8) -> Self {
+        self.beam_spot_type = beam_spot_type;
+        self
+    }
+
+    pub fn with_beam_spot_cross_section_semi_major_axis(mut self, semi_major_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_major_axis = semi_major_axis;
+        self
+    }
+
+    pub fn with_beam_spot_cross_section_semi_minor_axis(mut self, semi_minor_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_minor_axis = semi_minor_axis;
+        self
+    }
+
+    pub fn with_beam_spot_cross_section_orientation_angle(mut self, orientation_angle: f32) -> Self {
+        self.beam_spot_cross_section_orientation_angle = orientation_angle;
+        self
+    }
+
+    pub fn with_peak_irradiance(mut self, peak_irradiance: f32) -> Self {
+        self.peak_irradiance = peak_irradiance;
+        self
+    }
+}
+
+/// 6.2.20.2 DE Area Aiming record
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectedEnergyAreaAiming {
+    pub beam_antenna_patterns: Vec<BeamAntennaPattern>,
+    pub target_energy_depositions: Vec<DirectedEnergyTargetEnergyDeposition>,
+}
+
+impl DirectedEnergyAreaAiming {
+    pub fn with_beam_antenna_pattern(mut self, pattern: BeamAntennaPattern) -> Self {
+        self.beam_antenna_patterns.push(pattern);
+        self
+    }
+
+    pub fn with_beam_antenna_patterns(mut self, patterns: Vec<BeamAntennaPattern>) -> Self {
+        self.beam_antenna_patterns = patterns;
+        self
+    }
+
+    pub fn with_target_energy_deposition(mut self, deposition: DirectedEnergyTargetEnergyDeposition) -> Self {
+        self.target_energy_depositions.push(deposition);
+        self
+    }
+
+    pub fn with_target_energy_depositions(mut self, depositions: Vec<DirectedEnergyTargetEnergyDeposition>) -> Self {
+        self.target_energy_depositions = depositions;
+        self
+    }
+
+    /// The length of the record in octets, padded to a multiple of 8 octets.
+    pub fn record_length(&self) -> u16 {
+        let unpadded_length = BASE_DE_AREA_AIMING_RECORD_LENGTH
+            + BEAM_ANTENNA_PATTERN_OCTETS * self.beam_antenna_patterns.len() as u16
+            + DE_TARGET_ENERGY_DEPOSITION_LENGTH * self.target_energy_depositions.len() as u16;
+        unpadded_length.div_ceil(EIGHT_OCTETS as u16) * EIGHT_OCTETS as u16
+    }
+}
+
+/// 6.2.20.4 DE Target Energy Deposition record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DirectedEnergyTargetEnergyDeposition {
+    pub target_entity_id: EntityId,
+    pub peak_irradiance: f32,
+}
+
+impl DirectedEnergyTargetEnergyDeposition {
+    pub fn new(target_entity_id: EntityId, peak_irradiance: f32) -> Self {
+        Self {
+            target_entity_id,
+            peak_irradiance,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+use crate::common::directed_energy_fire::model::{DE_AREA_AIMING_RECORD_TYPE, DE_PRECISION_AIMING_RECORD_TYPE, DE_RECORD_HEADER_LENGTH, DirectedEnergyAreaAiming, DirectedEnergyFire, DirectedEnergyFireFlags, DirectedEnergyPrecisionAiming, DirectedEnergyRecord, DirectedEnergyTargetEnergyDeposition};
+use crate::common::model::PduBody;
+use crate::common::parser::{clock_time, entity_id, entity_type, event_id, location, vec3_f32};
+use crate::common::transmitter::parser::beam_antenna_pattern;
+
+pub fn directed_energy_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_entity_id) = context("Firing Entity ID", entity_id)(input)?;
+    let (input, event_id) = context("Event ID", event_id)(input)?;
+    let (input, munition_type) = context("Munition Type", entity_type)(input)?;
+    let (input, shot_start_time) = context("Shot Start Time", clock_time)(input)?;
+    let (input, cumulative_shot_time) = context("Cumulative Shot Time", be_f32)(input)?;
+    let (input, aperture_emitter_location) = context("Aperture Emitter Location", vec3_f32)(input)?;
+    let (input, aperture_diameter) = context("Aperture Diameter", be_f32)(input)?;
+    let (input, wavelength) = context("Wavelength", be_f32)(input)?;
+    let (input, peak_irradiance) = context("Peak Irradiance", be_f32)(input)?;
+    let (input, pulse_repetition_frequency) = context("Pulse Repetition Frequency", be_f32)(input)?;
+    let (input, pulse_width) = context("Pulse Width", be_f32)(input)?;
+    let (input, flags) = context("Flags", be_u16)(input)?;
+    let (input, pulse_shape) = context("Pulse Shape", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, number_of_de_records) = context("Number of DE Records", be_u16)(input)?;
+    let (input, de_records) = context("DE Records", count(de_record, number_of_de_records as usize))(input)?;
+
+    let body = DirectedEnergyFire::builder()
+        .with_firing_entity_id(firing_entity_id)
+        .with_event_id(event_id)
+        .with_munition_type(munition_type)
+        .with_shot_start_time(shot_start_time)
+        .with_cumulative_shot_time(cumulative_shot_time)
+        .with_aperture_emitter_location(aperture_emitter_location)
+        .with_aperture_diameter(aperture_diameter)
+        .with_wavelength(wavelength)
+        .with_peak_irradiance(peak_irradiance)
+        .with_pulse_repetition_frequency(pulse_repetition_frequency)
+        .with_pulse_width(pulse_width)
+        .with_flags(DirectedEnergyFireFlags::from(flags))
+        .with_pulse_shape(pulse_shape)
+        .with_de_records(de_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn de_record(input: &[u8]) -> IResult<&[u8], DirectedEnergyRecord> {
+    let (input, record_type) = be_u32(input)?;
+    let (input, record_length) = be_u16(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, record_data) = take(record_length.saturating_sub(DE_RECORD_HEADER_LENGTH))(input)?;
+
+    let record = match record_type {
+        DE_PRECISION_AIMING_RECORD_TYPE => {
+            let (_, record) = de_precision_aiming(record_data)?;
+            DirectedEnergyRecord::PrecisionAiming(record)
+        }
+        DE_AREA_AIMING_RECORD_TYPE => {
+            let (_, record) = de_area_aiming(record_data)?;
+            DirectedEnergyRecord::AreaAiming(record)
+        }
+        _ => { DirectedEnergyRecord::Unspecified(record_type, record_data.to_vec()) }
+    };
+
+    Ok((input, record))
+}
+
+fn de_precision_aiming(input: &[u8]) -> IResult<&[u8], DirectedEnergyPrecisionAiming> {
+    let (input, target_spot_location) = location(input)?;
+    let (input, target_spot_entity_location) = vec3_f32(input)?;
+    let (input, target_spot_velocity) = vec3_f32(input)?;
+    let (input, target_spot_acceleration) = vec3_f32(input)?;
+    let (input, target_entity_id) = entity_id(input)?;
+    let (input, target_component_id) = be_u8(input)?;
+    let (input, beam_spot_type) = be_u8(input)?;
+    let (input, semi_major_axis) = be_f32(input)?;
+    let (input, semi_minor_axis) = be_f32(input)?;
+    let (input, orientation_angle) = be_f32(input)?;
+    let (input, peak_irradiance) = be_f32(input)?;
+    let (input, _padding) = be_u32(input)?;
+
+    Ok((input, DirectedEnergyPrecisionAiming::default()
+        .with_target_spot_location(target_spot_location)
+        .with_target_spot_entity_location(target_spot_entity_location)
+        .with_target_spot_velocity(target_spot_velocity)
+        .with_target_spot_acceleration(target_spot_acceleration)
+        .with_target_entity_id(target_entity_id)
+        .with_target_component_id(target_component_id)
+        .with_beam_spot_type(beam_spot_type)
+        .with_beam_spot_cross_section_semi_major_axis(semi_major_axis)
+        .with_beam_spot_cross_section_semi_minor_axis(semi_minor_axis)
+        .with_beam_spot_cross_section_orientation_angle(orientation_angle)
+        .with_peak_irradiance(peak_irradiance)))
+}
+
+fn de_area_aiming(input: &[u8]) -> IResult<&[u8], DirectedEnergyAreaAiming> {
+    let (input, number_of_beam_antenna_patterns) = be_u16(input)?;
+    let (input, number_of_target_energy_depositions) = be_u16(input)?;
+    let (input, beam_antenna_patterns) = count(beam_antenna_pattern, number_of_beam_antenna_patterns as usize)(input)?;
+    let (input, target_energy_depositions) = count(de_target_energy_deposition, number_of_target_energy_depositions as usize)(input)?;
+
+    Ok((input, DirectedEnergyAreaAiming::default()
+        .with_beam_antenna_patterns(beam_antenna_patterns)
+        .with_target_energy_depositions(target_energy_depositions)))
+}
+
+fn de_target_energy_deposition(input: &[u8]) -> IResult<&[u8], DirectedEnergyTargetEnergyDeposition> {
+    let (input, target_entity_id) = entity_id(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, peak_irradiance) = be_f32(input)?;
+
+    Ok((input, DirectedEnergyTargetEnergyDeposition::new(target_entity_id, peak_irradiance)))
+}
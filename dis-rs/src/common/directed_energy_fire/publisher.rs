@@ -0,0 +1,277 @@
+use crate::common::dead_reckoning::{location_difference, orientation_difference};
+use crate::common::directed_energy_fire::model::{DirectedEnergyFire, DirectedEnergyRecord};
+use crate::common::publisher::PublishedState;
+use crate::VariableParameters;
+
+/// The reason why a new Directed Energy Fire PDU must be issued for a local DE weapon.
+/// All reasons except `Heartbeat` are state updates, for which the state update flag of the PDU is set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DirectedEnergyFireIssueReason {
+    /// No Directed Energy Fire PDU was issued yet for the weapon
+    Initial,
+    /// The target spot of a precision aiming record moved more than DE_PRECISION_AIMING_THRSH
+    PrecisionAimingThreshold,
+    /// The beam direction of an area aiming record turned more than DE_AREA_AIMING_THRSH
+    AreaAimingThreshold,
+    /// A peak irradiance changed more than DE_ENERGY_THRSH percent of the issued value
+    EnergyThreshold,
+    /// The weapon was switched on or off, or its beam or targets changed
+    StateChange,
+    /// HBT_PDU_DE_FIRE elapsed while the weapon is firing
+    Heartbeat,
+}
+
+/// Decides when a local directed energy weapon must issue a new Directed Energy Fire PDU (IEEE 1278.1-2012, 5.4.7).
+///
+/// Unlike entities, a DE weapon is only updated by heartbeat while it fires (weapon on).
+/// Aiming is compared per DE record: the target spot location for precision aiming, and the beam direction for
+/// area aiming. The energy threshold applies to the peak irradiance of the weapon, of the precision aiming records,
+/// and of the target energy depositions of the area aiming records.
+/// Records are compared pairwise by position; adding, removing or reordering records is a state change.
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time).
+pub struct DirectedEnergyFirePublisher {
+    directed_energy_fire: PublishedState<DirectedEnergyFire>,
+    precision_aiming_threshold: f64,
+    area_aiming_threshold: f64,
+    energy_threshold: f64,
+    heartbeat: f64,
+}
+
+impl DirectedEnergyFirePublisher {
+    /// Creates a new publisher for the local DE weapon with the given initial (true) state.
+    /// The DE thresholds and HBT_PDU_DE_FIRE are taken from the provided `VariableParameters`.
+    pub fn new(directed_energy_fire: DirectedEnergyFire, parameters: &VariableParameters) -> Self {
+        Self {
+            directed_energy_fire: PublishedState::new(directed_energy_fire),
+            precision_aiming_threshold: parameters.DE_PRECISION_AIMING_THRSH as f64,
+            area_aiming_threshold: (parameters.DE_AREA_AIMING_THRSH as f64).to_radians(),
+            energy_threshold: parameters.DE_ENERGY_THRSH as f64,
+            heartbeat: parameters.HBT_PDU_DE_FIRE as f64,
+        }
+    }
+
+    /// Sets the distance, in meters, a precision aiming target spot may move before an update is issued.
+    pub fn with_precision_aiming_threshold(mut self, threshold: f64) -> Self {
+        self.precision_aiming_threshold = threshold;
+        self
+    }
+
+    /// Sets the angle, in degrees, an area aiming beam may turn before an update is issued.
+    pub fn with_area_aiming_threshold(mut self, threshold: f64) -> Self {
+        self.area_aiming_threshold = threshold.to_radians();
+        self
+    }
+
+    /// Sets the change in peak irradiance, in percent of the issued value, that triggers an update.
+    pub fn with_energy_threshold(mut self, threshold: f64) -> Self {
+        self.energy_threshold = threshold;
+        self
+    }
+
+    /// Sets the interval, in seconds, between Directed Energy Fire PDUs of a firing weapon.
+    pub fn with_heartbeat(mut self, heartbeat: f64) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// The true state of the local DE weapon.
+    pub fn directed_energy_fire(&self) -> &DirectedEnergyFire {
+        self.directed_energy_fire.current()
+    }
+
+    /// Mutable access to the true state of the local DE weapon, e.g., to move the target spot.
+    pub fn directed_energy_fire_mut(&mut self) -> &mut DirectedEnergyFire {
+        self.directed_energy_fire.current_mut()
+    }
+
+    /// Replaces the true state of the local DE weapon.
+    pub fn update(&mut self, directed_energy_fire: DirectedEnergyFire) {
+        self.directed_energy_fire.update(directed_energy_fire);
+    }
+
+    /// The DirectedEnergyFire that was last issued, if any.
+    pub fn last_issued(&self) -> Option<&DirectedEnergyFire> {
+        self.directed_energy_fire.last_issued()
+    }
+
+    /// Checks whether a new Directed Energy Fire PDU must be issued at `time`, and if so, for what reason.
+    pub fn check(&self, time: f64) -> Option<DirectedEnergyFireIssueReason> {
+        let issued = if let Some(issued) = self.directed_energy_fire.issued() {
+            issued
+        } else {
+            return Some(DirectedEnergyFireIssueReason::Initial)
+        };
+        let issued_fire = &issued.state;
+        let current_fire = self.directed_energy_fire.current();
+
+        if has_state_changed(issued_fire, current_fire) {
+            return Some(DirectedEnergyFireIssueReason::StateChange);
+        }
+
+        let record_pairs = || issued_fire.de_records.iter().zip(current_fire.de_records.iter());
+        let precision_aiming_moved = record_pairs().any(|pair| match pair {
+            (DirectedEnergyRecord::PrecisionAiming(issued), DirectedEnergyRecord::PrecisionAiming(current)) => {
+                location_difference(&issued.target_spot_location, &current.target_spot_location) > self.precision_aiming_threshold
+            }
+            _ => { false }
+        });
+        if precision_aiming_moved {
+            return Some(DirectedEnergyFireIssueReason::PrecisionAimingThreshold);
+        }
+
+        let area_aiming_moved = record_pairs().any(|pair| match pair {
+            (DirectedEnergyRecord::AreaAiming(issued), DirectedEnergyRecord::AreaAiming(current)) => {
+                issued.beam_antenna_patterns.iter().zip(current.beam_antenna_patterns.iter())
+                    .any(|(issued, current)| orientation_difference(&issued.beam_direction, &current.beam_direction) > self.area_aiming_threshold)
+            }
+            _ => { false }
+        });
+        if area_aiming_moved {
+            return Some(DirectedEnergyFireIssueReason::AreaAimingThreshold);
+        }
+
+        let energy_changed = self.exceeds_energy_threshold(issued_fire.peak_irradiance, current_fire.peak_irradiance)
+            || record_pairs().any(|pair| match pair {
+            (DirectedEnergyRecord::PrecisionAiming(issued), DirectedEnergyRecord::PrecisionAiming(current)) => {
+                self.exceeds_energy_threshold(issued.peak_irradiance, current.peak_irradiance)
+            }
+            (DirectedEnergyRecord::AreaAiming(issued), DirectedEnergyRecord::AreaAiming(current)) => {
+                issued.target_energy_depositions.iter().zip(current.target_energy_depositions.iter())
+                    .any(|(issued, current)| self.exceeds_energy_threshold(issued.peak_irradiance, current.peak_irradiance))
+            }
+            _ => { false }
+        });
+        if energy_changed {
+            return Some(DirectedEnergyFireIssueReason::EnergyThreshold);
+        }
+
+        if current_fire.flags.weapon_on && issued.heartbeat_elapsed(time, self.heartbeat) {
+            Some(DirectedEnergyFireIssueReason::Heartbeat)
+        } else { None }
+    }
+
+    /// Records that the current true state is issued at `time`, and returns it to be sent in a Directed Energy Fire PDU.
+    /// The state update flag is sent as set by the user; `poll` sets it according to the reason for issuing.
+    pub fn issue(&mut self, time: f64) -> DirectedEnergyFire {
+        self.directed_energy_fire.issue(time)
+    }
+
+    /// Issues a Directed Energy Fire PDU at `time` when `check` returns a reason, with the state update flag
+    /// cleared for heartbeats and set otherwise.
+    pub fn poll(&mut self, time: f64) -> Option<(DirectedEnergyFire, DirectedEnergyFireIssueReason)> {
+        self.check(time)
+            .map(|reason| {
+                self.directed_energy_fire.current_mut().flags.state_update = reason != DirectedEnergyFireIssueReason::Heartbeat;
+                (self.issue(time), reason)
+            })
+    }
+
+    /// Checks whether a peak irradiance changed more than the energy threshold, relative to the issued value.
+    fn exceeds_energy_threshold(&self, issued: f32, current: f32) -> bool {
+        let difference = (current as f64 - issued as f64).abs();
+        difference > (issued as f64).abs() * self.energy_threshold / 100.0
+    }
+}
+
+/// Checks whether the parts of the state that are not covered by a threshold differ between the two DirectedEnergyFires.
+fn has_state_changed(issued: &DirectedEnergyFire, current: &DirectedEnergyFire) -> bool {
+    issued.flags.weapon_on != current.flags.weapon_on
+        || issued.munition_type != current.munition_type
+        || issued.wavelength != current.wavelength
+        || issued.pulse_repetition_frequency != current.pulse_repetition_frequency
+        || issued.pulse_width != current.pulse_width
+        || issued.pulse_shape != current.pulse_shape
+        || issued.de_records.len() != current.de_records.len()
+        || issued.de_records.iter().zip(current.de_records.iter())
+            .any(|pair| match pair {
+                (DirectedEnergyRecord::PrecisionAiming(issued), DirectedEnergyRecord::PrecisionAiming(current)) => {
+                    issued.target_entity_id != current.target_entity_id
+                        || issued.target_component_id != current.target_component_id
+                        || issued.beam_spot_type != current.beam_spot_type
+                }
+                (DirectedEnergyRecord::AreaAiming(issued), DirectedEnergyRecord::AreaAiming(current)) => {
+                    issued.beam_antenna_patterns.len() != current.beam_antenna_patterns.len()
+                        || issued.target_energy_depositions.len() != current.target_energy_depositions.len()
+                        || issued.target_energy_depositions.iter().zip(current.target_energy_depositions.iter())
+                            .any(|(issued, current)| issued.target_entity_id != current.target_entity_id)
+                }
+                (issued, current) => { issued != current }
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::directed_energy_fire::model::{DirectedEnergyFire, DirectedEnergyFireFlags, DirectedEnergyPrecisionAiming, DirectedEnergyRecord};
+    use crate::common::directed_energy_fire::publisher::{DirectedEnergyFireIssueReason, DirectedEnergyFirePublisher};
+    use crate::common::model::{EntityId, EventId, Location, SimulationAddress};
+    use crate::VariableParameters;
+
+    fn laser() -> DirectedEnergyFire {
+        DirectedEnergyFire::builder()
+            .with_firing_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 1), 1))
+            .with_wavelength(1.064e-6)
+            .with_peak_irradiance(1000.0)
+            .with_flags(DirectedEnergyFireFlags::new(true, false))
+            .with_de_record(DirectedEnergyRecord::PrecisionAiming(DirectedEnergyPrecisionAiming::default()
+                .with_target_spot_location(Location::new(100.0, 0.0, 0.0))
+                .with_target_entity_id(EntityId::new(1, 1, 2))
+                .with_peak_irradiance(500.0)))
+            .build()
+    }
+
+    fn target_spot_mut(publisher: &mut DirectedEnergyFirePublisher) -> &mut DirectedEnergyPrecisionAiming {
+        match publisher.directed_energy_fire_mut().de_records.first_mut() {
+            Some(DirectedEnergyRecord::PrecisionAiming(record)) => { record }
+            _ => { panic!("expected a precision aiming record") }
+        }
+    }
+
+    #[test]
+    fn initial_issue() {
+        let mut publisher = DirectedEnergyFirePublisher::new(laser(), &VariableParameters::default());
+
+        let (issued, reason) = publisher.poll(0.0).unwrap();
+        assert_eq!(reason, DirectedEnergyFireIssueReason::Initial);
+        assert!(issued.flags.state_update);
+        assert_eq!(publisher.check(0.1), None);
+    }
+
+    #[test]
+    fn precision_aiming_threshold() {
+        let mut publisher = DirectedEnergyFirePublisher::new(laser(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        target_spot_mut(&mut publisher).target_spot_location = Location::new(100.4, 0.0, 0.0);
+        assert_eq!(publisher.check(0.1), None);
+        target_spot_mut(&mut publisher).target_spot_location = Location::new(100.6, 0.0, 0.0);
+        assert_eq!(publisher.check(0.1), Some(DirectedEnergyFireIssueReason::PrecisionAimingThreshold));
+    }
+
+    #[test]
+    fn energy_threshold() {
+        let mut publisher = DirectedEnergyFirePublisher::new(laser(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        target_spot_mut(&mut publisher).peak_irradiance = 504.0;
+        assert_eq!(publisher.check(0.1), None);
+        target_spot_mut(&mut publisher).peak_irradiance = 506.0;
+        assert_eq!(publisher.check(0.1), Some(DirectedEnergyFireIssueReason::EnergyThreshold));
+    }
+
+    #[test]
+    fn heartbeat_only_while_weapon_on() {
+        let mut publisher = DirectedEnergyFirePublisher::new(laser(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        assert_eq!(publisher.check(0.4), None);
+        let (issued, reason) = publisher.poll(0.5).unwrap();
+        assert_eq!(reason, DirectedEnergyFireIssueReason::Heartbeat);
+        assert!(!issued.flags.state_update);
+
+        publisher.directed_energy_fire_mut().flags.weapon_on = false;
+        assert_eq!(publisher.poll(0.6).map(|(_, reason)| reason), Some(DirectedEnergyFireIssueReason::StateChange));
+        assert_eq!(publisher.check(10.0), None);
+    }
+}
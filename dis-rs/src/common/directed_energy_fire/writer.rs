@@ -0,0 +1,96 @@
+use bytes::BufMut;
+use crate::common::directed_energy_fire::model::{BASE_DE_AREA_AIMING_RECORD_LENGTH, DE_PRECISION_AIMING_RECORD_LENGTH, DE_RECORD_HEADER_LENGTH, DE_TARGET_ENERGY_DEPOSITION_LENGTH, DirectedEnergyAreaAiming, DirectedEnergyFire, DirectedEnergyPrecisionAiming, DirectedEnergyRecord, DirectedEnergyTargetEnergyDeposition};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for DirectedEnergyFire {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let firing_entity_id_bytes = self.firing_entity_id.serialize(buf);
+        let event_id_bytes = self.event_id.serialize(buf);
+        let munition_type_bytes = self.munition_type.serialize(buf);
+        let shot_start_time_bytes = self.shot_start_time.serialize(buf);
+        buf.put_f32(self.cumulative_shot_time);
+        let aperture_emitter_location_bytes = self.aperture_emitter_location.serialize(buf);
+        buf.put_f32(self.aperture_diameter);
+        buf.put_f32(self.wavelength);
+        buf.put_f32(self.peak_irradiance);
+        buf.put_f32(self.pulse_repetition_frequency);
+        buf.put_f32(self.pulse_width);
+        buf.put_u16((&self.flags).into());
+        buf.put_u8(self.pulse_shape);
+        buf.put_u8(0u8);
+        buf.put_u32(0u32);
+        buf.put_u16(0u16);
+        buf.put_u16(self.de_records.len() as u16);
+        let de_records_bytes = self.de_records.iter()
+            .map(|record| record.serialize(buf))
+            .sum::<u16>();
+
+        firing_entity_id_bytes + event_id_bytes + munition_type_bytes + shot_start_time_bytes + 4
+            + aperture_emitter_location_bytes + 20 + 2 + 2 + 6 + 2 + de_records_bytes
+    }
+}
+
+impl Serialize for DirectedEnergyRecord {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(self.record_type());
+        buf.put_u16(self.record_length());
+        buf.put_u16(0u16);
+        let record_bytes = match self {
+            DirectedEnergyRecord::PrecisionAiming(record) => { record.serialize(buf) }
+            DirectedEnergyRecord::AreaAiming(record) => { record.serialize(buf) }
+            DirectedEnergyRecord::Unspecified(_, data) => {
+                buf.put_slice(data);
+                data.len() as u16
+            }
+        };
+
+        DE_RECORD_HEADER_LENGTH + record_bytes
+    }
+}
+
+impl Serialize for DirectedEnergyPrecisionAiming {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        self.target_spot_location.serialize(buf);
+        self.target_spot_entity_location.serialize(buf);
+        self.target_spot_velocity.serialize(buf);
+        self.target_spot_acceleration.serialize(buf);
+        self.target_entity_id.serialize(buf);
+        buf.put_u8(self.target_component_id);
+        buf.put_u8(self.beam_spot_type);
+        buf.put_f32(self.beam_spot_cross_section_semi_major_axis);
+        buf.put_f32(self.beam_spot_cross_section_semi_minor_axis);
+        buf.put_f32(self.beam_spot_cross_section_orientation_angle);
+        buf.put_f32(self.peak_irradiance);
+        buf.put_u32(0u32);
+
+        DE_PRECISION_AIMING_RECORD_LENGTH - DE_RECORD_HEADER_LENGTH
+    }
+}
+
+impl Serialize for DirectedEnergyAreaAiming {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.beam_antenna_patterns.len() as u16);
+        buf.put_u16(self.target_energy_depositions.len() as u16);
+        let patterns_bytes = self.beam_antenna_patterns.iter()
+            .map(|pattern| pattern.serialize(buf))
+            .sum::<u16>();
+        let depositions_bytes = self.target_energy_depositions.iter()
+            .map(|deposition| deposition.serialize(buf))
+            .sum::<u16>();
+        let unpadded_length = BASE_DE_AREA_AIMING_RECORD_LENGTH + patterns_bytes + depositions_bytes;
+        let padding_bytes = self.record_length() - unpadded_length;
+        buf.put_bytes(0u8, padding_bytes as usize);
+
+        self.record_length() - DE_RECORD_HEADER_LENGTH
+    }
+}
+
+impl Serialize for DirectedEnergyTargetEnergyDeposition {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        self.target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_f32(self.peak_irradiance);
+
+        DE_TARGET_ENERGY_DEPOSITION_LENGTH
+    }
+}
@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::electromagnetic_emission::model::{Beam, ElectromagneticEmission, EmitterSystem, FundamentalParameterData, JammingTechnique, TrackJam};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 
 impl SerializePdu for ElectromagneticEmission {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let entity_bytes = self.emitting_entity_id.serialize(buf);
         let event_bytes = self.event_id.serialize(buf);
         buf.put_u8(self.state_update_indicator.into());
@@ -18,7 +18,7 @@ impl SerializePdu for ElectromagneticEmission {
 }
 
 impl Serialize for EmitterSystem {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let system_length_in_words = self.system_data_length_bytes() / 4;
         buf.put_u8(system_length_in_words as u8);
         buf.put_u8(self.beams.len() as u8);
@@ -37,7 +37,7 @@ impl Serialize for EmitterSystem {
 }
 
 impl Serialize for Beam {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let beam_length_in_words = self.beam_data_length_bytes() / 4;
         buf.put_u8(beam_length_in_words as u8);
         buf.put_u8(self.number);
@@ -59,7 +59,7 @@ impl Serialize for Beam {
 }
 
 impl Serialize for FundamentalParameterData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f32(self.frequency);
         buf.put_f32(self.frequency_range);
         buf.put_f32(self.effective_power);
@@ -71,7 +71,7 @@ impl Serialize for FundamentalParameterData {
 }
 
 impl Serialize for JammingTechnique {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.kind);
         buf.put_u8(self.category);
         buf.put_u8(self.subcategory);
@@ -82,7 +82,7 @@ impl Serialize for JammingTechnique {
 }
 
 impl Serialize for TrackJam {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let entity_bytes = self.entity_id.serialize(buf);
         buf.put_u8(self.emitter);
         buf.put_u8(self.beam);
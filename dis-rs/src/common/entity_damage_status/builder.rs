@@ -0,0 +1,33 @@
+use crate::common::entity_damage_status::model::{DirectedEnergyDamage, EntityDamageStatus};
+use crate::common::model::EntityId;
+
+pub struct EntityDamageStatusBuilder(EntityDamageStatus);
+
+impl EntityDamageStatusBuilder {
+    pub fn new() -> Self {
+        EntityDamageStatusBuilder(EntityDamageStatus::default())
+    }
+
+    pub fn new_from_body(body: EntityDamageStatus) -> Self {
+        EntityDamageStatusBuilder(body)
+    }
+
+    pub fn build(self) -> EntityDamageStatus {
+        self.0
+    }
+
+    pub fn with_damaged_entity_id(mut self, damaged_entity_id: EntityId) -> Self {
+        self.0.damaged_entity_id = damaged_entity_id;
+        self
+    }
+
+    pub fn with_damage_descriptions(mut self, damage_descriptions: Vec<DirectedEnergyDamage>) -> Self {
+        self.0.damage_descriptions = damage_descriptions;
+        self
+    }
+
+    pub fn with_damage_description(mut self, damage_description: DirectedEnergyDamage) -> Self {
+        self.0.damage_descriptions.push(damage_description);
+        self
+    }
+}
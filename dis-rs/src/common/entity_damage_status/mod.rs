@@ -0,0 +1,52 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+pub mod publisher;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::entity_damage_status::model::{DirectedEnergyDamage, EntityDamageStatus};
+    use crate::common::model::{DisTimeStamp, EntityId, EventId, Pdu, PduHeader, SimulationAddress, VectorF32};
+    use crate::enumerations::PduType;
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn entity_damage_status_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::EntityDamageStatus);
+
+        let body = EntityDamageStatus::builder()
+            .with_damaged_entity_id(EntityId::new(1, 1, 2))
+            .with_damage_description(DirectedEnergyDamage::default()
+                .with_damage_location(VectorF32::new(1.0, 0.5, -0.2))
+                .with_damage_diameter(0.1)
+                .with_temperature(150.0)
+                .with_component_identification(3)
+                .with_component_damage_status(2)
+                .with_component_visual_damage_status(1)
+                .with_component_visual_smoke_color(2)
+                .with_fire_event_id(EventId::new(SimulationAddress::new(1, 1), 10)))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
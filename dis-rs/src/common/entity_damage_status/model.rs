@@ -0,0 +1,108 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::entity_damage_status::builder::EntityDamageStatusBuilder;
+use crate::common::model::{EntityId, EventId, PduBody, VectorF32};
+use crate::enumerations::PduType;
+
+const BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH: u16 = 12;
+pub(crate) const DE_DAMAGE_DESCRIPTION_RECORD_LENGTH: u16 = 40;
+pub(crate) const DE_DAMAGE_DESCRIPTION_RECORD_TYPE: u32 = 4500;
+
+/// 5.4.8 Entity Damage Status PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EntityDamageStatus {
+    pub damaged_entity_id: EntityId,
+    pub damage_descriptions: Vec<DirectedEnergyDamage>,
+}
+
+impl EntityDamageStatus {
+    pub fn builder() -> EntityDamageStatusBuilder {
+        EntityDamageStatusBuilder::new()
+    }
+
+    pub fn into_builder(self) -> EntityDamageStatusBuilder {
+        EntityDamageStatusBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::EntityDamageStatus(self)
+    }
+}
+
+impl BodyInfo for EntityDamageStatus {
+    fn body_length(&self) -> u16 {
+        BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH
+            + DE_DAMAGE_DESCRIPTION_RECORD_LENGTH * self.damage_descriptions.len() as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EntityDamageStatus
+    }
+}
+
+impl Interaction for EntityDamageStatus {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.damaged_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.20.1 DE Damage Description record
+///
+/// The component identification, damage status, visual damage status and visual smoke color are kept as the raw values
+/// of their respective enumerations (SISO-REF-010). A temperature of -273.15 °C indicates that the temperature is not known.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DirectedEnergyDamage {
+    pub damage_location: VectorF32,
+    pub damage_diameter: f32,
+    pub temperature: f32,
+    pub component_identification: u8,
+    pub component_damage_status: u8,
+    pub component_visual_damage_status: u8,
+    pub component_visual_smoke_color: u8,
+    pub fire_event_id: EventId,
+}
+
+impl DirectedEnergyDamage {
+    pub fn with_damage_location(mut self, damage_location: VectorF32) -> Self {
+        self.damage_location = damage_location;
+        self
+    }
+
+    pub fn with_damage_diameter(mut self, damage_diameter: f32) -> Self {
+        self.damage_diameter = damage_diameter;
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn with_component_identification(mut self, component_identification: u8) -> Self {
+        self.component_identification = component_identification;
+        self
+    }
+
+    pub fn with_component_damage_status(mut self, component_damage_status: u8) -> Self {
+        self.component_damage_status = component_damage_status;
+        self
+    }
+
+    pub fn with_component_visual_damage_status(mut self, component_visual_damage_status: u8) -> Self {
+        self.component_visual_damage_status = component_visual_damage_status;
+        self
+    }
+
+    pub fn with_component_visual_smoke_color(mut self, component_visual_smoke_color: u8) -> Self {
+        self.component_visual_smoke_color = component_visual_smoke_color;
+        self
+    }
+
+    pub fn with_fire_event_id(mut self, fire_event_id: EventId) -> Self {
+        self.fire_event_id = fire_event_id;
+        self
+    }
+}
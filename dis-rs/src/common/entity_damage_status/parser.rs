@@ -0,0 +1,51 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::error::ErrorKind::Switch;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+use crate::common::entity_damage_status::model::{DE_DAMAGE_DESCRIPTION_RECORD_TYPE, DirectedEnergyDamage, EntityDamageStatus};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, event_id, vec3_f32};
+
+pub fn entity_damage_status_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, damaged_entity_id) = context("Damaged Entity ID", entity_id)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, number_of_damage_descriptions) = context("Number of DE Damage Description Records", be_u16)(input)?;
+    let (input, damage_descriptions) = context("DE Damage Description Records", count(de_damage_description, number_of_damage_descriptions as usize))(input)?;
+
+    let body = EntityDamageStatus::builder()
+        .with_damaged_entity_id(damaged_entity_id)
+        .with_damage_descriptions(damage_descriptions)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn de_damage_description(input: &[u8]) -> IResult<&[u8], DirectedEnergyDamage> {
+    let (input, record_type) = be_u32(input)?;
+    if record_type != DE_DAMAGE_DESCRIPTION_RECORD_TYPE {
+        return Err(nom::Err::Error(nom::error::make_error(input, Switch)));
+    }
+    let (input, _record_length) = be_u16(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, damage_location) = vec3_f32(input)?;
+    let (input, damage_diameter) = be_f32(input)?;
+    let (input, temperature) = be_f32(input)?;
+    let (input, component_identification) = be_u8(input)?;
+    let (input, component_damage_status) = be_u8(input)?;
+    let (input, component_visual_damage_status) = be_u8(input)?;
+    let (input, component_visual_smoke_color) = be_u8(input)?;
+    let (input, fire_event_id) = event_id(input)?;
+    let (input, _padding) = be_u16(input)?;
+
+    Ok((input, DirectedEnergyDamage::default()
+        .with_damage_location(damage_location)
+        .with_damage_diameter(damage_diameter)
+        .with_temperature(temperature)
+        .with_component_identification(component_identification)
+        .with_component_damage_status(component_damage_status)
+        .with_component_visual_damage_status(component_visual_damage_status)
+        .with_component_visual_smoke_color(component_visual_smoke_color)
+        .with_fire_event_id(fire_event_id)))
+}
@@ -0,0 +1,116 @@
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::publisher::PublishedState;
+use crate::VariableParameters;
+
+/// The reason why a new Entity Damage Status PDU must be issued for a local entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityDamageStatusIssueReason {
+    /// No Entity Damage Status PDU was issued yet for the entity
+    Initial,
+    /// A damage description was added, removed or changed
+    DamageChange,
+    /// HBT_PDU_ENTITY_DAMAGE elapsed since the last Entity Damage Status PDU
+    Heartbeat,
+}
+
+/// Decides when a local entity must report its directed energy damage in a new Entity Damage Status PDU
+/// (IEEE 1278.1-2012, 5.4.8): whenever its damage descriptions change, and otherwise at the HBT_PDU_ENTITY_DAMAGE interval.
+/// There are no thresholds; any change to a damage description is reported.
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time).
+pub struct EntityDamageStatusPublisher {
+    entity_damage_status: PublishedState<EntityDamageStatus>,
+    heartbeat: f64,
+}
+
+impl EntityDamageStatusPublisher {
+    /// Creates a new publisher for the local entity with the given initial damage state,
+    /// taking HBT_PDU_ENTITY_DAMAGE from the provided `VariableParameters`.
+    pub fn new(entity_damage_status: EntityDamageStatus, parameters: &VariableParameters) -> Self {
+        Self {
+            entity_damage_status: PublishedState::new(entity_damage_status),
+            heartbeat: parameters.HBT_PDU_ENTITY_DAMAGE as f64,
+        }
+    }
+
+    /// Sets the interval, in seconds, between Entity Damage Status PDUs of an entity whose damage does not change.
+    pub fn with_heartbeat(mut self, heartbeat: f64) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// The current damage state of the local entity.
+    pub fn entity_damage_status(&self) -> &EntityDamageStatus {
+        self.entity_damage_status.current()
+    }
+
+    /// Mutable access to the current damage state of the local entity, e.g., to add a damage description.
+    pub fn entity_damage_status_mut(&mut self) -> &mut EntityDamageStatus {
+        self.entity_damage_status.current_mut()
+    }
+
+    /// Replaces the current damage state of the local entity.
+    pub fn update(&mut self, entity_damage_status: EntityDamageStatus) {
+        self.entity_damage_status.update(entity_damage_status);
+    }
+
+    /// The EntityDamageStatus that was last issued, if any.
+    pub fn last_issued(&self) -> Option<&EntityDamageStatus> {
+        self.entity_damage_status.last_issued()
+    }
+
+    /// Checks whether a new Entity Damage Status PDU must be issued at `time`, and if so, for what reason.
+    pub fn check(&self, time: f64) -> Option<EntityDamageStatusIssueReason> {
+        let issued = if let Some(issued) = self.entity_damage_status.issued() {
+            issued
+        } else {
+            return Some(EntityDamageStatusIssueReason::Initial)
+        };
+
+        if &issued.state != self.entity_damage_status.current() {
+            return Some(EntityDamageStatusIssueReason::DamageChange);
+        }
+
+        if issued.heartbeat_elapsed(time, self.heartbeat) {
+            Some(EntityDamageStatusIssueReason::Heartbeat)
+        } else { None }
+    }
+
+    /// Records that the current damage state is issued at `time`, and returns it to be sent in an Entity Damage Status PDU.
+    pub fn issue(&mut self, time: f64) -> EntityDamageStatus {
+        self.entity_damage_status.issue(time)
+    }
+
+    /// Issues an Entity Damage Status PDU at `time` when `check` returns a reason.
+    pub fn poll(&mut self, time: f64) -> Option<(EntityDamageStatus, EntityDamageStatusIssueReason)> {
+        self.check(time)
+            .map(|reason| (self.issue(time), reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::entity_damage_status::model::{DirectedEnergyDamage, EntityDamageStatus};
+    use crate::common::entity_damage_status::publisher::{EntityDamageStatusIssueReason, EntityDamageStatusPublisher};
+    use crate::common::model::EntityId;
+    use crate::VariableParameters;
+
+    #[test]
+    fn damage_change_and_heartbeat() {
+        let status = EntityDamageStatus::builder()
+            .with_damaged_entity_id(EntityId::new(1, 1, 2))
+            .build();
+        let mut publisher = EntityDamageStatusPublisher::new(status, &VariableParameters::default());
+
+        assert_eq!(publisher.poll(0.0).map(|(_, reason)| reason), Some(EntityDamageStatusIssueReason::Initial));
+        assert_eq!(publisher.check(1.0), None);
+
+        publisher.entity_damage_status_mut().damage_descriptions.push(DirectedEnergyDamage::default()
+            .with_damage_diameter(0.1)
+            .with_component_damage_status(2));
+        assert_eq!(publisher.poll(1.0).map(|(_, reason)| reason), Some(EntityDamageStatusIssueReason::DamageChange));
+
+        assert_eq!(publisher.check(10.9), None);
+        assert_eq!(publisher.check(11.0), Some(EntityDamageStatusIssueReason::Heartbeat));
+    }
+}
@@ -0,0 +1,36 @@
+use bytes::BufMut;
+use crate::common::entity_damage_status::model::{DE_DAMAGE_DESCRIPTION_RECORD_LENGTH, DE_DAMAGE_DESCRIPTION_RECORD_TYPE, DirectedEnergyDamage, EntityDamageStatus};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for EntityDamageStatus {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let damaged_entity_id_bytes = self.damaged_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u16(0u16);
+        buf.put_u16(self.damage_descriptions.len() as u16);
+        let damage_descriptions_bytes = self.damage_descriptions.iter()
+            .map(|description| description.serialize(buf))
+            .sum::<u16>();
+
+        damaged_entity_id_bytes + 6 + damage_descriptions_bytes
+    }
+}
+
+impl Serialize for DirectedEnergyDamage {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(DE_DAMAGE_DESCRIPTION_RECORD_TYPE);
+        buf.put_u16(DE_DAMAGE_DESCRIPTION_RECORD_LENGTH);
+        buf.put_u16(0u16);
+        self.damage_location.serialize(buf);
+        buf.put_f32(self.damage_diameter);
+        buf.put_f32(self.temperature);
+        buf.put_u8(self.component_identification);
+        buf.put_u8(self.component_damage_status);
+        buf.put_u8(self.component_visual_damage_status);
+        buf.put_u8(self.component_visual_smoke_color);
+        self.fire_event_id.serialize(buf);
+        buf.put_u16(0u16);
+
+        DE_DAMAGE_DESCRIPTION_RECORD_LENGTH
+    }
+}
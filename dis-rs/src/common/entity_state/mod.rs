@@ -1,6 +1,8 @@
 pub mod parser;
 pub mod model;
 pub mod writer;
+pub mod view;
+pub mod publisher;
 mod compatibility;
 
 #[cfg(test)]
@@ -3,7 +3,7 @@ use crate::common::model::{EntityId, EntityType, Location, Orientation, PduBody,
 use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
 use crate::enumerations::{ForceId, EntityCapabilities, PduType, EntityMarkingCharacterSet, LandPlatformAppearance, AirPlatformAppearance, SurfacePlatformAppearance, SubsurfacePlatformAppearance, SpacePlatformAppearance, MunitionAppearance, LifeFormsAppearance, EnvironmentalAppearance, CulturalFeatureAppearance, RadioAppearance, ExpendableAppearance, SensorEmitterAppearance, SupplyAppearance, DeadReckoningAlgorithm};
 
-const BASE_ENTITY_STATE_BODY_LENGTH : u16 = 132;
+pub(crate) const BASE_ENTITY_STATE_BODY_LENGTH : u16 = 132;
 
 // TODO sensible errors for EntityState
 #[allow(dead_code)]
@@ -11,7 +11,7 @@ pub enum EntityStateValidationError {
     SomeFieldNotOkError,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct EntityState {
     pub entity_id : EntityId, // struct
     pub force_id : ForceId, // enum
@@ -136,7 +136,7 @@ impl Interaction for EntityState {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum EntityAppearance {
     LandPlatform(LandPlatformAppearance),
     AirPlatform(AirPlatformAppearance),
@@ -160,7 +160,7 @@ impl Default for EntityAppearance {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct EntityMarking {
     pub marking_character_set : EntityMarkingCharacterSet,
     pub marking_string : String, // 11 byte String
@@ -189,7 +189,7 @@ impl Default for EntityMarking {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct DrParameters {
     pub algorithm : DeadReckoningAlgorithm,
     pub other_parameters : DrOtherParameters,
@@ -219,7 +219,7 @@ impl DrParameters {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum DrOtherParameters {
     None([u8; 15]),
     LocalEulerAngles(DrEulerAngles),
@@ -232,7 +232,7 @@ impl Default for DrOtherParameters {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct DrEulerAngles {
     pub local_yaw : f32,
     pub local_pitch : f32,
@@ -256,7 +256,7 @@ impl DrEulerAngles {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct DrWorldOrientationQuaternion {
     pub nil : u16,
     pub x: f32,
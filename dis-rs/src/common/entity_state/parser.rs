@@ -1,5 +1,6 @@
 use nom::bytes::complete::take;
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
 use crate::common::entity_state::model::{EntityState, DrOtherParameters, DrParameters, EntityMarking, DrEulerAngles, DrWorldOrientationQuaternion, EntityAppearance};
@@ -11,29 +12,29 @@ use crate::v6::entity_state::parser::entity_capabilities;
 
 pub fn entity_state_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
-        let (input, entity_id_val) = entity_id(input)?;
-        let (input, force_id_val) = force_id(input)?;
-        let (input, variable_parameters_no) = be_u8(input)?;
-        let (input, entity_type_val) = entity_type(input)?;
-        let (input, alternative_entity_type) = entity_type(input)?;
-        let (input, entity_linear_velocity) = vec3_f32(input)?;
-        let (input, entity_location) = parser::location(input)?;
-        let (input, entity_orientation) = parser::orientation(input)?;
-        let (input, entity_appearance) = entity_appearance(entity_type_val)(input)?;
-        let (input, dead_reckoning_parameters) = dr_parameters(input)?;
-        let (input, entity_marking) = entity_marking(input)?;
+        let (input, entity_id_val) = context("Entity ID", entity_id)(input)?;
+        let (input, force_id_val) = context("Force ID", force_id)(input)?;
+        let (input, variable_parameters_no) = context("Number of Variable Parameter Records", be_u8)(input)?;
+        let (input, entity_type_val) = context("Entity Type", entity_type)(input)?;
+        let (input, alternative_entity_type) = context("Alternative Entity Type", entity_type)(input)?;
+        let (input, entity_linear_velocity) = context("Entity Linear Velocity", vec3_f32)(input)?;
+        let (input, entity_location) = context("Entity Location", parser::location)(input)?;
+        let (input, entity_orientation) = context("Entity Orientation", parser::orientation)(input)?;
+        let (input, entity_appearance) = context("Entity Appearance", entity_appearance(entity_type_val))(input)?;
+        let (input, dead_reckoning_parameters) = context("Dead Reckoning Parameters", dr_parameters)(input)?;
+        let (input, entity_marking) = context("Entity Marking", entity_marking)(input)?;
         #[allow(clippy::wildcard_in_or_patterns)]
         let (input, entity_capabilities) = match header.protocol_version {
             ProtocolVersion::IEEE1278_12012 => {
-                crate::v7::entity_state::parser::entity_capabilities(entity_type_val)(input)?
+                context("Capabilities", crate::v7::entity_state::parser::entity_capabilities(entity_type_val))(input)?
             }
             ProtocolVersion::IEEE1278_1A1998 | _ => {
-                let (input, entity_capabilities) = entity_capabilities(input)?;
+                let (input, entity_capabilities) = context("Capabilities", entity_capabilities)(input)?;
                 (input, crate::enumerations::EntityCapabilities::from(entity_capabilities))
             }
         };
         let (input, variable_parameters) = if variable_parameters_no > 0 {
-            count(parser::variable_parameter, variable_parameters_no as usize)(input)?
+            context("Variable Parameter Records", count(parser::variable_parameter, variable_parameters_no as usize))(input)?
         } else { (input, vec![]) };
 
         let body = EntityState::new(entity_id_val, force_id_val, entity_type_val)
@@ -0,0 +1,256 @@
+use crate::common::dead_reckoning::{extrapolate, location_difference, orientation_difference};
+use crate::common::entity_state::model::EntityState;
+use crate::common::publisher::PublishedState;
+use crate::VariableParameters;
+
+/// The reason why a new EntityState PDU must be issued for a local entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityStateIssueReason {
+    /// No EntityState PDU was issued yet for the entity
+    Initial,
+    /// The dead reckoned location deviates more than the position threshold (DRA_POS_THRSH) from the true location
+    PositionThreshold,
+    /// The dead reckoned orientation deviates more than the orientation threshold (DRA_ORIENT_THRSH) from the true orientation
+    OrientationThreshold,
+    /// The appearance, capabilities, dead reckoning algorithm or variable parameters of the entity changed
+    StateChange,
+    /// The heartbeat interval for the entity elapsed since the last issued EntityState PDU
+    Heartbeat,
+}
+
+/// Owns the true state of a local entity and decides when a new EntityState PDU must be issued,
+/// following the issuance rules of IEEE 1278.1-2012 (5.3.2.3 / Annex E):
+/// - when the difference between the true location and the dead reckoned location exceeds the position threshold,
+/// - when the difference between the true orientation and the dead reckoned orientation exceeds the orientation threshold,
+/// - when a non-dead reckoned part of the state (e.g., appearance) changed,
+/// - when the heartbeat interval for the kind and domain of the entity elapsed.
+///
+/// The heartbeat interval follows the current state of the entity,
+/// so it switches to HBT_STATIONARY when the entity changes to the Static dead reckoning algorithm, and back when it moves again.
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time).
+pub struct EntityStatePublisher {
+    entity_state: PublishedState<EntityState>,
+    position_threshold: f64,
+    orientation_threshold: f64,
+    heartbeat: Option<f64>,
+    parameters: VariableParameters,
+}
+
+impl EntityStatePublisher {
+    /// Creates a new publisher for the local entity with the given initial (true) state.
+    /// The thresholds and heartbeat intervals are taken from the provided `VariableParameters`.
+    pub fn new(entity_state: EntityState, parameters: &VariableParameters) -> Self {
+        Self {
+            entity_state: PublishedState::new(entity_state),
+            position_threshold: parameters.DRA_POS_THRSH as f64,
+            orientation_threshold: (parameters.DRA_ORIENT_THRSH as f64).to_radians(),
+            heartbeat: None,
+            parameters: parameters.clone(),
+        }
+    }
+
+    /// Sets the position threshold, in meters.
+    pub fn with_position_threshold(mut self, threshold: f64) -> Self {
+        self.position_threshold = threshold;
+        self
+    }
+
+    /// Sets the orientation threshold, in degrees.
+    pub fn with_orientation_threshold(mut self, threshold: f64) -> Self {
+        self.orientation_threshold = threshold.to_radians();
+        self
+    }
+
+    /// Sets the heartbeat interval, in seconds, overriding the interval based on the state of the entity.
+    pub fn with_heartbeat(mut self, heartbeat: f64) -> Self {
+        self.heartbeat = Some(heartbeat);
+        self
+    }
+
+    /// The heartbeat interval, in seconds, that applies to the current true state of the entity.
+    pub fn heartbeat(&self) -> f64 {
+        self.heartbeat
+            .unwrap_or_else(|| self.parameters.entity_state_heartbeat(self.entity_state.current()) as f64)
+    }
+
+    /// The true state of the local entity.
+    pub fn entity_state(&self) -> &EntityState {
+        self.entity_state.current()
+    }
+
+    /// Mutable access to the true state of the local entity, to update it in place.
+    pub fn entity_state_mut(&mut self) -> &mut EntityState {
+        self.entity_state.current_mut()
+    }
+
+    /// Replaces the true state of the local entity.
+    pub fn update(&mut self, entity_state: EntityState) {
+        self.entity_state.update(entity_state);
+    }
+
+    /// The EntityState that was last issued, if any.
+    pub fn last_issued(&self) -> Option<&EntityState> {
+        self.entity_state.last_issued()
+    }
+
+    /// Checks whether a new EntityState PDU must be issued at `time`, and if so, for what reason.
+    pub fn check(&self, time: f64) -> Option<EntityStateIssueReason> {
+        let issued = if let Some(issued) = self.entity_state.issued() {
+            issued
+        } else {
+            return Some(EntityStateIssueReason::Initial)
+        };
+        let current = self.entity_state.current();
+
+        if has_state_changed(&issued.state, current) {
+            return Some(EntityStateIssueReason::StateChange);
+        }
+
+        let dead_reckoned = extrapolate(&issued.state, time - issued.time);
+        if location_difference(&dead_reckoned.location, &current.entity_location) > self.position_threshold {
+            return Some(EntityStateIssueReason::PositionThreshold);
+        }
+        if orientation_difference(&dead_reckoned.orientation, &current.entity_orientation) > self.orientation_threshold {
+            return Some(EntityStateIssueReason::OrientationThreshold);
+        }
+
+        if issued.heartbeat_elapsed(time, self.heartbeat()) {
+            Some(EntityStateIssueReason::Heartbeat)
+        } else { None }
+    }
+
+    /// Records that the current true state is issued at `time`, and returns it to be sent in an EntityState PDU.
+    pub fn issue(&mut self, time: f64) -> EntityState {
+        self.entity_state.issue(time)
+    }
+
+    /// Checks whether a new EntityState PDU must be issued at `time`, and if so records and returns the state to send,
+    /// together with the reason for issuing.
+    pub fn poll(&mut self, time: f64) -> Option<(EntityState, EntityStateIssueReason)> {
+        self.check(time)
+            .map(|reason| (self.issue(time), reason))
+    }
+}
+
+/// Checks whether the parts of the state that are not dead reckoned differ between the two EntityStates.
+fn has_state_changed(issued: &EntityState, current: &EntityState) -> bool {
+    issued.entity_appearance != current.entity_appearance
+        || issued.entity_capabilities != current.entity_capabilities
+        || issued.dead_reckoning_parameters.algorithm != current.dead_reckoning_parameters.algorithm
+        || issued.variable_parameters != current.variable_parameters
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::entity_state::model::{DrParameters, EntityAppearance, EntityState};
+    use crate::common::entity_state::publisher::{EntityStatePublisher, EntityStateIssueReason};
+    use crate::common::model::{EntityId, EntityType, Location, Orientation, VectorF32};
+    use crate::enumerations::{DeadReckoningAlgorithm, EntityKind, ForceId, PlatformDomain};
+    use crate::VariableParameters;
+
+    fn ground_vehicle() -> EntityState {
+        EntityState::new(EntityId::new(1, 1, 1), ForceId::Friendly, EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land))
+            .with_location(Location::new(0.0, 0.0, 0.0))
+            .with_orientation(Orientation::new(0.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(10.0, 0.0, 0.0))
+            .with_dead_reckoning_parameters(DrParameters::default()
+                .with_algorithm(DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity))
+    }
+
+    #[test]
+    fn initial_issue() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+
+        assert_eq!(publisher.check(0.0), Some(EntityStateIssueReason::Initial));
+        let (issued, reason) = publisher.poll(0.0).unwrap();
+        assert_eq!(reason, EntityStateIssueReason::Initial);
+        assert_eq!(&issued, publisher.entity_state());
+        assert_eq!(publisher.check(0.0), None);
+    }
+
+    #[test]
+    fn position_threshold() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        // moving as predicted by dead reckoning
+        publisher.entity_state_mut().entity_location = Location::new(20.0, 0.0, 0.0);
+        assert_eq!(publisher.check(2.0), None);
+
+        // deviating 1.5 meters sideways from the predicted location
+        publisher.entity_state_mut().entity_location = Location::new(30.0, 1.5, 0.0);
+        assert_eq!(publisher.check(3.0), Some(EntityStateIssueReason::PositionThreshold));
+    }
+
+    #[test]
+    fn orientation_threshold() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        publisher.entity_state_mut().entity_location = Location::new(10.0, 0.0, 0.0);
+        publisher.entity_state_mut().entity_orientation = Orientation::new(2.0f32.to_radians(), 0.0, 0.0);
+        assert_eq!(publisher.check(1.0), None);
+
+        publisher.entity_state_mut().entity_orientation = Orientation::new(4.0f32.to_radians(), 0.0, 0.0);
+        assert_eq!(publisher.check(1.0), Some(EntityStateIssueReason::OrientationThreshold));
+    }
+
+    #[test]
+    fn heartbeat() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        publisher.entity_state_mut().entity_location = Location::new(49.0, 0.0, 0.0);
+        assert_eq!(publisher.check(4.9), None);
+        publisher.entity_state_mut().entity_location = Location::new(50.0, 0.0, 0.0);
+        assert_eq!(publisher.check(5.0), Some(EntityStateIssueReason::Heartbeat));
+    }
+
+    #[test]
+    fn stationary_heartbeat() {
+        let entity = ground_vehicle()
+            .with_velocity(VectorF32::default())
+            .with_dead_reckoning_parameters(DrParameters::default()
+                .with_algorithm(DeadReckoningAlgorithm::StaticNonmovingEntity));
+        let mut publisher = EntityStatePublisher::new(entity, &VariableParameters::default());
+        publisher.issue(0.0);
+
+        assert_eq!(publisher.check(59.0), None);
+        assert_eq!(publisher.check(60.0), Some(EntityStateIssueReason::Heartbeat));
+    }
+
+    #[test]
+    fn heartbeat_follows_current_state() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+        assert_eq!(publisher.heartbeat(), 5.0);
+
+        // the vehicle stops
+        publisher.entity_state_mut().entity_linear_velocity = VectorF32::default();
+        publisher.entity_state_mut().dead_reckoning_parameters.algorithm = DeadReckoningAlgorithm::StaticNonmovingEntity;
+        assert_eq!(publisher.heartbeat(), 60.0);
+        publisher.issue(0.0);
+        assert_eq!(publisher.check(59.0), None);
+        assert_eq!(publisher.check(60.0), Some(EntityStateIssueReason::Heartbeat));
+        publisher.issue(60.0);
+
+        // and starts moving again, which is a state change; thereafter the moving heartbeat applies
+        publisher.entity_state_mut().dead_reckoning_parameters.algorithm = DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity;
+        assert_eq!(publisher.heartbeat(), 5.0);
+        assert_eq!(publisher.check(61.0), Some(EntityStateIssueReason::StateChange));
+        publisher.issue(61.0);
+        assert_eq!(publisher.check(65.9), None);
+        assert_eq!(publisher.check(66.0), Some(EntityStateIssueReason::Heartbeat));
+    }
+
+    #[test]
+    fn state_change() {
+        let mut publisher = EntityStatePublisher::new(ground_vehicle(), &VariableParameters::default());
+        publisher.issue(0.0);
+
+        publisher.entity_state_mut().entity_appearance = EntityAppearance::Unspecified([1, 0, 0, 0]);
+        assert_eq!(publisher.check(0.0), Some(EntityStateIssueReason::StateChange));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::common::entity_state::model::{BASE_ENTITY_STATE_BODY_LENGTH, DrParameters, EntityAppearance, EntityMarking};
+use crate::common::entity_state::parser::{dr_parameters, entity_appearance, entity_marking, force_id};
+use crate::common::errors::DisError;
+use crate::common::model::{EntityId, EntityType, Location, Orientation, VariableParameter, VectorF32};
+use crate::common::parser::{entity_id, entity_type, location, orientation, variable_parameter, vec3_f32};
+use crate::common::view::{check_body_length, decode, read_u32};
+use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
+use crate::enumerations::{EntityCapabilities, ForceId, ProtocolVersion};
+
+const ENTITY_ID_OFFSET: usize = 0;
+const FORCE_ID_OFFSET: usize = 6;
+const NUMBER_OF_VARIABLE_PARAMETERS_OFFSET: usize = 7;
+const ENTITY_TYPE_OFFSET: usize = 8;
+const ALTERNATIVE_ENTITY_TYPE_OFFSET: usize = 16;
+const LINEAR_VELOCITY_OFFSET: usize = 24;
+const LOCATION_OFFSET: usize = 36;
+const ORIENTATION_OFFSET: usize = 60;
+const APPEARANCE_OFFSET: usize = 72;
+const DEAD_RECKONING_PARAMETERS_OFFSET: usize = 76;
+const MARKING_OFFSET: usize = 116;
+const MARKING_STRING_OFFSET: usize = 117;
+const MARKING_STRING_LENGTH: usize = 11;
+const CAPABILITIES_OFFSET: usize = 128;
+
+/// A borrowed view over the body of an EntityState PDU (5.3.2), decoding fields when they are accessed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EntityStateView<'a> {
+    body: &'a [u8],
+    version: ProtocolVersion,
+}
+
+impl<'a> EntityStateView<'a> {
+    /// Creates a view over an EntityState body, checking that the body holds the fixed fields and all variable parameters.
+    pub fn new(body: &'a [u8], version: ProtocolVersion) -> Result<Self, DisError> {
+        check_body_length(body, BASE_ENTITY_STATE_BODY_LENGTH as usize)?;
+        let number_of_variable_parameters = body[NUMBER_OF_VARIABLE_PARAMETERS_OFFSET] as usize;
+        check_body_length(body, BASE_ENTITY_STATE_BODY_LENGTH as usize
+            + number_of_variable_parameters * VARIABLE_PARAMETER_RECORD_LENGTH as usize)?;
+
+        Ok(Self { body, version })
+    }
+
+    pub fn entity_id(&self) -> EntityId {
+        decode(self.body, ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn force_id(&self) -> ForceId {
+        decode(self.body, FORCE_ID_OFFSET, force_id)
+    }
+
+    pub fn number_of_variable_parameters(&self) -> u8 {
+        self.body[NUMBER_OF_VARIABLE_PARAMETERS_OFFSET]
+    }
+
+    pub fn entity_type(&self) -> EntityType {
+        decode(self.body, ENTITY_TYPE_OFFSET, entity_type)
+    }
+
+    pub fn alternative_entity_type(&self) -> EntityType {
+        decode(self.body, ALTERNATIVE_ENTITY_TYPE_OFFSET, entity_type)
+    }
+
+    pub fn linear_velocity(&self) -> VectorF32 {
+        decode(self.body, LINEAR_VELOCITY_OFFSET, vec3_f32)
+    }
+
+    pub fn location(&self) -> Location {
+        decode(self.body, LOCATION_OFFSET, location)
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        decode(self.body, ORIENTATION_OFFSET, orientation)
+    }
+
+    /// The raw value of the appearance field.
+    pub fn appearance_raw(&self) -> u32 {
+        read_u32(self.body, APPEARANCE_OFFSET)
+    }
+
+    /// The appearance, interpreted according to the kind and domain of the entity type.
+    pub fn appearance(&self) -> EntityAppearance {
+        decode(self.body, APPEARANCE_OFFSET, entity_appearance(self.entity_type()))
+    }
+
+    pub fn dead_reckoning_parameters(&self) -> DrParameters {
+        decode(self.body, DEAD_RECKONING_PARAMETERS_OFFSET, dr_parameters)
+    }
+
+    /// The raw 11 octets of the marking string, including any padding.
+    pub fn marking_bytes(&self) -> &'a [u8] {
+        &self.body[MARKING_STRING_OFFSET..MARKING_STRING_OFFSET + MARKING_STRING_LENGTH]
+    }
+
+    pub fn marking(&self) -> EntityMarking {
+        decode(self.body, MARKING_OFFSET, entity_marking)
+    }
+
+    /// The raw value of the capabilities field.
+    pub fn capabilities_raw(&self) -> u32 {
+        read_u32(self.body, CAPABILITIES_OFFSET)
+    }
+
+    /// The capabilities, interpreted according to the protocol version and the kind and domain of the entity type.
+    #[allow(clippy::wildcard_in_or_patterns)]
+    pub fn capabilities(&self) -> EntityCapabilities {
+        match self.version {
+            ProtocolVersion::IEEE1278_12012 => {
+                decode(self.body, CAPABILITIES_OFFSET,
+                       crate::v7::entity_state::parser::entity_capabilities(self.entity_type()))
+            }
+            ProtocolVersion::IEEE1278_1A1998 | _ => {
+                EntityCapabilities::from(decode(self.body, CAPABILITIES_OFFSET,
+                                                crate::v6::entity_state::parser::entity_capabilities))
+            }
+        }
+    }
+
+    /// Iterates over the variable parameter records, decoding each record when it is reached.
+    pub fn variable_parameters(&self) -> impl Iterator<Item = VariableParameter> + 'a {
+        let end = BASE_ENTITY_STATE_BODY_LENGTH as usize
+            + self.number_of_variable_parameters() as usize * VARIABLE_PARAMETER_RECORD_LENGTH as usize;
+        self.body[BASE_ENTITY_STATE_BODY_LENGTH as usize..end]
+            .chunks(VARIABLE_PARAMETER_RECORD_LENGTH as usize)
+            .map(|record| decode(record, 0, variable_parameter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::entity_state::model::{DrParameters, EntityMarking, EntityState};
+    use crate::common::model::{ArticulatedPart, EntityId, EntityType, Location, Orientation, Pdu, PduBody, PduHeader, VariableParameter, VectorF32};
+    use crate::common::Serialize;
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, ChangeIndicator, DeadReckoningAlgorithm, EntityKind, EntityMarkingCharacterSet, ForceId, PduType, PlatformDomain};
+
+    #[test]
+    fn entity_state_view_matches_parsed() {
+        let body = EntityState::new(EntityId::new(1, 2, 3), ForceId::Friendly, EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land))
+            .with_velocity(VectorF32::new(1.0, 2.0, 3.0))
+            .with_location(Location::new(4.0, 5.0, 6.0))
+            .with_orientation(Orientation::new(0.1, 0.2, 0.3))
+            .with_dead_reckoning_parameters(DrParameters::default()
+                .with_algorithm(DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity))
+            .with_marking(EntityMarking::new("TANK 1".to_string(), EntityMarkingCharacterSet::ASCII))
+            .with_variable_parameter(VariableParameter::Articulated(ArticulatedPart {
+                change_indicator: ChangeIndicator::from(0u8),
+                attachment_id: 0,
+                type_class: ArticulatedPartsTypeClass::PrimaryTurretNumber1,
+                type_metric: ArticulatedPartsTypeMetric::Azimuth,
+                parameter_value: 2.0
+            }))
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityState), body, 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+
+        let pdu_view = PduView::new(&buf).unwrap();
+        let view = if let Ok(BodyView::EntityState(view)) = pdu_view.body_view() { view } else { panic!("Expected an EntityState view") };
+        let entity_state = if let PduBody::EntityState(entity_state) = pdu_view.to_pdu().unwrap().body { entity_state } else { panic!() };
+
+        assert_eq!(view.entity_id(), entity_state.entity_id);
+        assert_eq!(view.force_id(), entity_state.force_id);
+        assert_eq!(view.entity_type(), entity_state.entity_type);
+        assert_eq!(view.linear_velocity(), entity_state.entity_linear_velocity);
+        assert_eq!(view.location(), entity_state.entity_location);
+        assert_eq!(view.orientation(), entity_state.entity_orientation);
+        assert_eq!(view.appearance(), entity_state.entity_appearance);
+        assert_eq!(view.dead_reckoning_parameters(), entity_state.dead_reckoning_parameters);
+        assert_eq!(view.marking(), entity_state.entity_marking);
+        assert_eq!(&view.marking_bytes()[..6], b"TANK 1");
+        assert_eq!(view.capabilities(), entity_state.entity_capabilities);
+        assert_eq!(view.variable_parameters().collect::<Vec<VariableParameter>>(), entity_state.variable_parameters);
+    }
+}
@@ -1,4 +1,4 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::common::entity_state::model::{DrParameters, EntityMarking, EntityState};
 use crate::common::model::{EntityType};
@@ -7,7 +7,7 @@ use crate::enumerations::{DrParametersType, ForceId};
 use crate::v6::entity_state::model::EntityCapabilities;
 
 impl SerializePdu for EntityState {
-    fn serialize_pdu(&self, version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16 {
         let entity_id_bytes = self.entity_id.serialize(buf);
         let force_id_bytes = self.force_id.serialize(buf);
         buf.put_u8(self.variable_parameters.len() as u8);
@@ -47,9 +47,9 @@ impl SerializePdu for EntityState {
     }
 }
 
-impl Serialize for EntityAppearance {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
-        let appearance: u32 = match self {
+impl From<&EntityAppearance> for u32 {
+    fn from(value: &EntityAppearance) -> Self {
+        match value {
             EntityAppearance::LandPlatform(appearance) => u32::from(*appearance),
             EntityAppearance::AirPlatform(appearance) => u32::from(*appearance),
             EntityAppearance::SurfacePlatform(appearance) => u32::from(*appearance),
@@ -64,14 +64,19 @@ impl Serialize for EntityAppearance {
             EntityAppearance::Expendable(appearance) => u32::from(*appearance),
             EntityAppearance::SensorEmitter(appearance) => u32::from(*appearance),
             EntityAppearance::Unspecified(appearance) => u32::from_be_bytes(*appearance),
-        };
-        buf.put_u32(appearance);
+        }
+    }
+}
+
+impl Serialize for EntityAppearance {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(u32::from(self));
         4
     }
 }
 
 impl Serialize for DrParameters {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.algorithm.into());
         let other_parameters_bytes = self.other_parameters.serialize(buf);
         let lin_acc_bytes = self.linear_acceleration.serialize(buf);
@@ -81,7 +86,7 @@ impl Serialize for DrParameters {
 }
 
 impl Serialize for DrOtherParameters {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         match self {
             DrOtherParameters::None(bytes) => {
                 for x in bytes {
@@ -96,7 +101,7 @@ impl Serialize for DrOtherParameters {
 }
 
 impl Serialize for DrEulerAngles {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(DrParametersType::LocalEulerAngles_Yaw_Pitch_Roll_.into());
         buf.put_u16(0u16);
         buf.put_f32(self.local_yaw);
@@ -107,7 +112,7 @@ impl Serialize for DrEulerAngles {
 }
 
 impl Serialize for DrWorldOrientationQuaternion {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(DrParametersType::WorldOrientationQuaternion.into());
         buf.put_u16(self.nil);
         buf.put_f32(self.x);
@@ -118,7 +123,7 @@ impl Serialize for DrWorldOrientationQuaternion {
 }
 
 impl Serialize for ForceId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let force_id = *self;
         buf.put_u8(force_id.into());
         1
@@ -126,7 +131,7 @@ impl Serialize for ForceId {
 }
 
 impl Serialize for EntityType {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.kind.into());
         buf.put_u8(self.domain.into());
         buf.put_u16(self.country.into());
@@ -139,7 +144,7 @@ impl Serialize for EntityType {
 }
 
 impl Serialize for EntityMarking {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.marking_character_set.into());
         let num_pad = 11 - self.marking_string.len();
         let marking = self.marking_string.clone(); // clone necessary because into_bytes consumes self.
@@ -1,4 +1,4 @@
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::multi::count;
 use nom::number::complete::be_u8;
 use crate::enumerations::EntityKind;
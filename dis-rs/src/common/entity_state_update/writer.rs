@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::entity_state_update::model::EntityStateUpdate;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 
 impl SerializePdu for EntityStateUpdate {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let entity_id_bytes = self.entity_id.serialize(buf);
         buf.put_u8(0u8);
         buf.put_u8(self.variable_parameters.len() as u8);
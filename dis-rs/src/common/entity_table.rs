@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use crate::common::dead_reckoning::{extrapolate, DrResult};
+use crate::common::entity_state::model::EntityState;
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::model::{EntityId, Pdu, PduBody};
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::fixed_parameters::{ALL_APPLIC, ALL_ENTITIES, ALL_SITES, NO_ENTITY};
+use crate::VariableParameters;
+
+/// Events emitted by the `EntityTable` when the set of known remote entities changes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityEvent {
+    /// An EntityState PDU was received for an entity that was not known yet
+    Appeared(EntityId),
+    /// An EntityState or EntityStateUpdate PDU was received for a known entity
+    Updated(EntityId),
+    /// No update was received for the entity within its timeout period; the entity is removed from the table
+    TimedOut(EntityId),
+    /// The entity is removed from the table because of a RemoveEntity PDU
+    Removed(EntityId),
+}
+
+/// The last known state of a remote entity, together with the time (in seconds) the state was received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteEntity {
+    pub entity_state: EntityState,
+    pub last_update: f64,
+    pub timeout: f64,
+}
+
+impl RemoteEntity {
+    /// Extrapolates the location and orientation of the entity to `time`,
+    /// using the dead reckoning parameters of the last received state.
+    pub fn dead_reckon(&self, time: f64) -> DrResult {
+        extrapolate(&self.entity_state, time - self.last_update)
+    }
+
+    /// Indicates whether the entity timed out at `time`.
+    pub fn is_timed_out(&self, time: f64) -> bool {
+        time - self.last_update > self.timeout
+    }
+}
+
+/// A table of remote entities, built from received EntityState and EntityStateUpdate PDUs.
+///
+/// Entities are removed when no update is received within the heartbeat interval for the entity
+/// multiplied by the timeout multiplier (HBT_TIMEOUT_MPLIER), or when a RemoveEntity PDU is received.
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time or time since start).
+pub struct EntityTable {
+    parameters: VariableParameters,
+    entities: HashMap<EntityId, RemoteEntity>,
+}
+
+impl Default for EntityTable {
+    fn default() -> Self {
+        Self::new(VariableParameters::default())
+    }
+}
+
+impl EntityTable {
+    pub fn new(parameters: VariableParameters) -> Self {
+        Self {
+            parameters,
+            entities: HashMap::new(),
+        }
+    }
+
+    /// Processes a received PDU at `time`, returning the resulting events.
+    /// PDUs other than EntityState, EntityStateUpdate and RemoveEntity are ignored.
+    pub fn process(&mut self, pdu: &Pdu, time: f64) -> Vec<EntityEvent> {
+        match &pdu.body {
+            PduBody::EntityState(body) => { self.process_entity_state(body, time) }
+            PduBody::EntityStateUpdate(body) => { self.process_entity_state_update(body, time) }
+            PduBody::RemoveEntity(body) => { self.process_remove_entity(body) }
+            _ => { vec![] }
+        }
+    }
+
+    /// Removes all entities that timed out at `time`, returning a `TimedOut` event for each of them.
+    pub fn check_timeouts(&mut self, time: f64) -> Vec<EntityEvent> {
+        let timed_out: Vec<EntityId> = self.entities.iter()
+            .filter(|(_, entity)| entity.is_timed_out(time))
+            .map(|(entity_id, _)| *entity_id)
+            .collect();
+
+        timed_out.into_iter()
+            .map(|entity_id| {
+                self.entities.remove(&entity_id);
+                EntityEvent::TimedOut(entity_id)
+            }).collect()
+    }
+
+    pub fn get(&self, entity_id: &EntityId) -> Option<&RemoteEntity> {
+        self.entities.get(entity_id)
+    }
+
+    pub fn contains(&self, entity_id: &EntityId) -> bool {
+        self.entities.contains_key(entity_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&EntityId, &RemoteEntity)> {
+        self.entities.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn process_entity_state(&mut self, entity_state: &EntityState, time: f64) -> Vec<EntityEvent> {
+        let entity_id = entity_state.entity_id;
+        let timeout = self.timeout(entity_state);
+        let previous = self.entities.insert(entity_id, RemoteEntity {
+            entity_state: entity_state.clone(),
+            last_update: time,
+            timeout,
+        });
+
+        if previous.is_some() {
+            vec![EntityEvent::Updated(entity_id)]
+        } else {
+            vec![EntityEvent::Appeared(entity_id)]
+        }
+    }
+
+    /// An EntityStateUpdate only updates entities that are already known;
+    /// it does not contain enough information (such as the entity type) to add a new entity.
+    fn process_entity_state_update(&mut self, update: &EntityStateUpdate, time: f64) -> Vec<EntityEvent> {
+        if let Some(entity) = self.entities.get_mut(&update.entity_id) {
+            entity.entity_state.entity_linear_velocity = update.entity_linear_velocity;
+            entity.entity_state.entity_location = update.entity_location;
+            entity.entity_state.entity_orientation = update.entity_orientation;
+            entity.entity_state.entity_appearance = update.entity_appearance;
+            entity.entity_state.variable_parameters = update.variable_parameters.clone();
+            entity.last_update = time;
+            vec![EntityEvent::Updated(update.entity_id)]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Removes the entities identified by the receiving id of the RemoveEntity PDU.
+    /// When the receiving id identifies a simulation application (entity number NO_ENTITY) or uses
+    /// ALL_SITES, ALL_APPLIC or ALL_ENTITIES, all matching entities are removed.
+    fn process_remove_entity(&mut self, remove_entity: &RemoveEntity) -> Vec<EntityEvent> {
+        let target = remove_entity.receiving_id;
+        let removed: Vec<EntityId> = self.entities.keys()
+            .filter(|entity_id| matches_entity_id(&target, entity_id))
+            .copied()
+            .collect();
+
+        removed.into_iter()
+            .map(|entity_id| {
+                self.entities.remove(&entity_id);
+                EntityEvent::Removed(entity_id)
+            }).collect()
+    }
+
+    fn timeout(&self, entity_state: &EntityState) -> f64 {
+        (self.parameters.entity_state_heartbeat(entity_state) * self.parameters.HBT_TIMEOUT_MPLIER) as f64
+    }
+}
+
+pub(crate) fn matches_entity_id(target: &EntityId, entity_id: &EntityId) -> bool {
+    let site = target.simulation_address.site_id as u32 == ALL_SITES
+        || target.simulation_address.site_id == entity_id.simulation_address.site_id;
+    let application = target.simulation_address.application_id as u32 == ALL_APPLIC
+        || target.simulation_address.application_id == entity_id.simulation_address.application_id;
+    let entity = target.entity_id as u32 == ALL_ENTITIES
+        || target.entity_id == NO_ENTITY
+        || target.entity_id == entity_id.entity_id;
+
+    site && application && entity
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::entity_state_update::model::EntityStateUpdate;
+    use crate::common::entity_table::{EntityEvent, EntityTable};
+    use crate::common::model::{EntityId, EntityType, Location, Pdu, PduHeader};
+    use crate::common::remove_entity::model::RemoveEntity;
+    use crate::enumerations::{EntityKind, ForceId, PduType, PlatformDomain};
+
+    fn entity_state_pdu(entity_id: EntityId) -> Pdu {
+        let body = EntityState::new(entity_id, ForceId::Friendly, EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Air))
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::EntityState), body, 0)
+    }
+
+    #[test]
+    fn appear_and_update() {
+        let mut table = EntityTable::default();
+        let entity_id = EntityId::new(1, 2, 3);
+
+        assert_eq!(table.process(&entity_state_pdu(entity_id), 0.0), vec![EntityEvent::Appeared(entity_id)]);
+        assert_eq!(table.process(&entity_state_pdu(entity_id), 1.0), vec![EntityEvent::Updated(entity_id)]);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get(&entity_id).unwrap().last_update, 1.0);
+    }
+
+    #[test]
+    fn entity_state_update() {
+        let mut table = EntityTable::default();
+        let entity_id = EntityId::new(1, 2, 3);
+        let update = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityStateUpdate),
+                                              EntityStateUpdate::new(entity_id)
+                                                  .with_location(Location::new(1.0, 2.0, 3.0))
+                                                  .into_pdu_body(), 0);
+
+        // unknown entity; update is ignored
+        assert!(table.process(&update, 0.0).is_empty());
+
+        table.process(&entity_state_pdu(entity_id), 0.0);
+        assert_eq!(table.process(&update, 2.0), vec![EntityEvent::Updated(entity_id)]);
+        let entity = table.get(&entity_id).unwrap();
+        assert_eq!(entity.entity_state.entity_location, Location::new(1.0, 2.0, 3.0));
+        assert_eq!(entity.entity_state.entity_type.domain, PlatformDomain::Air);
+    }
+
+    #[test]
+    fn timeout() {
+        let mut table = EntityTable::default();
+        let entity_id = EntityId::new(1, 2, 3);
+        table.process(&entity_state_pdu(entity_id), 0.0);
+
+        // default heartbeat of 5 s times the multiplier of 2.4
+        assert!(table.check_timeouts(12.0).is_empty());
+        assert_eq!(table.check_timeouts(12.1), vec![EntityEvent::TimedOut(entity_id)]);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn remove_entity() {
+        let mut table = EntityTable::default();
+        let first = EntityId::new(1, 2, 3);
+        let second = EntityId::new(1, 2, 4);
+        table.process(&entity_state_pdu(first), 0.0);
+        table.process(&entity_state_pdu(second), 0.0);
+
+        let remove = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::RemoveEntity),
+                                              RemoveEntity::new().with_receiving_id(first).into_pdu_body(), 0);
+        assert_eq!(table.process(&remove, 1.0), vec![EntityEvent::Removed(first)]);
+        assert!(table.contains(&second));
+    }
+
+    #[test]
+    fn remove_application() {
+        let mut table = EntityTable::default();
+        table.process(&entity_state_pdu(EntityId::new(1, 2, 3)), 0.0);
+        table.process(&entity_state_pdu(EntityId::new(1, 2, 4)), 0.0);
+        table.process(&entity_state_pdu(EntityId::new(1, 5, 4)), 0.0);
+
+        let remove = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::RemoveEntity),
+                                              RemoveEntity::new().with_receiving_id(EntityId::new(1, 2, 0)).into_pdu_body(), 0);
+        assert_eq!(table.process(&remove, 1.0).len(), 2);
+        assert_eq!(table.len(), 1);
+        assert!(table.contains(&EntityId::new(1, 5, 4)));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::common::environmental_process::model::{EnvironmentalProcess, EnvironmentRecord, EnvironmentStatus};
+use crate::common::model::{EntityId, EntityType};
+
+pub struct EnvironmentalProcessBuilder(EnvironmentalProcess);
+
+impl EnvironmentalProcessBuilder {
+    pub fn new() -> Self {
+        EnvironmentalProcessBuilder(EnvironmentalProcess::default())
+    }
+
+    pub fn new_from_body(body: EnvironmentalProcess) -> Self {
+        EnvironmentalProcessBuilder(body)
+    }
+
+    pub fn build(self) -> EnvironmentalProcess {
+        self.0
+    }
+
+    pub fn with_environmental_process_id(mut self, environmental_process_id: EntityId) -> Self {
+        self.0.environmental_process_id = environmental_process_id;
+        self
+    }
+
+    pub fn with_environment_type(mut self, environment_type: EntityType) -> Self {
+        self.0.environment_type = environment_type;
+        self
+    }
+
+    pub fn with_model_type(mut self, model_type: u8) -> Self {
+        self.0.model_type = model_type;
+        self
+    }
+
+    pub fn with_environment_status(mut self, environment_status: EnvironmentStatus) -> Self {
+        self.0.environment_status = environment_status;
+        self
+    }
+
+    pub fn with_sequence_number(mut self, sequence_number: u16) -> Self {
+        self.0.sequence_number = sequence_number;
+        self
+    }
+
+    pub fn with_environment_records(mut self, environment_records: Vec<EnvironmentRecord>) -> Self {
+        self.0.environment_records = environment_records;
+        self
+    }
+
+    pub fn with_environment_record(mut self, environment_record: EnvironmentRecord) -> Self {
+        self.0.environment_records.push(environment_record);
+        self
+    }
+}
@@ -0,0 +1,61 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::environmental_process::model::{ConeRecord1, EnvironmentalProcess, EnvironmentRecord, EnvironmentRecordData, EnvironmentStatus, LineRecord1, SphereRecord2};
+    use crate::common::model::{DisTimeStamp, EntityId, EntityType, Location, Orientation, Pdu, PduHeader, VectorF32};
+    use crate::enumerations::{EntityKind, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn environmental_process_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::EnvironmentalProcess);
+
+        let body = EnvironmentalProcess::builder()
+            .with_environmental_process_id(EntityId::new(1, 1, 500))
+            .with_environment_type(EntityType::default()
+                .with_kind(EntityKind::Environmental))
+            .with_model_type(1)
+            .with_environment_status(EnvironmentStatus::default()
+                .with_is_last(true)
+                .with_is_active(true))
+            .with_sequence_number(4)
+            .with_environment_record(EnvironmentRecord::new(0, EnvironmentRecordData::Line1(
+                LineRecord1::new(Location::new(1.0, 2.0, 3.0), Location::new(4.0, 5.0, 6.0)))))
+            .with_environment_record(EnvironmentRecord::new(1, EnvironmentRecordData::Sphere2(SphereRecord2 {
+                centroid: Location::new(10.0, 10.0, 10.0),
+                radius: 25.0,
+                radius_rate: 0.5,
+                velocity: VectorF32::new(1.0, 0.0, 0.0),
+                angular_velocity: VectorF32::default(),
+            })))
+            .with_environment_record(EnvironmentRecord::new(2, EnvironmentRecordData::Cone1(
+                ConeRecord1::new(Location::new(0.0, 0.0, 100.0), Orientation::new(0.0, 1.5, 0.0), 50.0, 0.3))))
+            .with_environment_record(EnvironmentRecord::new(3, EnvironmentRecordData::Unspecified(256, vec![1, 2, 3, 4, 5])))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
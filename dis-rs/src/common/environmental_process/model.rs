@@ -0,0 +1,382 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::environmental_process::builder::EnvironmentalProcessBuilder;
+use crate::common::model::{EntityId, EntityType, Location, Orientation, PduBody, VectorF32};
+use crate::constants::EIGHT_OCTETS;
+use crate::enumerations::PduType;
+
+const BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH: u16 = 20;
+pub const BASE_ENVIRONMENT_RECORD_LENGTH: u16 = 8;
+
+pub const BOUNDING_SPHERE_RECORD_TYPE: u32 = 65536;
+pub const POINT_RECORD_1_TYPE: u32 = 655360;
+pub const LINE_RECORD_1_TYPE: u32 = 786432;
+pub const SPHERE_RECORD_1_TYPE: u32 = 851968;
+pub const ELLIPSOID_RECORD_1_TYPE: u32 = 1048576;
+pub const CONE_RECORD_1_TYPE: u32 = 3145728;
+pub const RECTANGULAR_VOLUME_RECORD_1_TYPE: u32 = 5242880;
+pub const POINT_RECORD_2_TYPE: u32 = 167772160;
+pub const LINE_RECORD_2_TYPE: u32 = 201326592;
+pub const SPHERE_RECORD_2_TYPE: u32 = 218103808;
+pub const ELLIPSOID_RECORD_2_TYPE: u32 = 268435456;
+pub const CONE_RECORD_2_TYPE: u32 = 805306368;
+pub const RECTANGULAR_VOLUME_RECORD_2_TYPE: u32 = 1342177280;
+
+/// 5.11.2.2 Environmental Process PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct EnvironmentalProcess {
+    pub environmental_process_id: EntityId,
+    pub environment_type: EntityType,
+    pub model_type: u8, // Environmental Process-Model Type enumeration
+    pub environment_status: EnvironmentStatus,
+    pub sequence_number: u16,
+    pub environment_records: Vec<EnvironmentRecord>,
+}
+
+impl EnvironmentalProcess {
+    pub fn builder() -> EnvironmentalProcessBuilder {
+        EnvironmentalProcessBuilder::new()
+    }
+
+    pub fn into_builder(self) -> EnvironmentalProcessBuilder {
+        EnvironmentalProcessBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::EnvironmentalProcess(self)
+    }
+}
+
+impl BodyInfo for EnvironmentalProcess {
+    fn body_length(&self) -> u16 {
+        BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH
+            + self.environment_records.iter().map(|record| record.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EnvironmentalProcess
+    }
+}
+
+impl Interaction for EnvironmentalProcess {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_process_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Environment Status field of the Environmental Process PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EnvironmentStatus {
+    pub is_last: bool,
+    pub is_active: bool,
+}
+
+impl EnvironmentStatus {
+    pub fn with_is_last(mut self, is_last: bool) -> Self {
+        self.is_last = is_last;
+        self
+    }
+
+    pub fn with_is_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+}
+
+/// 6.2.31 Environment record
+///
+/// Each record starts with an 8-octet header (type, length in bits of the record-specific fields, and index),
+/// and is padded to a 64-bit boundary.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvironmentRecord {
+    pub index: u8,
+    pub data: EnvironmentRecordData,
+}
+
+impl EnvironmentRecord {
+    pub fn new(index: u8, data: EnvironmentRecordData) -> Self {
+        Self {
+            index,
+            data,
+        }
+    }
+
+    pub fn record_length(&self) -> u16 {
+        BASE_ENVIRONMENT_RECORD_LENGTH + (self.data.data_length() + self.padding_length()) as u16
+    }
+
+    /// The number of octets needed to pad the record-specific fields to a 64-bit boundary.
+    pub fn padding_length(&self) -> usize {
+        (EIGHT_OCTETS - self.data.data_length() % EIGHT_OCTETS) % EIGHT_OCTETS
+    }
+}
+
+/// The record-specific fields of an Environment record.
+///
+/// Geometry records are decoded; state records and other record types are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnvironmentRecordData {
+    BoundingSphere(BoundingSphereRecord),
+    Point1(PointRecord1),
+    Point2(PointRecord2),
+    Line1(LineRecord1),
+    Line2(LineRecord2),
+    Sphere1(SphereRecord1),
+    Sphere2(SphereRecord2),
+    Ellipsoid1(EllipsoidRecord1),
+    Ellipsoid2(EllipsoidRecord2),
+    Cone1(ConeRecord1),
+    Cone2(ConeRecord2),
+    RectangularVolume1(RectangularVolumeRecord1),
+    RectangularVolume2(RectangularVolumeRecord2),
+    Unspecified(u32, Vec<u8>),
+}
+
+impl Default for EnvironmentRecordData {
+    fn default() -> Self {
+        EnvironmentRecordData::Point1(PointRecord1::default())
+    }
+}
+
+impl EnvironmentRecordData {
+    pub fn record_type(&self) -> u32 {
+        match self {
+            EnvironmentRecordData::BoundingSphere(_) => { BOUNDING_SPHERE_RECORD_TYPE }
+            EnvironmentRecordData::Point1(_) => { POINT_RECORD_1_TYPE }
+            EnvironmentRecordData::Point2(_) => { POINT_RECORD_2_TYPE }
+            EnvironmentRecordData::Line1(_) => { LINE_RECORD_1_TYPE }
+            EnvironmentRecordData::Line2(_) => { LINE_RECORD_2_TYPE }
+            EnvironmentRecordData::Sphere1(_) => { SPHERE_RECORD_1_TYPE }
+            EnvironmentRecordData::Sphere2(_) => { SPHERE_RECORD_2_TYPE }
+            EnvironmentRecordData::Ellipsoid1(_) => { ELLIPSOID_RECORD_1_TYPE }
+            EnvironmentRecordData::Ellipsoid2(_) => { ELLIPSOID_RECORD_2_TYPE }
+            EnvironmentRecordData::Cone1(_) => { CONE_RECORD_1_TYPE }
+            EnvironmentRecordData::Cone2(_) => { CONE_RECORD_2_TYPE }
+            EnvironmentRecordData::RectangularVolume1(_) => { RECTANGULAR_VOLUME_RECORD_1_TYPE }
+            EnvironmentRecordData::RectangularVolume2(_) => { RECTANGULAR_VOLUME_RECORD_2_TYPE }
+            EnvironmentRecordData::Unspecified(record_type, _) => { *record_type }
+        }
+    }
+
+    /// Length in octets of the record-specific fields, without padding.
+    pub fn data_length(&self) -> usize {
+        match self {
+            EnvironmentRecordData::BoundingSphere(_) => { 28 }
+            EnvironmentRecordData::Point1(_) => { 24 }
+            EnvironmentRecordData::Point2(_) => { 36 }
+            EnvironmentRecordData::Line1(_) => { 48 }
+            EnvironmentRecordData::Line2(_) => { 72 }
+            EnvironmentRecordData::Sphere1(_) => { 28 }
+            EnvironmentRecordData::Sphere2(_) => { 56 }
+            EnvironmentRecordData::Ellipsoid1(_) => { 48 }
+            EnvironmentRecordData::Ellipsoid2(_) => { 84 }
+            EnvironmentRecordData::Cone1(_) => { 44 }
+            EnvironmentRecordData::Cone2(_) => { 76 }
+            EnvironmentRecordData::RectangularVolume1(_) => { 48 }
+            EnvironmentRecordData::RectangularVolume2(_) => { 84 }
+            EnvironmentRecordData::Unspecified(_, data) => { data.len() }
+        }
+    }
+}
+
+/// Bounding Sphere record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BoundingSphereRecord {
+    pub centroid: Location,
+    pub radius: f32,
+}
+
+impl BoundingSphereRecord {
+    pub fn new(centroid: Location, radius: f32) -> Self {
+        Self {
+            centroid,
+            radius,
+        }
+    }
+}
+
+/// Point 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PointRecord1 {
+    pub location: Location,
+}
+
+impl PointRecord1 {
+    pub fn new(location: Location) -> Self {
+        Self {
+            location,
+        }
+    }
+}
+
+/// Point 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PointRecord2 {
+    pub location: Location,
+    pub velocity: VectorF32,
+}
+
+impl PointRecord2 {
+    pub fn new(location: Location, velocity: VectorF32) -> Self {
+        Self {
+            location,
+            velocity,
+        }
+    }
+}
+
+/// Line 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LineRecord1 {
+    pub start_point: Location,
+    pub end_point: Location,
+}
+
+impl LineRecord1 {
+    pub fn new(start_point: Location, end_point: Location) -> Self {
+        Self {
+            start_point,
+            end_point,
+        }
+    }
+}
+
+/// Line 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LineRecord2 {
+    pub start_point: Location,
+    pub end_point: Location,
+    pub start_point_velocity: VectorF32,
+    pub end_point_velocity: VectorF32,
+}
+
+impl LineRecord2 {
+    pub fn new(start_point: Location, end_point: Location, start_point_velocity: VectorF32, end_point_velocity: VectorF32) -> Self {
+        Self {
+            start_point,
+            end_point,
+            start_point_velocity,
+            end_point_velocity,
+        }
+    }
+}
+
+/// Sphere 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SphereRecord1 {
+    pub centroid: Location,
+    pub radius: f32,
+}
+
+impl SphereRecord1 {
+    pub fn new(centroid: Location, radius: f32) -> Self {
+        Self {
+            centroid,
+            radius,
+        }
+    }
+}
+
+/// Sphere 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SphereRecord2 {
+    pub centroid: Location,
+    pub radius: f32,
+    pub radius_rate: f32,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// Ellipsoid 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EllipsoidRecord1 {
+    pub centroid: Location,
+    pub sigma: VectorF32,
+    pub orientation: Orientation,
+}
+
+impl EllipsoidRecord1 {
+    pub fn new(centroid: Location, sigma: VectorF32, orientation: Orientation) -> Self {
+        Self {
+            centroid,
+            sigma,
+            orientation,
+        }
+    }
+}
+
+/// Ellipsoid 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EllipsoidRecord2 {
+    pub centroid: Location,
+    pub sigma: VectorF32,
+    pub sigma_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// Cone 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ConeRecord1 {
+    pub vertex: Location,
+    pub orientation: Orientation,
+    pub height: f32,
+    pub peak_angle: f32,
+}
+
+impl ConeRecord1 {
+    pub fn new(vertex: Location, orientation: Orientation, height: f32, peak_angle: f32) -> Self {
+        Self {
+            vertex,
+            orientation,
+            height,
+            peak_angle,
+        }
+    }
+}
+
+/// Cone 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ConeRecord2 {
+    pub vertex: Location,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+    pub height: f32,
+    pub height_rate: f32,
+    pub peak_angle: f32,
+    pub peak_angle_rate: f32,
+}
+
+/// Rectangular Volume 1 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RectangularVolumeRecord1 {
+    pub corner_location: Location,
+    pub lengths: VectorF32,
+    pub orientation: Orientation,
+}
+
+impl RectangularVolumeRecord1 {
+    pub fn new(corner_location: Location, lengths: VectorF32, orientation: Orientation) -> Self {
+        Self {
+            corner_location,
+            lengths,
+            orientation,
+        }
+    }
+}
+
+/// Rectangular Volume 2 record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RectangularVolumeRecord2 {
+    pub corner_location: Location,
+    pub lengths: VectorF32,
+    pub lengths_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
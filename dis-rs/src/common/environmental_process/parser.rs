@@ -0,0 +1,284 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+use crate::common::environmental_process::model::{BOUNDING_SPHERE_RECORD_TYPE, BoundingSphereRecord, CONE_RECORD_1_TYPE, CONE_RECORD_2_TYPE, ConeRecord1, ConeRecord2, ELLIPSOID_RECORD_1_TYPE, ELLIPSOID_RECORD_2_TYPE, EllipsoidRecord1, EllipsoidRecord2, EnvironmentalProcess, EnvironmentRecord, EnvironmentRecordData, EnvironmentStatus, LINE_RECORD_1_TYPE, LINE_RECORD_2_TYPE, LineRecord1, LineRecord2, POINT_RECORD_1_TYPE, POINT_RECORD_2_TYPE, PointRecord1, PointRecord2, RECTANGULAR_VOLUME_RECORD_1_TYPE, RECTANGULAR_VOLUME_RECORD_2_TYPE, RectangularVolumeRecord1, RectangularVolumeRecord2, SPHERE_RECORD_1_TYPE, SPHERE_RECORD_2_TYPE, SphereRecord1, SphereRecord2};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, entity_type, location, orientation, vec3_f32};
+use crate::constants::{LEAST_SIGNIFICANT_BIT, ONE_BYTE_IN_BITS};
+
+pub fn environmental_process_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, environmental_process_id) = context("Environmental Process ID", entity_id)(input)?;
+    let (input, environment_type) = context("Environment Type", entity_type)(input)?;
+    let (input, model_type) = context("Model Type", be_u8)(input)?;
+    let (input, environment_status) = context("Environment Status", environment_status)(input)?;
+    let (input, number_of_environment_records) = context("Number of Environment Records", be_u16)(input)?;
+    let (input, sequence_number) = context("Sequence Number", be_u16)(input)?;
+    let (input, environment_records) = context("Environment Records", count(environment_record, number_of_environment_records as usize))(input)?;
+
+    let body = EnvironmentalProcess::builder()
+        .with_environmental_process_id(environmental_process_id)
+        .with_environment_type(environment_type)
+        .with_model_type(model_type)
+        .with_environment_status(environment_status)
+        .with_sequence_number(sequence_number)
+        .with_environment_records(environment_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn environment_status(input: &[u8]) -> IResult<&[u8], EnvironmentStatus> {
+    let (input, status) = be_u8(input)?;
+
+    Ok((input, EnvironmentStatus::default()
+        .with_is_last(status & LEAST_SIGNIFICANT_BIT as u8 != 0)
+        .with_is_active((status >> 1) & LEAST_SIGNIFICANT_BIT as u8 != 0)))
+}
+
+pub(crate) fn environment_record(input: &[u8]) -> IResult<&[u8], EnvironmentRecord> {
+    let (input, record_type) = be_u32(input)?;
+    let (input, record_length_bits) = be_u16(input)?;
+    let (input, index) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+
+    let (input, data) = match record_type {
+        BOUNDING_SPHERE_RECORD_TYPE => {
+            let (input, record) = bounding_sphere_record(input)?;
+            (input, EnvironmentRecordData::BoundingSphere(record))
+        }
+        POINT_RECORD_1_TYPE => {
+            let (input, record) = point_record_1(input)?;
+            (input, EnvironmentRecordData::Point1(record))
+        }
+        POINT_RECORD_2_TYPE => {
+            let (input, record) = point_record_2(input)?;
+            (input, EnvironmentRecordData::Point2(record))
+        }
+        LINE_RECORD_1_TYPE => {
+            let (input, record) = line_record_1(input)?;
+            (input, EnvironmentRecordData::Line1(record))
+        }
+        LINE_RECORD_2_TYPE => {
+            let (input, record) = line_record_2(input)?;
+            (input, EnvironmentRecordData::Line2(record))
+        }
+        SPHERE_RECORD_1_TYPE => {
+            let (input, record) = sphere_record_1(input)?;
+            (input, EnvironmentRecordData::Sphere1(record))
+        }
+        SPHERE_RECORD_2_TYPE => {
+            let (input, record) = sphere_record_2(input)?;
+            (input, EnvironmentRecordData::Sphere2(record))
+        }
+        ELLIPSOID_RECORD_1_TYPE => {
+            let (input, record) = ellipsoid_record_1(input)?;
+            (input, EnvironmentRecordData::Ellipsoid1(record))
+        }
+        ELLIPSOID_RECORD_2_TYPE => {
+            let (input, record) = ellipsoid_record_2(input)?;
+            (input, EnvironmentRecordData::Ellipsoid2(record))
+        }
+        CONE_RECORD_1_TYPE => {
+            let (input, record) = cone_record_1(input)?;
+            (input, EnvironmentRecordData::Cone1(record))
+        }
+        CONE_RECORD_2_TYPE => {
+            let (input, record) = cone_record_2(input)?;
+            (input, EnvironmentRecordData::Cone2(record))
+        }
+        RECTANGULAR_VOLUME_RECORD_1_TYPE => {
+            let (input, record) = rectangular_volume_record_1(input)?;
+            (input, EnvironmentRecordData::RectangularVolume1(record))
+        }
+        RECTANGULAR_VOLUME_RECORD_2_TYPE => {
+            let (input, record) = rectangular_volume_record_2(input)?;
+            (input, EnvironmentRecordData::RectangularVolume2(record))
+        }
+        _ => {
+            let record_length_bytes = (record_length_bits as usize).div_ceil(ONE_BYTE_IN_BITS);
+            let (input, data) = take(record_length_bytes)(input)?;
+            (input, EnvironmentRecordData::Unspecified(record_type, data.to_vec()))
+        }
+    };
+
+    let record = EnvironmentRecord::new(index, data);
+    let (input, _padding) = take(record.padding_length())(input)?;
+
+    Ok((input, record))
+}
+
+fn bounding_sphere_record(input: &[u8]) -> IResult<&[u8], BoundingSphereRecord> {
+    let (input, centroid) = location(input)?;
+    let (input, radius) = be_f32(input)?;
+
+    Ok((input, BoundingSphereRecord {
+        centroid,
+        radius,
+    }))
+}
+
+fn point_record_1(input: &[u8]) -> IResult<&[u8], PointRecord1> {
+    let (input, location) = location(input)?;
+
+    Ok((input, PointRecord1 {
+        location,
+    }))
+}
+
+fn point_record_2(input: &[u8]) -> IResult<&[u8], PointRecord2> {
+    let (input, location) = location(input)?;
+    let (input, velocity) = vec3_f32(input)?;
+
+    Ok((input, PointRecord2 {
+        location,
+        velocity,
+    }))
+}
+
+fn line_record_1(input: &[u8]) -> IResult<&[u8], LineRecord1> {
+    let (input, start_point) = location(input)?;
+    let (input, end_point) = location(input)?;
+
+    Ok((input, LineRecord1 {
+        start_point,
+        end_point,
+    }))
+}
+
+fn line_record_2(input: &[u8]) -> IResult<&[u8], LineRecord2> {
+    let (input, start_point) = location(input)?;
+    let (input, end_point) = location(input)?;
+    let (input, start_point_velocity) = vec3_f32(input)?;
+    let (input, end_point_velocity) = vec3_f32(input)?;
+
+    Ok((input, LineRecord2 {
+        start_point,
+        end_point,
+        start_point_velocity,
+        end_point_velocity,
+    }))
+}
+
+fn sphere_record_1(input: &[u8]) -> IResult<&[u8], SphereRecord1> {
+    let (input, centroid) = location(input)?;
+    let (input, radius) = be_f32(input)?;
+
+    Ok((input, SphereRecord1 {
+        centroid,
+        radius,
+    }))
+}
+
+fn sphere_record_2(input: &[u8]) -> IResult<&[u8], SphereRecord2> {
+    let (input, centroid) = location(input)?;
+    let (input, radius) = be_f32(input)?;
+    let (input, radius_rate) = be_f32(input)?;
+    let (input, velocity) = vec3_f32(input)?;
+    let (input, angular_velocity) = vec3_f32(input)?;
+
+    Ok((input, SphereRecord2 {
+        centroid,
+        radius,
+        radius_rate,
+        velocity,
+        angular_velocity,
+    }))
+}
+
+fn ellipsoid_record_1(input: &[u8]) -> IResult<&[u8], EllipsoidRecord1> {
+    let (input, centroid) = location(input)?;
+    let (input, sigma) = vec3_f32(input)?;
+    let (input, orientation) = orientation(input)?;
+
+    Ok((input, EllipsoidRecord1 {
+        centroid,
+        sigma,
+        orientation,
+    }))
+}
+
+fn ellipsoid_record_2(input: &[u8]) -> IResult<&[u8], EllipsoidRecord2> {
+    let (input, centroid) = location(input)?;
+    let (input, sigma) = vec3_f32(input)?;
+    let (input, sigma_rate) = vec3_f32(input)?;
+    let (input, orientation) = orientation(input)?;
+    let (input, velocity) = vec3_f32(input)?;
+    let (input, angular_velocity) = vec3_f32(input)?;
+
+    Ok((input, EllipsoidRecord2 {
+        centroid,
+        sigma,
+        sigma_rate,
+        orientation,
+        velocity,
+        angular_velocity,
+    }))
+}
+
+fn cone_record_1(input: &[u8]) -> IResult<&[u8], ConeRecord1> {
+    let (input, vertex) = location(input)?;
+    let (input, orientation) = orientation(input)?;
+    let (input, height) = be_f32(input)?;
+    let (input, peak_angle) = be_f32(input)?;
+
+    Ok((input, ConeRecord1 {
+        vertex,
+        orientation,
+        height,
+        peak_angle,
+    }))
+}
+
+fn cone_record_2(input: &[u8]) -> IResult<&[u8], ConeRecord2> {
+    let (input, vertex) = location(input)?;
+    let (input, orientation) = orientation(input)?;
+    let (input, velocity) = vec3_f32(input)?;
+    let (input, angular_velocity) = vec3_f32(input)?;
+    let (input, height) = be_f32(input)?;
+    let (input, height_rate) = be_f32(input)?;
+    let (input, peak_angle) = be_f32(input)?;
+    let (input, peak_angle_rate) = be_f32(input)?;
+
+    Ok((input, ConeRecord2 {
+        vertex,
+        orientation,
+        velocity,
+        angular_velocity,
+        height,
+        height_rate,
+        peak_angle,
+        peak_angle_rate,
+    }))
+}
+
+fn rectangular_volume_record_1(input: &[u8]) -> IResult<&[u8], RectangularVolumeRecord1> {
+    let (input, corner_location) = location(input)?;
+    let (input, lengths) = vec3_f32(input)?;
+    let (input, orientation) = orientation(input)?;
+
+    Ok((input, RectangularVolumeRecord1 {
+        corner_location,
+        lengths,
+        orientation,
+    }))
+}
+
+fn rectangular_volume_record_2(input: &[u8]) -> IResult<&[u8], RectangularVolumeRecord2> {
+    let (input, corner_location) = location(input)?;
+    let (input, lengths) = vec3_f32(input)?;
+    let (input, lengths_rate) = vec3_f32(input)?;
+    let (input, orientation) = orientation(input)?;
+    let (input, velocity) = vec3_f32(input)?;
+    let (input, angular_velocity) = vec3_f32(input)?;
+
+    Ok((input, RectangularVolumeRecord2 {
+        corner_location,
+        lengths,
+        lengths_rate,
+        orientation,
+        velocity,
+        angular_velocity,
+    }))
+}
\ No newline at end of file
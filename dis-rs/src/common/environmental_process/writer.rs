@@ -0,0 +1,120 @@
+use bytes::BufMut;
+use crate::common::environmental_process::model::{EnvironmentalProcess, EnvironmentRecord, EnvironmentRecordData, EnvironmentStatus};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+use crate::constants::ONE_BYTE_IN_BITS;
+
+impl SerializePdu for EnvironmentalProcess {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let process_id_bytes = self.environmental_process_id.serialize(buf);
+        let environment_type_bytes = self.environment_type.serialize(buf);
+        buf.put_u8(self.model_type);
+        let status_bytes = self.environment_status.serialize(buf);
+        buf.put_u16(self.environment_records.len() as u16);
+        buf.put_u16(self.sequence_number);
+        let records_bytes = self.environment_records.iter()
+            .map(|record| record.serialize(buf))
+            .sum::<u16>();
+
+        process_id_bytes + environment_type_bytes + 1 + status_bytes + 4 + records_bytes
+    }
+}
+
+impl Serialize for EnvironmentStatus {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let status = u8::from(self.is_last) |
+            (u8::from(self.is_active) << 1);
+        buf.put_u8(status);
+        1
+    }
+}
+
+impl Serialize for EnvironmentRecord {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(self.data.record_type());
+        buf.put_u16((self.data.data_length() * ONE_BYTE_IN_BITS) as u16);
+        buf.put_u8(self.index);
+        buf.put_u8(0u8);
+        match &self.data {
+            EnvironmentRecordData::BoundingSphere(record) => {
+                record.centroid.serialize(buf);
+                buf.put_f32(record.radius);
+            }
+            EnvironmentRecordData::Point1(record) => {
+                record.location.serialize(buf);
+            }
+            EnvironmentRecordData::Point2(record) => {
+                record.location.serialize(buf);
+                record.velocity.serialize(buf);
+            }
+            EnvironmentRecordData::Line1(record) => {
+                record.start_point.serialize(buf);
+                record.end_point.serialize(buf);
+            }
+            EnvironmentRecordData::Line2(record) => {
+                record.start_point.serialize(buf);
+                record.end_point.serialize(buf);
+                record.start_point_velocity.serialize(buf);
+                record.end_point_velocity.serialize(buf);
+            }
+            EnvironmentRecordData::Sphere1(record) => {
+                record.centroid.serialize(buf);
+                buf.put_f32(record.radius);
+            }
+            EnvironmentRecordData::Sphere2(record) => {
+                record.centroid.serialize(buf);
+                buf.put_f32(record.radius);
+                buf.put_f32(record.radius_rate);
+                record.velocity.serialize(buf);
+                record.angular_velocity.serialize(buf);
+            }
+            EnvironmentRecordData::Ellipsoid1(record) => {
+                record.centroid.serialize(buf);
+                record.sigma.serialize(buf);
+                record.orientation.serialize(buf);
+            }
+            EnvironmentRecordData::Ellipsoid2(record) => {
+                record.centroid.serialize(buf);
+                record.sigma.serialize(buf);
+                record.sigma_rate.serialize(buf);
+                record.orientation.serialize(buf);
+                record.velocity.serialize(buf);
+                record.angular_velocity.serialize(buf);
+            }
+            EnvironmentRecordData::Cone1(record) => {
+                record.vertex.serialize(buf);
+                record.orientation.serialize(buf);
+                buf.put_f32(record.height);
+                buf.put_f32(record.peak_angle);
+            }
+            EnvironmentRecordData::Cone2(record) => {
+                record.vertex.serialize(buf);
+                record.orientation.serialize(buf);
+                record.velocity.serialize(buf);
+                record.angular_velocity.serialize(buf);
+                buf.put_f32(record.height);
+                buf.put_f32(record.height_rate);
+                buf.put_f32(record.peak_angle);
+                buf.put_f32(record.peak_angle_rate);
+            }
+            EnvironmentRecordData::RectangularVolume1(record) => {
+                record.corner_location.serialize(buf);
+                record.lengths.serialize(buf);
+                record.orientation.serialize(buf);
+            }
+            EnvironmentRecordData::RectangularVolume2(record) => {
+                record.corner_location.serialize(buf);
+                record.lengths.serialize(buf);
+                record.lengths_rate.serialize(buf);
+                record.orientation.serialize(buf);
+                record.velocity.serialize(buf);
+                record.angular_velocity.serialize(buf);
+            }
+            EnvironmentRecordData::Unspecified(_, data) => {
+                buf.put_slice(data);
+            }
+        }
+        buf.put_bytes(0u8, self.padding_length());
+
+        self.record_length()
+    }
+}
@@ -1,4 +1,8 @@
 use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use nom::ErrorConvert;
+use nom::error::{ContextError, ParseError};
+use crate::enumerations::{IsGroupOfGroupedEntityCategory, PduType, ProtocolVersion};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum DisError {
@@ -10,6 +14,16 @@ pub enum DisError {
     StringTooLongError,     // the String value to serialize is too large for the field specification
     IffIncorrectSystemType, // the System Type in an IFF PDU is incorrect (to determine the type for parsing the basic data)
     IffUndeterminedSystemType, // the System Type in an IFF PDU does not determine whether it is an Interrogator or a Transponder
+    PduTooLarge(usize), // the PDU to serialize exceeds MAX_PDU_SIZE_OCTETS; (usize size of the PDU)
+    PduLengthMismatch(u16, u16), // the length of the PDU to serialize is inconsistent; (u16 expected, u16 found)
+    RecordCountOverflow(usize, usize), // a list of records is too long for the count field on the wire; (usize found, usize maximum)
+    InsufficientBufferSize(usize, usize), // the buffer is too small to serialize the PDU into; (usize required, usize available)
+    IoError(ErrorKind, String), // an I/O operation of a transport failed; (kind of the error, description)
+    NotARequest(PduType), // the PDU is not a simulation management request that expects a response; (PduType of the PDU)
+    ExceedsMtu(usize, usize), // the PDU does not fit in a single datagram; (usize size of the PDU, usize MTU)
+    CaptureError(String), // a capture file is malformed, or a datagram cannot be written to it; (description)
+    RecordLengthMismatch(usize, u16), // a record in a record set differs in length from the record length of the set; (usize length of the record, u16 record length of the set)
+    GroupEntityDescriptionMismatch(IsGroupOfGroupedEntityCategory), // the GED records of an IsGroupOf PDU do not all match the Grouped Entity Category, or records of an undefined category differ in length; (the Grouped Entity Category)
 }
 
 impl Display for DisError {
@@ -22,6 +36,84 @@ impl Display for DisError {
             DisError::StringTooLongError => { f.write_str("Provided String is too long.") }
             DisError::IffIncorrectSystemType => { f.write_str("IFF PDU - Incorrect System Time provided.") }
             DisError::IffUndeterminedSystemType => { f.write_str("IFF PDU - Undetermined System Time.") }
+            DisError::PduTooLarge(size) => { f.write_fmt(format_args!("PDU of {} octets exceeds the maximum PDU size", size)) }
+            DisError::PduLengthMismatch(expected, found) => { f.write_fmt(format_args!("PDU length is inconsistent. Expected {}, found {}", expected, found)) }
+            DisError::RecordCountOverflow(found, maximum) => { f.write_fmt(format_args!("Number of records {} exceeds the maximum of {}", found, maximum)) }
+            DisError::InsufficientBufferSize(required, available) => { f.write_fmt(format_args!("Buffer has insufficient size. Required {}, available {}", required, available)) }
+            DisError::IoError(_kind, message) => { f.write_fmt(format_args!("I/O error: {}", message)) }
+            DisError::NotARequest(pdu_type) => { f.write_fmt(format_args!("PDU of type {:?} is not a simulation management request", pdu_type)) }
+            DisError::ExceedsMtu(size, mtu) => { f.write_fmt(format_args!("PDU of {} octets exceeds the MTU of {} octets", size, mtu)) }
+            DisError::CaptureError(message) => { f.write_fmt(format_args!("Capture error: {}", message)) }
+            DisError::RecordLengthMismatch(found, expected) => { f.write_fmt(format_args!("Record of {} octets does not match the record length of {} octets of its record set", found, expected)) }
+            DisError::GroupEntityDescriptionMismatch(category) => { f.write_fmt(format_args!("Group Entity Description records do not match the Grouped Entity Category {:?}", category)) }
         }
     }
-}
\ No newline at end of file
+}
+
+impl From<std::io::Error> for DisError {
+    fn from(error: std::io::Error) -> Self {
+        DisError::IoError(error.kind(), error.to_string())
+    }
+}
+
+/// The reason why a single PDU in a buffer could not be parsed.
+/// Field offsets are in octets, counted from the start of the PDU.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PduParseError {
+    /// The PDU Length field of the header is smaller than a header, or larger than the remaining input
+    LengthMismatch { pdu_length: u16, available: usize },
+    /// A field contains a value that is not a known enumeration value, so the PDU cannot be interpreted
+    UnknownEnumeration { field: &'static str, value: u32 },
+    /// The PDU ends before the field at the given offset could be read
+    TruncatedRecord { field: &'static str, field_offset: usize },
+    /// The field at the given offset contains invalid data
+    InvalidRecord { field: &'static str, field_offset: usize },
+    /// The protocol version of the PDU is not supported by this crate
+    UnsupportedProtocolVersion(ProtocolVersion),
+}
+
+impl Display for PduParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PduParseError::LengthMismatch { pdu_length, available } => { f.write_fmt(format_args!("PDU Length field states {} octets, but {} octets are available", pdu_length, available)) }
+            PduParseError::UnknownEnumeration { field, value } => { f.write_fmt(format_args!("Unknown enumeration value {} for field {}", value, field)) }
+            PduParseError::TruncatedRecord { field, field_offset } => { f.write_fmt(format_args!("PDU is truncated at field {} (octet {})", field, field_offset)) }
+            PduParseError::InvalidRecord { field, field_offset } => { f.write_fmt(format_args!("Invalid data at field {} (octet {})", field, field_offset)) }
+            PduParseError::UnsupportedProtocolVersion(version) => { f.write_fmt(format_args!("Unsupported protocol version {:?}", version)) }
+        }
+    }
+}
+
+/// The error type of the nom parsers of this crate.
+/// Like `nom::error::Error` it holds the remaining input and the kind of error,
+/// and in addition the name of the innermost field or record of the PDU that failed to parse, when known.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldParseError<I> {
+    pub input: I,
+    pub code: nom::error::ErrorKind,
+    pub field: Option<&'static str>,
+}
+
+impl<I> ParseError<I> for FieldParseError<I> {
+    fn from_error_kind(input: I, kind: nom::error::ErrorKind) -> Self {
+        Self { input, code: kind, field: None }
+    }
+
+    fn append(_input: I, _kind: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> ContextError<I> for FieldParseError<I> {
+    /// Keeps the innermost context, being the most specific name for the location of the error.
+    fn add_context(_input: I, context: &'static str, mut other: Self) -> Self {
+        other.field.get_or_insert(context);
+        other
+    }
+}
+
+impl<I> ErrorConvert<FieldParseError<I>> for FieldParseError<(I, usize)> {
+    fn convert(self) -> FieldParseError<I> {
+        FieldParseError { input: self.input.0, code: self.code, field: self.field }
+    }
+}
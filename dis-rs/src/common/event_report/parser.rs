@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::parser::{datum_specification, entity_id};
 use crate::enumerations::EventType;
@@ -6,12 +7,12 @@ use crate::common::event_report::model::EventReport;
 use crate::common::model::PduBody;
 
 pub fn event_report_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, event_type) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, event_type) = context("Event Type", be_u32)(input)?;
     let event_type = EventType::from(event_type);
-    let (input, _padding) = be_u32(input)?;
-    let (input, datums) = datum_specification(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
 
     let body = EventReport::new()
         .with_origination_id(originating_id)
@@ -1,3 +1,4 @@
 pub mod parser;
 pub mod model;
-pub mod writer;
\ No newline at end of file
+pub mod writer;
+pub mod view;
\ No newline at end of file
@@ -2,7 +2,7 @@ use crate::common::{BodyInfo, Interaction};
 use crate::common::model::{DescriptorRecord, EntityId, EventId, Location, VectorF32, EntityType, MunitionDescriptor, PduBody};
 use crate::enumerations::PduType;
 
-const FIRE_BODY_LENGTH : u16 = 28;
+pub(crate) const FIRE_BODY_LENGTH : u16 = 84;
 
 #[derive(Debug, PartialEq)]
 pub struct Fire {
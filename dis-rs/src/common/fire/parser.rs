@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::{be_f32, be_u32};
 use crate::common::fire::model::Fire;
 use crate::common::parser::{entity_id, event_id, location, vec3_f32};
@@ -10,15 +11,15 @@ pub fn fire_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody
     move |input: &[u8]| {
         let fti = header.pdu_status.unwrap_or_default()
             .fire_type_indicator.unwrap_or(FireTypeIndicator::Munition);
-        let (input, firing_entity_id) = entity_id(input)?;
-        let (input, target_entity_id) = entity_id(input)?;
-        let (input, munition_id) = entity_id(input)?;
-        let (input, event_id) = event_id(input)?;
-        let (input, fire_mission_index) = be_u32(input)?;
-        let (input, location_in_world) = location(input)?;
-        let (input, descriptor) = parser::descriptor_record_fti(fti)(input)?;
-        let (input, velocity) = vec3_f32(input)?;
-        let (input, range) = be_f32(input)?;
+        let (input, firing_entity_id) = context("Firing Entity ID", entity_id)(input)?;
+        let (input, target_entity_id) = context("Target Entity ID", entity_id)(input)?;
+        let (input, munition_id) = context("Munition ID", entity_id)(input)?;
+        let (input, event_id) = context("Event ID", event_id)(input)?;
+        let (input, fire_mission_index) = context("Fire Mission Index", be_u32)(input)?;
+        let (input, location_in_world) = context("Location in World Coordinates", location)(input)?;
+        let (input, descriptor) = context("Descriptor", parser::descriptor_record_fti(fti))(input)?;
+        let (input, velocity) = context("Velocity", vec3_f32)(input)?;
+        let (input, range) = context("Range", be_f32)(input)?;
 
         let body = Fire {
             firing_entity_id,
@@ -0,0 +1,106 @@
+use crate::common::errors::DisError;
+use crate::common::fire::model::FIRE_BODY_LENGTH;
+use crate::common::model::{DescriptorRecord, EntityId, EventId, Location, VectorF32};
+use crate::common::parser::{descriptor_record_fti, entity_id, event_id, location, vec3_f32};
+use crate::common::view::{check_body_length, decode, read_f32, read_u32};
+use crate::enumerations::FireTypeIndicator;
+
+const FIRING_ENTITY_ID_OFFSET: usize = 0;
+const TARGET_ENTITY_ID_OFFSET: usize = 6;
+const MUNITION_ENTITY_ID_OFFSET: usize = 12;
+const EVENT_ID_OFFSET: usize = 18;
+const FIRE_MISSION_INDEX_OFFSET: usize = 24;
+const LOCATION_IN_WORLD_OFFSET: usize = 28;
+const DESCRIPTOR_OFFSET: usize = 52;
+const VELOCITY_OFFSET: usize = 68;
+const RANGE_OFFSET: usize = 80;
+
+/// A borrowed view over the body of a Fire PDU (5.4.3), decoding fields when they are accessed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FireView<'a> {
+    body: &'a [u8],
+    fire_type_indicator: FireTypeIndicator,
+}
+
+impl<'a> FireView<'a> {
+    /// Creates a view over a Fire body. The fire type indicator (from the PDU Status of the header) determines
+    /// whether the descriptor is a munition or an expendable descriptor.
+    pub fn new(body: &'a [u8], fire_type_indicator: FireTypeIndicator) -> Result<Self, DisError> {
+        check_body_length(body, FIRE_BODY_LENGTH as usize)?;
+        Ok(Self { body, fire_type_indicator })
+    }
+
+    pub fn firing_entity_id(&self) -> EntityId {
+        decode(self.body, FIRING_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn target_entity_id(&self) -> EntityId {
+        decode(self.body, TARGET_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    /// The id of the fired munition or expendable
+    pub fn entity_id(&self) -> EntityId {
+        decode(self.body, MUNITION_ENTITY_ID_OFFSET, entity_id)
+    }
+
+    pub fn event_id(&self) -> EventId {
+        decode(self.body, EVENT_ID_OFFSET, event_id)
+    }
+
+    pub fn fire_mission_index(&self) -> u32 {
+        read_u32(self.body, FIRE_MISSION_INDEX_OFFSET)
+    }
+
+    pub fn location_in_world(&self) -> Location {
+        decode(self.body, LOCATION_IN_WORLD_OFFSET, location)
+    }
+
+    pub fn descriptor(&self) -> DescriptorRecord {
+        decode(self.body, DESCRIPTOR_OFFSET, descriptor_record_fti(self.fire_type_indicator))
+    }
+
+    pub fn velocity(&self) -> VectorF32 {
+        decode(self.body, VELOCITY_OFFSET, vec3_f32)
+    }
+
+    pub fn range(&self) -> f32 {
+        read_f32(self.body, RANGE_OFFSET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::fire::model::Fire;
+    use crate::common::model::{EntityId, EntityType, EventId, Location, MunitionDescriptor, Pdu, PduBody, PduHeader, SimulationAddress, VectorF32};
+    use crate::common::Serialize;
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{EntityKind, PduType};
+
+    #[test]
+    fn fire_view_matches_parsed() {
+        let fire = Fire::new(EntityId::new(1, 1, 1), EntityId::new(2, 2, 2), EntityId::new(1, 1, 3), EventId::new(SimulationAddress::new(1, 1), 7))
+            .with_fire_mission_index(12)
+            .with_location_in_world(Location::new(10.0, 20.0, 30.0))
+            .with_munition_descriptor(EntityType::default().with_kind(EntityKind::Munition), MunitionDescriptor::default())
+            .with_velocity(VectorF32::new(100.0, 0.0, 0.0))
+            .with_range(500.0);
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::Fire), fire.into_pdu_body(), 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+
+        let pdu_view = PduView::new(&buf).unwrap();
+        let view = if let Ok(BodyView::Fire(view)) = pdu_view.body_view() { view } else { panic!("Expected a Fire view") };
+        let fire = if let PduBody::Fire(fire) = pdu_view.to_pdu().unwrap().body { fire } else { panic!() };
+
+        assert_eq!(view.firing_entity_id(), fire.firing_entity_id);
+        assert_eq!(view.target_entity_id(), fire.target_entity_id);
+        assert_eq!(view.entity_id(), fire.entity_id);
+        assert_eq!(view.event_id(), fire.event_id);
+        assert_eq!(view.fire_mission_index(), 12);
+        assert_eq!(view.location_in_world(), fire.location_in_world);
+        assert_eq!(view.descriptor(), fire.descriptor);
+        assert_eq!(view.velocity(), fire.velocity);
+        assert_eq!(view.range(), 500.0);
+    }
+}
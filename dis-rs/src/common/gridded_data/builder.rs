@@ -0,0 +1,88 @@
+use crate::common::gridded_data::model::{GridAxisDescriptor, GridData, GriddedData};
+use crate::common::model::{ClockTime, EntityId, EntityType, Orientation};
+
+pub struct GriddedDataBuilder(GriddedData);
+
+impl GriddedDataBuilder {
+    pub fn new() -> Self {
+        GriddedDataBuilder(GriddedData::default())
+    }
+
+    pub fn new_from_body(body: GriddedData) -> Self {
+        GriddedDataBuilder(body)
+    }
+
+    pub fn build(self) -> GriddedData {
+        self.0
+    }
+
+    pub fn with_environmental_simulation_id(mut self, environmental_simulation_id: EntityId) -> Self {
+        self.0.environmental_simulation_id = environmental_simulation_id;
+        self
+    }
+
+    pub fn with_field_number(mut self, field_number: u16) -> Self {
+        self.0.field_number = field_number;
+        self
+    }
+
+    pub fn with_pdu_number(mut self, pdu_number: u16) -> Self {
+        self.0.pdu_number = pdu_number;
+        self
+    }
+
+    pub fn with_pdu_total(mut self, pdu_total: u16) -> Self {
+        self.0.pdu_total = pdu_total;
+        self
+    }
+
+    pub fn with_coordinate_system(mut self, coordinate_system: u16) -> Self {
+        self.0.coordinate_system = coordinate_system;
+        self
+    }
+
+    pub fn with_constant_grid(mut self, constant_grid: u8) -> Self {
+        self.0.constant_grid = constant_grid;
+        self
+    }
+
+    pub fn with_environment_type(mut self, environment_type: EntityType) -> Self {
+        self.0.environment_type = environment_type;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.0.orientation = orientation;
+        self
+    }
+
+    pub fn with_sample_time(mut self, sample_time: ClockTime) -> Self {
+        self.0.sample_time = sample_time;
+        self
+    }
+
+    pub fn with_total_values(mut self, total_values: u32) -> Self {
+        self.0.total_values = total_values;
+        self
+    }
+
+    pub fn with_grid_axis_descriptors(mut self, grid_axis_descriptors: Vec<GridAxisDescriptor>) -> Self {
+        self.0.grid_axis_descriptors = grid_axis_descriptors;
+        self
+    }
+
+    pub fn with_grid_axis_descriptor(mut self, grid_axis_descriptor: GridAxisDescriptor) -> Self {
+        self.0.grid_axis_descriptors.push(grid_axis_descriptor);
+        self
+    }
+
+    pub fn with_grid_data(mut self, grid_data: Vec<GridData>) -> Self {
+        self.0.grid_data = grid_data;
+        self
+    }
+
+    pub fn with_grid_data_record(mut self, grid_data_record: GridData) -> Self {
+        self.0.grid_data.push(grid_data_record);
+        self
+    }
+}
@@ -0,0 +1,68 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::gridded_data::model::{GridAxisDescriptor, GridAxisIrregular, GridAxisRegular, GridData, GridDataRepresentation, GridDataType0, GridDataType1, GridDataType2, GriddedData};
+    use crate::common::model::{ClockTime, DisTimeStamp, EntityId, EntityType, Orientation, Pdu, PduHeader};
+    use crate::enumerations::{EntityKind, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn gridded_data_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::GriddedData);
+
+        let body = GriddedData::builder()
+            .with_environmental_simulation_id(EntityId::new(1, 1, 0))
+            .with_field_number(1)
+            .with_pdu_number(1)
+            .with_pdu_total(1)
+            .with_coordinate_system(1)
+            .with_constant_grid(1)
+            .with_environment_type(EntityType::default()
+                .with_kind(EntityKind::Environmental))
+            .with_orientation(Orientation::default())
+            .with_sample_time(ClockTime::new(10, 2000))
+            .with_total_values(9)
+            .with_grid_axis_descriptor(GridAxisDescriptor::Regular(GridAxisRegular::default()
+                .with_domain_initial_x_i(0.0)
+                .with_domain_final_x_i(100.0)
+                .with_domain_points_x_i(3)
+                .with_interleaf_factor(1)
+                .with_number_of_points_on_x_i_axis(3)))
+            .with_grid_axis_descriptor(GridAxisDescriptor::Irregular(GridAxisIrregular::default()
+                .with_domain_initial_x_i(0.0)
+                .with_domain_final_x_i(50.0)
+                .with_domain_points_x_i(3)
+                .with_interleaf_factor(1)
+                .with_coordinate_scale_x_i(1.0)
+                .with_x_i_values(vec![0, 10, 50])))
+            .with_grid_data_record(GridData::new(1, GridDataRepresentation::Type0(GridDataType0::new(vec![1, 2, 3]))))
+            .with_grid_data_record(GridData::new(2, GridDataRepresentation::Type1(GridDataType1::new(0.5, -10.0, vec![1, 2, 3]))))
+            .with_grid_data_record(GridData::new(3, GridDataRepresentation::Type2(GridDataType2::new(vec![1.5, 2.5, 3.5]))))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
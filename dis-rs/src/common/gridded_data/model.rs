@@ -0,0 +1,317 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::gridded_data::builder::GriddedDataBuilder;
+use crate::common::model::{ClockTime, EntityId, EntityType, Orientation, PduBody};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS};
+use crate::enumerations::PduType;
+
+const BASE_GRIDDED_DATA_BODY_LENGTH: u16 = 52;
+pub const REGULAR_GRID_AXIS_DESCRIPTOR_LENGTH: u16 = 24;
+pub const BASE_IRREGULAR_GRID_AXIS_DESCRIPTOR_LENGTH: u16 = 40;
+pub const BASE_GRID_DATA_TYPE_0_LENGTH: u16 = 6;
+pub const BASE_GRID_DATA_TYPE_1_LENGTH: u16 = 14;
+pub const BASE_GRID_DATA_TYPE_2_LENGTH: u16 = 8;
+
+pub const GRID_AXIS_TYPE_REGULAR: u8 = 0;
+pub const GRID_AXIS_TYPE_IRREGULAR: u8 = 1;
+pub const DATA_REPRESENTATION_TYPE_0: u16 = 0;
+pub const DATA_REPRESENTATION_TYPE_1: u16 = 1;
+pub const DATA_REPRESENTATION_TYPE_2: u16 = 2;
+
+/// 5.11.2.3 Gridded Data PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct GriddedData {
+    pub environmental_simulation_id: EntityId,
+    pub field_number: u16,
+    pub pdu_number: u16,
+    pub pdu_total: u16,
+    pub coordinate_system: u16, // Gridded Data-Coordinate System enumeration
+    pub constant_grid: u8, // Gridded Data-Constant Grid enumeration
+    pub environment_type: EntityType,
+    pub orientation: Orientation,
+    pub sample_time: ClockTime,
+    pub total_values: u32,
+    pub grid_axis_descriptors: Vec<GridAxisDescriptor>,
+    pub grid_data: Vec<GridData>,
+}
+
+impl GriddedData {
+    pub fn builder() -> GriddedDataBuilder {
+        GriddedDataBuilder::new()
+    }
+
+    pub fn into_builder(self) -> GriddedDataBuilder {
+        GriddedDataBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::GriddedData(self)
+    }
+}
+
+impl BodyInfo for GriddedData {
+    fn body_length(&self) -> u16 {
+        BASE_GRIDDED_DATA_BODY_LENGTH
+            + self.grid_axis_descriptors.iter().map(|axis| axis.record_length()).sum::<u16>()
+            + self.grid_data.iter().map(|data| data.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::GriddedData
+    }
+}
+
+impl Interaction for GriddedData {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.40 Grid Axis Descriptor record
+///
+/// Regular axes have evenly spaced points between the initial and final value,
+/// irregular axes list the location of each point explicitly.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridAxisDescriptor {
+    Regular(GridAxisRegular),
+    Irregular(GridAxisIrregular),
+}
+
+impl Default for GridAxisDescriptor {
+    fn default() -> Self {
+        GridAxisDescriptor::Regular(GridAxisRegular::default())
+    }
+}
+
+impl GridAxisDescriptor {
+    pub fn record_length(&self) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(_) => { REGULAR_GRID_AXIS_DESCRIPTOR_LENGTH }
+            GridAxisDescriptor::Irregular(axis) => {
+                let values_length = axis.x_i_values.len() * 2;
+                BASE_IRREGULAR_GRID_AXIS_DESCRIPTOR_LENGTH + (values_length + axis.padding_length()) as u16
+            }
+        }
+    }
+}
+
+/// 6.2.40.2 Grid Axis Descriptor record for a regular axis
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GridAxisRegular {
+    pub domain_initial_x_i: f64,
+    pub domain_final_x_i: f64,
+    pub domain_points_x_i: u16,
+    pub interleaf_factor: u8,
+    pub number_of_points_on_x_i_axis: u16,
+    pub initial_index_x_i: u16,
+}
+
+impl GridAxisRegular {
+    pub fn with_domain_initial_x_i(mut self, domain_initial_x_i: f64) -> Self {
+        self.domain_initial_x_i = domain_initial_x_i;
+        self
+    }
+
+    pub fn with_domain_final_x_i(mut self, domain_final_x_i: f64) -> Self {
+        self.domain_final_x_i = domain_final_x_i;
+        self
+    }
+
+    pub fn with_domain_points_x_i(mut self, domain_points_x_i: u16) -> Self {
+        self.domain_points_x_i = domain_points_x_i;
+        self
+    }
+
+    pub fn with_interleaf_factor(mut self, interleaf_factor: u8) -> Self {
+        self.interleaf_factor = interleaf_factor;
+        self
+    }
+
+    pub fn with_number_of_points_on_x_i_axis(mut self, number_of_points_on_x_i_axis: u16) -> Self {
+        self.number_of_points_on_x_i_axis = number_of_points_on_x_i_axis;
+        self
+    }
+
+    pub fn with_initial_index_x_i(mut self, initial_index_x_i: u16) -> Self {
+        self.initial_index_x_i = initial_index_x_i;
+        self
+    }
+}
+
+/// 6.2.40.3 Grid Axis Descriptor record for an irregular axis
+///
+/// The number of points on the axis is the number of `x_i_values`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridAxisIrregular {
+    pub domain_initial_x_i: f64,
+    pub domain_final_x_i: f64,
+    pub domain_points_x_i: u16,
+    pub interleaf_factor: u8,
+    pub initial_index_x_i: u16,
+    pub coordinate_scale_x_i: f64,
+    pub coordinate_offset_x_i: f64,
+    pub x_i_values: Vec<u16>,
+}
+
+impl GridAxisIrregular {
+    pub fn with_domain_initial_x_i(mut self, domain_initial_x_i: f64) -> Self {
+        self.domain_initial_x_i = domain_initial_x_i;
+        self
+    }
+
+    pub fn with_domain_final_x_i(mut self, domain_final_x_i: f64) -> Self {
+        self.domain_final_x_i = domain_final_x_i;
+        self
+    }
+
+    pub fn with_domain_points_x_i(mut self, domain_points_x_i: u16) -> Self {
+        self.domain_points_x_i = domain_points_x_i;
+        self
+    }
+
+    pub fn with_interleaf_factor(mut self, interleaf_factor: u8) -> Self {
+        self.interleaf_factor = interleaf_factor;
+        self
+    }
+
+    pub fn with_initial_index_x_i(mut self, initial_index_x_i: u16) -> Self {
+        self.initial_index_x_i = initial_index_x_i;
+        self
+    }
+
+    pub fn with_coordinate_scale_x_i(mut self, coordinate_scale_x_i: f64) -> Self {
+        self.coordinate_scale_x_i = coordinate_scale_x_i;
+        self
+    }
+
+    pub fn with_coordinate_offset_x_i(mut self, coordinate_offset_x_i: f64) -> Self {
+        self.coordinate_offset_x_i = coordinate_offset_x_i;
+        self
+    }
+
+    pub fn with_x_i_values(mut self, x_i_values: Vec<u16>) -> Self {
+        self.x_i_values = x_i_values;
+        self
+    }
+
+    pub fn with_x_i_value(mut self, x_i_value: u16) -> Self {
+        self.x_i_values.push(x_i_value);
+        self
+    }
+
+    /// The number of octets needed to pad the x_i values to a 64-bit boundary.
+    pub fn padding_length(&self) -> usize {
+        let values_length = self.x_i_values.len() * 2;
+        (EIGHT_OCTETS - values_length % EIGHT_OCTETS) % EIGHT_OCTETS
+    }
+}
+
+/// 6.2.41 Grid Data record
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridData {
+    pub sample_type: u16, // Gridded Data-Sample Type enumeration
+    pub data: GridDataRepresentation,
+}
+
+impl GridData {
+    pub fn new(sample_type: u16, data: GridDataRepresentation) -> Self {
+        Self {
+            sample_type,
+            data,
+        }
+    }
+
+    pub fn record_length(&self) -> u16 {
+        let unpadded_length = self.unpadded_length();
+        unpadded_length + self.padding_length() as u16
+    }
+
+    /// The number of octets needed to pad the data values to a 32-bit boundary.
+    pub fn padding_length(&self) -> usize {
+        let unpadded_length = self.unpadded_length() as usize;
+        (FOUR_OCTETS - unpadded_length % FOUR_OCTETS) % FOUR_OCTETS
+    }
+
+    fn unpadded_length(&self) -> u16 {
+        match &self.data {
+            GridDataRepresentation::Type0(data) => { BASE_GRID_DATA_TYPE_0_LENGTH + data.data_values.len() as u16 }
+            GridDataRepresentation::Type1(data) => { BASE_GRID_DATA_TYPE_1_LENGTH + (data.data_values.len() * 2) as u16 }
+            GridDataRepresentation::Type2(data) => { BASE_GRID_DATA_TYPE_2_LENGTH + (data.data_values.len() * 4) as u16 }
+        }
+    }
+}
+
+/// Grid Data values, encoded using one of the Data Representation types
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridDataRepresentation {
+    Type0(GridDataType0),
+    Type1(GridDataType1),
+    Type2(GridDataType2),
+}
+
+impl Default for GridDataRepresentation {
+    fn default() -> Self {
+        GridDataRepresentation::Type2(GridDataType2::default())
+    }
+}
+
+impl GridDataRepresentation {
+    pub fn data_representation(&self) -> u16 {
+        match self {
+            GridDataRepresentation::Type0(_) => { DATA_REPRESENTATION_TYPE_0 }
+            GridDataRepresentation::Type1(_) => { DATA_REPRESENTATION_TYPE_1 }
+            GridDataRepresentation::Type2(_) => { DATA_REPRESENTATION_TYPE_2 }
+        }
+    }
+}
+
+/// 6.2.41.2 Grid Data record, data representation type 0: opaque octets
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridDataType0 {
+    pub data_values: Vec<u8>,
+}
+
+impl GridDataType0 {
+    pub fn new(data_values: Vec<u8>) -> Self {
+        Self {
+            data_values,
+        }
+    }
+}
+
+/// 6.2.41.3 Grid Data record, data representation type 1: scaled 16-bit values
+///
+/// The actual value is `field_scale * value + field_offset`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridDataType1 {
+    pub field_scale: f32,
+    pub field_offset: f32,
+    pub data_values: Vec<u16>,
+}
+
+impl GridDataType1 {
+    pub fn new(field_scale: f32, field_offset: f32, data_values: Vec<u16>) -> Self {
+        Self {
+            field_scale,
+            field_offset,
+            data_values,
+        }
+    }
+}
+
+/// 6.2.41.4 Grid Data record, data representation type 2: 32-bit floating point values
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridDataType2 {
+    pub data_values: Vec<f32>,
+}
+
+impl GridDataType2 {
+    pub fn new(data_values: Vec<f32>) -> Self {
+        Self {
+            data_values,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::error::ErrorKind::Switch;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_f64, be_u16, be_u32, be_u8};
+use crate::common::gridded_data::model::{DATA_REPRESENTATION_TYPE_0, DATA_REPRESENTATION_TYPE_1, DATA_REPRESENTATION_TYPE_2, GRID_AXIS_TYPE_IRREGULAR, GRID_AXIS_TYPE_REGULAR, GridAxisDescriptor, GridAxisIrregular, GridAxisRegular, GridData, GridDataRepresentation, GridDataType0, GridDataType1, GridDataType2, GriddedData};
+use crate::common::model::PduBody;
+use crate::common::parser::{clock_time, entity_id, entity_type, orientation};
+
+pub fn gridded_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, environmental_simulation_id) = context("Environmental Simulation ID", entity_id)(input)?;
+    let (input, field_number) = context("Field Number", be_u16)(input)?;
+    let (input, pdu_number) = context("PDU Number", be_u16)(input)?;
+    let (input, pdu_total) = context("PDU Total", be_u16)(input)?;
+    let (input, coordinate_system) = context("Coordinate System", be_u16)(input)?;
+    let (input, number_of_grid_axes) = context("Number of Grid Axes", be_u8)(input)?;
+    let (input, constant_grid) = context("Constant Grid", be_u8)(input)?;
+    let (input, environment_type) = context("Environment Type", entity_type)(input)?;
+    let (input, orientation) = context("Orientation", orientation)(input)?;
+    let (input, sample_time) = context("Sample Time", clock_time)(input)?;
+    let (input, total_values) = context("Total Values", be_u32)(input)?;
+    let (input, vector_dimension) = context("Vector Dimension", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, grid_axis_descriptors) = context("Grid Axis Descriptors", count(grid_axis_descriptor, number_of_grid_axes as usize))(input)?;
+    let (input, grid_data) = context("Grid Data Records", count(grid_data, vector_dimension as usize))(input)?;
+
+    let body = GriddedData::builder()
+        .with_environmental_simulation_id(environmental_simulation_id)
+        .with_field_number(field_number)
+        .with_pdu_number(pdu_number)
+        .with_pdu_total(pdu_total)
+        .with_coordinate_system(coordinate_system)
+        .with_constant_grid(constant_grid)
+        .with_environment_type(environment_type)
+        .with_orientation(orientation)
+        .with_sample_time(sample_time)
+        .with_total_values(total_values)
+        .with_grid_axis_descriptors(grid_axis_descriptors)
+        .with_grid_data(grid_data)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+pub(crate) fn grid_axis_descriptor(input: &[u8]) -> IResult<&[u8], GridAxisDescriptor> {
+    let (input, domain_initial_x_i) = be_f64(input)?;
+    let (input, domain_final_x_i) = be_f64(input)?;
+    let (input, domain_points_x_i) = be_u16(input)?;
+    let (input, interleaf_factor) = be_u8(input)?;
+    let (input, axis_type) = be_u8(input)?;
+    let (input, number_of_points_on_x_i_axis) = be_u16(input)?;
+    let (input, initial_index_x_i) = be_u16(input)?;
+
+    match axis_type {
+        GRID_AXIS_TYPE_REGULAR => {
+            Ok((input, GridAxisDescriptor::Regular(GridAxisRegular::default()
+                .with_domain_initial_x_i(domain_initial_x_i)
+                .with_domain_final_x_i(domain_final_x_i)
+                .with_domain_points_x_i(domain_points_x_i)
+                .with_interleaf_factor(interleaf_factor)
+                .with_number_of_points_on_x_i_axis(number_of_points_on_x_i_axis)
+                .with_initial_index_x_i(initial_index_x_i))))
+        }
+        GRID_AXIS_TYPE_IRREGULAR => {
+            let (input, coordinate_scale_x_i) = be_f64(input)?;
+            let (input, coordinate_offset_x_i) = be_f64(input)?;
+            let (input, x_i_values) = count(be_u16, number_of_points_on_x_i_axis as usize)(input)?;
+            let axis = GridAxisIrregular::default()
+                .with_domain_initial_x_i(domain_initial_x_i)
+                .with_domain_final_x_i(domain_final_x_i)
+                .with_domain_points_x_i(domain_points_x_i)
+                .with_interleaf_factor(interleaf_factor)
+                .with_initial_index_x_i(initial_index_x_i)
+                .with_coordinate_scale_x_i(coordinate_scale_x_i)
+                .with_coordinate_offset_x_i(coordinate_offset_x_i)
+                .with_x_i_values(x_i_values);
+            let (input, _padding) = take(axis.padding_length())(input)?;
+
+            Ok((input, GridAxisDescriptor::Irregular(axis)))
+        }
+        _ => { Err(nom::Err::Error(nom::error::make_error(input, Switch))) }
+    }
+}
+
+pub(crate) fn grid_data(input: &[u8]) -> IResult<&[u8], GridData> {
+    let (input, sample_type) = be_u16(input)?;
+    let (input, data_representation) = be_u16(input)?;
+
+    let (input, data) = match data_representation {
+        DATA_REPRESENTATION_TYPE_0 => {
+            let (input, number_of_octets) = be_u16(input)?;
+            let (input, data_values) = take(number_of_octets)(input)?;
+            (input, GridDataRepresentation::Type0(GridDataType0::new(data_values.to_vec())))
+        }
+        DATA_REPRESENTATION_TYPE_1 => {
+            let (input, field_scale) = be_f32(input)?;
+            let (input, field_offset) = be_f32(input)?;
+            let (input, number_of_values) = be_u16(input)?;
+            let (input, data_values) = count(be_u16, number_of_values as usize)(input)?;
+            (input, GridDataRepresentation::Type1(GridDataType1::new(field_scale, field_offset, data_values)))
+        }
+        DATA_REPRESENTATION_TYPE_2 => {
+            let (input, number_of_values) = be_u16(input)?;
+            let (input, _padding) = be_u16(input)?;
+            let (input, data_values) = count(be_f32, number_of_values as usize)(input)?;
+            (input, GridDataRepresentation::Type2(GridDataType2::new(data_values)))
+        }
+        _ => { return Err(nom::Err::Error(nom::error::make_error(input, Switch))) }
+    };
+
+    let grid_data = GridData::new(sample_type, data);
+    let (input, _padding) = take(grid_data.padding_length())(input)?;
+
+    Ok((input, grid_data))
+}
@@ -0,0 +1,89 @@
+use bytes::BufMut;
+use crate::common::gridded_data::model::{GRID_AXIS_TYPE_IRREGULAR, GRID_AXIS_TYPE_REGULAR, GridAxisDescriptor, GridData, GridDataRepresentation, GriddedData};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for GriddedData {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let simulation_id_bytes = self.environmental_simulation_id.serialize(buf);
+        buf.put_u16(self.field_number);
+        buf.put_u16(self.pdu_number);
+        buf.put_u16(self.pdu_total);
+        buf.put_u16(self.coordinate_system);
+        buf.put_u8(self.grid_axis_descriptors.len() as u8);
+        buf.put_u8(self.constant_grid);
+        let environment_type_bytes = self.environment_type.serialize(buf);
+        let orientation_bytes = self.orientation.serialize(buf);
+        let sample_time_bytes = self.sample_time.serialize(buf);
+        buf.put_u32(self.total_values);
+        buf.put_u8(self.grid_data.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        let axes_bytes = self.grid_axis_descriptors.iter()
+            .map(|axis| axis.serialize(buf))
+            .sum::<u16>();
+        let grid_data_bytes = self.grid_data.iter()
+            .map(|data| data.serialize(buf))
+            .sum::<u16>();
+
+        simulation_id_bytes + 10 + environment_type_bytes + orientation_bytes + sample_time_bytes + 8
+            + axes_bytes + grid_data_bytes
+    }
+}
+
+impl Serialize for GridAxisDescriptor {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(axis) => {
+                buf.put_f64(axis.domain_initial_x_i);
+                buf.put_f64(axis.domain_final_x_i);
+                buf.put_u16(axis.domain_points_x_i);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(GRID_AXIS_TYPE_REGULAR);
+                buf.put_u16(axis.number_of_points_on_x_i_axis);
+                buf.put_u16(axis.initial_index_x_i);
+            }
+            GridAxisDescriptor::Irregular(axis) => {
+                buf.put_f64(axis.domain_initial_x_i);
+                buf.put_f64(axis.domain_final_x_i);
+                buf.put_u16(axis.domain_points_x_i);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(GRID_AXIS_TYPE_IRREGULAR);
+                buf.put_u16(axis.x_i_values.len() as u16);
+                buf.put_u16(axis.initial_index_x_i);
+                buf.put_f64(axis.coordinate_scale_x_i);
+                buf.put_f64(axis.coordinate_offset_x_i);
+                axis.x_i_values.iter().for_each(|value| buf.put_u16(*value));
+                buf.put_bytes(0u8, axis.padding_length());
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for GridData {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.sample_type);
+        buf.put_u16(self.data.data_representation());
+        match &self.data {
+            GridDataRepresentation::Type0(data) => {
+                buf.put_u16(data.data_values.len() as u16);
+                buf.put_slice(&data.data_values);
+            }
+            GridDataRepresentation::Type1(data) => {
+                buf.put_f32(data.field_scale);
+                buf.put_f32(data.field_offset);
+                buf.put_u16(data.data_values.len() as u16);
+                data.data_values.iter().for_each(|value| buf.put_u16(*value));
+            }
+            GridDataRepresentation::Type2(data) => {
+                buf.put_u16(data.data_values.len() as u16);
+                buf.put_u16(0u16);
+                data.data_values.iter().for_each(|value| buf.put_f32(*value));
+            }
+        }
+        buf.put_bytes(0u8, self.padding_length());
+
+        self.record_length()
+    }
+}
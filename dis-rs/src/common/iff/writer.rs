@@ -1,12 +1,13 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::iff::model::{ChangeOptionsRecord, DamageStatus, DapSource, DapValue, EnabledStatus, EnhancedMode1Code, FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification, IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, IffPresence, InformationLayers, LatLonAltSource, LayerHeader, LayersPresenceApplicability, MalfunctionStatus, Mode5BasicData, Mode5InterrogatorBasicData, Mode5InterrogatorStatus, Mode5MessageFormats, Mode5TransponderBasicData, Mode5TransponderStatus, Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData, ModeSInterrogatorBasicData, ModeSInterrogatorStatus, ModeSLevelsPresent, ModeSTransponderBasicData, ModeSTransponderStatus, OnOffStatus, OperationalStatus, ParameterCapable, SquitterStatus, SystemId, SystemSpecificData, SystemStatus};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
+use crate::common::writer::check_ascii_string;
 use crate::common::model::length_padded_to_num_bytes;
 use crate::constants::{BIT_0_IN_BYTE, BIT_1_IN_BYTE, BIT_2_IN_BYTE, BIT_3_IN_BYTE, BIT_4_IN_BYTE, BIT_5_IN_BYTE, BIT_6_IN_BYTE, BIT_7_IN_BYTE, EIGHT_OCTETS, FOUR_OCTETS, ONE_OCTET, SIX_OCTETS, THREE_OCTETS, TWO_OCTETS};
 use crate::{DisError};
 
 impl SerializePdu for Iff {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let entity_id_bytes = self.emitting_entity_id.serialize(buf);
         let event_id_bytes = self.event_id.serialize(buf);
         let antenna_location_bytes = self.relative_antenna_location.serialize(buf);
@@ -38,7 +39,7 @@ impl SerializePdu for Iff {
 }
 
 impl Serialize for IffLayer2 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let beam_data_bytes = self.beam_data.serialize(buf);
         buf.put_u8(self.operational_parameter_1);
@@ -54,7 +55,7 @@ impl Serialize for IffLayer2 {
 }
 
 impl Serialize for IffLayer3 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         let basic_data_bytes = match &self.mode_5_basic_data {
@@ -69,7 +70,7 @@ impl Serialize for IffLayer3 {
 }
 
 impl Serialize for IffLayer4 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         let basic_data_bytes = match &self.mode_s_basic_data {
@@ -84,7 +85,7 @@ impl Serialize for IffLayer4 {
 }
 
 impl Serialize for IffLayer5 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         buf.put_u16(0u16);
@@ -98,7 +99,7 @@ impl Serialize for IffLayer5 {
 }
 
 impl Serialize for ChangeOptionsRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let mut byte = 0u8;
         if self.change_indicator {
             byte = byte + BIT_0_IN_BYTE;
@@ -131,7 +132,7 @@ impl Serialize for ChangeOptionsRecord {
 }
 
 impl Serialize for FundamentalOperationalData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let system_status_bytes = self.system_status.serialize(buf);
         buf.put_u8(self.data_field_1);
         let information_layers_bytes = self.information_layers.serialize(buf);
@@ -175,7 +176,7 @@ impl From<&LayersPresenceApplicability> for u8 {
 }
 
 impl Serialize for IffDataRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let padded_record_lengths = length_padded_to_num_bytes(
             SIX_OCTETS + self.record_specific_fields.len(),
             FOUR_OCTETS);
@@ -191,7 +192,7 @@ impl Serialize for IffDataRecord {
 }
 
 impl Serialize for IffDataSpecification {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u16(self.iff_data_records.len() as u16);
         let records_bytes: u16 = self.iff_data_records.iter().map(|record| record.serialize(buf) ).sum();
 
@@ -200,7 +201,7 @@ impl Serialize for IffDataSpecification {
 }
 
 impl Serialize for InformationLayers {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let layer_1 = u8::from(&self.layer_1) << 6;
         let layer_2 = u8::from(&self.layer_2) << 5;
         let layer_3 = u8::from(&self.layer_3) << 4;
@@ -216,7 +217,7 @@ impl Serialize for InformationLayers {
 }
 
 impl Serialize for IffFundamentalParameterData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f32(self.erp);
         buf.put_f32(self.frequency);
         buf.put_f32(self.pgrf);
@@ -230,7 +231,7 @@ impl Serialize for IffFundamentalParameterData {
 }
 
 impl Serialize for LayerHeader {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.layer_number);
         buf.put_u8(self.layer_specific_information);
         buf.put_u16(self.length);
@@ -240,7 +241,7 @@ impl Serialize for LayerHeader {
 }
 
 impl Serialize for SystemSpecificData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.part_1);
         buf.put_u8(self.part_2);
         buf.put_u8(self.part_3);
@@ -250,7 +251,7 @@ impl Serialize for SystemSpecificData {
 }
 
 impl Serialize for SystemId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u16(self.system_type.into());
         buf.put_u16(self.system_name.into());
         buf.put_u8(self.system_mode.into());
@@ -261,7 +262,7 @@ impl Serialize for SystemId {
 }
 
 impl Serialize for DapSource {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let indicated_air_speed = u8::from(&self.indicated_air_speed) << 7;
         let mach_number = u8::from(&self.mach_number) << 6;
         let ground_speed = u8::from(&self.ground_speed) << 5;
@@ -296,7 +297,7 @@ impl From<&DapValue> for u8 {
 }
 
 impl Serialize for EnhancedMode1Code {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let code_element_1: u16 = self.code_element_1_d << 13;
         let code_element_2: u16 = self.code_element_2_c << 10;
         let code_element_3: u16 = self.code_element_3_b << 7;
@@ -320,7 +321,7 @@ impl Serialize for EnhancedMode1Code {
 }
 
 impl Serialize for Mode5InterrogatorBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let _status_bytes = self.status.serialize(buf);
         buf.put_u8(0u8);
         buf.put_u16(0u16);
@@ -333,7 +334,7 @@ impl Serialize for Mode5InterrogatorBasicData {
 }
 
 impl Serialize for Mode5InterrogatorStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let iff_mission: u8 = u8::from(self.iff_mission) << 5;
         let message_formats_status: u8 = u8::from(self.mode_5_message_formats_status) << 4;
         let on_off_status: u8 = u8::from(&self.on_off_status) << 2;
@@ -353,7 +354,7 @@ impl Serialize for Mode5InterrogatorStatus {
 }
 
 impl Serialize for Mode5MessageFormats {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let mf_0 = u32::from(&self.message_format_0) << 31;
         let mf_1 = u32::from(&self.message_format_1) << 30;
         let mf_2 = u32::from(&self.message_format_2) << 29;
@@ -402,7 +403,7 @@ impl Serialize for Mode5MessageFormats {
 }
 
 impl Serialize for Mode5TransponderBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let status_bytes = self.status.serialize(buf);
         buf.put_u16(self.pin);
         let formats_present_bytes = self.mode_5_message_formats_present.serialize(buf);
@@ -463,7 +464,7 @@ impl From<&LatLonAltSource> for u8 {
 }
 
 impl Serialize for Mode5TransponderSupplementalData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let squitter_status: u8 = u8::from(&self.squitter_on_off_status) << 7;
         let level_2_squitter_status: u8 = u8::from(self.level_2_squitter_status) << 6;
         let iff_mission: u8 = u8::from(self.iff_mission) << 3;
@@ -479,7 +480,7 @@ impl Serialize for Mode5TransponderSupplementalData {
 }
 
 impl Serialize for Mode5TransponderStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let mode_5_reply: u8 = u8::from(self.mode_5_reply) << 4;
         let line_test: u8 = u8::from(&self.line_test) << 3;
         let antenna_selection: u8 = u8::from(self.antenna_selection) << 1;
@@ -515,7 +516,7 @@ impl Serialize for Mode5TransponderStatus {
 }
 
 impl Serialize for ModeSAltitude {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let resolution: u8 = self.resolution.into();
         let resolution: u16 = resolution as u16;
 
@@ -529,7 +530,7 @@ impl Serialize for ModeSAltitude {
 }
 
 impl Serialize for ModeSInterrogatorBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         const PAD_168_BITS_IN_OCTETS: usize = 21;
         let _status_bytes = self.mode_s_interrogator_status.serialize(buf);
         buf.put_u8(0u8);
@@ -541,7 +542,7 @@ impl Serialize for ModeSInterrogatorBasicData {
 }
 
 impl Serialize for ModeSInterrogatorStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let on_off_status: u8 = u8::from(&self.on_off_status) << 7;
         let transmit_state: u8 = u8::from(self.transmit_state) << 4;
         let damage_status: u8 = u8::from(&self.damage_status) << 3;
@@ -559,7 +560,7 @@ impl Serialize for ModeSInterrogatorStatus {
 }
 
 impl Serialize for ModeSLevelsPresent {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let level_1: u8 = u8::from(&self.level_1) << 6;
         let level_2_els: u8 = u8::from(&self.level_2_els) << 5;
         let level_2_ehs: u8 = u8::from(&self.level_2_ehs) << 4;
@@ -597,7 +598,7 @@ impl From<&IffPresence> for u8 {
 }
 
 impl Serialize for ModeSTransponderBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let _status_bytes = self.status.serialize(buf);
         let _levels_present_bytes = self.levels_present.serialize(buf);
         buf.put_u8(self.aircraft_present_domain.into());
@@ -616,20 +617,15 @@ impl Serialize for ModeSTransponderBasicData {
     }
 }
 
-fn put_ascii_string_with_length(buf: &mut BytesMut, value: &str, length: usize) -> Result<u16, DisError> {
-    if value.len() > length {
-        Err(DisError::StringTooLongError)
-    } else if !value.is_ascii() {
-        Err(DisError::StringNotAsciiError)
-    } else {
-        buf.put_slice(value.as_bytes());
-        buf.put_bytes(0u8, length - value.len());
-        Ok(length as u16)
-    }
+fn put_ascii_string_with_length<B: BufMut>(buf: &mut B, value: &str, length: usize) -> Result<u16, DisError> {
+    check_ascii_string(value, length)?;
+    buf.put_slice(value.as_bytes());
+    buf.put_bytes(0u8, length - value.len());
+    Ok(length as u16)
 }
 
 impl Serialize for ModeSTransponderStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let squitter_status: u8 = u8::from(&self.squitter_status) << 7;
         let squitter_type: u8 = u8::from(self.squitter_type) << 4;
         let squitter_record_source: u8 = u8::from(self.squitter_record_source) << 3;
@@ -672,7 +668,7 @@ impl From<&SquitterStatus> for u8 {
 }
 
 impl Serialize for SystemStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let system_on_off_status = u8::from(&self.system_on_off_status) << 7;
         let parameter_1 = u8::from(&self.parameter_1_capable) << 6;
         let parameter_2 = u8::from(&self.parameter_2_capable) << 5;
@@ -0,0 +1,74 @@
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::model::{EntityId, IoRecord};
+use crate::enumerations::{IOActionIOActionPhase, IOActionIOActionType, IOActionIOSimulationSource, IOActionIOWarfareType};
+
+pub struct InformationOperationsActionBuilder(InformationOperationsAction);
+
+impl InformationOperationsActionBuilder {
+    pub fn new() -> Self {
+        InformationOperationsActionBuilder(InformationOperationsAction::default())
+    }
+
+    pub fn new_from_body(body: InformationOperationsAction) -> Self {
+        InformationOperationsActionBuilder(body)
+    }
+
+    pub fn build(self) -> InformationOperationsAction {
+        self.0
+    }
+
+    pub fn with_originating_simulation_id(mut self, originating_simulation_id: EntityId) -> Self {
+        self.0.originating_simulation_id = originating_simulation_id;
+        self
+    }
+
+    pub fn with_receiving_simulation_id(mut self, receiving_simulation_id: EntityId) -> Self {
+        self.0.receiving_simulation_id = receiving_simulation_id;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    pub fn with_io_warfare_type(mut self, io_warfare_type: IOActionIOWarfareType) -> Self {
+        self.0.io_warfare_type = io_warfare_type;
+        self
+    }
+
+    pub fn with_io_simulation_source(mut self, io_simulation_source: IOActionIOSimulationSource) -> Self {
+        self.0.io_simulation_source = io_simulation_source;
+        self
+    }
+
+    pub fn with_io_action_type(mut self, io_action_type: IOActionIOActionType) -> Self {
+        self.0.io_action_type = io_action_type;
+        self
+    }
+
+    pub fn with_io_action_phase(mut self, io_action_phase: IOActionIOActionPhase) -> Self {
+        self.0.io_action_phase = io_action_phase;
+        self
+    }
+
+    pub fn with_io_attacker_entity_id(mut self, io_attacker_entity_id: EntityId) -> Self {
+        self.0.io_attacker_entity_id = io_attacker_entity_id;
+        self
+    }
+
+    pub fn with_io_primary_target_entity_id(mut self, io_primary_target_entity_id: EntityId) -> Self {
+        self.0.io_primary_target_entity_id = io_primary_target_entity_id;
+        self
+    }
+
+    pub fn with_io_records(mut self, io_records: Vec<IoRecord>) -> Self {
+        self.0.io_records = io_records;
+        self
+    }
+
+    pub fn with_io_record(mut self, io_record: IoRecord) -> Self {
+        self.0.io_records.push(io_record);
+        self
+    }
+}
@@ -0,0 +1,59 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::information_operations_action::model::InformationOperationsAction;
+    use crate::common::model::{DisTimeStamp, EntityId, IoCommunicationsNode, IoEffect, IoRecord, Pdu, PduHeader};
+    use crate::enumerations::{IOActionIOActionPhase, IOActionIOActionType, IOActionIOSimulationSource, IOActionIOWarfareType, IOCommsNodeRecordCommsNodeType, IOEffectsRecordIOEffect, IOEffectsRecordIOProcess, IOEffectsRecordIOStatus, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn information_operations_action_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::InformationOperationsAction);
+
+        let body = InformationOperationsAction::builder()
+            .with_originating_simulation_id(EntityId::new(1, 1, 0))
+            .with_receiving_simulation_id(EntityId::new(2, 1, 0))
+            .with_request_id(5)
+            .with_io_warfare_type(IOActionIOWarfareType::from(1u32))
+            .with_io_simulation_source(IOActionIOSimulationSource::from(1u16))
+            .with_io_action_type(IOActionIOActionType::from(1u16))
+            .with_io_action_phase(IOActionIOActionPhase::from(2u16))
+            .with_io_attacker_entity_id(EntityId::new(1, 1, 1))
+            .with_io_primary_target_entity_id(EntityId::new(2, 1, 1))
+            .with_io_record(IoRecord::CommunicationsNode(IoCommunicationsNode::new(
+                IOCommsNodeRecordCommsNodeType::from(1u8), EntityId::new(2, 1, 7), 3)))
+            .with_io_record(IoRecord::Effect(IoEffect::default()
+                .with_status(IOEffectsRecordIOStatus::from(1u8))
+                .with_effect(IOEffectsRecordIOEffect::from(2u8))
+                .with_effect_duty_cycle(50)
+                .with_effect_duration(120)
+                .with_process(IOEffectsRecordIOProcess::from(1u16))))
+            .with_io_record(IoRecord::Unspecified(6000, vec![1, 2, 3, 4]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::information_operations_action::builder::InformationOperationsActionBuilder;
+use crate::common::model::{EntityId, IoRecord, PduBody};
+use crate::enumerations::{IOActionIOActionPhase, IOActionIOActionType, IOActionIOSimulationSource, IOActionIOWarfareType, PduType};
+
+const BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH: u16 = 44;
+
+/// 5.13.3.1 Information Operations Action PDU
+///
+/// An IO attack is described by the `io_action_type` (e.g., an attack with computed effects) and the IO Effect records.
+#[derive(Debug, Default, PartialEq)]
+pub struct InformationOperationsAction {
+    pub originating_simulation_id: EntityId,
+    pub receiving_simulation_id: EntityId,
+    pub request_id: u32,
+    pub io_warfare_type: IOActionIOWarfareType,
+    pub io_simulation_source: IOActionIOSimulationSource,
+    pub io_action_type: IOActionIOActionType,
+    pub io_action_phase: IOActionIOActionPhase,
+    pub io_attacker_entity_id: EntityId,
+    pub io_primary_target_entity_id: EntityId,
+    pub io_records: Vec<IoRecord>,
+}
+
+impl InformationOperationsAction {
+    pub fn builder() -> InformationOperationsActionBuilder {
+        InformationOperationsActionBuilder::new()
+    }
+
+    pub fn into_builder(self) -> InformationOperationsActionBuilder {
+        InformationOperationsActionBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsAction(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsAction {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH
+            + self.io_records.iter().map(|record| record.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsAction
+    }
+}
+
+impl Interaction for InformationOperationsAction {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_simulation_id)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32};
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, io_record};
+use crate::enumerations::{IOActionIOActionPhase, IOActionIOActionType, IOActionIOSimulationSource, IOActionIOWarfareType};
+
+pub fn information_operations_action_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_simulation_id) = context("Originating Simulation ID", entity_id)(input)?;
+    let (input, receiving_simulation_id) = context("Receiving Simulation ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, io_warfare_type) = context("IO Warfare Type", be_u32)(input)?;
+    let (input, io_simulation_source) = context("IO Simulation Source", be_u16)(input)?;
+    let (input, io_action_type) = context("IO Action Type", be_u16)(input)?;
+    let (input, io_action_phase) = context("IO Action Phase", be_u16)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, io_attacker_entity_id) = context("IO Attacker Entity ID", entity_id)(input)?;
+    let (input, io_primary_target_entity_id) = context("IO Primary Target Entity ID", entity_id)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, number_of_io_records) = context("Number of IO Records", be_u16)(input)?;
+    let (input, io_records) = context("IO Records", count(io_record, number_of_io_records as usize))(input)?;
+
+    let body = InformationOperationsAction::builder()
+        .with_originating_simulation_id(originating_simulation_id)
+        .with_receiving_simulation_id(receiving_simulation_id)
+        .with_request_id(request_id)
+        .with_io_warfare_type(IOActionIOWarfareType::from(io_warfare_type))
+        .with_io_simulation_source(IOActionIOSimulationSource::from(io_simulation_source))
+        .with_io_action_type(IOActionIOActionType::from(io_action_type))
+        .with_io_action_phase(IOActionIOActionPhase::from(io_action_phase))
+        .with_io_attacker_entity_id(io_attacker_entity_id)
+        .with_io_primary_target_entity_id(io_primary_target_entity_id)
+        .with_io_records(io_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,25 @@
+use bytes::BufMut;
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for InformationOperationsAction {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_simulation_id.serialize(buf);
+        let receiving_bytes = self.receiving_simulation_id.serialize(buf);
+        buf.put_u32(self.request_id);
+        buf.put_u32(self.io_warfare_type.into());
+        buf.put_u16(self.io_simulation_source.into());
+        buf.put_u16(self.io_action_type.into());
+        buf.put_u16(self.io_action_phase.into());
+        buf.put_u16(0u16);
+        let attacker_bytes = self.io_attacker_entity_id.serialize(buf);
+        let primary_target_bytes = self.io_primary_target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u16(self.io_records.len() as u16);
+        let records_bytes = self.io_records.iter()
+            .map(|record| record.serialize(buf))
+            .sum::<u16>();
+
+        originating_bytes + receiving_bytes + 16 + attacker_bytes + primary_target_bytes + 4 + records_bytes
+    }
+}
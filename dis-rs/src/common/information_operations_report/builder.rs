@@ -0,0 +1,54 @@
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::model::{EntityId, IoRecord};
+use crate::enumerations::{IOActionIOSimulationSource, IOReportIOReportType};
+
+pub struct InformationOperationsReportBuilder(InformationOperationsReport);
+
+impl InformationOperationsReportBuilder {
+    pub fn new() -> Self {
+        InformationOperationsReportBuilder(InformationOperationsReport::default())
+    }
+
+    pub fn new_from_body(body: InformationOperationsReport) -> Self {
+        InformationOperationsReportBuilder(body)
+    }
+
+    pub fn build(self) -> InformationOperationsReport {
+        self.0
+    }
+
+    pub fn with_originating_simulation_id(mut self, originating_simulation_id: EntityId) -> Self {
+        self.0.originating_simulation_id = originating_simulation_id;
+        self
+    }
+
+    pub fn with_io_simulation_source(mut self, io_simulation_source: IOActionIOSimulationSource) -> Self {
+        self.0.io_simulation_source = io_simulation_source;
+        self
+    }
+
+    pub fn with_io_report_type(mut self, io_report_type: IOReportIOReportType) -> Self {
+        self.0.io_report_type = io_report_type;
+        self
+    }
+
+    pub fn with_io_attacker_entity_id(mut self, io_attacker_entity_id: EntityId) -> Self {
+        self.0.io_attacker_entity_id = io_attacker_entity_id;
+        self
+    }
+
+    pub fn with_io_primary_target_entity_id(mut self, io_primary_target_entity_id: EntityId) -> Self {
+        self.0.io_primary_target_entity_id = io_primary_target_entity_id;
+        self
+    }
+
+    pub fn with_io_records(mut self, io_records: Vec<IoRecord>) -> Self {
+        self.0.io_records = io_records;
+        self
+    }
+
+    pub fn with_io_record(mut self, io_record: IoRecord) -> Self {
+        self.0.io_records.push(io_record);
+        self
+    }
+}
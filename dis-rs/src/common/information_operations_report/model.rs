@@ -0,0 +1,52 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::information_operations_report::builder::InformationOperationsReportBuilder;
+use crate::common::model::{EntityId, IoRecord, PduBody};
+use crate::enumerations::{IOActionIOSimulationSource, IOReportIOReportType, PduType};
+
+const BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH: u16 = 30;
+
+/// 5.13.3.2 Information Operations Report PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct InformationOperationsReport {
+    pub originating_simulation_id: EntityId,
+    pub io_simulation_source: IOActionIOSimulationSource,
+    pub io_report_type: IOReportIOReportType,
+    pub io_attacker_entity_id: EntityId,
+    pub io_primary_target_entity_id: EntityId,
+    pub io_records: Vec<IoRecord>,
+}
+
+impl InformationOperationsReport {
+    pub fn builder() -> InformationOperationsReportBuilder {
+        InformationOperationsReportBuilder::new()
+    }
+
+    pub fn into_builder(self) -> InformationOperationsReportBuilder {
+        InformationOperationsReportBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsReport(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsReport {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH
+            + self.io_records.iter().map(|record| record.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsReport
+    }
+}
+
+impl Interaction for InformationOperationsReport {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
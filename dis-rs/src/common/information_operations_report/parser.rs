@@ -0,0 +1,32 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, io_record};
+use crate::enumerations::{IOActionIOSimulationSource, IOReportIOReportType};
+
+pub fn information_operations_report_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_simulation_id) = context("Originating Simulation ID", entity_id)(input)?;
+    let (input, io_simulation_source) = context("IO Simulation Source", be_u16)(input)?;
+    let (input, io_report_type) = context("IO Report Type", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, io_attacker_entity_id) = context("IO Attacker Entity ID", entity_id)(input)?;
+    let (input, io_primary_target_entity_id) = context("IO Primary Target Entity ID", entity_id)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+    let (input, number_of_io_records) = context("Number of IO Records", be_u16)(input)?;
+    let (input, io_records) = context("IO Records", count(io_record, number_of_io_records as usize))(input)?;
+
+    let body = InformationOperationsReport::builder()
+        .with_originating_simulation_id(originating_simulation_id)
+        .with_io_simulation_source(IOActionIOSimulationSource::from(io_simulation_source))
+        .with_io_report_type(IOReportIOReportType::from(io_report_type))
+        .with_io_attacker_entity_id(io_attacker_entity_id)
+        .with_io_primary_target_entity_id(io_primary_target_entity_id)
+        .with_io_records(io_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,22 @@
+use bytes::BufMut;
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for InformationOperationsReport {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_simulation_id.serialize(buf);
+        buf.put_u16(self.io_simulation_source.into());
+        buf.put_u8(self.io_report_type.into());
+        buf.put_u8(0u8);
+        let attacker_bytes = self.io_attacker_entity_id.serialize(buf);
+        let primary_target_bytes = self.io_primary_target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u32(0u32);
+        buf.put_u16(self.io_records.len() as u16);
+        let records_bytes = self.io_records.iter()
+            .map(|record| record.serialize(buf))
+            .sum::<u16>();
+
+        originating_bytes + 4 + attacker_bytes + primary_target_bytes + 8 + records_bytes
+    }
+}
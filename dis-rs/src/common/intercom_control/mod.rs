@@ -0,0 +1,54 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::intercom_control::model::{IntercomCommunicationsParameters, IntercomControl};
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{IntercomControlCommand, IntercomControlCommunicationsType, IntercomControlControlType, IntercomControlRecordType, IntercomControlTransmitLineState, PduType};
+
+    #[test]
+    fn intercom_control_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::IntercomControl);
+
+        let body = IntercomControl::builder()
+            .with_control_type(IntercomControlControlType::from(1))
+            .with_communications_channel_type(IntercomControlCommunicationsType::from(1))
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_source_intercom_number(2)
+            .with_source_line_id(3)
+            .with_transmit_priority(4)
+            .with_transmit_line_state(IntercomControlTransmitLineState::from(1))
+            .with_command(IntercomControlCommand::from(1))
+            .with_master_intercom_reference_id(EntityId::new(1, 1, 2))
+            .with_master_intercom_number(5)
+            .with_master_channel_id(6)
+            .with_intercom_parameter(IntercomCommunicationsParameters::new(
+                IntercomControlRecordType::from(1), vec![0, 1, 2, 3, 4, 5, 6, 7]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(buf.len(), pdu_length as usize);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::intercom_control::builder::IntercomControlBuilder;
+use crate::common::model::{EntityId, PduBody};
+use crate::enumerations::{IntercomControlCommand, IntercomControlCommunicationsType, IntercomControlControlType, IntercomControlRecordType, IntercomControlTransmitLineState, PduType};
+
+pub const BASE_INTERCOM_CONTROL_BODY_LENGTH: u16 = 28;
+pub const BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH: u16 = 4;
+
+/// 5.8.9 Intercom Control PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct IntercomControl {
+    pub control_type: IntercomControlControlType,
+    pub communications_channel_type: IntercomControlCommunicationsType,
+    pub source_entity_id: EntityId,
+    pub source_intercom_number: u16,
+    pub source_line_id: u8,
+    pub transmit_priority: u8,
+    pub transmit_line_state: IntercomControlTransmitLineState,
+    pub command: IntercomControlCommand,
+    pub master_intercom_reference_id: EntityId,
+    pub master_intercom_number: u16,
+    pub master_channel_id: u16,
+    pub intercom_parameters: Vec<IntercomCommunicationsParameters>,
+}
+
+impl IntercomControl {
+    pub fn builder() -> IntercomControlBuilder {
+        IntercomControlBuilder::new()
+    }
+
+    pub fn into_builder(self) -> IntercomControlBuilder {
+        IntercomControlBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::IntercomControl(self)
+    }
+}
+
+impl BodyInfo for IntercomControl {
+    fn body_length(&self) -> u16 {
+        BASE_INTERCOM_CONTROL_BODY_LENGTH +
+            self.intercom_parameters.iter()
+                .map(|parameters| parameters.record_length())
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IntercomControl
+    }
+}
+
+impl Interaction for IntercomControl {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.source_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.48 Intercom Communications Parameters record
+///
+/// The record-specific field is kept as raw octets, its format depends on the record type.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntercomCommunicationsParameters {
+    pub record_type: IntercomControlRecordType,
+    pub record_specific_field: Vec<u8>,
+}
+
+impl IntercomCommunicationsParameters {
+    pub fn new(record_type: IntercomControlRecordType, record_specific_field: Vec<u8>) -> Self {
+        Self {
+            record_type,
+            record_specific_field,
+        }
+    }
+
+    pub fn record_length(&self) -> u16 {
+        BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH + self.record_specific_field.len() as u16
+    }
+}
@@ -0,0 +1,52 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::intercom_control::model::{IntercomCommunicationsParameters, IntercomControl};
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::enumerations::{IntercomControlCommand, IntercomControlCommunicationsType, IntercomControlControlType, IntercomControlRecordType, IntercomControlTransmitLineState};
+
+pub fn intercom_control_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, control_type) = context("Control Type", be_u8)(input)?;
+    let (input, communications_channel_type) = context("Communications Channel Type", be_u8)(input)?;
+    let (input, source_entity_id) = context("Source Entity ID", entity_id)(input)?;
+    let (input, source_intercom_number) = context("Source Intercom Number", be_u16)(input)?;
+    let (input, source_line_id) = context("Source Line ID", be_u8)(input)?;
+    let (input, transmit_priority) = context("Transmit Priority", be_u8)(input)?;
+    let (input, transmit_line_state) = context("Transmit Line State", be_u8)(input)?;
+    let (input, command) = context("Command", be_u8)(input)?;
+    let (input, master_intercom_reference_id) = context("Master Intercom Reference ID", entity_id)(input)?;
+    let (input, master_intercom_number) = context("Master Intercom Number", be_u16)(input)?;
+    let (input, master_channel_id) = context("Master Channel ID", be_u16)(input)?;
+    let (input, number_of_intercom_parameters) = context("Number of Intercom Communications Parameters", be_u32)(input)?;
+    let (input, intercom_parameters) = context("Intercom Communications Parameters", count(intercom_communications_parameters, number_of_intercom_parameters as usize))(input)?;
+
+    let body = IntercomControl::builder()
+        .with_control_type(IntercomControlControlType::from(control_type))
+        .with_communications_channel_type(IntercomControlCommunicationsType::from(communications_channel_type))
+        .with_source_entity_id(source_entity_id)
+        .with_source_intercom_number(source_intercom_number)
+        .with_source_line_id(source_line_id)
+        .with_transmit_priority(transmit_priority)
+        .with_transmit_line_state(IntercomControlTransmitLineState::from(transmit_line_state))
+        .with_command(IntercomControlCommand::from(command))
+        .with_master_intercom_reference_id(master_intercom_reference_id)
+        .with_master_intercom_number(master_intercom_number)
+        .with_master_channel_id(master_channel_id)
+        .with_intercom_parameters(intercom_parameters)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn intercom_communications_parameters(input: &[u8]) -> IResult<&[u8], IntercomCommunicationsParameters> {
+    let (input, record_type) = be_u16(input)?;
+    let (input, record_length) = be_u16(input)?;
+    let (input, record_specific_field) = take(record_length)(input)?;
+
+    Ok((input, IntercomCommunicationsParameters::new(
+        IntercomControlRecordType::from(record_type),
+        record_specific_field.to_vec())))
+}
@@ -0,0 +1,35 @@
+use bytes::BufMut;
+use crate::common::intercom_control::model::{IntercomCommunicationsParameters, IntercomControl};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for IntercomControl {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        buf.put_u8(self.control_type.into());
+        buf.put_u8(self.communications_channel_type.into());
+        let source_entity_id_bytes = self.source_entity_id.serialize(buf);
+        buf.put_u16(self.source_intercom_number);
+        buf.put_u8(self.source_line_id);
+        buf.put_u8(self.transmit_priority);
+        buf.put_u8(self.transmit_line_state.into());
+        buf.put_u8(self.command.into());
+        let master_intercom_reference_id_bytes = self.master_intercom_reference_id.serialize(buf);
+        buf.put_u16(self.master_intercom_number);
+        buf.put_u16(self.master_channel_id);
+        buf.put_u32(self.intercom_parameters.len() as u32);
+        let intercom_parameters_bytes = self.intercom_parameters.iter()
+            .map(|parameters| parameters.serialize(buf))
+            .sum::<u16>();
+
+        2 + source_entity_id_bytes + 6 + master_intercom_reference_id_bytes + 8 + intercom_parameters_bytes
+    }
+}
+
+impl Serialize for IntercomCommunicationsParameters {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.record_type.into());
+        buf.put_u16(self.record_specific_field.len() as u16);
+        buf.put_slice(&self.record_specific_field);
+
+        self.record_length()
+    }
+}
@@ -0,0 +1,55 @@
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::model::EntityId;
+use crate::common::signal::model::EncodingScheme;
+use crate::enumerations::SignalTdlType;
+
+pub struct IntercomSignalBuilder(IntercomSignal);
+
+impl IntercomSignalBuilder {
+    pub fn new() -> Self {
+        IntercomSignalBuilder(IntercomSignal::default())
+    }
+
+    pub fn new_from_body(body: IntercomSignal) -> Self {
+        IntercomSignalBuilder(body)
+    }
+
+    pub fn build(self) -> IntercomSignal {
+        self.0
+    }
+
+    pub fn with_intercom_reference_id(mut self, intercom_reference_id: EntityId) -> Self {
+        self.0.intercom_reference_id = intercom_reference_id;
+        self
+    }
+
+    pub fn with_intercom_number(mut self, intercom_number: u16) -> Self {
+        self.0.intercom_number = intercom_number;
+        self
+    }
+
+    pub fn with_encoding_scheme(mut self, encoding_scheme: EncodingScheme) -> Self {
+        self.0.encoding_scheme = encoding_scheme;
+        self
+    }
+
+    pub fn with_tdl_type(mut self, tdl_type: SignalTdlType) -> Self {
+        self.0.tdl_type = tdl_type;
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.0.sample_rate = sample_rate;
+        self
+    }
+
+    pub fn with_samples(mut self, samples: u16) -> Self {
+        self.0.samples = samples;
+        self
+    }
+
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.0.data = data;
+        self
+    }
+}
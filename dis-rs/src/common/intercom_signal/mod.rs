@@ -0,0 +1,50 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::intercom_signal::model::IntercomSignal;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::common::signal::model::EncodingScheme;
+    use crate::enumerations::{PduType, SignalEncodingClass, SignalEncodingType};
+
+    #[test]
+    fn intercom_signal_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::IntercomSignal);
+
+        let body = IntercomSignal::builder()
+            .with_intercom_reference_id(EntityId::new(1, 1, 1))
+            .with_intercom_number(2)
+            .with_encoding_scheme(EncodingScheme::EncodedAudio {
+                encoding_class: SignalEncodingClass::Encodedaudio,
+                encoding_type: SignalEncodingType::_8bitmulaw_ITUTG_711_1 })
+            .with_sample_rate(8000)
+            .with_samples(5)
+            .with_data(vec![1, 2, 3, 4, 5])
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(buf.len(), pdu_length as usize);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::intercom_signal::builder::IntercomSignalBuilder;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::signal::model::EncodingScheme;
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::{PduType, SignalEncodingClass, SignalEncodingType, SignalTdlType};
+
+pub const BASE_INTERCOM_SIGNAL_BODY_LENGTH: u16 = 20;
+
+/// 5.8.8 Intercom Signal PDU
+///
+/// The encoding scheme and data are modeled the same as in the Signal PDU.
+#[derive(Debug, PartialEq)]
+pub struct IntercomSignal {
+    pub intercom_reference_id: EntityId,
+    pub intercom_number: u16,
+    pub encoding_scheme: EncodingScheme,
+    pub tdl_type: SignalTdlType,
+    pub sample_rate: u32,
+    pub samples: u16,
+    pub data: Vec<u8>,
+}
+
+impl Default for IntercomSignal {
+    fn default() -> Self {
+        Self {
+            intercom_reference_id: Default::default(),
+            intercom_number: 0,
+            encoding_scheme: EncodingScheme::EncodedAudio { encoding_class: SignalEncodingClass::Encodedaudio, encoding_type: SignalEncodingType::_8bitmulaw_ITUTG_711_1 },
+            tdl_type: SignalTdlType::Other_0,
+            sample_rate: 0,
+            samples: 0,
+            data: vec![],
+        }
+    }
+}
+
+impl IntercomSignal {
+    pub fn builder() -> IntercomSignalBuilder {
+        IntercomSignalBuilder::new()
+    }
+
+    pub fn into_builder(self) -> IntercomSignalBuilder {
+        IntercomSignalBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::IntercomSignal(self)
+    }
+
+    /// The number of octets needed to pad the data to a 32-bit boundary.
+    pub fn padding_length(&self) -> usize {
+        (FOUR_OCTETS - self.data.len() % FOUR_OCTETS) % FOUR_OCTETS
+    }
+}
+
+impl BodyInfo for IntercomSignal {
+    fn body_length(&self) -> u16 {
+        BASE_INTERCOM_SIGNAL_BODY_LENGTH + (self.data.len() + self.padding_length()) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IntercomSignal
+    }
+}
+
+impl Interaction for IntercomSignal {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.intercom_reference_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
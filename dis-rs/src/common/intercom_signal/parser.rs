@@ -0,0 +1,34 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::number::complete::{be_u16, be_u32};
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::signal::parser::parse_encoding_scheme;
+use crate::constants::ONE_BYTE_IN_BITS;
+use crate::enumerations::SignalTdlType;
+
+pub fn intercom_signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, intercom_reference_id) = context("Intercom Reference ID", entity_id)(input)?;
+    let (input, intercom_number) = context("Intercom Number", be_u16)(input)?;
+    let (input, encoding_scheme) = context("Encoding Scheme", be_u16)(input)?;
+    let (input, tdl_type) = context("TDL Type", be_u16)(input)?;
+    let (input, sample_rate) = context("Sample Rate", be_u32)(input)?;
+    let (input, data_length_in_bits) = context("Data Length", be_u16)(input)?;
+    let (input, samples) = context("Samples", be_u16)(input)?;
+    let (input, data) = context("Data", take((data_length_in_bits as usize).div_ceil(ONE_BYTE_IN_BITS)))(input)?;
+
+    let body = IntercomSignal::builder()
+        .with_intercom_reference_id(intercom_reference_id)
+        .with_intercom_number(intercom_number)
+        .with_encoding_scheme(parse_encoding_scheme(encoding_scheme, data))
+        .with_tdl_type(SignalTdlType::from(tdl_type))
+        .with_sample_rate(sample_rate)
+        .with_samples(samples)
+        .with_data(data.to_vec())
+        .build();
+    let (input, _padding) = context("Padding", take(body.padding_length()))(input)?;
+
+    Ok((input, body.into_pdu_body()))
+}
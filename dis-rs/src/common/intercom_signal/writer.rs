@@ -0,0 +1,20 @@
+use bytes::BufMut;
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+use crate::constants::ONE_BYTE_IN_BITS;
+
+impl SerializePdu for IntercomSignal {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let intercom_reference_id_bytes = self.intercom_reference_id.serialize(buf);
+        buf.put_u16(self.intercom_number);
+        let encoding_scheme_bytes = self.encoding_scheme.serialize(buf);
+        buf.put_u16(self.tdl_type.into());
+        buf.put_u32(self.sample_rate);
+        buf.put_u16((self.data.len() * ONE_BYTE_IN_BITS) as u16);
+        buf.put_u16(self.samples);
+        buf.put_slice(&self.data);
+        buf.put_bytes(0u8, self.padding_length());
+
+        intercom_reference_id_bytes + 2 + encoding_scheme_bytes + 10 + (self.data.len() + self.padding_length()) as u16
+    }
+}
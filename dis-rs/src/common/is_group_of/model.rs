@@ -0,0 +1,261 @@
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::is_group_of::builder::IsGroupOfBuilder;
+use crate::enumerations::{IsGroupOfGroupedEntityCategory, IsGroupOfRestStatus, PduType};
+
+pub const BASE_IS_GROUP_OF_BODY_LENGTH: u16 = 28;
+pub const GED_BASIC_GROUND_COMBAT_VEHICLE_LENGTH: u16 = 20;
+pub const GED_ENHANCED_GROUND_COMBAT_VEHICLE_LENGTH: u16 = 24;
+pub const GED_BASIC_GROUND_COMBAT_SOLDIER_LENGTH: u16 = 20;
+pub const GED_ENHANCED_GROUND_COMBAT_SOLDIER_LENGTH: u16 = 24;
+pub const GED_BASIC_ROTOR_WING_AIRCRAFT_LENGTH: u16 = 24;
+pub const GED_ENHANCED_ROTOR_WING_AIRCRAFT_LENGTH: u16 = 28;
+pub const GED_BASIC_FIXED_WING_AIRCRAFT_LENGTH: u16 = 20;
+pub const GED_ENHANCED_FIXED_WING_AIRCRAFT_LENGTH: u16 = 24;
+pub const GED_GROUND_LOGISTICS_VEHICLE_LENGTH: u16 = 17;
+
+/// 5.9.3 IsGroupOf PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct IsGroupOf {
+    pub group_id: EntityId,
+    pub grouped_entity_category: IsGroupOfGroupedEntityCategory,
+    pub group_reference_point: GroupReferencePoint,
+    pub descriptions: Vec<GroupEntityDescription>,
+}
+
+impl IsGroupOf {
+    pub fn builder() -> IsGroupOfBuilder {
+        IsGroupOfBuilder::new()
+    }
+
+    pub fn into_builder(self) -> IsGroupOfBuilder {
+        IsGroupOfBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::IsGroupOf(self)
+    }
+}
+
+impl BodyInfo for IsGroupOf {
+    fn body_length(&self) -> u16 {
+        BASE_IS_GROUP_OF_BODY_LENGTH +
+            self.descriptions.iter().map(|description| description.record_length()).sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IsGroupOf
+    }
+}
+
+impl Interaction for IsGroupOf {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.group_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Geodetic location of the group, from which the locations of the grouped entities are offset.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GroupReferencePoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl GroupReferencePoint {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+/// 6.2.43 Group Entity Description (GED) records
+///
+/// The type of the records in an IsGroupOf PDU is determined by the Grouped Entity Category.
+/// Records of undefined or unknown categories are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GroupEntityDescription {
+    BasicGroundCombatVehicle(GedBasicGroundCombatVehicle),
+    EnhancedGroundCombatVehicle(GedEnhancedGroundCombatVehicle),
+    BasicGroundCombatSoldier(GedBasicGroundCombatSoldier),
+    EnhancedGroundCombatSoldier(GedEnhancedGroundCombatSoldier),
+    BasicRotorWingAircraft(GedBasicRotorWingAircraft),
+    EnhancedRotorWingAircraft(GedEnhancedRotorWingAircraft),
+    BasicFixedWingAircraft(GedBasicFixedWingAircraft),
+    EnhancedFixedWingAircraft(GedEnhancedFixedWingAircraft),
+    GroundLogisticsVehicle(GedGroundLogisticsVehicle),
+    Other(Vec<u8>),
+}
+
+impl GroupEntityDescription {
+    pub fn record_length(&self) -> u16 {
+        match self {
+            GroupEntityDescription::BasicGroundCombatVehicle(_) => { GED_BASIC_GROUND_COMBAT_VEHICLE_LENGTH }
+            GroupEntityDescription::EnhancedGroundCombatVehicle(_) => { GED_ENHANCED_GROUND_COMBAT_VEHICLE_LENGTH }
+            GroupEntityDescription::BasicGroundCombatSoldier(_) => { GED_BASIC_GROUND_COMBAT_SOLDIER_LENGTH }
+            GroupEntityDescription::EnhancedGroundCombatSoldier(_) => { GED_ENHANCED_GROUND_COMBAT_SOLDIER_LENGTH }
+            GroupEntityDescription::BasicRotorWingAircraft(_) => { GED_BASIC_ROTOR_WING_AIRCRAFT_LENGTH }
+            GroupEntityDescription::EnhancedRotorWingAircraft(_) => { GED_ENHANCED_ROTOR_WING_AIRCRAFT_LENGTH }
+            GroupEntityDescription::BasicFixedWingAircraft(_) => { GED_BASIC_FIXED_WING_AIRCRAFT_LENGTH }
+            GroupEntityDescription::EnhancedFixedWingAircraft(_) => { GED_ENHANCED_FIXED_WING_AIRCRAFT_LENGTH }
+            GroupEntityDescription::GroundLogisticsVehicle(_) => { GED_GROUND_LOGISTICS_VEHICLE_LENGTH }
+            GroupEntityDescription::Other(bytes) => { bytes.len() as u16 }
+        }
+    }
+
+    /// The wire value of the Grouped Entity Category of the record type, or `None` for records of undefined or unknown categories.
+    pub fn grouped_entity_category(&self) -> Option<u8> {
+        match self {
+            GroupEntityDescription::BasicGroundCombatVehicle(_) => { Some(1) }
+            GroupEntityDescription::EnhancedGroundCombatVehicle(_) => { Some(2) }
+            GroupEntityDescription::BasicGroundCombatSoldier(_) => { Some(3) }
+            GroupEntityDescription::EnhancedGroundCombatSoldier(_) => { Some(4) }
+            GroupEntityDescription::BasicRotorWingAircraft(_) => { Some(5) }
+            GroupEntityDescription::EnhancedRotorWingAircraft(_) => { Some(6) }
+            GroupEntityDescription::BasicFixedWingAircraft(_) => { Some(7) }
+            GroupEntityDescription::EnhancedFixedWingAircraft(_) => { Some(8) }
+            GroupEntityDescription::GroundLogisticsVehicle(_) => { Some(9) }
+            GroupEntityDescription::Other(_) => { None }
+        }
+    }
+}
+
+/// Location of a grouped entity, as offsets in meters from the group reference point.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEntityLocation {
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub z_offset: i16,
+}
+
+impl GedEntityLocation {
+    pub fn new(x_offset: i16, y_offset: i16, z_offset: i16) -> Self {
+        Self {
+            x_offset,
+            y_offset,
+            z_offset,
+        }
+    }
+}
+
+/// Orientation of a grouped entity, in units of 2 pi / 256 radians.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEntityOrientation {
+    pub psi: i8,
+    pub theta: i8,
+    pub phi: i8,
+}
+
+impl GedEntityOrientation {
+    pub fn new(psi: i8, theta: i8, phi: i8) -> Self {
+        Self {
+            psi,
+            theta,
+            phi,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedBasicGroundCombatVehicle {
+    pub entity_id: u16,
+    pub location: GedEntityLocation,
+    pub appearance: u32,
+    pub orientation: GedEntityOrientation,
+    pub speed: i8,
+    pub turret_azimuth: i8,
+    pub gun_elevation: i8,
+    pub turret_slew_rate: i8,
+    pub gun_elevation_rate: i8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEnhancedGroundCombatVehicle {
+    pub basic: GedBasicGroundCombatVehicle,
+    pub fuel_status: u8,
+    pub ground_maintenance_status: u8,
+    pub primary_ammunition: u8,
+    pub secondary_ammunition: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedBasicGroundCombatSoldier {
+    pub entity_id: u16,
+    pub location: GedEntityLocation,
+    pub appearance: u32,
+    pub orientation: GedEntityOrientation,
+    pub speed: i8,
+    pub head_azimuth: i8,
+    pub head_elevation: i8,
+    pub head_scan_rate: i8,
+    pub head_elevation_rate: i8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEnhancedGroundCombatSoldier {
+    pub basic: GedBasicGroundCombatSoldier,
+    pub water_status: u8,
+    pub rest_status: IsGroupOfRestStatus,
+    pub primary_ammunition: u8,
+    pub secondary_ammunition: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedBasicRotorWingAircraft {
+    pub entity_id: u16,
+    pub location: GedEntityLocation,
+    pub appearance: u32,
+    pub orientation: GedEntityOrientation,
+    pub fuel_status: u8,
+    pub movement_horizontal_deviation: i8,
+    pub movement_vertical_deviation: i8,
+    pub movement_speed: i16,
+    pub turret_azimuth: i8,
+    pub gun_elevation: i8,
+    pub turret_scan_rate: i8,
+    pub gun_elevation_rate: i8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEnhancedRotorWingAircraft {
+    pub basic: GedBasicRotorWingAircraft,
+    pub supplemental_fuel_status: u8,
+    pub air_maintenance_status: u8,
+    pub primary_ammunition: u8,
+    pub secondary_ammunition: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedBasicFixedWingAircraft {
+    pub entity_id: u16,
+    pub location: GedEntityLocation,
+    pub appearance: u32,
+    pub orientation: GedEntityOrientation,
+    pub fuel_status: u8,
+    pub movement_horizontal_deviation: i8,
+    pub movement_vertical_deviation: i8,
+    pub movement_speed: i16,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedEnhancedFixedWingAircraft {
+    pub basic: GedBasicFixedWingAircraft,
+    pub supplemental_fuel_status: u8,
+    pub air_maintenance_status: u8,
+    pub primary_ammunition: u8,
+    pub secondary_ammunition: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GedGroundLogisticsVehicle {
+    pub entity_id: u16,
+    pub location: GedEntityLocation,
+    pub appearance: u32,
+    pub orientation: GedEntityOrientation,
+    pub speed: i16,
+}
@@ -0,0 +1,256 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::combinator::map;
+use nom::multi::count;
+use nom::number::complete::{be_f64, be_i16, be_i8, be_u16, be_u32, be_u8};
+use crate::common::is_group_of::model::{BASE_IS_GROUP_OF_BODY_LENGTH, GedBasicFixedWingAircraft, GedBasicGroundCombatSoldier, GedBasicGroundCombatVehicle, GedBasicRotorWingAircraft, GedEnhancedFixedWingAircraft, GedEnhancedGroundCombatSoldier, GedEnhancedGroundCombatVehicle, GedEnhancedRotorWingAircraft, GedEntityLocation, GedEntityOrientation, GedGroundLogisticsVehicle, GroupEntityDescription, GroupReferencePoint, IsGroupOf};
+use crate::common::model::{PduBody, PduHeader};
+use crate::common::parser::entity_id;
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{IsGroupOfGroupedEntityCategory, IsGroupOfRestStatus};
+
+pub fn is_group_of_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
+    move |input: &[u8]| {
+        let (input, group_id) = context("Group ID", entity_id)(input)?;
+        let (input, grouped_entity_category) = context("Grouped Entity Category", be_u8)(input)?;
+        let (input, number_of_grouped_entities) = context("Number of Grouped Entities", be_u8)(input)?;
+        let (input, _padding) = context("Padding", be_u32)(input)?;
+        let (input, latitude) = context("Latitude", be_f64)(input)?;
+        let (input, longitude) = context("Longitude", be_f64)(input)?;
+
+        // Records of undefined categories have no defined length, so the remainder of the body is divided evenly.
+        let other_length = if number_of_grouped_entities > 0 {
+            header.pdu_length.saturating_sub(PDU_HEADER_LEN_BYTES + BASE_IS_GROUP_OF_BODY_LENGTH) as usize
+                / number_of_grouped_entities as usize
+        } else { 0 };
+        let (input, descriptions) = context("Group Entity Description Records", count(
+            group_entity_description(grouped_entity_category, other_length),
+            number_of_grouped_entities as usize))(input)?;
+
+        let body = IsGroupOf::builder()
+            .with_group_id(group_id)
+            .with_grouped_entity_category(IsGroupOfGroupedEntityCategory::from(grouped_entity_category))
+            .with_group_reference_point(GroupReferencePoint::new(latitude, longitude))
+            .with_descriptions(descriptions)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    }
+}
+
+fn group_entity_description(grouped_entity_category: u8, other_length: usize) -> impl Fn(&[u8]) -> IResult<&[u8], GroupEntityDescription> {
+    move |input: &[u8]| {
+        // match on the wire value, as the grouped entity categories map one-to-one to the GED record types
+        let (input, description) = match grouped_entity_category {
+            1 => { map(ged_basic_ground_combat_vehicle, GroupEntityDescription::BasicGroundCombatVehicle)(input)? }
+            2 => { map(ged_enhanced_ground_combat_vehicle, GroupEntityDescription::EnhancedGroundCombatVehicle)(input)? }
+            3 => { map(ged_basic_ground_combat_soldier, GroupEntityDescription::BasicGroundCombatSoldier)(input)? }
+            4 => { map(ged_enhanced_ground_combat_soldier, GroupEntityDescription::EnhancedGroundCombatSoldier)(input)? }
+            5 => { map(ged_basic_rotor_wing_aircraft, GroupEntityDescription::BasicRotorWingAircraft)(input)? }
+            6 => { map(ged_enhanced_rotor_wing_aircraft, GroupEntityDescription::EnhancedRotorWingAircraft)(input)? }
+            7 => { map(ged_basic_fixed_wing_aircraft, GroupEntityDescription::BasicFixedWingAircraft)(input)? }
+            8 => { map(ged_enhanced_fixed_wing_aircraft, GroupEntityDescription::EnhancedFixedWingAircraft)(input)? }
+            9 => { map(ged_ground_logistics_vehicle, GroupEntityDescription::GroundLogisticsVehicle)(input)? }
+            _ => { map(take(other_length), |bytes: &[u8]| GroupEntityDescription::Other(bytes.to_vec()))(input)? }
+        };
+
+        Ok((input, description))
+    }
+}
+
+fn ged_entity_location(input: &[u8]) -> IResult<&[u8], GedEntityLocation> {
+    let (input, x_offset) = be_i16(input)?;
+    let (input, y_offset) = be_i16(input)?;
+    let (input, z_offset) = be_i16(input)?;
+
+    Ok((input, GedEntityLocation::new(x_offset, y_offset, z_offset)))
+}
+
+fn ged_entity_orientation(input: &[u8]) -> IResult<&[u8], GedEntityOrientation> {
+    let (input, psi) = be_i8(input)?;
+    let (input, theta) = be_i8(input)?;
+    let (input, phi) = be_i8(input)?;
+
+    Ok((input, GedEntityOrientation::new(psi, theta, phi)))
+}
+
+fn ged_basic_ground_combat_vehicle(input: &[u8]) -> IResult<&[u8], GedBasicGroundCombatVehicle> {
+    let (input, entity_id) = be_u16(input)?;
+    let (input, location) = ged_entity_location(input)?;
+    let (input, appearance) = be_u32(input)?;
+    let (input, orientation) = ged_entity_orientation(input)?;
+    let (input, speed) = be_i8(input)?;
+    let (input, turret_azimuth) = be_i8(input)?;
+    let (input, gun_elevation) = be_i8(input)?;
+    let (input, turret_slew_rate) = be_i8(input)?;
+    let (input, gun_elevation_rate) = be_i8(input)?;
+
+    Ok((input, GedBasicGroundCombatVehicle {
+        entity_id,
+        location,
+        appearance,
+        orientation,
+        speed,
+        turret_azimuth,
+        gun_elevation,
+        turret_slew_rate,
+        gun_elevation_rate,
+    }))
+}
+
+fn ged_enhanced_ground_combat_vehicle(input: &[u8]) -> IResult<&[u8], GedEnhancedGroundCombatVehicle> {
+    let (input, basic) = ged_basic_ground_combat_vehicle(input)?;
+    let (input, fuel_status) = be_u8(input)?;
+    let (input, ground_maintenance_status) = be_u8(input)?;
+    let (input, primary_ammunition) = be_u8(input)?;
+    let (input, secondary_ammunition) = be_u8(input)?;
+
+    Ok((input, GedEnhancedGroundCombatVehicle {
+        basic,
+        fuel_status,
+        ground_maintenance_status,
+        primary_ammunition,
+        secondary_ammunition,
+    }))
+}
+
+fn ged_basic_ground_combat_soldier(input: &[u8]) -> IResult<&[u8], GedBasicGroundCombatSoldier> {
+    let (input, entity_id) = be_u16(input)?;
+    let (input, location) = ged_entity_location(input)?;
+    let (input, appearance) = be_u32(input)?;
+    let (input, orientation) = ged_entity_orientation(input)?;
+    let (input, speed) = be_i8(input)?;
+    let (input, head_azimuth) = be_i8(input)?;
+    let (input, head_elevation) = be_i8(input)?;
+    let (input, head_scan_rate) = be_i8(input)?;
+    let (input, head_elevation_rate) = be_i8(input)?;
+
+    Ok((input, GedBasicGroundCombatSoldier {
+        entity_id,
+        location,
+        appearance,
+        orientation,
+        speed,
+        head_azimuth,
+        head_elevation,
+        head_scan_rate,
+        head_elevation_rate,
+    }))
+}
+
+fn ged_enhanced_ground_combat_soldier(input: &[u8]) -> IResult<&[u8], GedEnhancedGroundCombatSoldier> {
+    let (input, basic) = ged_basic_ground_combat_soldier(input)?;
+    let (input, water_status) = be_u8(input)?;
+    let (input, rest_status) = be_u8(input)?;
+    let (input, primary_ammunition) = be_u8(input)?;
+    let (input, secondary_ammunition) = be_u8(input)?;
+
+    Ok((input, GedEnhancedGroundCombatSoldier {
+        basic,
+        water_status,
+        rest_status: IsGroupOfRestStatus::from(rest_status),
+        primary_ammunition,
+        secondary_ammunition,
+    }))
+}
+
+fn ged_basic_rotor_wing_aircraft(input: &[u8]) -> IResult<&[u8], GedBasicRotorWingAircraft> {
+    let (input, entity_id) = be_u16(input)?;
+    let (input, location) = ged_entity_location(input)?;
+    let (input, appearance) = be_u32(input)?;
+    let (input, orientation) = ged_entity_orientation(input)?;
+    let (input, fuel_status) = be_u8(input)?;
+    let (input, movement_horizontal_deviation) = be_i8(input)?;
+    let (input, movement_vertical_deviation) = be_i8(input)?;
+    let (input, movement_speed) = be_i16(input)?;
+    let (input, turret_azimuth) = be_i8(input)?;
+    let (input, gun_elevation) = be_i8(input)?;
+    let (input, turret_scan_rate) = be_i8(input)?;
+    let (input, gun_elevation_rate) = be_i8(input)?;
+
+    Ok((input, GedBasicRotorWingAircraft {
+        entity_id,
+        location,
+        appearance,
+        orientation,
+        fuel_status,
+        movement_horizontal_deviation,
+        movement_vertical_deviation,
+        movement_speed,
+        turret_azimuth,
+        gun_elevation,
+        turret_scan_rate,
+        gun_elevation_rate,
+    }))
+}
+
+fn ged_enhanced_rotor_wing_aircraft(input: &[u8]) -> IResult<&[u8], GedEnhancedRotorWingAircraft> {
+    let (input, basic) = ged_basic_rotor_wing_aircraft(input)?;
+    let (input, supplemental_fuel_status) = be_u8(input)?;
+    let (input, air_maintenance_status) = be_u8(input)?;
+    let (input, primary_ammunition) = be_u8(input)?;
+    let (input, secondary_ammunition) = be_u8(input)?;
+
+    Ok((input, GedEnhancedRotorWingAircraft {
+        basic,
+        supplemental_fuel_status,
+        air_maintenance_status,
+        primary_ammunition,
+        secondary_ammunition,
+    }))
+}
+
+fn ged_basic_fixed_wing_aircraft(input: &[u8]) -> IResult<&[u8], GedBasicFixedWingAircraft> {
+    let (input, entity_id) = be_u16(input)?;
+    let (input, location) = ged_entity_location(input)?;
+    let (input, appearance) = be_u32(input)?;
+    let (input, orientation) = ged_entity_orientation(input)?;
+    let (input, fuel_status) = be_u8(input)?;
+    let (input, movement_horizontal_deviation) = be_i8(input)?;
+    let (input, movement_vertical_deviation) = be_i8(input)?;
+    let (input, movement_speed) = be_i16(input)?;
+
+    Ok((input, GedBasicFixedWingAircraft {
+        entity_id,
+        location,
+        appearance,
+        orientation,
+        fuel_status,
+        movement_horizontal_deviation,
+        movement_vertical_deviation,
+        movement_speed,
+    }))
+}
+
+fn ged_enhanced_fixed_wing_aircraft(input: &[u8]) -> IResult<&[u8], GedEnhancedFixedWingAircraft> {
+    let (input, basic) = ged_basic_fixed_wing_aircraft(input)?;
+    let (input, supplemental_fuel_status) = be_u8(input)?;
+    let (input, air_maintenance_status) = be_u8(input)?;
+    let (input, primary_ammunition) = be_u8(input)?;
+    let (input, secondary_ammunition) = be_u8(input)?;
+
+    Ok((input, GedEnhancedFixedWingAircraft {
+        basic,
+        supplemental_fuel_status,
+        air_maintenance_status,
+        primary_ammunition,
+        secondary_ammunition,
+    }))
+}
+
+fn ged_ground_logistics_vehicle(input: &[u8]) -> IResult<&[u8], GedGroundLogisticsVehicle> {
+    let (input, entity_id) = be_u16(input)?;
+    let (input, location) = ged_entity_location(input)?;
+    let (input, appearance) = be_u32(input)?;
+    let (input, orientation) = ged_entity_orientation(input)?;
+    let (input, speed) = be_i16(input)?;
+
+    Ok((input, GedGroundLogisticsVehicle {
+        entity_id,
+        location,
+        appearance,
+        orientation,
+        speed,
+    }))
+}
@@ -0,0 +1,183 @@
+use bytes::BufMut;
+use crate::common::is_group_of::model::{GedBasicFixedWingAircraft, GedBasicGroundCombatSoldier, GedBasicGroundCombatVehicle, GedBasicRotorWingAircraft, GedEnhancedFixedWingAircraft, GedEnhancedGroundCombatSoldier, GedEnhancedGroundCombatVehicle, GedEnhancedRotorWingAircraft, GedEntityLocation, GedEntityOrientation, GedGroundLogisticsVehicle, GroupEntityDescription, IsGroupOf};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for IsGroupOf {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let group_id_bytes = self.group_id.serialize(buf);
+        buf.put_u8(self.grouped_entity_category.into());
+        buf.put_u8(self.descriptions.len() as u8);
+        buf.put_u32(0u32);
+        buf.put_f64(self.group_reference_point.latitude);
+        buf.put_f64(self.group_reference_point.longitude);
+        let descriptions_bytes = self.descriptions.iter()
+            .map(|description| description.serialize(buf))
+            .sum::<u16>();
+
+        group_id_bytes + 22 + descriptions_bytes
+    }
+}
+
+impl Serialize for GroupEntityDescription {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        match self {
+            GroupEntityDescription::BasicGroundCombatVehicle(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::EnhancedGroundCombatVehicle(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::BasicGroundCombatSoldier(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::EnhancedGroundCombatSoldier(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::BasicRotorWingAircraft(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::EnhancedRotorWingAircraft(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::BasicFixedWingAircraft(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::EnhancedFixedWingAircraft(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::GroundLogisticsVehicle(ged) => { ged.serialize(buf) }
+            GroupEntityDescription::Other(bytes) => {
+                buf.put_slice(bytes);
+                bytes.len() as u16
+            }
+        }
+    }
+}
+
+impl Serialize for GedEntityLocation {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_i16(self.x_offset);
+        buf.put_i16(self.y_offset);
+        buf.put_i16(self.z_offset);
+        6
+    }
+}
+
+impl Serialize for GedEntityOrientation {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_i8(self.psi);
+        buf.put_i8(self.theta);
+        buf.put_i8(self.phi);
+        3
+    }
+}
+
+impl Serialize for GedBasicGroundCombatVehicle {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.entity_id);
+        let location_bytes = self.location.serialize(buf);
+        buf.put_u32(self.appearance);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_i8(self.speed);
+        buf.put_i8(self.turret_azimuth);
+        buf.put_i8(self.gun_elevation);
+        buf.put_i8(self.turret_slew_rate);
+        buf.put_i8(self.gun_elevation_rate);
+
+        11 + location_bytes + orientation_bytes
+    }
+}
+
+impl Serialize for GedEnhancedGroundCombatVehicle {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let basic_bytes = self.basic.serialize(buf);
+        buf.put_u8(self.fuel_status);
+        buf.put_u8(self.ground_maintenance_status);
+        buf.put_u8(self.primary_ammunition);
+        buf.put_u8(self.secondary_ammunition);
+
+        basic_bytes + 4
+    }
+}
+
+impl Serialize for GedBasicGroundCombatSoldier {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.entity_id);
+        let location_bytes = self.location.serialize(buf);
+        buf.put_u32(self.appearance);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_i8(self.speed);
+        buf.put_i8(self.head_azimuth);
+        buf.put_i8(self.head_elevation);
+        buf.put_i8(self.head_scan_rate);
+        buf.put_i8(self.head_elevation_rate);
+
+        11 + location_bytes + orientation_bytes
+    }
+}
+
+impl Serialize for GedEnhancedGroundCombatSoldier {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let basic_bytes = self.basic.serialize(buf);
+        buf.put_u8(self.water_status);
+        buf.put_u8(self.rest_status.into());
+        buf.put_u8(self.primary_ammunition);
+        buf.put_u8(self.secondary_ammunition);
+
+        basic_bytes + 4
+    }
+}
+
+impl Serialize for GedBasicRotorWingAircraft {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.entity_id);
+        let location_bytes = self.location.serialize(buf);
+        buf.put_u32(self.appearance);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_u8(self.fuel_status);
+        buf.put_i8(self.movement_horizontal_deviation);
+        buf.put_i8(self.movement_vertical_deviation);
+        buf.put_i16(self.movement_speed);
+        buf.put_i8(self.turret_azimuth);
+        buf.put_i8(self.gun_elevation);
+        buf.put_i8(self.turret_scan_rate);
+        buf.put_i8(self.gun_elevation_rate);
+
+        15 + location_bytes + orientation_bytes
+    }
+}
+
+impl Serialize for GedEnhancedRotorWingAircraft {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let basic_bytes = self.basic.serialize(buf);
+        buf.put_u8(self.supplemental_fuel_status);
+        buf.put_u8(self.air_maintenance_status);
+        buf.put_u8(self.primary_ammunition);
+        buf.put_u8(self.secondary_ammunition);
+
+        basic_bytes + 4
+    }
+}
+
+impl Serialize for GedBasicFixedWingAircraft {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.entity_id);
+        let location_bytes = self.location.serialize(buf);
+        buf.put_u32(self.appearance);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_u8(self.fuel_status);
+        buf.put_i8(self.movement_horizontal_deviation);
+        buf.put_i8(self.movement_vertical_deviation);
+        buf.put_i16(self.movement_speed);
+
+        11 + location_bytes + orientation_bytes
+    }
+}
+
+impl Serialize for GedEnhancedFixedWingAircraft {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let basic_bytes = self.basic.serialize(buf);
+        buf.put_u8(self.supplemental_fuel_status);
+        buf.put_u8(self.air_maintenance_status);
+        buf.put_u8(self.primary_ammunition);
+        buf.put_u8(self.secondary_ammunition);
+
+        basic_bytes + 4
+    }
+}
+
+impl Serialize for GedGroundLogisticsVehicle {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.entity_id);
+        let location_bytes = self.location.serialize(buf);
+        buf.put_u32(self.appearance);
+        let orientation_bytes = self.orientation.serialize(buf);
+        buf.put_i16(self.speed);
+
+        8 + location_bytes + orientation_bytes
+    }
+}
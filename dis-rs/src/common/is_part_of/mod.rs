@@ -0,0 +1,52 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
+    use crate::common::model::{DisTimeStamp, EntityId, EntityType, Pdu, PduHeader, VectorF32};
+    use crate::enumerations::{EntityKind, IsPartOfNature, IsPartOfPosition, PduType, PlatformDomain, StationName};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn is_part_of_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::IsPartOf);
+
+        let body = IsPartOf::builder()
+            .with_originating_simulation_id(EntityId::new(1, 1, 1))
+            .with_receiving_entity_id(EntityId::new(1, 1, 2))
+            .with_relationship(Relationship::default()
+                .with_nature(IsPartOfNature::from(2))
+                .with_position(IsPartOfPosition::from(1)))
+            .with_part_location(VectorF32::new(1.0, 0.0, -2.5))
+            .with_named_location_id(NamedLocationId::default()
+                .with_station_name(StationName::from(2))
+                .with_station_number(3))
+            .with_part_type(EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Air))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::be_u16;
+use crate::common::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, entity_type, vec3_f32};
+use crate::enumerations::{IsPartOfNature, IsPartOfPosition, StationName};
+
+pub fn is_part_of_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_simulation_id) = context("Originating Simulation ID", entity_id)(input)?;
+    let (input, receiving_entity_id) = context("Receiving Entity ID", entity_id)(input)?;
+    let (input, relationship) = context("Relationship", relationship)(input)?;
+    let (input, part_location) = context("Part Location", vec3_f32)(input)?;
+    let (input, named_location_id) = context("Named Location ID", named_location_id)(input)?;
+    let (input, part_type) = context("Part Type", entity_type)(input)?;
+
+    let body = IsPartOf::builder()
+        .with_originating_simulation_id(originating_simulation_id)
+        .with_receiving_entity_id(receiving_entity_id)
+        .with_relationship(relationship)
+        .with_part_location(part_location)
+        .with_named_location_id(named_location_id)
+        .with_part_type(part_type)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+pub(crate) fn relationship(input: &[u8]) -> IResult<&[u8], Relationship> {
+    let (input, nature) = be_u16(input)?;
+    let (input, position) = be_u16(input)?;
+
+    Ok((input, Relationship::default()
+        .with_nature(IsPartOfNature::from(nature))
+        .with_position(IsPartOfPosition::from(position))))
+}
+
+pub(crate) fn named_location_id(input: &[u8]) -> IResult<&[u8], NamedLocationId> {
+    let (input, station_name) = be_u16(input)?;
+    let (input, station_number) = be_u16(input)?;
+
+    Ok((input, NamedLocationId::default()
+        .with_station_name(StationName::from(station_name))
+        .with_station_number(station_number)))
+}
@@ -0,0 +1,33 @@
+use bytes::BufMut;
+use crate::common::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for IsPartOf {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_simulation_id.serialize(buf);
+        let receiving_bytes = self.receiving_entity_id.serialize(buf);
+        let relationship_bytes = self.relationship.serialize(buf);
+        let location_bytes = self.part_location.serialize(buf);
+        let named_location_bytes = self.named_location_id.serialize(buf);
+        let part_type_bytes = self.part_type.serialize(buf);
+
+        originating_bytes + receiving_bytes + relationship_bytes + location_bytes
+            + named_location_bytes + part_type_bytes
+    }
+}
+
+impl Serialize for Relationship {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.nature.into());
+        buf.put_u16(self.position.into());
+        4
+    }
+}
+
+impl Serialize for NamedLocationId {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.station_name.into());
+        buf.put_u16(self.station_number);
+        4
+    }
+}
@@ -0,0 +1,66 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+    use crate::common::model::{DisTimeStamp, EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation, Pdu, PduHeader, SimulationAddress};
+    use crate::enumerations::{ForceId, ObjectKind, PduType, PlatformDomain};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn linear_object_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::LinearObjectState);
+
+        let body = LinearObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 200))
+            .with_referenced_object_id(EntityId::new(1, 1, 201))
+            .with_update_number(3)
+            .with_force_id(ForceId::Opposing)
+            .with_requester_id(SimulationAddress::new(1, 1))
+            .with_receiving_id(SimulationAddress::new(2, 2))
+            .with_object_type(ObjectType::new(PlatformDomain::Land, ObjectKind::from(1), 4, 0))
+            .with_linear_segment_parameter(LinearSegmentParameter::default()
+                .with_segment_number(1)
+                .with_segment_modification(ObjectStateModification::default()
+                    .with_location(true)
+                    .with_orientation(true))
+                .with_general_segment_appearance(GeneralObjectAppearance::default()
+                    .with_percent_complete(100)
+                    .with_predistributed(true))
+                .with_segment_location(Location::new(100.0, 200.0, 300.0))
+                .with_segment_orientation(Orientation::new(1.0, 0.0, 0.0))
+                .with_segment_length(50.0)
+                .with_segment_width(2.0)
+                .with_segment_height(1.5)
+                .with_segment_depth(0.5))
+            .with_linear_segment_parameter(LinearSegmentParameter::default()
+                .with_segment_number(2)
+                .with_specific_segment_appearance(0x0000_00FF)
+                .with_segment_length(25.0))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
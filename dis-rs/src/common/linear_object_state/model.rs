@@ -0,0 +1,122 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::linear_object_state::builder::LinearObjectStateBuilder;
+use crate::common::model::{EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation, PduBody, SimulationAddress};
+use crate::enumerations::{ForceId, PduType};
+
+const BASE_LINEAR_OBJECT_STATE_BODY_LENGTH: u16 = 28;
+pub const LINEAR_SEGMENT_PARAMETER_RECORD_LENGTH: u16 = 64;
+
+/// 5.11.3.3 Linear Object State PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct LinearObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_type: ObjectType,
+    pub linear_segment_parameters: Vec<LinearSegmentParameter>,
+}
+
+impl LinearObjectState {
+    pub fn builder() -> LinearObjectStateBuilder {
+        LinearObjectStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> LinearObjectStateBuilder {
+        LinearObjectStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LinearObjectState(self)
+    }
+}
+
+impl BodyInfo for LinearObjectState {
+    fn body_length(&self) -> u16 {
+        BASE_LINEAR_OBJECT_STATE_BODY_LENGTH
+            + (self.linear_segment_parameters.len() as u16 * LINEAR_SEGMENT_PARAMETER_RECORD_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LinearObjectState
+    }
+}
+
+impl Interaction for LinearObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.52 Linear Segment Parameter record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LinearSegmentParameter {
+    pub segment_number: u8,
+    pub segment_modification: ObjectStateModification,
+    pub general_segment_appearance: GeneralObjectAppearance,
+    pub specific_segment_appearance: u32,
+    pub segment_location: Location,
+    pub segment_orientation: Orientation,
+    pub segment_length: f32,
+    pub segment_width: f32,
+    pub segment_height: f32,
+    pub segment_depth: f32,
+}
+
+impl LinearSegmentParameter {
+    pub fn with_segment_number(mut self, segment_number: u8) -> Self {
+        self.segment_number = segment_number;
+        self
+    }
+
+    pub fn with_segment_modification(mut self, segment_modification: ObjectStateModification) -> Self {
+        self.segment_modification = segment_modification;
+        self
+    }
+
+    pub fn with_general_segment_appearance(mut self, general_segment_appearance: GeneralObjectAppearance) -> Self {
+        self.general_segment_appearance = general_segment_appearance;
+        self
+    }
+
+    pub fn with_specific_segment_appearance(mut self, specific_segment_appearance: u32) -> Self {
+        self.specific_segment_appearance = specific_segment_appearance;
+        self
+    }
+
+    pub fn with_segment_location(mut self, segment_location: Location) -> Self {
+        self.segment_location = segment_location;
+        self
+    }
+
+    pub fn with_segment_orientation(mut self, segment_orientation: Orientation) -> Self {
+        self.segment_orientation = segment_orientation;
+        self
+    }
+
+    pub fn with_segment_length(mut self, segment_length: f32) -> Self {
+        self.segment_length = segment_length;
+        self
+    }
+
+    pub fn with_segment_width(mut self, segment_width: f32) -> Self {
+        self.segment_width = segment_width;
+        self
+    }
+
+    pub fn with_segment_height(mut self, segment_height: f32) -> Self {
+        self.segment_height = segment_height;
+        self
+    }
+
+    pub fn with_segment_depth(mut self, segment_depth: f32) -> Self {
+        self.segment_depth = segment_depth;
+        self
+    }
+}
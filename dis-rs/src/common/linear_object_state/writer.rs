@@ -0,0 +1,40 @@
+use bytes::BufMut;
+use crate::common::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for LinearObjectState {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let object_id_bytes = self.object_id.serialize(buf);
+        let referenced_object_id_bytes = self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8(self.linear_segment_parameters.len() as u8);
+        let requester_id_bytes = self.requester_id.serialize(buf);
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        let object_type_bytes = self.object_type.serialize(buf);
+        let segments_bytes = self.linear_segment_parameters.iter()
+            .map(|segment| segment.serialize(buf))
+            .sum::<u16>();
+
+        object_id_bytes + referenced_object_id_bytes + 4 + requester_id_bytes + receiving_id_bytes
+            + object_type_bytes + segments_bytes
+    }
+}
+
+impl Serialize for LinearSegmentParameter {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.segment_number);
+        let modification_bytes = self.segment_modification.serialize(buf);
+        let general_appearance_bytes = self.general_segment_appearance.serialize(buf);
+        buf.put_u32(self.specific_segment_appearance);
+        let location_bytes = self.segment_location.serialize(buf);
+        let orientation_bytes = self.segment_orientation.serialize(buf);
+        buf.put_f32(self.segment_length);
+        buf.put_f32(self.segment_width);
+        buf.put_f32(self.segment_height);
+        buf.put_f32(self.segment_depth);
+        buf.put_u32(0u32);
+
+        1 + modification_bytes + general_appearance_bytes + 4 + location_bytes + orientation_bytes + 20
+    }
+}
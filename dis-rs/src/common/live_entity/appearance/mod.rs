@@ -0,0 +1,70 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::entity_state::model::{EntityAppearance, EntityMarking, EntityState};
+    use crate::common::live_entity::appearance::model::Appearance;
+    use crate::common::model::{DisTimeStamp, EntityId, EntityType, LiveEntityId, Pdu, PduHeader};
+    use crate::enumerations::{EntityKind, EntityMarkingCharacterSet, ForceId, PduType, PlatformDomain};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn appearance_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::Appearance);
+
+        let body = Appearance::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_force_id(ForceId::Friendly)
+            .with_entity_type(EntityType::default()
+                .with_kind(EntityKind::Platform)
+                .with_domain(PlatformDomain::Land))
+            .with_entity_marking(EntityMarking::new(String::from("TANK1"), EntityMarkingCharacterSet::ASCII))
+            .with_appearance_visual(0x0000_0010)
+            .with_appearance_audio(0x0000_0001)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn appearance_entity_state_conversion() {
+        let entity_state = EntityState::new(EntityId::new(1, 2, 3), ForceId::Opposing, EntityType::default()
+                .with_kind(EntityKind::Lifeform)
+                .with_domain(PlatformDomain::Land))
+            .with_appearance(EntityAppearance::Unspecified(16u32.to_be_bytes()));
+
+        let appearance = Appearance::from_entity_state(&entity_state);
+        assert_eq!(appearance.live_entity_id, LiveEntityId::new(1, 2, 3));
+        assert_eq!(appearance.force_id, Some(ForceId::Opposing));
+        assert_eq!(appearance.appearance_visual, Some(16));
+
+        let updated = Appearance::builder()
+            .with_force_id(ForceId::Friendly)
+            .build()
+            .update_entity_state(entity_state);
+        assert_eq!(updated.force_id, ForceId::Friendly);
+        assert_eq!(updated.entity_type.kind, EntityKind::Lifeform);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::entity_state::model::{EntityMarking, EntityState};
+use crate::common::entity_state::parser::entity_appearance;
+use crate::common::live_entity::appearance::builder::AppearanceBuilder;
+use crate::common::model::{EntityId, EntityType, LiveEntityId, PduBody};
+use crate::enumerations::{ForceId, PduType};
+use crate::v7::entity_state::parser::entity_capabilities;
+
+const BASE_APPEARANCE_BODY_LENGTH: u16 = 5;
+const FLAGS_2_LENGTH: u16 = 1;
+const FORCE_ID_LENGTH: u16 = 1;
+const ENTITY_TYPE_LENGTH: u16 = 8;
+const ENTITY_MARKING_LENGTH: u16 = 12;
+const CAPABILITIES_LENGTH: u16 = 4;
+const APPEARANCE_LENGTH: u16 = 4;
+
+pub(crate) const FORCE_ID_FLAG: u8 = 0x01;
+pub(crate) const ENTITY_TYPE_FLAG: u8 = 0x02;
+pub(crate) const ALTERNATE_ENTITY_TYPE_FLAG: u8 = 0x04;
+pub(crate) const ENTITY_MARKING_FLAG: u8 = 0x08;
+pub(crate) const CAPABILITIES_FLAG: u8 = 0x10;
+pub(crate) const APPEARANCE_VISUAL_FLAG: u8 = 0x20;
+pub(crate) const APPEARANCE_IR_FLAG: u8 = 0x40;
+pub(crate) const FLAGS_2_FLAG: u8 = 0x80;
+pub(crate) const APPEARANCE_EM_FLAG: u8 = 0x01;
+pub(crate) const APPEARANCE_AUDIO_FLAG: u8 = 0x02;
+
+/// 9.4.3 Appearance PDU
+///
+/// Only the attributes that changed are sent; they are present in the PDU when they are `Some`.
+/// The appearance and capabilities fields are kept as raw values, as their meaning depends on the entity type,
+/// which is not necessarily part of the same PDU.
+#[derive(Debug, Default, PartialEq)]
+pub struct Appearance {
+    pub live_entity_id: LiveEntityId,
+    pub force_id: Option<ForceId>,
+    pub entity_type: Option<EntityType>,
+    pub alternate_entity_type: Option<EntityType>,
+    pub entity_marking: Option<EntityMarking>,
+    pub capabilities: Option<u32>,
+    pub appearance_visual: Option<u32>,
+    pub appearance_ir: Option<u32>,
+    pub appearance_em: Option<u32>,
+    pub appearance_audio: Option<u32>,
+}
+
+impl Appearance {
+    pub fn builder() -> AppearanceBuilder {
+        AppearanceBuilder::new()
+    }
+
+    pub fn into_builder(self) -> AppearanceBuilder {
+        AppearanceBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::Appearance(self)
+    }
+
+    pub fn appearance_flags_1(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.force_id.is_some() { flags |= FORCE_ID_FLAG; }
+        if self.entity_type.is_some() { flags |= ENTITY_TYPE_FLAG; }
+        if self.alternate_entity_type.is_some() { flags |= ALTERNATE_ENTITY_TYPE_FLAG; }
+        if self.entity_marking.is_some() { flags |= ENTITY_MARKING_FLAG; }
+        if self.capabilities.is_some() { flags |= CAPABILITIES_FLAG; }
+        if self.appearance_visual.is_some() { flags |= APPEARANCE_VISUAL_FLAG; }
+        if self.appearance_ir.is_some() { flags |= APPEARANCE_IR_FLAG; }
+        if self.appearance_flags_2() != 0 { flags |= FLAGS_2_FLAG; }
+        flags
+    }
+
+    pub fn appearance_flags_2(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.appearance_em.is_some() { flags |= APPEARANCE_EM_FLAG; }
+        if self.appearance_audio.is_some() { flags |= APPEARANCE_AUDIO_FLAG; }
+        flags
+    }
+
+    /// Creates an Appearance PDU holding all appearance related attributes of an `EntityState`.
+    pub fn from_entity_state(entity_state: &EntityState) -> Self {
+        Appearance::builder()
+            .with_live_entity_id(LiveEntityId::from(&entity_state.entity_id))
+            .with_force_id(entity_state.force_id)
+            .with_entity_type(entity_state.entity_type)
+            .with_alternate_entity_type(entity_state.alternative_entity_type)
+            .with_entity_marking(entity_state.entity_marking.clone())
+            .with_capabilities(entity_state.entity_capabilities.into())
+            .with_appearance_visual(u32::from(&entity_state.entity_appearance))
+            .build()
+    }
+
+    /// Applies the attributes present in this PDU to `entity_state`.
+    /// The visual appearance and capabilities are decoded using the entity type of this PDU when present,
+    /// otherwise using the entity type of `entity_state`.
+    pub fn update_entity_state(&self, mut entity_state: EntityState) -> EntityState {
+        let entity_type = self.entity_type.unwrap_or(entity_state.entity_type);
+        if let Some(force_id) = self.force_id {
+            entity_state.force_id = force_id;
+        }
+        entity_state.entity_type = entity_type;
+        if let Some(alternate_entity_type) = self.alternate_entity_type {
+            entity_state.alternative_entity_type = alternate_entity_type;
+        }
+        if let Some(entity_marking) = &self.entity_marking {
+            entity_state.entity_marking = entity_marking.clone();
+        }
+        if let Some(capabilities) = self.capabilities {
+            if let Ok((_, capabilities)) = entity_capabilities(entity_type)(&capabilities.to_be_bytes()) {
+                entity_state.entity_capabilities = capabilities;
+            }
+        }
+        if let Some(appearance) = self.appearance_visual {
+            if let Ok((_, appearance)) = entity_appearance(entity_type)(&appearance.to_be_bytes()) {
+                entity_state.entity_appearance = appearance;
+            }
+        }
+        entity_state
+    }
+}
+
+impl BodyInfo for Appearance {
+    fn body_length(&self) -> u16 {
+        BASE_APPEARANCE_BODY_LENGTH
+            + if self.appearance_flags_2() != 0 { FLAGS_2_LENGTH } else { 0 }
+            + self.force_id.map_or(0, |_| FORCE_ID_LENGTH)
+            + self.entity_type.map_or(0, |_| ENTITY_TYPE_LENGTH)
+            + self.alternate_entity_type.map_or(0, |_| ENTITY_TYPE_LENGTH)
+            + self.entity_marking.as_ref().map_or(0, |_| ENTITY_MARKING_LENGTH)
+            + self.capabilities.map_or(0, |_| CAPABILITIES_LENGTH)
+            + self.appearance_visual.map_or(0, |_| APPEARANCE_LENGTH)
+            + self.appearance_ir.map_or(0, |_| APPEARANCE_LENGTH)
+            + self.appearance_em.map_or(0, |_| APPEARANCE_LENGTH)
+            + self.appearance_audio.map_or(0, |_| APPEARANCE_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::Appearance
+    }
+}
+
+impl Interaction for Appearance {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
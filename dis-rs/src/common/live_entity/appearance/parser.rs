@@ -0,0 +1,40 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::combinator::cond;
+use nom::number::complete::{be_u32, be_u8};
+use crate::common::entity_state::parser::{entity_marking, force_id};
+use crate::common::live_entity::appearance::model::{ALTERNATE_ENTITY_TYPE_FLAG, Appearance, APPEARANCE_AUDIO_FLAG, APPEARANCE_EM_FLAG, APPEARANCE_IR_FLAG, APPEARANCE_VISUAL_FLAG, CAPABILITIES_FLAG, ENTITY_MARKING_FLAG, ENTITY_TYPE_FLAG, FLAGS_2_FLAG, FORCE_ID_FLAG};
+use crate::common::model::PduBody;
+use crate::common::parser;
+use crate::common::parser::live_entity_id;
+
+pub fn appearance_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id)(input)?;
+    let (input, flags_1) = context("Flags 1", be_u8)(input)?;
+    let (input, flags_2) = context("Flags 2", cond(flags_1 & FLAGS_2_FLAG != 0, be_u8))(input)?;
+    let flags_2 = flags_2.unwrap_or_default();
+    let (input, force_id) = context("Force ID", cond(flags_1 & FORCE_ID_FLAG != 0, force_id))(input)?;
+    let (input, entity_type) = context("Entity Type", cond(flags_1 & ENTITY_TYPE_FLAG != 0, parser::entity_type))(input)?;
+    let (input, alternate_entity_type) = context("Alternate Entity Type", cond(flags_1 & ALTERNATE_ENTITY_TYPE_FLAG != 0, parser::entity_type))(input)?;
+    let (input, entity_marking) = context("Entity Marking", cond(flags_1 & ENTITY_MARKING_FLAG != 0, entity_marking))(input)?;
+    let (input, capabilities) = context("Capabilities", cond(flags_1 & CAPABILITIES_FLAG != 0, be_u32))(input)?;
+    let (input, appearance_visual) = context("Appearance Visual", cond(flags_1 & APPEARANCE_VISUAL_FLAG != 0, be_u32))(input)?;
+    let (input, appearance_ir) = context("Appearance IR", cond(flags_1 & APPEARANCE_IR_FLAG != 0, be_u32))(input)?;
+    let (input, appearance_em) = context("Appearance EM", cond(flags_2 & APPEARANCE_EM_FLAG != 0, be_u32))(input)?;
+    let (input, appearance_audio) = context("Appearance Audio", cond(flags_2 & APPEARANCE_AUDIO_FLAG != 0, be_u32))(input)?;
+
+    let body = Appearance {
+        live_entity_id,
+        force_id,
+        entity_type,
+        alternate_entity_type,
+        entity_marking,
+        capabilities,
+        appearance_visual,
+        appearance_ir,
+        appearance_em,
+        appearance_audio,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
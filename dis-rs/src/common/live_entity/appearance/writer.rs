@@ -0,0 +1,30 @@
+use bytes::BufMut;
+use crate::common::live_entity::appearance::model::Appearance;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for Appearance {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let live_entity_id_bytes = self.live_entity_id.serialize(buf);
+        buf.put_u8(self.appearance_flags_1());
+        let flags_2 = self.appearance_flags_2();
+        let flags_2_bytes = if flags_2 != 0 {
+            buf.put_u8(flags_2);
+            1
+        } else { 0 };
+        let force_id_bytes = self.force_id.map_or(0, |force_id| force_id.serialize(buf));
+        let entity_type_bytes = self.entity_type.map_or(0, |entity_type| entity_type.serialize(buf));
+        let alternate_entity_type_bytes = self.alternate_entity_type.map_or(0, |entity_type| entity_type.serialize(buf));
+        let marking_bytes = self.entity_marking.as_ref().map_or(0, |marking| marking.serialize(buf));
+        let appearances_bytes = [self.capabilities, self.appearance_visual, self.appearance_ir, self.appearance_em, self.appearance_audio]
+            .iter()
+            .flatten()
+            .map(|value| {
+                buf.put_u32(*value);
+                4
+            })
+            .sum::<u16>();
+
+        live_entity_id_bytes + 1 + flags_2_bytes + force_id_bytes + entity_type_bytes + alternate_entity_type_bytes
+            + marking_bytes + appearances_bytes
+    }
+}
@@ -0,0 +1,33 @@
+use crate::common::live_entity::articulated_parts::model::ArticulatedParts;
+use crate::common::model::{LiveEntityId, VariableParameter};
+
+pub struct ArticulatedPartsBuilder(ArticulatedParts);
+
+impl ArticulatedPartsBuilder {
+    pub fn new() -> Self {
+        ArticulatedPartsBuilder(ArticulatedParts::default())
+    }
+
+    pub fn new_from_body(body: ArticulatedParts) -> Self {
+        ArticulatedPartsBuilder(body)
+    }
+
+    pub fn build(self) -> ArticulatedParts {
+        self.0
+    }
+
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    pub fn with_variable_parameters(mut self, variable_parameters: Vec<VariableParameter>) -> Self {
+        self.0.variable_parameters = variable_parameters;
+        self
+    }
+
+    pub fn with_variable_parameter(mut self, variable_parameter: VariableParameter) -> Self {
+        self.0.variable_parameters.push(variable_parameter);
+        self
+    }
+}
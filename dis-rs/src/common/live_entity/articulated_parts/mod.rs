@@ -0,0 +1,47 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::live_entity::articulated_parts::model::ArticulatedParts;
+    use crate::common::model::{ArticulatedPart, DisTimeStamp, LiveEntityId, Pdu, PduHeader};
+    use crate::enumerations::{ChangeIndicator, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn articulated_parts_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::ArticulatedParts);
+
+        let body = ArticulatedParts::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_variable_parameter(ArticulatedPart::default()
+                .with_change_indicator(ChangeIndicator::from(1u8))
+                .with_attachment_id(0)
+                .with_parameter_value(1.5)
+                .to_variable_parameter())
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
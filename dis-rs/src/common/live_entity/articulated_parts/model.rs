@@ -0,0 +1,49 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::live_entity::articulated_parts::builder::ArticulatedPartsBuilder;
+use crate::common::model::{EntityId, LiveEntityId, PduBody, VariableParameter};
+use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
+use crate::enumerations::PduType;
+
+const BASE_ARTICULATED_PARTS_BODY_LENGTH: u16 = 5;
+
+/// 9.4.4 Articulated Parts PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct ArticulatedParts {
+    pub live_entity_id: LiveEntityId,
+    pub variable_parameters: Vec<VariableParameter>,
+}
+
+impl ArticulatedParts {
+    pub fn builder() -> ArticulatedPartsBuilder {
+        ArticulatedPartsBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ArticulatedPartsBuilder {
+        ArticulatedPartsBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ArticulatedParts(self)
+    }
+}
+
+impl BodyInfo for ArticulatedParts {
+    fn body_length(&self) -> u16 {
+        BASE_ARTICULATED_PARTS_BODY_LENGTH
+            + (VARIABLE_PARAMETER_RECORD_LENGTH * (self.variable_parameters.len() as u16))
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArticulatedParts
+    }
+}
+
+impl Interaction for ArticulatedParts {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
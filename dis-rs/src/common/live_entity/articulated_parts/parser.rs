@@ -0,0 +1,20 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+use crate::common::live_entity::articulated_parts::model::ArticulatedParts;
+use crate::common::model::PduBody;
+use crate::common::parser::{live_entity_id, variable_parameter};
+
+pub fn articulated_parts_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id)(input)?;
+    let (input, number_of_parameter_records) = context("Number of Parameter Records", be_u8)(input)?;
+    let (input, variable_parameters) = context("Variable Parameter Records", count(variable_parameter, number_of_parameter_records as usize))(input)?;
+
+    let body = ArticulatedParts::builder()
+        .with_live_entity_id(live_entity_id)
+        .with_variable_parameters(variable_parameters)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,15 @@
+use bytes::BufMut;
+use crate::common::live_entity::articulated_parts::model::ArticulatedParts;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ArticulatedParts {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let live_entity_id_bytes = self.live_entity_id.serialize(buf);
+        buf.put_u8(self.variable_parameters.len() as u8);
+        let variable_parameters_bytes = self.variable_parameters.iter()
+            .map(|parameter| parameter.serialize(buf))
+            .sum::<u16>();
+
+        live_entity_id_bytes + 1 + variable_parameters_bytes
+    }
+}
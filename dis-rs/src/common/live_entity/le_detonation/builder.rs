@@ -0,0 +1,74 @@
+use crate::common::live_entity::le_detonation::model::LeDetonation;
+use crate::common::model::{EntityType, LiveEntityId, LiveEntityOrientation, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEventId, MunitionDescriptor};
+use crate::enumerations::DetonationResult;
+
+pub struct LeDetonationBuilder(LeDetonation);
+
+impl LeDetonationBuilder {
+    pub fn new() -> Self {
+        LeDetonationBuilder(LeDetonation::default())
+    }
+
+    pub fn new_from_body(body: LeDetonation) -> Self {
+        LeDetonationBuilder(body)
+    }
+
+    pub fn build(self) -> LeDetonation {
+        self.0
+    }
+
+    pub fn with_firing_live_entity_id(mut self, firing_live_entity_id: LiveEntityId) -> Self {
+        self.0.firing_live_entity_id = firing_live_entity_id;
+        self
+    }
+
+    pub fn with_target_live_entity_id(mut self, target_live_entity_id: LiveEntityId) -> Self {
+        self.0.target_live_entity_id = Some(target_live_entity_id);
+        self
+    }
+
+    pub fn with_munition_live_entity_id(mut self, munition_live_entity_id: LiveEntityId) -> Self {
+        self.0.munition_live_entity_id = munition_live_entity_id;
+        self
+    }
+
+    pub fn with_event_id(mut self, event_id: LiveEventId) -> Self {
+        self.0.event_id = event_id;
+        self
+    }
+
+    pub fn with_world_location(mut self, world_location: LiveEntityRelativeWorldCoordinates) -> Self {
+        self.0.world_location = world_location;
+        self
+    }
+
+    pub fn with_velocity(mut self, velocity: LiveEntityVector) -> Self {
+        self.0.velocity = velocity;
+        self
+    }
+
+    pub fn with_munition_orientation(mut self, munition_orientation: LiveEntityOrientation) -> Self {
+        self.0.munition_orientation = Some(munition_orientation);
+        self
+    }
+
+    pub fn with_munition_type(mut self, munition_type: EntityType) -> Self {
+        self.0.munition_type = munition_type;
+        self
+    }
+
+    pub fn with_munition_descriptor(mut self, munition_descriptor: MunitionDescriptor) -> Self {
+        self.0.munition_descriptor = munition_descriptor;
+        self
+    }
+
+    pub fn with_entity_location(mut self, entity_location: LiveEntityVector) -> Self {
+        self.0.entity_location = entity_location;
+        self
+    }
+
+    pub fn with_detonation_result(mut self, detonation_result: DetonationResult) -> Self {
+        self.0.detonation_result = detonation_result;
+        self
+    }
+}
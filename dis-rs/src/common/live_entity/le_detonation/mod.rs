@@ -0,0 +1,75 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::live_entity::le_detonation::model::LeDetonation;
+    use crate::common::model::{DisTimeStamp, EntityType, LiveEntityId, LiveEntityOrientation, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEventId, Location, MunitionDescriptor, Pdu, PduHeader};
+    use crate::enumerations::{DetonationResult, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn le_detonation_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::LEDetonation);
+
+        let body = LeDetonation::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_target_live_entity_id(LiveEntityId::new(7, 8, 9))
+            .with_munition_live_entity_id(LiveEntityId::new(1, 2, 4))
+            .with_event_id(LiveEventId::new(4, 5, 10))
+            .with_world_location(LiveEntityRelativeWorldCoordinates::new(1, 10, 20, 30))
+            .with_velocity(LiveEntityVector::new(-20, 0, 0))
+            .with_munition_orientation(LiveEntityOrientation::new(0, -64, 0))
+            .with_munition_type(EntityType::default().with_category(2))
+            .with_munition_descriptor(MunitionDescriptor::default().with_quantity(1).with_rate(1))
+            .with_entity_location(LiveEntityVector::new(5, 5, 0))
+            .with_detonation_result(DetonationResult::from(1u8))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn le_detonation_detonation_conversion() {
+        let reference_point = Location::new(3_900_000.0, 300_000.0, 5_000_000.0);
+        let le_detonation = LeDetonation::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_munition_live_entity_id(LiveEntityId::new(1, 2, 4))
+            .with_event_id(LiveEventId::new(1, 2, 5))
+            .with_world_location(LiveEntityRelativeWorldCoordinates::new(3, 10, 20, 30))
+            .with_velocity(LiveEntityVector::new(-25, 0, 0))
+            .with_munition_type(EntityType::default().with_category(2))
+            .with_entity_location(LiveEntityVector::new(15, 0, 0))
+            .with_detonation_result(DetonationResult::from(1u8))
+            .build();
+
+        let detonation = le_detonation.to_detonation(&reference_point);
+        assert_eq!(detonation.location_in_world_coordinates, Location::new(3_900_010.0, 300_020.0, 5_000_030.0));
+        assert_eq!(detonation.velocity.first_vector_component, -2.5);
+        assert_eq!(detonation.location_in_entity_coordinates.first_vector_component, 1.5);
+
+        let converted = LeDetonation::from_detonation(&detonation, 3, &reference_point);
+        assert_eq!(converted, le_detonation);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::detonation::model::Detonation;
+use crate::common::live_entity::le_detonation::builder::LeDetonationBuilder;
+use crate::common::live_entity::le_fire::model::{implied_munition_descriptor, includes_quantity_rate, includes_warhead_fuse, munition_type_and_descriptor, QUANTITY_RATE_LENGTH, same_site_application, SITE_APPLICATION_LENGTH, WARHEAD_FUSE_LENGTH};
+use crate::common::model::{EntityId, EntityType, EventId, LIVE_ENTITY_LOCATION_SCALE, LIVE_ENTITY_VELOCITY_SCALE, LiveEntityId, LiveEntityOrientation, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEventId, Location, MunitionDescriptor, PduBody};
+use crate::enumerations::{DetonationResult, PduType};
+
+const BASE_LE_DETONATION_BODY_LENGTH: u16 = 38;
+const TARGET_ID_LENGTH: u16 = 4;
+const MUNITION_ORIENTATION_LENGTH: u16 = 3;
+
+pub(crate) const TARGET_ID_FLAG: u8 = 0x01;
+pub(crate) const MUNITION_SITE_APPLICATION_FLAG: u8 = 0x02;
+pub(crate) const EVENT_SITE_APPLICATION_FLAG: u8 = 0x04;
+pub(crate) const WARHEAD_FUSE_FLAG: u8 = 0x08;
+pub(crate) const QUANTITY_RATE_FLAG: u8 = 0x10;
+pub(crate) const MUNITION_ORIENTATION_FLAG: u8 = 0x20;
+pub(crate) const FLAGS_2_FLAG: u8 = 0x80;
+
+/// 9.4.6 LE Detonation PDU
+///
+/// As with the LE Fire PDU, the site and application numbers of the munition and event IDs, and the optional parts
+/// of the munition descriptor are only included when they differ from the firing entity, resp. their defaults
+/// (a single round for the quantity and rate).
+/// The second flags octet is not used by this version of the standard; it is skipped when present in a received PDU.
+#[derive(Debug, PartialEq)]
+pub struct LeDetonation {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: LiveEntityId,
+    pub event_id: LiveEventId,
+    pub world_location: LiveEntityRelativeWorldCoordinates,
+    pub velocity: LiveEntityVector,
+    pub munition_orientation: Option<LiveEntityOrientation>,
+    pub munition_type: EntityType,
+    pub munition_descriptor: MunitionDescriptor,
+    pub entity_location: LiveEntityVector,
+    pub detonation_result: DetonationResult,
+}
+
+impl Default for LeDetonation {
+    fn default() -> Self {
+        Self {
+            firing_live_entity_id: LiveEntityId::default(),
+            target_live_entity_id: None,
+            munition_live_entity_id: LiveEntityId::default(),
+            event_id: LiveEventId::default(),
+            world_location: LiveEntityRelativeWorldCoordinates::default(),
+            velocity: LiveEntityVector::default(),
+            munition_orientation: None,
+            munition_type: EntityType::default(),
+            munition_descriptor: implied_munition_descriptor(),
+            entity_location: LiveEntityVector::default(),
+            detonation_result: DetonationResult::default(),
+        }
+    }
+}
+
+impl LeDetonation {
+    pub fn builder() -> LeDetonationBuilder {
+        LeDetonationBuilder::new()
+    }
+
+    pub fn into_builder(self) -> LeDetonationBuilder {
+        LeDetonationBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LEDetonation(self)
+    }
+
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.target_live_entity_id.is_some() { flags |= TARGET_ID_FLAG; }
+        if self.includes_munition_site_application() { flags |= MUNITION_SITE_APPLICATION_FLAG; }
+        if self.includes_event_site_application() { flags |= EVENT_SITE_APPLICATION_FLAG; }
+        if includes_warhead_fuse(&self.munition_descriptor) { flags |= WARHEAD_FUSE_FLAG; }
+        if includes_quantity_rate(&self.munition_descriptor) { flags |= QUANTITY_RATE_FLAG; }
+        if self.munition_orientation.is_some() { flags |= MUNITION_ORIENTATION_FLAG; }
+        flags
+    }
+
+    pub(crate) fn includes_munition_site_application(&self) -> bool {
+        !same_site_application(&self.firing_live_entity_id, self.munition_live_entity_id.site_number, self.munition_live_entity_id.application_number)
+    }
+
+    pub(crate) fn includes_event_site_application(&self) -> bool {
+        !same_site_application(&self.firing_live_entity_id, self.event_id.site_number, self.event_id.application_number)
+    }
+
+    /// Creates a `Detonation` PDU body from this LE Detonation PDU, given the location of the reference point
+    /// the location of the detonation is relative to. The munition orientation has no counterpart in the
+    /// Detonation PDU and is dropped.
+    pub fn to_detonation(&self, reference_point: &Location) -> Detonation {
+        Detonation::builder()
+            .with_source_entity_id(EntityId::from(self.firing_live_entity_id))
+            .with_target_entity_id(self.target_live_entity_id.map(EntityId::from).unwrap_or_default())
+            .with_exploding_entity_id(EntityId::from(self.munition_live_entity_id))
+            .with_event_id(EventId::from(self.event_id))
+            .with_velocity(self.velocity.to_vector_f32(LIVE_ENTITY_VELOCITY_SCALE))
+            .with_world_location(self.world_location.to_location(reference_point))
+            .with_munition_descriptor(self.munition_type, self.munition_descriptor)
+            .with_entity_location(self.entity_location.to_vector_f32(LIVE_ENTITY_LOCATION_SCALE))
+            .with_detonation_result(self.detonation_result)
+            .build()
+    }
+
+    /// Creates an LE Detonation PDU from a `Detonation` PDU body, with the location expressed relative to
+    /// the reference point with number `reference_point_id`, located at `reference_point`.
+    /// A target entity id equal to the default `EntityId` is considered absent. Variable parameters are dropped.
+    pub fn from_detonation(detonation: &Detonation, reference_point_id: u16, reference_point: &Location) -> Self {
+        let (munition_type, munition_descriptor) = munition_type_and_descriptor(&detonation.descriptor);
+        LeDetonation {
+            firing_live_entity_id: LiveEntityId::from(&detonation.source_entity_id),
+            target_live_entity_id: if detonation.target_entity_id == EntityId::default() { None }
+                else { Some(LiveEntityId::from(&detonation.target_entity_id)) },
+            munition_live_entity_id: LiveEntityId::from(&detonation.exploding_entity_id),
+            event_id: LiveEventId::from(&detonation.event_id),
+            world_location: LiveEntityRelativeWorldCoordinates::from_location(
+                &detonation.location_in_world_coordinates, reference_point_id, reference_point),
+            velocity: LiveEntityVector::from_vector_f32(&detonation.velocity, LIVE_ENTITY_VELOCITY_SCALE),
+            munition_orientation: None,
+            munition_type,
+            munition_descriptor,
+            entity_location: LiveEntityVector::from_vector_f32(&detonation.location_in_entity_coordinates, LIVE_ENTITY_LOCATION_SCALE),
+            detonation_result: detonation.detonation_result,
+        }
+    }
+}
+
+impl BodyInfo for LeDetonation {
+    fn body_length(&self) -> u16 {
+        BASE_LE_DETONATION_BODY_LENGTH
+            + self.target_live_entity_id.map_or(0, |_| TARGET_ID_LENGTH)
+            + if self.includes_munition_site_application() { SITE_APPLICATION_LENGTH } else { 0 }
+            + if self.includes_event_site_application() { SITE_APPLICATION_LENGTH } else { 0 }
+            + self.munition_orientation.map_or(0, |_| MUNITION_ORIENTATION_LENGTH)
+            + if includes_warhead_fuse(&self.munition_descriptor) { WARHEAD_FUSE_LENGTH } else { 0 }
+            + if includes_quantity_rate(&self.munition_descriptor) { QUANTITY_RATE_LENGTH } else { 0 }
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEDetonation
+    }
+}
+
+impl Interaction for LeDetonation {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
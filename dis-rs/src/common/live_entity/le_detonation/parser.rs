@@ -0,0 +1,44 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::combinator::cond;
+use nom::number::complete::be_u8;
+use crate::common::live_entity::le_detonation::model::{EVENT_SITE_APPLICATION_FLAG, FLAGS_2_FLAG, LeDetonation, MUNITION_ORIENTATION_FLAG, MUNITION_SITE_APPLICATION_FLAG, QUANTITY_RATE_FLAG, TARGET_ID_FLAG, WARHEAD_FUSE_FLAG};
+use crate::common::live_entity::le_fire::parser::{live_event_id, munition_descriptor, munition_live_entity_id};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_type, live_entity_id, live_entity_orientation, live_entity_relative_world_coordinates, live_entity_vector};
+use crate::enumerations::DetonationResult;
+
+pub fn le_detonation_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_live_entity_id) = context("Firing Live Entity ID", live_entity_id)(input)?;
+    let (input, flags) = context("Flags", be_u8)(input)?;
+    let (input, _flags_2) = context("Flags 2", cond(flags & FLAGS_2_FLAG != 0, be_u8))(input)?;
+    let (input, target_live_entity_id) = context("Target Live Entity ID", cond(flags & TARGET_ID_FLAG != 0, live_entity_id))(input)?;
+    let (input, munition_live_entity_id) = context("Munition Live Entity ID", |input| munition_live_entity_id(
+        input, &firing_live_entity_id, flags & MUNITION_SITE_APPLICATION_FLAG != 0))(input)?;
+    let (input, event_id) = context("Event ID", |input| live_event_id(
+        input, &firing_live_entity_id, flags & EVENT_SITE_APPLICATION_FLAG != 0))(input)?;
+    let (input, world_location) = context("Location in World Coordinates", live_entity_relative_world_coordinates)(input)?;
+    let (input, velocity) = context("Velocity", live_entity_vector)(input)?;
+    let (input, munition_orientation) = context("Munition Orientation", cond(flags & MUNITION_ORIENTATION_FLAG != 0, live_entity_orientation))(input)?;
+    let (input, munition_type) = context("Munition Type", entity_type)(input)?;
+    let (input, munition_descriptor) = context("Munition Descriptor", |input| munition_descriptor(
+        input, flags & WARHEAD_FUSE_FLAG != 0, flags & QUANTITY_RATE_FLAG != 0))(input)?;
+    let (input, entity_location) = context("Entity Location", live_entity_vector)(input)?;
+    let (input, detonation_result) = context("Detonation Result", be_u8)(input)?;
+
+    let body = LeDetonation {
+        firing_live_entity_id,
+        target_live_entity_id,
+        munition_live_entity_id,
+        event_id,
+        world_location,
+        velocity,
+        munition_orientation,
+        munition_type,
+        munition_descriptor,
+        entity_location,
+        detonation_result: DetonationResult::from(detonation_result),
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,30 @@
+use bytes::BufMut;
+use crate::common::live_entity::le_detonation::model::LeDetonation;
+use crate::common::live_entity::le_fire::writer::{serialize_munition_descriptor, serialize_site_application};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for LeDetonation {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let firing_bytes = self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.flags());
+        let target_bytes = self.target_live_entity_id.map_or(0, |target| target.serialize(buf));
+        let munition_bytes = serialize_site_application(
+            self.includes_munition_site_application(),
+            self.munition_live_entity_id.site_number, self.munition_live_entity_id.application_number, buf);
+        buf.put_u16(self.munition_live_entity_id.entity_number);
+        let event_bytes = serialize_site_application(
+            self.includes_event_site_application(),
+            self.event_id.site_number, self.event_id.application_number, buf);
+        buf.put_u16(self.event_id.event_number);
+        let world_location_bytes = self.world_location.serialize(buf);
+        let velocity_bytes = self.velocity.serialize(buf);
+        let orientation_bytes = self.munition_orientation.map_or(0, |orientation| orientation.serialize(buf));
+        let munition_type_bytes = self.munition_type.serialize(buf);
+        let descriptor_bytes = serialize_munition_descriptor(&self.munition_descriptor, buf);
+        let entity_location_bytes = self.entity_location.serialize(buf);
+        buf.put_u8(self.detonation_result.into());
+
+        firing_bytes + 1 + target_bytes + munition_bytes + 2 + event_bytes + 2 + world_location_bytes
+            + velocity_bytes + orientation_bytes + munition_type_bytes + descriptor_bytes + entity_location_bytes + 1
+    }
+}
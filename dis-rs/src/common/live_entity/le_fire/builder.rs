@@ -0,0 +1,63 @@
+use crate::common::live_entity::le_fire::model::LeFire;
+use crate::common::model::{EntityType, LiveEntityId, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEventId, MunitionDescriptor};
+
+pub struct LeFireBuilder(LeFire);
+
+impl LeFireBuilder {
+    pub fn new() -> Self {
+        LeFireBuilder(LeFire::default())
+    }
+
+    pub fn new_from_body(body: LeFire) -> Self {
+        LeFireBuilder(body)
+    }
+
+    pub fn build(self) -> LeFire {
+        self.0
+    }
+
+    pub fn with_firing_live_entity_id(mut self, firing_live_entity_id: LiveEntityId) -> Self {
+        self.0.firing_live_entity_id = firing_live_entity_id;
+        self
+    }
+
+    pub fn with_target_live_entity_id(mut self, target_live_entity_id: LiveEntityId) -> Self {
+        self.0.target_live_entity_id = Some(target_live_entity_id);
+        self
+    }
+
+    pub fn with_munition_live_entity_id(mut self, munition_live_entity_id: LiveEntityId) -> Self {
+        self.0.munition_live_entity_id = munition_live_entity_id;
+        self
+    }
+
+    pub fn with_event_id(mut self, event_id: LiveEventId) -> Self {
+        self.0.event_id = event_id;
+        self
+    }
+
+    pub fn with_location(mut self, location: LiveEntityRelativeWorldCoordinates) -> Self {
+        self.0.location = location;
+        self
+    }
+
+    pub fn with_munition_type(mut self, munition_type: EntityType) -> Self {
+        self.0.munition_type = munition_type;
+        self
+    }
+
+    pub fn with_munition_descriptor(mut self, munition_descriptor: MunitionDescriptor) -> Self {
+        self.0.munition_descriptor = munition_descriptor;
+        self
+    }
+
+    pub fn with_velocity(mut self, velocity: LiveEntityVector) -> Self {
+        self.0.velocity = velocity;
+        self
+    }
+
+    pub fn with_range(mut self, range: u16) -> Self {
+        self.0.range = range;
+        self
+    }
+}
@@ -0,0 +1,175 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::fire::model::Fire;
+use crate::common::live_entity::le_fire::builder::LeFireBuilder;
+use crate::common::model::{DescriptorRecord, EntityId, EntityType, EventId, LIVE_ENTITY_VELOCITY_SCALE, LiveEntityId, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEventId, Location, MunitionDescriptor, PduBody};
+use crate::enumerations::PduType;
+
+const BASE_LE_FIRE_BODY_LENGTH: u16 = 34;
+const TARGET_ID_LENGTH: u16 = 4;
+pub(crate) const SITE_APPLICATION_LENGTH: u16 = 2;
+pub(crate) const WARHEAD_FUSE_LENGTH: u16 = 4;
+pub(crate) const QUANTITY_RATE_LENGTH: u16 = 4;
+
+pub(crate) const TARGET_ID_FLAG: u8 = 0x01;
+pub(crate) const MUNITION_SITE_APPLICATION_FLAG: u8 = 0x02;
+pub(crate) const EVENT_SITE_APPLICATION_FLAG: u8 = 0x04;
+pub(crate) const WARHEAD_FUSE_FLAG: u8 = 0x08;
+pub(crate) const QUANTITY_RATE_FLAG: u8 = 0x10;
+
+/// The quantity and rate implied when they are not included in the PDU: a single round.
+pub(crate) const IMPLIED_QUANTITY: u16 = 1;
+pub(crate) const IMPLIED_RATE: u16 = 0;
+
+/// 9.4.5 LE Fire PDU
+///
+/// The site and application numbers of the munition and event IDs are only included in the PDU when they differ from
+/// those of the firing entity. The warhead and fuse of the munition descriptor are only included when they differ
+/// from their default values, the quantity and rate only when they differ from a single round (quantity 1, rate 0).
+#[derive(Debug, PartialEq)]
+pub struct LeFire {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: LiveEntityId,
+    pub event_id: LiveEventId,
+    pub location: LiveEntityRelativeWorldCoordinates,
+    pub munition_type: EntityType,
+    pub munition_descriptor: MunitionDescriptor,
+    pub velocity: LiveEntityVector,
+    pub range: u16,
+}
+
+impl Default for LeFire {
+    fn default() -> Self {
+        Self {
+            firing_live_entity_id: LiveEntityId::default(),
+            target_live_entity_id: None,
+            munition_live_entity_id: LiveEntityId::default(),
+            event_id: LiveEventId::default(),
+            location: LiveEntityRelativeWorldCoordinates::default(),
+            munition_type: EntityType::default(),
+            munition_descriptor: implied_munition_descriptor(),
+            velocity: LiveEntityVector::default(),
+            range: 0,
+        }
+    }
+}
+
+impl LeFire {
+    pub fn builder() -> LeFireBuilder {
+        LeFireBuilder::new()
+    }
+
+    pub fn into_builder(self) -> LeFireBuilder {
+        LeFireBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LEFire(self)
+    }
+
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.target_live_entity_id.is_some() { flags |= TARGET_ID_FLAG; }
+        if self.includes_munition_site_application() { flags |= MUNITION_SITE_APPLICATION_FLAG; }
+        if self.includes_event_site_application() { flags |= EVENT_SITE_APPLICATION_FLAG; }
+        if includes_warhead_fuse(&self.munition_descriptor) { flags |= WARHEAD_FUSE_FLAG; }
+        if includes_quantity_rate(&self.munition_descriptor) { flags |= QUANTITY_RATE_FLAG; }
+        flags
+    }
+
+    pub(crate) fn includes_munition_site_application(&self) -> bool {
+        !same_site_application(&self.firing_live_entity_id, self.munition_live_entity_id.site_number, self.munition_live_entity_id.application_number)
+    }
+
+    pub(crate) fn includes_event_site_application(&self) -> bool {
+        !same_site_application(&self.firing_live_entity_id, self.event_id.site_number, self.event_id.application_number)
+    }
+
+    /// Creates a `Fire` PDU body from this LE Fire PDU, given the location of the reference point
+    /// the location of the fire is relative to.
+    pub fn to_fire(&self, reference_point: &Location) -> Fire {
+        Fire::new(
+            EntityId::from(self.firing_live_entity_id),
+            self.target_live_entity_id.map(EntityId::from).unwrap_or_default(),
+            EntityId::from(self.munition_live_entity_id),
+            EventId::from(self.event_id))
+            .with_location_in_world(self.location.to_location(reference_point))
+            .with_munition_descriptor(self.munition_type, self.munition_descriptor)
+            .with_velocity(self.velocity.to_vector_f32(LIVE_ENTITY_VELOCITY_SCALE))
+            .with_range(self.range as f32)
+    }
+
+    /// Creates an LE Fire PDU from a `Fire` PDU body, with the location expressed relative to
+    /// the reference point with number `reference_point_id`, located at `reference_point`.
+    /// A target entity id equal to the default `EntityId` is considered absent.
+    pub fn from_fire(fire: &Fire, reference_point_id: u16, reference_point: &Location) -> Self {
+        let (munition_type, munition_descriptor) = munition_type_and_descriptor(&fire.descriptor);
+        LeFire {
+            firing_live_entity_id: LiveEntityId::from(&fire.firing_entity_id),
+            target_live_entity_id: if fire.target_entity_id == EntityId::default() { None }
+                else { Some(LiveEntityId::from(&fire.target_entity_id)) },
+            munition_live_entity_id: LiveEntityId::from(&fire.entity_id),
+            event_id: LiveEventId::from(&fire.event_id),
+            location: LiveEntityRelativeWorldCoordinates::from_location(&fire.location_in_world, reference_point_id, reference_point),
+            munition_type,
+            munition_descriptor,
+            velocity: LiveEntityVector::from_vector_f32(&fire.velocity, LIVE_ENTITY_VELOCITY_SCALE),
+            range: fire.range.round() as u16,
+        }
+    }
+}
+
+impl BodyInfo for LeFire {
+    fn body_length(&self) -> u16 {
+        BASE_LE_FIRE_BODY_LENGTH
+            + self.target_live_entity_id.map_or(0, |_| TARGET_ID_LENGTH)
+            + if self.includes_munition_site_application() { SITE_APPLICATION_LENGTH } else { 0 }
+            + if self.includes_event_site_application() { SITE_APPLICATION_LENGTH } else { 0 }
+            + if includes_warhead_fuse(&self.munition_descriptor) { WARHEAD_FUSE_LENGTH } else { 0 }
+            + if includes_quantity_rate(&self.munition_descriptor) { QUANTITY_RATE_LENGTH } else { 0 }
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEFire
+    }
+}
+
+impl Interaction for LeFire {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+pub(crate) fn same_site_application(entity_id: &LiveEntityId, site_number: u8, application_number: u8) -> bool {
+    entity_id.site_number == site_number && entity_id.application_number == application_number
+}
+
+pub(crate) fn includes_warhead_fuse(descriptor: &MunitionDescriptor) -> bool {
+    let default = MunitionDescriptor::default();
+    descriptor.warhead != default.warhead || descriptor.fuse != default.fuse
+}
+
+/// A munition descriptor with default warhead and fuse, and the implied quantity and rate of a single round.
+pub(crate) fn implied_munition_descriptor() -> MunitionDescriptor {
+    MunitionDescriptor::default()
+        .with_quantity(IMPLIED_QUANTITY)
+        .with_rate(IMPLIED_RATE)
+}
+
+pub(crate) fn includes_quantity_rate(descriptor: &MunitionDescriptor) -> bool {
+    descriptor.quantity != IMPLIED_QUANTITY || descriptor.rate != IMPLIED_RATE
+}
+
+/// Extracts the munition type and munition descriptor from a `DescriptorRecord`.
+/// Expendable and explosion descriptors result in the implied munition descriptor of a single round.
+pub(crate) fn munition_type_and_descriptor(descriptor: &DescriptorRecord) -> (EntityType, MunitionDescriptor) {
+    match descriptor {
+        DescriptorRecord::Munition { entity_type, munition } => { (*entity_type, *munition) }
+        DescriptorRecord::Expendable { entity_type } => { (*entity_type, implied_munition_descriptor()) }
+        DescriptorRecord::Explosion { entity_type, .. } => { (*entity_type, implied_munition_descriptor()) }
+    }
+}
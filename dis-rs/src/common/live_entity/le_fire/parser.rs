@@ -0,0 +1,83 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::combinator::cond;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::live_entity::le_fire::model::{EVENT_SITE_APPLICATION_FLAG, implied_munition_descriptor, LeFire, MUNITION_SITE_APPLICATION_FLAG, QUANTITY_RATE_FLAG, TARGET_ID_FLAG, WARHEAD_FUSE_FLAG};
+use crate::common::model::{LiveEntityId, LiveEventId, MunitionDescriptor, PduBody};
+use crate::common::parser::{entity_type, fuse, live_entity_id, live_entity_relative_world_coordinates, live_entity_vector, warhead};
+
+pub fn le_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, firing_live_entity_id) = context("Firing Live Entity ID", live_entity_id)(input)?;
+    let (input, flags) = context("Flags", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, target_live_entity_id) = context("Target Live Entity ID", cond(flags & TARGET_ID_FLAG != 0, live_entity_id))(input)?;
+    let (input, munition_live_entity_id) = context("Munition Live Entity ID", |input| munition_live_entity_id(
+        input, &firing_live_entity_id, flags & MUNITION_SITE_APPLICATION_FLAG != 0))(input)?;
+    let (input, event_id) = context("Event ID", |input| live_event_id(
+        input, &firing_live_entity_id, flags & EVENT_SITE_APPLICATION_FLAG != 0))(input)?;
+    let (input, location) = context("Location in World Coordinates", live_entity_relative_world_coordinates)(input)?;
+    let (input, munition_type) = context("Munition Type", entity_type)(input)?;
+    let (input, munition_descriptor) = context("Munition Descriptor", |input| munition_descriptor(
+        input, flags & WARHEAD_FUSE_FLAG != 0, flags & QUANTITY_RATE_FLAG != 0))(input)?;
+    let (input, velocity) = context("Velocity", live_entity_vector)(input)?;
+    let (input, range) = context("Range", be_u16)(input)?;
+
+    let body = LeFire {
+        firing_live_entity_id,
+        target_live_entity_id,
+        munition_live_entity_id,
+        event_id,
+        location,
+        munition_type,
+        munition_descriptor,
+        velocity,
+        range,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
+
+/// Parses a munition id, of which the site and application numbers are taken from the firing entity
+/// when they are not included in the PDU.
+pub(crate) fn munition_live_entity_id<'a>(input: &'a [u8], firing_live_entity_id: &LiveEntityId, includes_site_application: bool) -> IResult<&'a [u8], LiveEntityId> {
+    let (input, site_application) = cond(includes_site_application, site_application)(input)?;
+    let (input, entity_number) = be_u16(input)?;
+    let (site_number, application_number) = site_application
+        .unwrap_or((firing_live_entity_id.site_number, firing_live_entity_id.application_number));
+
+    Ok((input, LiveEntityId::new(site_number, application_number, entity_number)))
+}
+
+/// Parses an event id, of which the site and application numbers are taken from the firing entity
+/// when they are not included in the PDU.
+pub(crate) fn live_event_id<'a>(input: &'a [u8], firing_live_entity_id: &LiveEntityId, includes_site_application: bool) -> IResult<&'a [u8], LiveEventId> {
+    let (input, site_application) = cond(includes_site_application, site_application)(input)?;
+    let (input, event_number) = be_u16(input)?;
+    let (site_number, application_number) = site_application
+        .unwrap_or((firing_live_entity_id.site_number, firing_live_entity_id.application_number));
+
+    Ok((input, LiveEventId::new(site_number, application_number, event_number)))
+}
+
+/// Parses the optional parts of a munition descriptor. Absent warhead and fuse fields default,
+/// an absent quantity and rate imply a single round.
+pub(crate) fn munition_descriptor(input: &[u8], includes_warhead_fuse: bool, includes_quantity_rate: bool) -> IResult<&[u8], MunitionDescriptor> {
+    let (input, warhead) = cond(includes_warhead_fuse, warhead)(input)?;
+    let (input, fuse) = cond(includes_warhead_fuse, fuse)(input)?;
+    let (input, quantity) = cond(includes_quantity_rate, be_u16)(input)?;
+    let (input, rate) = cond(includes_quantity_rate, be_u16)(input)?;
+
+    let implied = implied_munition_descriptor();
+    Ok((input, MunitionDescriptor {
+        warhead: warhead.unwrap_or(implied.warhead),
+        fuse: fuse.unwrap_or(implied.fuse),
+        quantity: quantity.unwrap_or(implied.quantity),
+        rate: rate.unwrap_or(implied.rate),
+    }))
+}
+
+fn site_application(input: &[u8]) -> IResult<&[u8], (u8, u8)> {
+    let (input, site_number) = be_u8(input)?;
+    let (input, application_number) = be_u8(input)?;
+    Ok((input, (site_number, application_number)))
+}
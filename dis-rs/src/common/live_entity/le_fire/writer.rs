@@ -0,0 +1,53 @@
+use bytes::BufMut;
+use crate::common::live_entity::le_fire::model::{includes_quantity_rate, includes_warhead_fuse, LeFire, QUANTITY_RATE_LENGTH, SITE_APPLICATION_LENGTH, WARHEAD_FUSE_LENGTH};
+use crate::common::model::MunitionDescriptor;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for LeFire {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let firing_bytes = self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.flags());
+        buf.put_u8(0u8);
+        let target_bytes = self.target_live_entity_id.map_or(0, |target| target.serialize(buf));
+        let munition_bytes = serialize_site_application(
+            self.includes_munition_site_application(),
+            self.munition_live_entity_id.site_number, self.munition_live_entity_id.application_number, buf);
+        buf.put_u16(self.munition_live_entity_id.entity_number);
+        let event_bytes = serialize_site_application(
+            self.includes_event_site_application(),
+            self.event_id.site_number, self.event_id.application_number, buf);
+        buf.put_u16(self.event_id.event_number);
+        let location_bytes = self.location.serialize(buf);
+        let munition_type_bytes = self.munition_type.serialize(buf);
+        let descriptor_bytes = serialize_munition_descriptor(&self.munition_descriptor, buf);
+        let velocity_bytes = self.velocity.serialize(buf);
+        buf.put_u16(self.range);
+
+        firing_bytes + 2 + target_bytes + munition_bytes + 2 + event_bytes + 2 + location_bytes
+            + munition_type_bytes + descriptor_bytes + velocity_bytes + 2
+    }
+}
+
+pub(crate) fn serialize_site_application<B: BufMut>(included: bool, site_number: u8, application_number: u8, buf: &mut B) -> u16 {
+    if included {
+        buf.put_u8(site_number);
+        buf.put_u8(application_number);
+        SITE_APPLICATION_LENGTH
+    } else { 0 }
+}
+
+/// Writes the parts of the munition descriptor that differ from their defaults.
+pub(crate) fn serialize_munition_descriptor<B: BufMut>(descriptor: &MunitionDescriptor, buf: &mut B) -> u16 {
+    let warhead_fuse_bytes = if includes_warhead_fuse(descriptor) {
+        buf.put_u16(descriptor.warhead.into());
+        buf.put_u16(descriptor.fuse.into());
+        WARHEAD_FUSE_LENGTH
+    } else { 0 };
+    let quantity_rate_bytes = if includes_quantity_rate(descriptor) {
+        buf.put_u16(descriptor.quantity);
+        buf.put_u16(descriptor.rate);
+        QUANTITY_RATE_LENGTH
+    } else { 0 };
+
+    warhead_fuse_bytes + quantity_rate_bytes
+}
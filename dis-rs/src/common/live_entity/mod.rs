@@ -0,0 +1,5 @@
+pub mod tspi;
+pub mod appearance;
+pub mod articulated_parts;
+pub mod le_fire;
+pub mod le_detonation;
@@ -0,0 +1,63 @@
+use crate::common::live_entity::tspi::model::Tspi;
+use crate::common::model::{LiveDeadReckoningParameters, LiveEntityId, LiveEntityOrientation, LiveEntityOrientationError, LiveEntityPositionError, LiveEntityRelativeWorldCoordinates, LiveEntityVector};
+
+pub struct TspiBuilder(Tspi);
+
+impl TspiBuilder {
+    pub fn new() -> Self {
+        TspiBuilder(Tspi::default())
+    }
+
+    pub fn new_from_body(body: Tspi) -> Self {
+        TspiBuilder(body)
+    }
+
+    pub fn build(self) -> Tspi {
+        self.0
+    }
+
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    pub fn with_entity_location(mut self, entity_location: LiveEntityRelativeWorldCoordinates) -> Self {
+        self.0.entity_location = entity_location;
+        self
+    }
+
+    pub fn with_entity_linear_velocity(mut self, entity_linear_velocity: LiveEntityVector) -> Self {
+        self.0.entity_linear_velocity = Some(entity_linear_velocity);
+        self
+    }
+
+    pub fn with_entity_orientation(mut self, entity_orientation: LiveEntityOrientation) -> Self {
+        self.0.entity_orientation = Some(entity_orientation);
+        self
+    }
+
+    pub fn with_position_error(mut self, position_error: LiveEntityPositionError) -> Self {
+        self.0.position_error = Some(position_error);
+        self
+    }
+
+    pub fn with_orientation_error(mut self, orientation_error: LiveEntityOrientationError) -> Self {
+        self.0.orientation_error = Some(orientation_error);
+        self
+    }
+
+    pub fn with_dead_reckoning_parameters(mut self, dead_reckoning_parameters: LiveDeadReckoningParameters) -> Self {
+        self.0.dead_reckoning_parameters = Some(dead_reckoning_parameters);
+        self
+    }
+
+    pub fn with_measured_speed(mut self, measured_speed: u16) -> Self {
+        self.0.measured_speed = Some(measured_speed);
+        self
+    }
+
+    pub fn with_system_specific_data(mut self, system_specific_data: Vec<u8>) -> Self {
+        self.0.system_specific_data = Some(system_specific_data);
+        self
+    }
+}
@@ -0,0 +1,76 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::live_entity::tspi::model::Tspi;
+    use crate::common::model::{DisTimeStamp, LiveDeadReckoningParameters, LiveEntityId, LiveEntityOrientation, LiveEntityOrientationError, LiveEntityPositionError, LiveEntityRelativeWorldCoordinates, LiveEntityVector, Location, Pdu, PduHeader};
+    use crate::enumerations::{DeadReckoningAlgorithm, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn tspi_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::TSPI);
+
+        let body = Tspi::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_entity_location(LiveEntityRelativeWorldCoordinates::new(1, 100, -200, 30))
+            .with_entity_linear_velocity(LiveEntityVector::new(50, 0, -5))
+            .with_entity_orientation(LiveEntityOrientation::new(64, 0, -10))
+            .with_position_error(LiveEntityPositionError::new(2, 3))
+            .with_orientation_error(LiveEntityOrientationError::new(10, 20, 30))
+            .with_dead_reckoning_parameters(LiveDeadReckoningParameters::new(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+                LiveEntityVector::default(),
+                LiveEntityVector::new(0, 0, 100)))
+            .with_measured_speed(50)
+            .with_system_specific_data(vec![1, 2, 3])
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn tspi_entity_state_conversion() {
+        let reference_point = Location::new(3_900_000.0, 300_000.0, 5_000_000.0);
+        let tspi = Tspi::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_entity_location(LiveEntityRelativeWorldCoordinates::new(7, 100, -200, 30))
+            .with_entity_linear_velocity(LiveEntityVector::new(55, 0, -5))
+            .with_entity_orientation(LiveEntityOrientation::new(64, 0, -10))
+            .with_dead_reckoning_parameters(LiveDeadReckoningParameters::new(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+                LiveEntityVector::new(10, 0, 0),
+                LiveEntityVector::new(0, 0, 100)))
+            .build();
+
+        let entity_state = tspi.to_entity_state(&reference_point);
+        assert_eq!(entity_state.entity_location, Location::new(3_900_100.0, 299_800.0, 5_000_030.0));
+        assert_eq!(entity_state.entity_linear_velocity.first_vector_component, 5.5);
+        assert_eq!(entity_state.entity_orientation.psi, std::f32::consts::FRAC_PI_2);
+
+        let converted = Tspi::from_entity_state(&entity_state, 7, &reference_point);
+        assert_eq!(converted, tspi);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::entity_state::model::{DrParameters, EntityState};
+use crate::common::live_entity::tspi::builder::TspiBuilder;
+use crate::common::model::{EntityId, EntityType, LIVE_ENTITY_ACCELERATION_SCALE, LIVE_ENTITY_ANGULAR_VELOCITY_SCALE, LIVE_ENTITY_VELOCITY_SCALE, LiveDeadReckoningParameters, LiveEntityId, LiveEntityOrientation, LiveEntityOrientationError, LiveEntityPositionError, LiveEntityRelativeWorldCoordinates, LiveEntityVector, Location, PduBody};
+use crate::enumerations::{ForceId, PduType};
+
+const BASE_TSPI_BODY_LENGTH: u16 = 13;
+const LINEAR_VELOCITY_LENGTH: u16 = 6;
+const ORIENTATION_LENGTH: u16 = 3;
+const POSITION_ERROR_LENGTH: u16 = 4;
+const ORIENTATION_ERROR_LENGTH: u16 = 6;
+const DEAD_RECKONING_PARAMETERS_LENGTH: u16 = 13;
+const MEASURED_SPEED_LENGTH: u16 = 2;
+const SYSTEM_SPECIFIC_DATA_LENGTH_LENGTH: u16 = 1;
+
+pub(crate) const LINEAR_VELOCITY_FLAG: u8 = 0x01;
+pub(crate) const ORIENTATION_FLAG: u8 = 0x02;
+pub(crate) const POSITION_ERROR_FLAG: u8 = 0x04;
+pub(crate) const ORIENTATION_ERROR_FLAG: u8 = 0x08;
+pub(crate) const DEAD_RECKONING_PARAMETERS_FLAG: u8 = 0x10;
+pub(crate) const MEASURED_SPEED_FLAG: u8 = 0x20;
+pub(crate) const SYSTEM_SPECIFIC_DATA_FLAG: u8 = 0x40;
+
+/// 9.4.2 Time Space Position Information (TSPI) PDU
+///
+/// The optional fields are present in the PDU when they are `Some`; the TSPI Flag field is derived from them.
+#[derive(Debug, Default, PartialEq)]
+pub struct Tspi {
+    pub live_entity_id: LiveEntityId,
+    pub entity_location: LiveEntityRelativeWorldCoordinates,
+    pub entity_linear_velocity: Option<LiveEntityVector>,
+    pub entity_orientation: Option<LiveEntityOrientation>,
+    pub position_error: Option<LiveEntityPositionError>,
+    pub orientation_error: Option<LiveEntityOrientationError>,
+    pub dead_reckoning_parameters: Option<LiveDeadReckoningParameters>,
+    pub measured_speed: Option<u16>,
+    pub system_specific_data: Option<Vec<u8>>,
+}
+
+impl Tspi {
+    pub fn builder() -> TspiBuilder {
+        TspiBuilder::new()
+    }
+
+    pub fn into_builder(self) -> TspiBuilder {
+        TspiBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::TSPI(self)
+    }
+
+    pub fn tspi_flag(&self) -> u8 {
+        let mut flag = 0u8;
+        if self.entity_linear_velocity.is_some() { flag |= LINEAR_VELOCITY_FLAG; }
+        if self.entity_orientation.is_some() { flag |= ORIENTATION_FLAG; }
+        if self.position_error.is_some() { flag |= POSITION_ERROR_FLAG; }
+        if self.orientation_error.is_some() { flag |= ORIENTATION_ERROR_FLAG; }
+        if self.dead_reckoning_parameters.is_some() { flag |= DEAD_RECKONING_PARAMETERS_FLAG; }
+        if self.measured_speed.is_some() { flag |= MEASURED_SPEED_FLAG; }
+        if self.system_specific_data.is_some() { flag |= SYSTEM_SPECIFIC_DATA_FLAG; }
+        flag
+    }
+
+    /// Creates an `EntityState` holding the spatial information of this PDU, given the location of the reference point
+    /// the entity location is relative to. Fields that are not present in the TSPI PDU keep their default values.
+    pub fn to_entity_state(&self, reference_point: &Location) -> EntityState {
+        let mut entity_state = EntityState::new(EntityId::from(self.live_entity_id), ForceId::default(), EntityType::default())
+            .with_location(self.entity_location.to_location(reference_point));
+        if let Some(velocity) = self.entity_linear_velocity {
+            entity_state = entity_state.with_velocity(velocity.to_vector_f32(LIVE_ENTITY_VELOCITY_SCALE));
+        }
+        if let Some(orientation) = self.entity_orientation {
+            entity_state = entity_state.with_orientation(orientation.to_orientation());
+        }
+        if let Some(parameters) = self.dead_reckoning_parameters {
+            entity_state = entity_state.with_dead_reckoning_parameters(DrParameters::default()
+                .with_algorithm(parameters.algorithm)
+                .with_linear_acceleration(parameters.linear_acceleration.to_vector_f32(LIVE_ENTITY_ACCELERATION_SCALE))
+                .with_angular_velocity(parameters.angular_velocity.to_vector_f32(LIVE_ENTITY_ANGULAR_VELOCITY_SCALE)));
+        }
+        entity_state
+    }
+
+    /// Creates a TSPI PDU from the spatial information of an `EntityState`, with the location expressed relative to
+    /// the reference point with number `reference_point_id`, located at `reference_point`.
+    pub fn from_entity_state(entity_state: &EntityState, reference_point_id: u16, reference_point: &Location) -> Self {
+        let dead_reckoning = &entity_state.dead_reckoning_parameters;
+        Tspi::builder()
+            .with_live_entity_id(LiveEntityId::from(&entity_state.entity_id))
+            .with_entity_location(LiveEntityRelativeWorldCoordinates::from_location(
+                &entity_state.entity_location, reference_point_id, reference_point))
+            .with_entity_linear_velocity(LiveEntityVector::from_vector_f32(
+                &entity_state.entity_linear_velocity, LIVE_ENTITY_VELOCITY_SCALE))
+            .with_entity_orientation(LiveEntityOrientation::from_orientation(&entity_state.entity_orientation))
+            .with_dead_reckoning_parameters(LiveDeadReckoningParameters::new(
+                dead_reckoning.algorithm,
+                LiveEntityVector::from_vector_f32(&dead_reckoning.linear_acceleration, LIVE_ENTITY_ACCELERATION_SCALE),
+                LiveEntityVector::from_vector_f32(&dead_reckoning.angular_velocity, LIVE_ENTITY_ANGULAR_VELOCITY_SCALE)))
+            .build()
+    }
+}
+
+impl BodyInfo for Tspi {
+    fn body_length(&self) -> u16 {
+        BASE_TSPI_BODY_LENGTH
+            + self.entity_linear_velocity.map_or(0, |_| LINEAR_VELOCITY_LENGTH)
+            + self.entity_orientation.map_or(0, |_| ORIENTATION_LENGTH)
+            + self.position_error.map_or(0, |_| POSITION_ERROR_LENGTH)
+            + self.orientation_error.map_or(0, |_| ORIENTATION_ERROR_LENGTH)
+            + self.dead_reckoning_parameters.map_or(0, |_| DEAD_RECKONING_PARAMETERS_LENGTH)
+            + self.measured_speed.map_or(0, |_| MEASURED_SPEED_LENGTH)
+            + self.system_specific_data.as_ref().map_or(0, |data| SYSTEM_SPECIFIC_DATA_LENGTH_LENGTH + data.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::TSPI
+    }
+}
+
+impl Interaction for Tspi {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::combinator::cond;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::live_entity::tspi::model::{DEAD_RECKONING_PARAMETERS_FLAG, LINEAR_VELOCITY_FLAG, MEASURED_SPEED_FLAG, ORIENTATION_ERROR_FLAG, ORIENTATION_FLAG, POSITION_ERROR_FLAG, SYSTEM_SPECIFIC_DATA_FLAG, Tspi};
+use crate::common::model::PduBody;
+use crate::common::parser::{live_dead_reckoning_parameters, live_entity_id, live_entity_orientation, live_entity_orientation_error, live_entity_position_error, live_entity_relative_world_coordinates, live_entity_vector};
+
+pub fn tspi_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, live_entity_id) = context("Live Entity ID", live_entity_id)(input)?;
+    let (input, tspi_flag) = context("TSPI Flag", be_u8)(input)?;
+    let (input, entity_location) = context("Entity Location", live_entity_relative_world_coordinates)(input)?;
+    let (input, entity_linear_velocity) = context("Entity Linear Velocity", cond(tspi_flag & LINEAR_VELOCITY_FLAG != 0, live_entity_vector))(input)?;
+    let (input, entity_orientation) = context("Entity Orientation", cond(tspi_flag & ORIENTATION_FLAG != 0, live_entity_orientation))(input)?;
+    let (input, position_error) = context("Position Error", cond(tspi_flag & POSITION_ERROR_FLAG != 0, live_entity_position_error))(input)?;
+    let (input, orientation_error) = context("Orientation Error", cond(tspi_flag & ORIENTATION_ERROR_FLAG != 0, live_entity_orientation_error))(input)?;
+    let (input, dead_reckoning_parameters) = context("Dead Reckoning Parameters", cond(tspi_flag & DEAD_RECKONING_PARAMETERS_FLAG != 0, live_dead_reckoning_parameters))(input)?;
+    let (input, measured_speed) = context("Measured Speed", cond(tspi_flag & MEASURED_SPEED_FLAG != 0, be_u16))(input)?;
+    let (input, system_specific_data) = context("System Specific Data", cond(tspi_flag & SYSTEM_SPECIFIC_DATA_FLAG != 0, system_specific_data))(input)?;
+
+    let body = Tspi {
+        live_entity_id,
+        entity_location,
+        entity_linear_velocity,
+        entity_orientation,
+        position_error,
+        orientation_error,
+        dead_reckoning_parameters,
+        measured_speed,
+        system_specific_data,
+    };
+
+    Ok((input, body.into_pdu_body()))
+}
+
+fn system_specific_data(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+    let (input, data_length) = be_u8(input)?;
+    let (input, data) = take(data_length)(input)?;
+    Ok((input, data.to_vec()))
+}
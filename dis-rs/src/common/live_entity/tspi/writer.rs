@@ -0,0 +1,28 @@
+use bytes::BufMut;
+use crate::common::live_entity::tspi::model::Tspi;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for Tspi {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let live_entity_id_bytes = self.live_entity_id.serialize(buf);
+        buf.put_u8(self.tspi_flag());
+        let location_bytes = self.entity_location.serialize(buf);
+        let velocity_bytes = self.entity_linear_velocity.map_or(0, |velocity| velocity.serialize(buf));
+        let orientation_bytes = self.entity_orientation.map_or(0, |orientation| orientation.serialize(buf));
+        let position_error_bytes = self.position_error.map_or(0, |error| error.serialize(buf));
+        let orientation_error_bytes = self.orientation_error.map_or(0, |error| error.serialize(buf));
+        let dead_reckoning_bytes = self.dead_reckoning_parameters.map_or(0, |parameters| parameters.serialize(buf));
+        let measured_speed_bytes = self.measured_speed.map_or(0, |speed| {
+            buf.put_u16(speed);
+            2
+        });
+        let system_specific_data_bytes = self.system_specific_data.as_ref().map_or(0, |data| {
+            buf.put_u8(data.len() as u8);
+            buf.put_slice(data);
+            1 + data.len() as u16
+        });
+
+        live_entity_id_bytes + 1 + location_bytes + velocity_bytes + orientation_bytes + position_error_bytes
+            + orientation_error_bytes + dead_reckoning_bytes + measured_speed_bytes + system_specific_data_bytes
+    }
+}
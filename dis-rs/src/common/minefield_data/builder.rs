@@ -0,0 +1,78 @@
+use crate::common::minefield_data::model::{Mine, MinefieldData};
+use crate::common::model::{DataFilter, EntityId, EntityType};
+
+pub struct MinefieldDataBuilder(MinefieldData);
+
+impl MinefieldDataBuilder {
+    pub fn new() -> Self {
+        MinefieldDataBuilder(MinefieldData::default())
+    }
+
+    pub fn new_from_body(body: MinefieldData) -> Self {
+        MinefieldDataBuilder(body)
+    }
+
+    pub fn build(self) -> MinefieldData {
+        self.0
+    }
+
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    pub fn with_minefield_sequence_number(mut self, minefield_sequence_number: u16) -> Self {
+        self.0.minefield_sequence_number = minefield_sequence_number;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    pub fn with_pdu_sequence_number(mut self, pdu_sequence_number: u8) -> Self {
+        self.0.pdu_sequence_number = pdu_sequence_number;
+        self
+    }
+
+    pub fn with_number_of_pdus(mut self, number_of_pdus: u8) -> Self {
+        self.0.number_of_pdus = number_of_pdus;
+        self
+    }
+
+    pub fn with_data_filter(mut self, data_filter: DataFilter) -> Self {
+        self.0.data_filter = data_filter;
+        self
+    }
+
+    pub fn with_mine_type(mut self, mine_type: EntityType) -> Self {
+        self.0.mine_type = mine_type;
+        self
+    }
+
+    pub fn with_sensor_types(mut self, sensor_types: Vec<u16>) -> Self {
+        self.0.sensor_types = sensor_types;
+        self
+    }
+
+    pub fn with_sensor_type(mut self, sensor_type: u16) -> Self {
+        self.0.sensor_types.push(sensor_type);
+        self
+    }
+
+    pub fn with_mines(mut self, mines: Vec<Mine>) -> Self {
+        self.0.mines = mines;
+        self
+    }
+
+    pub fn with_mine(mut self, mine: Mine) -> Self {
+        self.0.mines.push(mine);
+        self
+    }
+}
@@ -0,0 +1,116 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::minefield_data::model::{Mine, MinefieldData, TripDetonationWire};
+    use crate::common::model::{ClockTime, DataFilter, DisTimeStamp, EntityId, EntityType, Orientation, Pdu, PduHeader, PerimeterPoint, VectorF32};
+    use crate::enumerations::{EntityKind, PduType};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn minefield_data_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::MinefieldData);
+
+        let all_fields = DataFilter::default()
+            .with_ground_burial_depth_offset(true)
+            .with_water_burial_depth_offset(true)
+            .with_snow_burial_depth_offset(true)
+            .with_mine_orientation(true)
+            .with_thermal_contrast(true)
+            .with_reflectance(true)
+            .with_mine_emplacement_time(true)
+            .with_trip_detonation_wire(true)
+            .with_fusing(true)
+            .with_scalar_detection_coefficient(true)
+            .with_paint_scheme(true);
+        let mine = Mine::new(VectorF32::new(1.0, 2.0, 0.0), 1)
+            .with_ground_burial_depth_offset(0.1)
+            .with_water_burial_depth_offset(0.0)
+            .with_snow_burial_depth_offset(0.2)
+            .with_orientation(Orientation::new(0.1, 0.2, 0.3))
+            .with_thermal_contrast(1.5)
+            .with_reflectance(0.3)
+            .with_emplacement_time(ClockTime::new(10, 200))
+            .with_fusing(0x0101)
+            .with_scalar_detection_coefficient(20)
+            .with_paint_scheme(1);
+        let body = MinefieldData::builder()
+            .with_minefield_id(EntityId::new(1, 1, 30))
+            .with_requesting_entity_id(EntityId::new(1, 2, 5))
+            .with_minefield_sequence_number(4)
+            .with_request_id(7)
+            .with_pdu_sequence_number(1)
+            .with_number_of_pdus(1)
+            .with_data_filter(all_fields)
+            .with_mine_type(EntityType::default()
+                .with_kind(EntityKind::Munition))
+            .with_sensor_type(1)
+            .with_mine(mine.clone()
+                .with_trip_detonation_wire(TripDetonationWire::new(vec![PerimeterPoint::new(0.0, 0.0), PerimeterPoint::new(5.0, 0.0)]))
+                .with_trip_detonation_wire(TripDetonationWire::new(vec![PerimeterPoint::new(0.0, 0.0), PerimeterPoint::new(0.0, 5.0), PerimeterPoint::new(5.0, 5.0)])))
+            .with_mine(Mine { entity_number: 2, trip_detonation_wires: Some(vec![]), ..mine.clone() })
+            .with_mine(Mine { entity_number: 3, trip_detonation_wires: Some(vec![]), ..mine })
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn minefield_data_filtered_fields() {
+        let header = PduHeader::new_v6(1, PduType::MinefieldData);
+
+        let body = MinefieldData::builder()
+            .with_minefield_id(EntityId::new(1, 1, 30))
+            .with_requesting_entity_id(EntityId::new(1, 2, 5))
+            .with_data_filter(DataFilter::default()
+                .with_thermal_contrast(true)
+                .with_paint_scheme(true))
+            .with_mine(Mine::new(VectorF32::new(1.0, 2.0, 0.0), 1)
+                .with_thermal_contrast(1.5)
+                .with_paint_scheme(2))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
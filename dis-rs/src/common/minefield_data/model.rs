@@ -0,0 +1,213 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::minefield_data::builder::MinefieldDataBuilder;
+use crate::common::model::{ClockTime, DataFilter, EntityId, EntityType, Orientation, PduBody, PerimeterPoint, VectorF32, PERIMETER_POINT_RECORD_LENGTH};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::PduType;
+
+pub const BASE_MINEFIELD_DATA_BODY_LENGTH: u16 = 32;
+
+/// 5.10.4 Minefield Data PDU
+///
+/// The Data Filter states which of the optional fields of the mines are included in the PDU.
+/// When serializing, optional fields of a mine that are included by the filter but not set are written as zero,
+/// and fields that are set but not included by the filter are not written.
+#[derive(Debug, Default, PartialEq)]
+pub struct MinefieldData {
+    pub minefield_id: EntityId,
+    pub requesting_entity_id: EntityId,
+    pub minefield_sequence_number: u16,
+    pub request_id: u8,
+    pub pdu_sequence_number: u8,
+    pub number_of_pdus: u8,
+    pub data_filter: DataFilter,
+    pub mine_type: EntityType,
+    pub sensor_types: Vec<u16>,
+    pub mines: Vec<Mine>,
+}
+
+impl MinefieldData {
+    pub fn builder() -> MinefieldDataBuilder {
+        MinefieldDataBuilder::new()
+    }
+
+    pub fn into_builder(self) -> MinefieldDataBuilder {
+        MinefieldDataBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldData(self)
+    }
+
+    /// The number of octets of padding after the list of sensor types, to align the body to 32 bits.
+    pub fn sensor_types_padding_length(&self) -> u16 {
+        padding_to_four_octets(self.sensor_types.len() * 2)
+    }
+
+    /// The total number of trip and detonation wires of all mines.
+    pub fn number_of_wires(&self) -> usize {
+        self.mines.iter()
+            .map(|mine| mine.trip_detonation_wires.as_ref().map_or(0, |wires| wires.len()))
+            .sum()
+    }
+
+    fn trip_detonation_wires_length(&self) -> u16 {
+        if !self.data_filter.trip_detonation_wire {
+            return 0;
+        }
+        let number_of_wires = self.number_of_wires();
+        let number_of_vertices = self.mines.iter()
+            .flat_map(|mine| mine.trip_detonation_wires.iter().flatten())
+            .map(|wire| wire.vertices.len() as u16)
+            .sum::<u16>();
+
+        self.mines.len() as u16 + padding_to_four_octets(self.mines.len())
+            + number_of_wires as u16 + padding_to_four_octets(number_of_wires)
+            + number_of_vertices * PERIMETER_POINT_RECORD_LENGTH
+    }
+}
+
+/// The number of octets of the one- and two-octet per-mine fields, from the mine entity numbers up to the paint schemes.
+pub(crate) fn short_fields_length(data_filter: &DataFilter, number_of_mines: usize) -> usize {
+    let fusing = if data_filter.fusing { 2 } else { 0 };
+    let scalar_detection_coefficient = if data_filter.scalar_detection_coefficient { 1 } else { 0 };
+    let paint_scheme = if data_filter.paint_scheme { 1 } else { 0 };
+    number_of_mines * (2 + fusing + scalar_detection_coefficient + paint_scheme)
+}
+
+pub(crate) fn padding_to_four_octets(length: usize) -> u16 {
+    ((FOUR_OCTETS - length % FOUR_OCTETS) % FOUR_OCTETS) as u16
+}
+
+impl BodyInfo for MinefieldData {
+    fn body_length(&self) -> u16 {
+        let filter = &self.data_filter;
+        let number_of_mines = self.mines.len() as u16;
+        let per_mine_length = 12
+            + if filter.ground_burial_depth_offset { 4 } else { 0 }
+            + if filter.water_burial_depth_offset { 4 } else { 0 }
+            + if filter.snow_burial_depth_offset { 4 } else { 0 }
+            + if filter.mine_orientation { 12 } else { 0 }
+            + if filter.thermal_contrast { 4 } else { 0 }
+            + if filter.reflectance { 4 } else { 0 }
+            + if filter.mine_emplacement_time { 8 } else { 0 };
+        let short_fields_length = short_fields_length(filter, self.mines.len());
+
+        BASE_MINEFIELD_DATA_BODY_LENGTH
+            + (self.sensor_types.len() as u16 * 2) + self.sensor_types_padding_length()
+            + (number_of_mines * per_mine_length)
+            + short_fields_length as u16 + padding_to_four_octets(short_fields_length)
+            + self.trip_detonation_wires_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldData
+    }
+}
+
+impl Interaction for MinefieldData {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+}
+
+/// The data of a single mine in a Minefield Data PDU.
+/// Optional fields are present when the corresponding flag of the Data Filter is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mine {
+    pub location: VectorF32,
+    pub ground_burial_depth_offset: Option<f32>,
+    pub water_burial_depth_offset: Option<f32>,
+    pub snow_burial_depth_offset: Option<f32>,
+    pub orientation: Option<Orientation>,
+    pub thermal_contrast: Option<f32>,
+    pub reflectance: Option<f32>,
+    pub emplacement_time: Option<ClockTime>,
+    pub entity_number: u16,
+    pub fusing: Option<u16>, // Fusing bitfield
+    pub scalar_detection_coefficient: Option<u8>,
+    pub paint_scheme: Option<u8>,
+    pub trip_detonation_wires: Option<Vec<TripDetonationWire>>,
+}
+
+impl Mine {
+    pub fn new(location: VectorF32, entity_number: u16) -> Self {
+        Self {
+            location,
+            entity_number,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_ground_burial_depth_offset(mut self, ground_burial_depth_offset: f32) -> Self {
+        self.ground_burial_depth_offset = Some(ground_burial_depth_offset);
+        self
+    }
+
+    pub fn with_water_burial_depth_offset(mut self, water_burial_depth_offset: f32) -> Self {
+        self.water_burial_depth_offset = Some(water_burial_depth_offset);
+        self
+    }
+
+    pub fn with_snow_burial_depth_offset(mut self, snow_burial_depth_offset: f32) -> Self {
+        self.snow_burial_depth_offset = Some(snow_burial_depth_offset);
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn with_thermal_contrast(mut self, thermal_contrast: f32) -> Self {
+        self.thermal_contrast = Some(thermal_contrast);
+        self
+    }
+
+    pub fn with_reflectance(mut self, reflectance: f32) -> Self {
+        self.reflectance = Some(reflectance);
+        self
+    }
+
+    pub fn with_emplacement_time(mut self, emplacement_time: ClockTime) -> Self {
+        self.emplacement_time = Some(emplacement_time);
+        self
+    }
+
+    pub fn with_fusing(mut self, fusing: u16) -> Self {
+        self.fusing = Some(fusing);
+        self
+    }
+
+    pub fn with_scalar_detection_coefficient(mut self, scalar_detection_coefficient: u8) -> Self {
+        self.scalar_detection_coefficient = Some(scalar_detection_coefficient);
+        self
+    }
+
+    pub fn with_paint_scheme(mut self, paint_scheme: u8) -> Self {
+        self.paint_scheme = Some(paint_scheme);
+        self
+    }
+
+    pub fn with_trip_detonation_wire(mut self, wire: TripDetonationWire) -> Self {
+        self.trip_detonation_wires.get_or_insert_with(Vec::new).push(wire);
+        self
+    }
+}
+
+/// A trip or detonation wire of a mine, as a list of vertices relative to the location of the mine.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TripDetonationWire {
+    pub vertices: Vec<PerimeterPoint>,
+}
+
+impl TripDetonationWire {
+    pub fn new(vertices: Vec<PerimeterPoint>) -> Self {
+        Self {
+            vertices,
+        }
+    }
+}
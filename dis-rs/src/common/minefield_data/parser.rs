@@ -0,0 +1,116 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u8};
+use crate::common::minefield_data::model::{Mine, MinefieldData, padding_to_four_octets, short_fields_length, TripDetonationWire};
+use crate::common::model::PduBody;
+use crate::common::parser::{clock_time, data_filter, entity_id, entity_type, orientation, perimeter_point, vec3_f32};
+
+pub fn minefield_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", entity_id)(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id)(input)?;
+    let (input, minefield_sequence_number) = context("Minefield Sequence Number", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u8)(input)?;
+    let (input, pdu_sequence_number) = context("PDU Sequence Number", be_u8)(input)?;
+    let (input, number_of_pdus) = context("Number of PDUs", be_u8)(input)?;
+    let (input, number_of_mines) = context("Number of Mines", be_u8)(input)?;
+    let (input, number_of_sensor_types) = context("Number of Sensor Types", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, data_filter) = context("Data Filter", data_filter)(input)?;
+    let (input, mine_type) = context("Mine Type", entity_type)(input)?;
+    let (input, sensor_types) = context("Sensor Types", count(be_u16, number_of_sensor_types as usize))(input)?;
+    let (input, _padding) = context("Padding", take(padding_to_four_octets(number_of_sensor_types as usize * 2)))(input)?;
+
+    let number_of_mines = number_of_mines as usize;
+    let (input, locations) = context("Mine Locations", count(vec3_f32, number_of_mines))(input)?;
+    let (input, ground_burial_depth_offsets) = context("Ground Burial Depth Offsets", |input| optional_mine_field(input, data_filter.ground_burial_depth_offset, number_of_mines, be_f32))(input)?;
+    let (input, water_burial_depth_offsets) = context("Water Burial Depth Offsets", |input| optional_mine_field(input, data_filter.water_burial_depth_offset, number_of_mines, be_f32))(input)?;
+    let (input, snow_burial_depth_offsets) = context("Snow Burial Depth Offsets", |input| optional_mine_field(input, data_filter.snow_burial_depth_offset, number_of_mines, be_f32))(input)?;
+    let (input, orientations) = context("Mine Orientations", |input| optional_mine_field(input, data_filter.mine_orientation, number_of_mines, orientation))(input)?;
+    let (input, thermal_contrasts) = context("Thermal Contrasts", |input| optional_mine_field(input, data_filter.thermal_contrast, number_of_mines, be_f32))(input)?;
+    let (input, reflectances) = context("Reflectances", |input| optional_mine_field(input, data_filter.reflectance, number_of_mines, be_f32))(input)?;
+    let (input, emplacement_times) = context("Mine Emplacement Times", |input| optional_mine_field(input, data_filter.mine_emplacement_time, number_of_mines, clock_time))(input)?;
+    let (input, entity_numbers) = context("Mine Entity Numbers", count(be_u16, number_of_mines))(input)?;
+    let (input, fusings) = context("Fusings", |input| optional_mine_field(input, data_filter.fusing, number_of_mines, be_u16))(input)?;
+    let (input, scalar_detection_coefficients) = context("Scalar Detection Coefficients", |input| optional_mine_field(input, data_filter.scalar_detection_coefficient, number_of_mines, be_u8))(input)?;
+    let (input, paint_schemes) = context("Paint Schemes", |input| optional_mine_field(input, data_filter.paint_scheme, number_of_mines, be_u8))(input)?;
+    let (input, _padding) = context("Padding", take(padding_to_four_octets(short_fields_length(&data_filter, number_of_mines))))(input)?;
+    let (input, wires) = if data_filter.trip_detonation_wire {
+        let (input, wires) = context("Trip/Detonation Wires", |input| trip_detonation_wires(input, number_of_mines))(input)?;
+        (input, wires.into_iter().map(Some).collect())
+    } else { (input, vec![None; number_of_mines]) };
+
+    let mines = locations.into_iter()
+        .zip(entity_numbers)
+        .zip(wires)
+        .enumerate()
+        .map(|(index, ((location, entity_number), wires))| Mine {
+            location,
+            ground_burial_depth_offset: ground_burial_depth_offsets[index],
+            water_burial_depth_offset: water_burial_depth_offsets[index],
+            snow_burial_depth_offset: snow_burial_depth_offsets[index],
+            orientation: orientations[index],
+            thermal_contrast: thermal_contrasts[index],
+            reflectance: reflectances[index],
+            emplacement_time: emplacement_times[index],
+            entity_number,
+            fusing: fusings[index],
+            scalar_detection_coefficient: scalar_detection_coefficients[index],
+            paint_scheme: paint_schemes[index],
+            trip_detonation_wires: wires,
+        })
+        .collect();
+
+    let body = MinefieldData::builder()
+        .with_minefield_id(minefield_id)
+        .with_requesting_entity_id(requesting_entity_id)
+        .with_minefield_sequence_number(minefield_sequence_number)
+        .with_request_id(request_id)
+        .with_pdu_sequence_number(pdu_sequence_number)
+        .with_number_of_pdus(number_of_pdus)
+        .with_data_filter(data_filter)
+        .with_mine_type(mine_type)
+        .with_sensor_types(sensor_types)
+        .with_mines(mines)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+/// Parses the values of a per-mine field for all mines when the field is included by the Data Filter,
+/// returning a value (or `None`) for each mine.
+fn optional_mine_field<'a, O: Copy, F>(input: &'a [u8], included: bool, number_of_mines: usize, parser: F)
+    -> IResult<&'a [u8], Vec<Option<O>>>
+where F: FnMut(&'a [u8]) -> IResult<&'a [u8], O> {
+    if included {
+        let (input, values) = count(parser, number_of_mines)(input)?;
+        Ok((input, values.into_iter().map(Some).collect()))
+    } else {
+        Ok((input, vec![None; number_of_mines]))
+    }
+}
+
+/// Parses the trip and detonation wires of all mines, returning the wires for each mine.
+fn trip_detonation_wires(input: &[u8], number_of_mines: usize) -> IResult<&[u8], Vec<Vec<TripDetonationWire>>> {
+    let (input, wires_per_mine) = count(be_u8, number_of_mines)(input)?;
+    let (input, _padding) = take(padding_to_four_octets(number_of_mines))(input)?;
+    let number_of_wires = wires_per_mine.iter().map(|wires| *wires as usize).sum::<usize>();
+    let (input, vertices_per_wire) = count(be_u8, number_of_wires)(input)?;
+    let (input, _padding) = take(padding_to_four_octets(number_of_wires))(input)?;
+
+    let mut input = input;
+    let mut wires = Vec::with_capacity(number_of_wires);
+    for number_of_vertices in vertices_per_wire {
+        let (remainder, vertices) = count(perimeter_point, number_of_vertices as usize)(input)?;
+        wires.push(TripDetonationWire::new(vertices));
+        input = remainder;
+    }
+
+    let mut wires = wires.into_iter();
+    let wires_per_mine = wires_per_mine.iter()
+        .map(|number_of_wires| wires.by_ref().take(*number_of_wires as usize).collect())
+        .collect();
+
+    Ok((input, wires_per_mine))
+}
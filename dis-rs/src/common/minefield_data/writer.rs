@@ -0,0 +1,102 @@
+use bytes::BufMut;
+use crate::common::minefield_data::model::{MinefieldData, padding_to_four_octets, short_fields_length};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for MinefieldData {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let filter = &self.data_filter;
+        let minefield_id_bytes = self.minefield_id.serialize(buf);
+        let requesting_id_bytes = self.requesting_entity_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.pdu_sequence_number);
+        buf.put_u8(self.number_of_pdus);
+        buf.put_u8(self.mines.len() as u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        buf.put_u8(0u8);
+        let data_filter_bytes = filter.serialize(buf);
+        let mine_type_bytes = self.mine_type.serialize(buf);
+        self.sensor_types.iter().for_each(|sensor_type| buf.put_u16(*sensor_type));
+        let sensor_types_padding = self.sensor_types_padding_length();
+        (0..sensor_types_padding).for_each(|_i| buf.put_u8(0u8));
+        let sensor_types_bytes = self.sensor_types.len() as u16 * 2 + sensor_types_padding;
+
+        let mut mines_bytes = self.mines.iter()
+            .map(|mine| mine.location.serialize(buf))
+            .sum::<u16>();
+        if filter.ground_burial_depth_offset {
+            self.mines.iter().for_each(|mine| buf.put_f32(mine.ground_burial_depth_offset.unwrap_or_default()));
+            mines_bytes += self.mines.len() as u16 * 4;
+        }
+        if filter.water_burial_depth_offset {
+            self.mines.iter().for_each(|mine| buf.put_f32(mine.water_burial_depth_offset.unwrap_or_default()));
+            mines_bytes += self.mines.len() as u16 * 4;
+        }
+        if filter.snow_burial_depth_offset {
+            self.mines.iter().for_each(|mine| buf.put_f32(mine.snow_burial_depth_offset.unwrap_or_default()));
+            mines_bytes += self.mines.len() as u16 * 4;
+        }
+        if filter.mine_orientation {
+            mines_bytes += self.mines.iter()
+                .map(|mine| mine.orientation.unwrap_or_default().serialize(buf))
+                .sum::<u16>();
+        }
+        if filter.thermal_contrast {
+            self.mines.iter().for_each(|mine| buf.put_f32(mine.thermal_contrast.unwrap_or_default()));
+            mines_bytes += self.mines.len() as u16 * 4;
+        }
+        if filter.reflectance {
+            self.mines.iter().for_each(|mine| buf.put_f32(mine.reflectance.unwrap_or_default()));
+            mines_bytes += self.mines.len() as u16 * 4;
+        }
+        if filter.mine_emplacement_time {
+            mines_bytes += self.mines.iter()
+                .map(|mine| mine.emplacement_time.unwrap_or_default().serialize(buf))
+                .sum::<u16>();
+        }
+        self.mines.iter().for_each(|mine| buf.put_u16(mine.entity_number));
+        if filter.fusing {
+            self.mines.iter().for_each(|mine| buf.put_u16(mine.fusing.unwrap_or_default()));
+        }
+        if filter.scalar_detection_coefficient {
+            self.mines.iter().for_each(|mine| buf.put_u8(mine.scalar_detection_coefficient.unwrap_or_default()));
+        }
+        if filter.paint_scheme {
+            self.mines.iter().for_each(|mine| buf.put_u8(mine.paint_scheme.unwrap_or_default()));
+        }
+        let short_fields_length = short_fields_length(filter, self.mines.len());
+        let short_fields_padding = padding_to_four_octets(short_fields_length);
+        (0..short_fields_padding).for_each(|_i| buf.put_u8(0u8));
+        mines_bytes += short_fields_length as u16 + short_fields_padding;
+
+        if filter.trip_detonation_wire {
+            mines_bytes += self.serialize_trip_detonation_wires(buf);
+        }
+
+        minefield_id_bytes + requesting_id_bytes + 8 + data_filter_bytes + mine_type_bytes
+            + sensor_types_bytes + mines_bytes
+    }
+}
+
+impl MinefieldData {
+    fn serialize_trip_detonation_wires<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let wires = self.mines.iter()
+            .flat_map(|mine| mine.trip_detonation_wires.iter().flatten());
+
+        self.mines.iter().for_each(|mine| buf.put_u8(mine.trip_detonation_wires.as_ref().map_or(0, |wires| wires.len()) as u8));
+        let wires_padding = padding_to_four_octets(self.mines.len());
+        (0..wires_padding).for_each(|_i| buf.put_u8(0u8));
+
+        let number_of_wires = self.number_of_wires();
+        wires.clone().for_each(|wire| buf.put_u8(wire.vertices.len() as u8));
+        let vertices_padding = padding_to_four_octets(number_of_wires);
+        (0..vertices_padding).for_each(|_i| buf.put_u8(0u8));
+
+        let vertices_bytes = wires
+            .flat_map(|wire| wire.vertices.iter())
+            .map(|vertex| vertex.serialize(buf))
+            .sum::<u16>();
+
+        self.mines.len() as u16 + wires_padding + number_of_wires as u16 + vertices_padding + vertices_bytes
+    }
+}
@@ -0,0 +1,63 @@
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::model::{DataFilter, EntityId, EntityType, PerimeterPoint};
+
+pub struct MinefieldQueryBuilder(MinefieldQuery);
+
+impl MinefieldQueryBuilder {
+    pub fn new() -> Self {
+        MinefieldQueryBuilder(MinefieldQuery::default())
+    }
+
+    pub fn new_from_body(body: MinefieldQuery) -> Self {
+        MinefieldQueryBuilder(body)
+    }
+
+    pub fn build(self) -> MinefieldQuery {
+        self.0
+    }
+
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    pub fn with_data_filter(mut self, data_filter: DataFilter) -> Self {
+        self.0.data_filter = data_filter;
+        self
+    }
+
+    pub fn with_requested_mine_type(mut self, requested_mine_type: EntityType) -> Self {
+        self.0.requested_mine_type = requested_mine_type;
+        self
+    }
+
+    pub fn with_requested_perimeter_points(mut self, requested_perimeter_points: Vec<PerimeterPoint>) -> Self {
+        self.0.requested_perimeter_points = requested_perimeter_points;
+        self
+    }
+
+    pub fn with_requested_perimeter_point(mut self, requested_perimeter_point: PerimeterPoint) -> Self {
+        self.0.requested_perimeter_points.push(requested_perimeter_point);
+        self
+    }
+
+    pub fn with_sensor_types(mut self, sensor_types: Vec<u16>) -> Self {
+        self.0.sensor_types = sensor_types;
+        self
+    }
+
+    pub fn with_sensor_type(mut self, sensor_type: u16) -> Self {
+        self.0.sensor_types.push(sensor_type);
+        self
+    }
+}
@@ -0,0 +1,55 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::minefield_query::builder::MinefieldQueryBuilder;
+use crate::common::model::{DataFilter, EntityId, EntityType, PduBody, PerimeterPoint, PERIMETER_POINT_RECORD_LENGTH};
+use crate::enumerations::PduType;
+
+pub const BASE_MINEFIELD_QUERY_BODY_LENGTH: u16 = 28;
+pub const SENSOR_TYPE_RECORD_LENGTH: u16 = 2;
+
+/// 5.10.3 Minefield Query PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct MinefieldQuery {
+    pub minefield_id: EntityId,
+    pub requesting_entity_id: EntityId,
+    pub request_id: u8,
+    pub data_filter: DataFilter,
+    pub requested_mine_type: EntityType,
+    pub requested_perimeter_points: Vec<PerimeterPoint>,
+    pub sensor_types: Vec<u16>,
+}
+
+impl MinefieldQuery {
+    pub fn builder() -> MinefieldQueryBuilder {
+        MinefieldQueryBuilder::new()
+    }
+
+    pub fn into_builder(self) -> MinefieldQueryBuilder {
+        MinefieldQueryBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldQuery(self)
+    }
+}
+
+impl BodyInfo for MinefieldQuery {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_QUERY_BODY_LENGTH
+            + (self.requested_perimeter_points.len() as u16 * PERIMETER_POINT_RECORD_LENGTH)
+            + (self.sensor_types.len() as u16 * SENSOR_TYPE_RECORD_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldQuery
+    }
+}
+
+impl Interaction for MinefieldQuery {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::model::PduBody;
+use crate::common::parser::{data_filter, entity_id, entity_type, perimeter_point};
+
+pub fn minefield_query_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", entity_id)(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u8)(input)?;
+    let (input, number_of_perimeter_points) = context("Number of Perimeter Points", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, number_of_sensor_types) = context("Number of Sensor Types", be_u8)(input)?;
+    let (input, data_filter) = context("Data Filter", data_filter)(input)?;
+    let (input, requested_mine_type) = context("Requested Mine Type", entity_type)(input)?;
+    let (input, requested_perimeter_points) = context("Requested Perimeter Points", count(perimeter_point, number_of_perimeter_points as usize))(input)?;
+    let (input, sensor_types) = context("Sensor Types", count(be_u16, number_of_sensor_types as usize))(input)?;
+
+    let body = MinefieldQuery::builder()
+        .with_minefield_id(minefield_id)
+        .with_requesting_entity_id(requesting_entity_id)
+        .with_request_id(request_id)
+        .with_data_filter(data_filter)
+        .with_requested_mine_type(requested_mine_type)
+        .with_requested_perimeter_points(requested_perimeter_points)
+        .with_sensor_types(sensor_types)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
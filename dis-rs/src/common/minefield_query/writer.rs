@@ -0,0 +1,24 @@
+use bytes::BufMut;
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for MinefieldQuery {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let minefield_id_bytes = self.minefield_id.serialize(buf);
+        let requesting_id_bytes = self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.requested_perimeter_points.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        let data_filter_bytes = self.data_filter.serialize(buf);
+        let mine_type_bytes = self.requested_mine_type.serialize(buf);
+        let perimeter_points_bytes = self.requested_perimeter_points.iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        self.sensor_types.iter().for_each(|sensor_type| buf.put_u16(*sensor_type));
+        let sensor_types_bytes = self.sensor_types.len() as u16 * 2;
+
+        minefield_id_bytes + requesting_id_bytes + 4 + data_filter_bytes + mine_type_bytes
+            + perimeter_points_bytes + sensor_types_bytes
+    }
+}
@@ -0,0 +1,43 @@
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::model::EntityId;
+
+pub struct MinefieldResponseNackBuilder(MinefieldResponseNack);
+
+impl MinefieldResponseNackBuilder {
+    pub fn new() -> Self {
+        MinefieldResponseNackBuilder(MinefieldResponseNack::default())
+    }
+
+    pub fn new_from_body(body: MinefieldResponseNack) -> Self {
+        MinefieldResponseNackBuilder(body)
+    }
+
+    pub fn build(self) -> MinefieldResponseNack {
+        self.0
+    }
+
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    pub fn with_missing_pdu_sequence_numbers(mut self, missing_pdu_sequence_numbers: Vec<u8>) -> Self {
+        self.0.missing_pdu_sequence_numbers = missing_pdu_sequence_numbers;
+        self
+    }
+
+    pub fn with_missing_pdu_sequence_number(mut self, missing_pdu_sequence_number: u8) -> Self {
+        self.0.missing_pdu_sequence_numbers.push(missing_pdu_sequence_number);
+        self
+    }
+}
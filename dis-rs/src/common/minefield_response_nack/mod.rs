@@ -0,0 +1,46 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::enumerations::PduType;
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn minefield_response_nack_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::MinefieldResponseNACK);
+
+        let body = MinefieldResponseNack::builder()
+            .with_minefield_id(EntityId::new(1, 1, 30))
+            .with_requesting_entity_id(EntityId::new(1, 2, 5))
+            .with_request_id(7)
+            .with_missing_pdu_sequence_numbers(vec![2, 3, 5])
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
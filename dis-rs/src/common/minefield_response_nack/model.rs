@@ -0,0 +1,49 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::minefield_response_nack::builder::MinefieldResponseNackBuilder;
+use crate::common::model::{EntityId, PduBody};
+use crate::enumerations::PduType;
+
+pub const BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH: u16 = 14;
+
+/// 5.10.5 Minefield Response NACK PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct MinefieldResponseNack {
+    pub minefield_id: EntityId,
+    pub requesting_entity_id: EntityId,
+    pub request_id: u8,
+    pub missing_pdu_sequence_numbers: Vec<u8>,
+}
+
+impl MinefieldResponseNack {
+    pub fn builder() -> MinefieldResponseNackBuilder {
+        MinefieldResponseNackBuilder::new()
+    }
+
+    pub fn into_builder(self) -> MinefieldResponseNackBuilder {
+        MinefieldResponseNackBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldResponseNACK(self)
+    }
+}
+
+impl BodyInfo for MinefieldResponseNack {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_RESPONSE_NACK_BODY_LENGTH + self.missing_pdu_sequence_numbers.len() as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldResponseNACK
+    }
+}
+
+impl Interaction for MinefieldResponseNack {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+}
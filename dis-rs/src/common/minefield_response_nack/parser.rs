@@ -0,0 +1,24 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+
+pub fn minefield_response_nack_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", entity_id)(input)?;
+    let (input, requesting_entity_id) = context("Requesting Entity ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u8)(input)?;
+    let (input, number_of_missing_pdus) = context("Number of Missing PDUs", be_u8)(input)?;
+    let (input, missing_pdu_sequence_numbers) = context("Missing PDU Sequence Numbers", count(be_u8, number_of_missing_pdus as usize))(input)?;
+
+    let body = MinefieldResponseNack::builder()
+        .with_minefield_id(minefield_id)
+        .with_requesting_entity_id(requesting_entity_id)
+        .with_request_id(request_id)
+        .with_missing_pdu_sequence_numbers(missing_pdu_sequence_numbers)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
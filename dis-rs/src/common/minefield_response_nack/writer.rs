@@ -0,0 +1,15 @@
+use bytes::BufMut;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for MinefieldResponseNack {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let minefield_id_bytes = self.minefield_id.serialize(buf);
+        let requesting_id_bytes = self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.missing_pdu_sequence_numbers.len() as u8);
+        buf.put_slice(&self.missing_pdu_sequence_numbers);
+
+        minefield_id_bytes + requesting_id_bytes + 2 + self.missing_pdu_sequence_numbers.len() as u16
+    }
+}
@@ -0,0 +1,79 @@
+use crate::common::minefield_state::model::MinefieldState;
+use crate::common::model::{EntityId, EntityType, Location, Orientation, PerimeterPoint};
+use crate::enumerations::ForceId;
+
+pub struct MinefieldStateBuilder(MinefieldState);
+
+impl MinefieldStateBuilder {
+    pub fn new() -> Self {
+        MinefieldStateBuilder(MinefieldState::default())
+    }
+
+    pub fn new_from_body(body: MinefieldState) -> Self {
+        MinefieldStateBuilder(body)
+    }
+
+    pub fn build(self) -> MinefieldState {
+        self.0
+    }
+
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    pub fn with_minefield_sequence_number(mut self, minefield_sequence_number: u16) -> Self {
+        self.0.minefield_sequence_number = minefield_sequence_number;
+        self
+    }
+
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    pub fn with_minefield_type(mut self, minefield_type: EntityType) -> Self {
+        self.0.minefield_type = minefield_type;
+        self
+    }
+
+    pub fn with_minefield_location(mut self, minefield_location: Location) -> Self {
+        self.0.minefield_location = minefield_location;
+        self
+    }
+
+    pub fn with_minefield_orientation(mut self, minefield_orientation: Orientation) -> Self {
+        self.0.minefield_orientation = minefield_orientation;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: u16) -> Self {
+        self.0.appearance = appearance;
+        self
+    }
+
+    pub fn with_protocol_mode(mut self, protocol_mode: u16) -> Self {
+        self.0.protocol_mode = protocol_mode;
+        self
+    }
+
+    pub fn with_perimeter_points(mut self, perimeter_points: Vec<PerimeterPoint>) -> Self {
+        self.0.perimeter_points = perimeter_points;
+        self
+    }
+
+    pub fn with_perimeter_point(mut self, perimeter_point: PerimeterPoint) -> Self {
+        self.0.perimeter_points.push(perimeter_point);
+        self
+    }
+
+    pub fn with_mine_types(mut self, mine_types: Vec<EntityType>) -> Self {
+        self.0.mine_types = mine_types;
+        self
+    }
+
+    pub fn with_mine_type(mut self, mine_type: EntityType) -> Self {
+        self.0.mine_types.push(mine_type);
+        self
+    }
+}
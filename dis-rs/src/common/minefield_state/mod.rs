@@ -0,0 +1,58 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::minefield_state::model::MinefieldState;
+    use crate::common::model::{DisTimeStamp, EntityId, EntityType, Location, Orientation, Pdu, PduHeader, PerimeterPoint};
+    use crate::enumerations::{EntityKind, ForceId, PduType, PlatformDomain};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn minefield_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::MinefieldState);
+
+        let body = MinefieldState::builder()
+            .with_minefield_id(EntityId::new(1, 1, 30))
+            .with_minefield_sequence_number(4)
+            .with_force_id(ForceId::Opposing)
+            .with_minefield_type(EntityType::default()
+                .with_kind(EntityKind::Munition)
+                .with_domain(PlatformDomain::Land))
+            .with_minefield_location(Location::new(3_900_000.0, 350_000.0, 5_000_000.0))
+            .with_minefield_orientation(Orientation::new(0.5, 0.0, 0.0))
+            .with_appearance(0x0001)
+            .with_protocol_mode(0)
+            .with_perimeter_point(PerimeterPoint::new(-100.0, -50.0))
+            .with_perimeter_point(PerimeterPoint::new(100.0, -50.0))
+            .with_perimeter_point(PerimeterPoint::new(0.0, 75.0))
+            .with_mine_type(EntityType::default()
+                .with_kind(EntityKind::Munition)
+                .with_category(5))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
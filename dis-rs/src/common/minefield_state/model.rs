@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::minefield_state::builder::MinefieldStateBuilder;
+use crate::common::model::{EntityId, EntityType, Location, Orientation, PduBody, PerimeterPoint, PERIMETER_POINT_RECORD_LENGTH};
+use crate::enumerations::{ForceId, PduType};
+
+pub const BASE_MINEFIELD_STATE_BODY_LENGTH: u16 = 60;
+pub const MINE_TYPE_RECORD_LENGTH: u16 = 8;
+
+/// 5.10.2 Minefield State PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct MinefieldState {
+    pub minefield_id: EntityId,
+    pub minefield_sequence_number: u16,
+    pub force_id: ForceId,
+    pub minefield_type: EntityType,
+    pub minefield_location: Location,
+    pub minefield_orientation: Orientation,
+    pub appearance: u16, // Minefield Appearance bitfield
+    pub protocol_mode: u16, // Protocol Mode record
+    pub perimeter_points: Vec<PerimeterPoint>,
+    pub mine_types: Vec<EntityType>,
+}
+
+impl MinefieldState {
+    pub fn builder() -> MinefieldStateBuilder {
+        MinefieldStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> MinefieldStateBuilder {
+        MinefieldStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldState(self)
+    }
+}
+
+impl BodyInfo for MinefieldState {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_STATE_BODY_LENGTH
+            + (self.perimeter_points.len() as u16 * PERIMETER_POINT_RECORD_LENGTH)
+            + (self.mine_types.len() as u16 * MINE_TYPE_RECORD_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldState
+    }
+}
+
+impl Interaction for MinefieldState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,38 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::entity_state::parser::force_id;
+use crate::common::minefield_state::model::MinefieldState;
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, entity_type, location, orientation, perimeter_point};
+
+pub fn minefield_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, minefield_id) = context("Minefield ID", entity_id)(input)?;
+    let (input, minefield_sequence_number) = context("Minefield Sequence Number", be_u16)(input)?;
+    let (input, force_id) = context("Force ID", force_id)(input)?;
+    let (input, number_of_perimeter_points) = context("Number of Perimeter Points", be_u8)(input)?;
+    let (input, minefield_type) = context("Minefield Type", entity_type)(input)?;
+    let (input, number_of_mine_types) = context("Number of Mine Types", be_u16)(input)?;
+    let (input, minefield_location) = context("Minefield Location", location)(input)?;
+    let (input, minefield_orientation) = context("Minefield Orientation", orientation)(input)?;
+    let (input, appearance) = context("Appearance", be_u16)(input)?;
+    let (input, protocol_mode) = context("Protocol Mode", be_u16)(input)?;
+    let (input, perimeter_points) = context("Perimeter Points", count(perimeter_point, number_of_perimeter_points as usize))(input)?;
+    let (input, mine_types) = context("Mine Types", count(entity_type, number_of_mine_types as usize))(input)?;
+
+    let body = MinefieldState::builder()
+        .with_minefield_id(minefield_id)
+        .with_minefield_sequence_number(minefield_sequence_number)
+        .with_force_id(force_id)
+        .with_minefield_type(minefield_type)
+        .with_minefield_location(minefield_location)
+        .with_minefield_orientation(minefield_orientation)
+        .with_appearance(appearance)
+        .with_protocol_mode(protocol_mode)
+        .with_perimeter_points(perimeter_points)
+        .with_mine_types(mine_types)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,27 @@
+use bytes::BufMut;
+use crate::common::minefield_state::model::MinefieldState;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for MinefieldState {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let minefield_id_bytes = self.minefield_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        buf.put_u8(self.force_id.into());
+        buf.put_u8(self.perimeter_points.len() as u8);
+        let minefield_type_bytes = self.minefield_type.serialize(buf);
+        buf.put_u16(self.mine_types.len() as u16);
+        let location_bytes = self.minefield_location.serialize(buf);
+        let orientation_bytes = self.minefield_orientation.serialize(buf);
+        buf.put_u16(self.appearance);
+        buf.put_u16(self.protocol_mode);
+        let perimeter_points_bytes = self.perimeter_points.iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        let mine_types_bytes = self.mine_types.iter()
+            .map(|mine_type| mine_type.serialize(buf))
+            .sum::<u16>();
+
+        minefield_id_bytes + 4 + minefield_type_bytes + 2 + location_bytes + orientation_bytes + 4
+            + perimeter_points_bytes + mine_types_bytes
+    }
+}
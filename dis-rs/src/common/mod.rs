@@ -12,12 +12,34 @@ pub mod data;
 pub mod data_query;
 pub mod designator;
 pub mod detonation;
+pub mod directed_energy_fire;
 pub mod electromagnetic_emission;
+pub mod underwater_acoustic;
+pub mod supplemental_emission_entity_state;
+pub mod entity_damage_status;
 pub mod entity_state;
 pub mod entity_state_update;
 pub mod event_report;
 pub mod fire;
 pub mod iff;
+pub mod information_operations_action;
+pub mod information_operations_report;
+pub mod intercom_control;
+pub mod intercom_signal;
+pub mod aggregate_state;
+pub mod is_group_of;
+pub mod transfer_ownership;
+pub mod is_part_of;
+pub mod minefield_state;
+pub mod minefield_query;
+pub mod minefield_data;
+pub mod minefield_response_nack;
+pub mod environmental_process;
+pub mod gridded_data;
+pub mod point_object_state;
+pub mod linear_object_state;
+pub mod areal_object_state;
+pub mod live_entity;
 pub mod create_entity;
 pub mod remove_entity;
 pub mod set_data;
@@ -27,14 +49,38 @@ pub mod other;
 pub mod signal;
 pub mod transmitter;
 pub mod receiver;
+pub mod service_request;
+pub mod resupply_offer;
+pub mod resupply_received;
+pub mod resupply_cancel;
+pub mod repair_complete;
+pub mod repair_response;
+pub mod simulation_management_with_reliability;
+
+pub mod dead_reckoning;
+pub(crate) mod publisher;
+pub mod coordinates;
+pub mod entity_table;
+pub mod transaction_manager;
+pub mod simulation_clock;
+pub mod bundler;
+pub mod pcap;
+pub mod decoder;
+pub mod view;
+#[cfg(feature = "udp")]
+pub mod udp;
+#[cfg(feature = "tokio")]
+pub mod codec;
+#[cfg(feature = "tokio")]
+pub mod async_udp;
 
 pub mod errors;
 mod writer;
 
-use bytes::BytesMut;
-use crate::common::errors::DisError;
+use bytes::BufMut;
+use crate::common::errors::{DisError, PduParseError};
 use crate::common::model::{Pdu};
-use crate::common::parser::parse_multiple_pdu;
+use crate::common::parser::{parse_multiple_pdu, parse_multiple_pdu_results};
 use crate::enumerations::{PduType, ProtocolVersion};
 
 #[allow(dead_code)]
@@ -72,14 +118,28 @@ trait Interaction {
 /// based on the protocol version of the PDU.
 /// Returns the number of bytes written to the buffer.
 pub trait SerializePdu {
-    fn serialize_pdu(&self, version: SupportedVersion, buf : &mut BytesMut) -> u16;
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16;
 }
 
 /// Trait that implements writing data structures to a buffer.
 /// This serialize must be independent of protocol version differences for the data structure.
 /// Returns the number of bytes written to the buffer.
 pub trait Serialize {
-    fn serialize(&self, buf : &mut BytesMut) -> u16;
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16;
+}
+
+/// Trait that implements fallible writing of PDUs to a buffer.
+/// The PDU is validated before writing: the PDU may not exceed MAX_PDU_SIZE_OCTETS, the length in the header must match the body,
+/// strings must be ASCII and fit their field, and the number of records in lists must fit their count fields.
+/// Returns the number of bytes written to the buffer.
+pub trait TrySerialize {
+    fn try_serialize<B: BufMut>(&self, buf: &mut B) -> Result<usize, DisError>;
+
+    /// Writes into a caller-provided buffer, without allocating.
+    fn try_serialize_to_slice(&self, buf: &mut [u8]) -> Result<usize, DisError> {
+        let mut slice = buf;
+        self.try_serialize(&mut slice)
+    }
 }
 
 /// Parses the contents of the input, determining the DIS version by itself.
@@ -91,6 +151,25 @@ pub fn parse(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
     parse_multiple_pdu(input)
 }
 
+/// The result of parsing a single PDU from a buffer that can contain multiple PDUs.
+#[derive(Debug, PartialEq)]
+pub struct PduParseResult {
+    /// Offset of the start of the PDU in the input, in octets
+    pub offset: usize,
+    /// The PDU Type as stated in the header, if the input contains the field
+    pub pdu_type: Option<PduType>,
+    pub result: Result<Pdu, PduParseError>,
+}
+
+/// Parses the contents of the input, determining the DIS version by itself,
+/// and produces a separate result for each PDU in the input.
+///
+/// In contrast to `parse`, PDUs that cannot be parsed do not cause the other PDUs in the buffer to be lost.
+/// Only PDUs of protocol versions 6 and 7 are supported; other versions are reported as unsupported.
+pub fn parse_per_pdu(input: &[u8]) -> Vec<PduParseResult> {
+    parse_multiple_pdu_results(input)
+}
+
 /// Parses the contents of the input as DIS version 6.
 /// This function tries to parse as many PDUs as there are in the buffer,
 /// assuming there are only complete PDUs present in the input.
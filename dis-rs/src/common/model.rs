@@ -1,5 +1,7 @@
+use std::cmp::Ordering;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use crate::enumerations::{ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator, AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus, EntityAssociationGroupMemberType, EntityAssociationPhysicalAssociationType, EntityAssociationPhysicalConnectionType, SeparationPreEntityIndicator, SeparationReasonForSeparation, StationName};
-use crate::enumerations::{Country, EntityKind, ExplosiveMaterialCategories, MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion, VariableRecordType};
+use crate::enumerations::{Country, EntityKind, ExplosiveMaterialCategories, MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion, VariableRecordType, ObjectKind, DeadReckoningAlgorithm, IOCommsNodeRecordCommsNodeType, IOEffectsRecordIOEffect, IOEffectsRecordIOLinkType, IOEffectsRecordIOProcess, IOEffectsRecordIOStatus};
 use crate::common::entity_state::model::EntityState;
 use crate::common::{BodyInfo, Interaction};
 use crate::common::acknowledge::model::Acknowledge;
@@ -20,15 +22,62 @@ use crate::common::event_report::model::EventReport;
 use crate::common::other::model::Other;
 use crate::common::fire::model::Fire;
 use crate::common::iff::model::Iff;
+use crate::common::intercom_control::model::IntercomControl;
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::aggregate_state::model::AggregateState;
+use crate::common::is_group_of::model::IsGroupOf;
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::common::is_part_of::model::IsPartOf;
+use crate::common::minefield_state::model::MinefieldState;
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::minefield_data::model::MinefieldData;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::environmental_process::model::EnvironmentalProcess;
+use crate::common::gridded_data::model::GriddedData;
+use crate::common::point_object_state::model::PointObjectState;
+use crate::common::linear_object_state::model::LinearObjectState;
+use crate::common::areal_object_state::model::ArealObjectState;
+use crate::common::live_entity::tspi::model::Tspi;
+use crate::common::live_entity::appearance::model::Appearance;
+use crate::common::live_entity::articulated_parts::model::ArticulatedParts;
+use crate::common::live_entity::le_fire::model::LeFire;
+use crate::common::live_entity::le_detonation::model::LeDetonation;
+use crate::common::directed_energy_fire::model::DirectedEnergyFire;
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::underwater_acoustic::model::UnderwaterAcoustic;
+use crate::common::supplemental_emission_entity_state::model::SupplementalEmissionEntityState;
 use crate::common::receiver::model::Receiver;
 use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::repair_complete::model::RepairComplete;
+use crate::common::repair_response::model::RepairResponse;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::resupply_offer::model::ResupplyOffer;
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::service_request::model::ServiceRequest;
 use crate::common::set_data::model::SetData;
 use crate::common::signal::model::Signal;
 use crate::common::start_resume::model::StartResume;
 use crate::common::stop_freeze::model::StopFreeze;
 use crate::common::transmitter::model::Transmitter;
+use crate::common::simulation_management_with_reliability::create_entity_r::model::CreateEntityR;
+use crate::common::simulation_management_with_reliability::remove_entity_r::model::RemoveEntityR;
+use crate::common::simulation_management_with_reliability::start_resume_r::model::StartResumeR;
+use crate::common::simulation_management_with_reliability::stop_freeze_r::model::StopFreezeR;
+use crate::common::simulation_management_with_reliability::acknowledge_r::model::AcknowledgeR;
+use crate::common::simulation_management_with_reliability::action_request_r::model::ActionRequestR;
+use crate::common::simulation_management_with_reliability::action_response_r::model::ActionResponseR;
+use crate::common::simulation_management_with_reliability::data_query_r::model::DataQueryR;
+use crate::common::simulation_management_with_reliability::set_data_r::model::SetDataR;
+use crate::common::simulation_management_with_reliability::data_r::model::DataR;
+use crate::common::simulation_management_with_reliability::event_report_r::model::EventReportR;
+use crate::common::simulation_management_with_reliability::comment_r::model::CommentR;
+use crate::common::simulation_management_with_reliability::record_r::model::RecordR;
+use crate::common::simulation_management_with_reliability::set_record_r::model::SetRecordR;
+use crate::common::simulation_management_with_reliability::record_query_r::model::RecordQueryR;
 use crate::v7::model::PduStatus;
-use crate::constants::{LEAST_SIGNIFICANT_BIT, NANOSECONDS_PER_TIME_UNIT, NO_REMAINDER, PDU_HEADER_LEN_BYTES};
+use crate::constants::{EIGHT_OCTETS, LEAST_SIGNIFICANT_BIT, NANOSECONDS_PER_HOUR, NO_REMAINDER, PDU_HEADER_LEN_BYTES, TIME_UNITS_PER_HOUR};
 use crate::fixed_parameters::{NO_APPLIC, NO_ENTITY, NO_SITE};
 
 #[derive(Debug, PartialEq)]
@@ -125,12 +174,12 @@ pub enum PduBody {
     Fire(Fire),
     Detonation(Detonation),
     Collision(Collision),
-    ServiceRequest,
-    ResupplyOffer,
-    ResupplyReceived,
-    ResupplyCancel,
-    RepairComplete,
-    RepairResponse,
+    ServiceRequest(ServiceRequest),
+    ResupplyOffer(ResupplyOffer),
+    ResupplyReceived(ResupplyReceived),
+    ResupplyCancel(ResupplyCancel),
+    RepairComplete(RepairComplete),
+    RepairResponse(RepairResponse),
     CreateEntity(CreateEntity),
     RemoveEntity(RemoveEntity),
     StartResume(StartResume),
@@ -149,49 +198,49 @@ pub enum PduBody {
     Signal(Signal),
     Receiver(Receiver),
     IFF(Iff),
-    UnderwaterAcoustic,
-    SupplementalEmissionEntityState,
-    IntercomSignal,
-    IntercomControl,
-    AggregateState,
-    IsGroupOf,
-    TransferOwnership,
-    IsPartOf,
-    MinefieldState,
-    MinefieldQuery,
-    MinefieldData,
-    MinefieldResponseNACK,
-    EnvironmentalProcess,
-    GriddedData,
-    PointObjectState,
-    LinearObjectState,
-    ArealObjectState,
-    TSPI,
-    Appearance,
-    ArticulatedParts,
-    LEFire,
-    LEDetonation,
-    CreateEntityR,
-    RemoveEntityR,
-    StartResumeR,
-    StopFreezeR,
-    AcknowledgeR,
-    ActionRequestR,
-    ActionResponseR,
-    DataQueryR,
-    SetDataR,
-    DataR,
-    EventReportR,
-    CommentR,
-    RecordR,
-    SetRecordR,
-    RecordQueryR,
+    UnderwaterAcoustic(UnderwaterAcoustic),
+    SupplementalEmissionEntityState(SupplementalEmissionEntityState),
+    IntercomSignal(IntercomSignal),
+    IntercomControl(IntercomControl),
+    AggregateState(AggregateState),
+    IsGroupOf(IsGroupOf),
+    TransferOwnership(TransferOwnership),
+    IsPartOf(IsPartOf),
+    MinefieldState(MinefieldState),
+    MinefieldQuery(MinefieldQuery),
+    MinefieldData(MinefieldData),
+    MinefieldResponseNACK(MinefieldResponseNack),
+    EnvironmentalProcess(EnvironmentalProcess),
+    GriddedData(GriddedData),
+    PointObjectState(PointObjectState),
+    LinearObjectState(LinearObjectState),
+    ArealObjectState(ArealObjectState),
+    TSPI(Tspi),
+    Appearance(Appearance),
+    ArticulatedParts(ArticulatedParts),
+    LEFire(LeFire),
+    LEDetonation(LeDetonation),
+    CreateEntityR(CreateEntityR),
+    RemoveEntityR(RemoveEntityR),
+    StartResumeR(StartResumeR),
+    StopFreezeR(StopFreezeR),
+    AcknowledgeR(AcknowledgeR),
+    ActionRequestR(ActionRequestR),
+    ActionResponseR(ActionResponseR),
+    DataQueryR(DataQueryR),
+    SetDataR(SetDataR),
+    DataR(DataR),
+    EventReportR(EventReportR),
+    CommentR(CommentR),
+    RecordR(RecordR),
+    SetRecordR(SetRecordR),
+    RecordQueryR(RecordQueryR),
     CollisionElastic(CollisionElastic),
     EntityStateUpdate(EntityStateUpdate),
-    DirectedEnergyFire,
-    EntityDamageStatus,
-    InformationOperationsAction,
-    InformationOperationsReport,
+    DirectedEnergyFire(DirectedEnergyFire),
+    EntityDamageStatus(EntityDamageStatus),
+    InformationOperationsAction(InformationOperationsAction),
+    InformationOperationsReport(InformationOperationsReport),
     Attribute(Attribute),
 }
 
@@ -203,12 +252,12 @@ impl BodyInfo for PduBody {
             PduBody::Fire(body) => { body.body_length() }
             PduBody::Detonation(body) => { body.body_length() }
             PduBody::Collision(body) => { body.body_length() }
-            PduBody::ServiceRequest => { 0 }
-            PduBody::ResupplyOffer => { 0 }
-            PduBody::ResupplyReceived => { 0 }
-            PduBody::ResupplyCancel => { 0 }
-            PduBody::RepairComplete => { 0 }
-            PduBody::RepairResponse => { 0 }
+            PduBody::ServiceRequest(body) => { body.body_length() }
+            PduBody::ResupplyOffer(body) => { body.body_length() }
+            PduBody::ResupplyReceived(body) => { body.body_length() }
+            PduBody::ResupplyCancel(body) => { body.body_length() }
+            PduBody::RepairComplete(body) => { body.body_length() }
+            PduBody::RepairResponse(body) => { body.body_length() }
             PduBody::CreateEntity(body) => { body.body_length() }
             PduBody::RemoveEntity(body) => { body.body_length() }
             PduBody::StartResume(body) => { body.body_length() }
@@ -227,49 +276,49 @@ impl BodyInfo for PduBody {
             PduBody::Signal(body) => { body.body_length() }
             PduBody::Receiver(body) => { body.body_length() }
             PduBody::IFF(body) => { body.body_length() }
-            PduBody::UnderwaterAcoustic => { 0 }
-            PduBody::SupplementalEmissionEntityState => { 0 }
-            PduBody::IntercomSignal => { 0 }
-            PduBody::IntercomControl => { 0 }
-            PduBody::AggregateState => { 0 }
-            PduBody::IsGroupOf => { 0 }
-            PduBody::TransferOwnership => { 0 }
-            PduBody::IsPartOf => { 0 }
-            PduBody::MinefieldState => { 0 }
-            PduBody::MinefieldQuery => { 0 }
-            PduBody::MinefieldData => { 0 }
-            PduBody::MinefieldResponseNACK => { 0 }
-            PduBody::EnvironmentalProcess => { 0 }
-            PduBody::GriddedData => { 0 }
-            PduBody::PointObjectState => { 0 }
-            PduBody::LinearObjectState => { 0 }
-            PduBody::ArealObjectState => { 0 }
-            PduBody::TSPI => { 0 }
-            PduBody::Appearance => { 0 }
-            PduBody::ArticulatedParts => { 0 }
-            PduBody::LEFire => { 0 }
-            PduBody::LEDetonation => { 0 }
-            PduBody::CreateEntityR => { 0 }
-            PduBody::RemoveEntityR => { 0 }
-            PduBody::StartResumeR => { 0 }
-            PduBody::StopFreezeR => { 0 }
-            PduBody::AcknowledgeR => { 0 }
-            PduBody::ActionRequestR => { 0 }
-            PduBody::ActionResponseR => { 0 }
-            PduBody::DataQueryR => { 0 }
-            PduBody::SetDataR => { 0 }
-            PduBody::DataR => { 0 }
-            PduBody::EventReportR => { 0 }
-            PduBody::CommentR => { 0 }
-            PduBody::RecordR => { 0 }
-            PduBody::SetRecordR => { 0 }
-            PduBody::RecordQueryR => { 0 }
+            PduBody::UnderwaterAcoustic(body) => { body.body_length() }
+            PduBody::SupplementalEmissionEntityState(body) => { body.body_length() }
+            PduBody::IntercomSignal(body) => { body.body_length() }
+            PduBody::IntercomControl(body) => { body.body_length() }
+            PduBody::AggregateState(body) => { body.body_length() }
+            PduBody::IsGroupOf(body) => { body.body_length() }
+            PduBody::TransferOwnership(body) => { body.body_length() }
+            PduBody::IsPartOf(body) => { body.body_length() }
+            PduBody::MinefieldState(body) => { body.body_length() }
+            PduBody::MinefieldQuery(body) => { body.body_length() }
+            PduBody::MinefieldData(body) => { body.body_length() }
+            PduBody::MinefieldResponseNACK(body) => { body.body_length() }
+            PduBody::EnvironmentalProcess(body) => { body.body_length() }
+            PduBody::GriddedData(body) => { body.body_length() }
+            PduBody::PointObjectState(body) => { body.body_length() }
+            PduBody::LinearObjectState(body) => { body.body_length() }
+            PduBody::ArealObjectState(body) => { body.body_length() }
+            PduBody::TSPI(body) => { body.body_length() }
+            PduBody::Appearance(body) => { body.body_length() }
+            PduBody::ArticulatedParts(body) => { body.body_length() }
+            PduBody::LEFire(body) => { body.body_length() }
+            PduBody::LEDetonation(body) => { body.body_length() }
+            PduBody::CreateEntityR(body) => { body.body_length() }
+            PduBody::RemoveEntityR(body) => { body.body_length() }
+            PduBody::StartResumeR(body) => { body.body_length() }
+            PduBody::StopFreezeR(body) => { body.body_length() }
+            PduBody::AcknowledgeR(body) => { body.body_length() }
+            PduBody::ActionRequestR(body) => { body.body_length() }
+            PduBody::ActionResponseR(body) => { body.body_length() }
+            PduBody::DataQueryR(body) => { body.body_length() }
+            PduBody::SetDataR(body) => { body.body_length() }
+            PduBody::DataR(body) => { body.body_length() }
+            PduBody::EventReportR(body) => { body.body_length() }
+            PduBody::CommentR(body) => { body.body_length() }
+            PduBody::RecordR(body) => { body.body_length() }
+            PduBody::SetRecordR(body) => { body.body_length() }
+            PduBody::RecordQueryR(body) => { body.body_length() }
             PduBody::CollisionElastic(body) => { body.body_length() }
             PduBody::EntityStateUpdate(body) => { body.body_length() }
-            PduBody::DirectedEnergyFire => { 0 }
-            PduBody::EntityDamageStatus => { 0 }
-            PduBody::InformationOperationsAction => { 0 }
-            PduBody::InformationOperationsReport => { 0 }
+            PduBody::DirectedEnergyFire(body) => { body.body_length() }
+            PduBody::EntityDamageStatus(body) => { body.body_length() }
+            PduBody::InformationOperationsAction(body) => { body.body_length() }
+            PduBody::InformationOperationsReport(body) => { body.body_length() }
             PduBody::Attribute(body) => { body.body_length() }
         }
     }
@@ -281,12 +330,12 @@ impl BodyInfo for PduBody {
             PduBody::Fire(body) => { body.body_type() }
             PduBody::Detonation(body) => { body.body_type() }
             PduBody::Collision(body) => { body.body_type() }
-            PduBody::ServiceRequest => { PduType::ServiceRequest }
-            PduBody::ResupplyOffer => { PduType::ResupplyOffer }
-            PduBody::ResupplyReceived => { PduType::ResupplyReceived }
-            PduBody::ResupplyCancel => { PduType::ResupplyCancel }
-            PduBody::RepairComplete => { PduType::RepairComplete }
-            PduBody::RepairResponse => { PduType::RepairResponse }
+            PduBody::ServiceRequest(body) => { body.body_type() }
+            PduBody::ResupplyOffer(body) => { body.body_type() }
+            PduBody::ResupplyReceived(body) => { body.body_type() }
+            PduBody::ResupplyCancel(body) => { body.body_type() }
+            PduBody::RepairComplete(body) => { body.body_type() }
+            PduBody::RepairResponse(body) => { body.body_type() }
             PduBody::CreateEntity(body) => { body.body_type() }
             PduBody::RemoveEntity(body) => { body.body_type() }
             PduBody::StartResume(body) => { body.body_type() }
@@ -305,49 +354,49 @@ impl BodyInfo for PduBody {
             PduBody::Signal(body) => { body.body_type() }
             PduBody::Receiver(body) => { body.body_type() }
             PduBody::IFF(body) => { body.body_type() }
-            PduBody::UnderwaterAcoustic => { PduType::UnderwaterAcoustic }
-            PduBody::SupplementalEmissionEntityState => { PduType::SupplementalEmissionEntityState }
-            PduBody::IntercomSignal => { PduType::IntercomSignal }
-            PduBody::IntercomControl => { PduType::IntercomControl }
-            PduBody::AggregateState => { PduType::AggregateState }
-            PduBody::IsGroupOf => { PduType::IsGroupOf }
-            PduBody::TransferOwnership => { PduType::TransferOwnership }
-            PduBody::IsPartOf => { PduType::IsPartOf }
-            PduBody::MinefieldState => { PduType::MinefieldState }
-            PduBody::MinefieldQuery => { PduType::MinefieldQuery }
-            PduBody::MinefieldData => { PduType::MinefieldData }
-            PduBody::MinefieldResponseNACK => { PduType::MinefieldResponseNACK }
-            PduBody::EnvironmentalProcess => { PduType::EnvironmentalProcess }
-            PduBody::GriddedData => { PduType::GriddedData }
-            PduBody::PointObjectState => { PduType::PointObjectState }
-            PduBody::LinearObjectState => { PduType::LinearObjectState }
-            PduBody::ArealObjectState => { PduType::ArealObjectState }
-            PduBody::TSPI => { PduType::TSPI }
-            PduBody::Appearance => { PduType::Appearance }
-            PduBody::ArticulatedParts => { PduType::ArticulatedParts }
-            PduBody::LEFire => { PduType::LEFire }
-            PduBody::LEDetonation => { PduType::LEDetonation }
-            PduBody::CreateEntityR => { PduType::CreateEntityR }
-            PduBody::RemoveEntityR => { PduType::RemoveEntityR }
-            PduBody::StartResumeR => { PduType::StartResumeR }
-            PduBody::StopFreezeR => { PduType::StopFreezeR }
-            PduBody::AcknowledgeR => { PduType::AcknowledgeR }
-            PduBody::ActionRequestR => { PduType::ActionRequestR }
-            PduBody::ActionResponseR => { PduType::ActionResponseR }
-            PduBody::DataQueryR => { PduType::DataQueryR }
-            PduBody::SetDataR => { PduType::SetDataR }
-            PduBody::DataR => { PduType::DataR }
-            PduBody::EventReportR => { PduType::EventReportR }
-            PduBody::CommentR => { PduType::CommentR }
-            PduBody::RecordR => { PduType::RecordR }
-            PduBody::SetRecordR => { PduType::SetRecordR }
-            PduBody::RecordQueryR => { PduType::RecordQueryR }
+            PduBody::UnderwaterAcoustic(body) => { body.body_type() }
+            PduBody::SupplementalEmissionEntityState(body) => { body.body_type() }
+            PduBody::IntercomSignal(body) => { body.body_type() }
+            PduBody::IntercomControl(body) => { body.body_type() }
+            PduBody::AggregateState(body) => { body.body_type() }
+            PduBody::IsGroupOf(body) => { body.body_type() }
+            PduBody::TransferOwnership(body) => { body.body_type() }
+            PduBody::IsPartOf(body) => { body.body_type() }
+            PduBody::MinefieldState(body) => { body.body_type() }
+            PduBody::MinefieldQuery(body) => { body.body_type() }
+            PduBody::MinefieldData(body) => { body.body_type() }
+            PduBody::MinefieldResponseNACK(body) => { body.body_type() }
+            PduBody::EnvironmentalProcess(body) => { body.body_type() }
+            PduBody::GriddedData(body) => { body.body_type() }
+            PduBody::PointObjectState(body) => { body.body_type() }
+            PduBody::LinearObjectState(body) => { body.body_type() }
+            PduBody::ArealObjectState(body) => { body.body_type() }
+            PduBody::TSPI(body) => { body.body_type() }
+            PduBody::Appearance(body) => { body.body_type() }
+            PduBody::ArticulatedParts(body) => { body.body_type() }
+            PduBody::LEFire(body) => { body.body_type() }
+            PduBody::LEDetonation(body) => { body.body_type() }
+            PduBody::CreateEntityR(body) => { body.body_type() }
+            PduBody::RemoveEntityR(body) => { body.body_type() }
+            PduBody::StartResumeR(body) => { body.body_type() }
+            PduBody::StopFreezeR(body) => { body.body_type() }
+            PduBody::AcknowledgeR(body) => { body.body_type() }
+            PduBody::ActionRequestR(body) => { body.body_type() }
+            PduBody::ActionResponseR(body) => { body.body_type() }
+            PduBody::DataQueryR(body) => { body.body_type() }
+            PduBody::SetDataR(body) => { body.body_type() }
+            PduBody::DataR(body) => { body.body_type() }
+            PduBody::EventReportR(body) => { body.body_type() }
+            PduBody::CommentR(body) => { body.body_type() }
+            PduBody::RecordR(body) => { body.body_type() }
+            PduBody::SetRecordR(body) => { body.body_type() }
+            PduBody::RecordQueryR(body) => { body.body_type() }
             PduBody::CollisionElastic(body) => { body.body_type() }
             PduBody::EntityStateUpdate(body) => { body.body_type() }
-            PduBody::DirectedEnergyFire => { PduType::DirectedEnergyFire }
-            PduBody::EntityDamageStatus => { PduType::EntityDamageStatus }
-            PduBody::InformationOperationsAction => { PduType::InformationOperationsAction }
-            PduBody::InformationOperationsReport => { PduType::InformationOperationsReport }
+            PduBody::DirectedEnergyFire(body) => { body.body_type() }
+            PduBody::EntityDamageStatus(body) => { body.body_type() }
+            PduBody::InformationOperationsAction(body) => { body.body_type() }
+            PduBody::InformationOperationsReport(body) => { body.body_type() }
             PduBody::Attribute(body) => { body.body_type() }
         }
     }
@@ -361,12 +410,12 @@ impl Interaction for PduBody {
             PduBody::Fire(body) => { body.originator() }
             PduBody::Detonation(body) => { body.originator() }
             PduBody::Collision(body) => { body.originator() }
-            PduBody::ServiceRequest => { None }
-            PduBody::ResupplyOffer => { None }
-            PduBody::ResupplyReceived => { None }
-            PduBody::ResupplyCancel => { None }
-            PduBody::RepairComplete => { None }
-            PduBody::RepairResponse => { None }
+            PduBody::ServiceRequest(body) => { body.originator() }
+            PduBody::ResupplyOffer(body) => { body.originator() }
+            PduBody::ResupplyReceived(body) => { body.originator() }
+            PduBody::ResupplyCancel(body) => { body.originator() }
+            PduBody::RepairComplete(body) => { body.originator() }
+            PduBody::RepairResponse(body) => { body.originator() }
             PduBody::CreateEntity(body) => { body.originator() }
             PduBody::RemoveEntity(body) => { body.originator() }
             PduBody::StartResume(body) => { body.originator() }
@@ -385,49 +434,49 @@ impl Interaction for PduBody {
             PduBody::Signal(body) => { body.originator() }
             PduBody::Receiver(body) => { body.originator() }
             PduBody::IFF(body) => { body.originator() }
-            PduBody::UnderwaterAcoustic => { None }
-            PduBody::SupplementalEmissionEntityState => { None }
-            PduBody::IntercomSignal => { None }
-            PduBody::IntercomControl => { None }
-            PduBody::AggregateState => { None }
-            PduBody::IsGroupOf => { None }
-            PduBody::TransferOwnership => { None }
-            PduBody::IsPartOf => { None }
-            PduBody::MinefieldState => { None }
-            PduBody::MinefieldQuery => { None }
-            PduBody::MinefieldData => { None }
-            PduBody::MinefieldResponseNACK => { None }
-            PduBody::EnvironmentalProcess => { None }
-            PduBody::GriddedData => { None }
-            PduBody::PointObjectState => { None }
-            PduBody::LinearObjectState => { None }
-            PduBody::ArealObjectState => { None }
-            PduBody::TSPI => { None }
-            PduBody::Appearance => { None }
-            PduBody::ArticulatedParts => { None }
-            PduBody::LEFire => { None }
-            PduBody::LEDetonation => { None }
-            PduBody::CreateEntityR => { None }
-            PduBody::RemoveEntityR => { None }
-            PduBody::StartResumeR => { None }
-            PduBody::StopFreezeR => { None }
-            PduBody::AcknowledgeR => { None }
-            PduBody::ActionRequestR => { None }
-            PduBody::ActionResponseR => { None }
-            PduBody::DataQueryR => { None }
-            PduBody::SetDataR => { None }
-            PduBody::DataR => { None }
-            PduBody::EventReportR => { None }
-            PduBody::CommentR => { None }
-            PduBody::RecordR => { None }
-            PduBody::SetRecordR => { None }
-            PduBody::RecordQueryR => { None }
+            PduBody::UnderwaterAcoustic(body) => { body.originator() }
+            PduBody::SupplementalEmissionEntityState(body) => { body.originator() }
+            PduBody::IntercomSignal(body) => { body.originator() }
+            PduBody::IntercomControl(body) => { body.originator() }
+            PduBody::AggregateState(body) => { body.originator() }
+            PduBody::IsGroupOf(body) => { body.originator() }
+            PduBody::TransferOwnership(body) => { body.originator() }
+            PduBody::IsPartOf(body) => { body.originator() }
+            PduBody::MinefieldState(body) => { body.originator() }
+            PduBody::MinefieldQuery(body) => { body.originator() }
+            PduBody::MinefieldData(body) => { body.originator() }
+            PduBody::MinefieldResponseNACK(body) => { body.originator() }
+            PduBody::EnvironmentalProcess(body) => { body.originator() }
+            PduBody::GriddedData(body) => { body.originator() }
+            PduBody::PointObjectState(body) => { body.originator() }
+            PduBody::LinearObjectState(body) => { body.originator() }
+            PduBody::ArealObjectState(body) => { body.originator() }
+            PduBody::TSPI(body) => { body.originator() }
+            PduBody::Appearance(body) => { body.originator() }
+            PduBody::ArticulatedParts(body) => { body.originator() }
+            PduBody::LEFire(body) => { body.originator() }
+            PduBody::LEDetonation(body) => { body.originator() }
+            PduBody::CreateEntityR(body) => { body.originator() }
+            PduBody::RemoveEntityR(body) => { body.originator() }
+            PduBody::StartResumeR(body) => { body.originator() }
+            PduBody::StopFreezeR(body) => { body.originator() }
+            PduBody::AcknowledgeR(body) => { body.originator() }
+            PduBody::ActionRequestR(body) => { body.originator() }
+            PduBody::ActionResponseR(body) => { body.originator() }
+            PduBody::DataQueryR(body) => { body.originator() }
+            PduBody::SetDataR(body) => { body.originator() }
+            PduBody::DataR(body) => { body.originator() }
+            PduBody::EventReportR(body) => { body.originator() }
+            PduBody::CommentR(body) => { body.originator() }
+            PduBody::RecordR(body) => { body.originator() }
+            PduBody::SetRecordR(body) => { body.originator() }
+            PduBody::RecordQueryR(body) => { body.originator() }
             PduBody::CollisionElastic(body) => { body.originator() }
             PduBody::EntityStateUpdate(body) => { body.originator() }
-            PduBody::DirectedEnergyFire => { None }
-            PduBody::EntityDamageStatus => { None }
-            PduBody::InformationOperationsAction => { None }
-            PduBody::InformationOperationsReport => { None }
+            PduBody::DirectedEnergyFire(body) => { body.originator() }
+            PduBody::EntityDamageStatus(body) => { body.originator() }
+            PduBody::InformationOperationsAction(body) => { body.originator() }
+            PduBody::InformationOperationsReport(body) => { body.originator() }
             PduBody::Attribute(body) => { body.originator() }
         }
     }
@@ -439,12 +488,12 @@ impl Interaction for PduBody {
             PduBody::Fire(body) => { body.receiver() }
             PduBody::Detonation(body) => { body.receiver() }
             PduBody::Collision(body) => { body.receiver() }
-            PduBody::ServiceRequest => { None }
-            PduBody::ResupplyOffer => { None }
-            PduBody::ResupplyReceived => { None }
-            PduBody::ResupplyCancel => { None }
-            PduBody::RepairComplete => { None }
-            PduBody::RepairResponse => { None }
+            PduBody::ServiceRequest(body) => { body.receiver() }
+            PduBody::ResupplyOffer(body) => { body.receiver() }
+            PduBody::ResupplyReceived(body) => { body.receiver() }
+            PduBody::ResupplyCancel(body) => { body.receiver() }
+            PduBody::RepairComplete(body) => { body.receiver() }
+            PduBody::RepairResponse(body) => { body.receiver() }
             PduBody::CreateEntity(body) => { body.receiver() }
             PduBody::RemoveEntity(body) => { body.receiver() }
             PduBody::StartResume(body) => { body.receiver() }
@@ -463,49 +512,49 @@ impl Interaction for PduBody {
             PduBody::Signal(body) => { body.receiver() }
             PduBody::Receiver(body) => { body.receiver() }
             PduBody::IFF(body) => { body.receiver() }
-            PduBody::UnderwaterAcoustic => { None }
-            PduBody::SupplementalEmissionEntityState => { None }
-            PduBody::IntercomSignal => { None }
-            PduBody::IntercomControl => { None }
-            PduBody::AggregateState => { None }
-            PduBody::IsGroupOf => { None }
-            PduBody::TransferOwnership => { None }
-            PduBody::IsPartOf => { None }
-            PduBody::MinefieldState => { None }
-            PduBody::MinefieldQuery => { None }
-            PduBody::MinefieldData => { None }
-            PduBody::MinefieldResponseNACK => { None }
-            PduBody::EnvironmentalProcess => { None }
-            PduBody::GriddedData => { None }
-            PduBody::PointObjectState => { None }
-            PduBody::LinearObjectState => { None }
-            PduBody::ArealObjectState => { None }
-            PduBody::TSPI => { None }
-            PduBody::Appearance => { None }
-            PduBody::ArticulatedParts => { None }
-            PduBody::LEFire => { None }
-            PduBody::LEDetonation => { None }
-            PduBody::CreateEntityR => { None }
-            PduBody::RemoveEntityR => { None }
-            PduBody::StartResumeR => { None }
-            PduBody::StopFreezeR => { None }
-            PduBody::AcknowledgeR => { None }
-            PduBody::ActionRequestR => { None }
-            PduBody::ActionResponseR => { None }
-            PduBody::DataQueryR => { None }
-            PduBody::SetDataR => { None }
-            PduBody::DataR => { None }
-            PduBody::EventReportR => { None }
-            PduBody::CommentR => { None }
-            PduBody::RecordR => { None }
-            PduBody::SetRecordR => { None }
-            PduBody::RecordQueryR => { None }
+            PduBody::UnderwaterAcoustic(body) => { body.receiver() }
+            PduBody::SupplementalEmissionEntityState(body) => { body.receiver() }
+            PduBody::IntercomSignal(body) => { body.receiver() }
+            PduBody::IntercomControl(body) => { body.receiver() }
+            PduBody::AggregateState(body) => { body.receiver() }
+            PduBody::IsGroupOf(body) => { body.receiver() }
+            PduBody::TransferOwnership(body) => { body.receiver() }
+            PduBody::IsPartOf(body) => { body.receiver() }
+            PduBody::MinefieldState(body) => { body.receiver() }
+            PduBody::MinefieldQuery(body) => { body.receiver() }
+            PduBody::MinefieldData(body) => { body.receiver() }
+            PduBody::MinefieldResponseNACK(body) => { body.receiver() }
+            PduBody::EnvironmentalProcess(body) => { body.receiver() }
+            PduBody::GriddedData(body) => { body.receiver() }
+            PduBody::PointObjectState(body) => { body.receiver() }
+            PduBody::LinearObjectState(body) => { body.receiver() }
+            PduBody::ArealObjectState(body) => { body.receiver() }
+            PduBody::TSPI(body) => { body.receiver() }
+            PduBody::Appearance(body) => { body.receiver() }
+            PduBody::ArticulatedParts(body) => { body.receiver() }
+            PduBody::LEFire(body) => { body.receiver() }
+            PduBody::LEDetonation(body) => { body.receiver() }
+            PduBody::CreateEntityR(body) => { body.receiver() }
+            PduBody::RemoveEntityR(body) => { body.receiver() }
+            PduBody::StartResumeR(body) => { body.receiver() }
+            PduBody::StopFreezeR(body) => { body.receiver() }
+            PduBody::AcknowledgeR(body) => { body.receiver() }
+            PduBody::ActionRequestR(body) => { body.receiver() }
+            PduBody::ActionResponseR(body) => { body.receiver() }
+            PduBody::DataQueryR(body) => { body.receiver() }
+            PduBody::SetDataR(body) => { body.receiver() }
+            PduBody::DataR(body) => { body.receiver() }
+            PduBody::EventReportR(body) => { body.receiver() }
+            PduBody::CommentR(body) => { body.receiver() }
+            PduBody::RecordR(body) => { body.receiver() }
+            PduBody::SetRecordR(body) => { body.receiver() }
+            PduBody::RecordQueryR(body) => { body.receiver() }
             PduBody::CollisionElastic(body) => { body.receiver() }
             PduBody::EntityStateUpdate(body) => { body.receiver() }
-            PduBody::DirectedEnergyFire => { None }
-            PduBody::EntityDamageStatus => { None }
-            PduBody::InformationOperationsAction => { None }
-            PduBody::InformationOperationsReport => { None }
+            PduBody::DirectedEnergyFire(body) => { body.receiver() }
+            PduBody::EntityDamageStatus(body) => { body.receiver() }
+            PduBody::InformationOperationsAction(body) => { body.receiver() }
+            PduBody::InformationOperationsReport(body) => { body.receiver() }
             PduBody::Attribute(body) => { body.receiver() }
         }
     }
@@ -592,7 +641,7 @@ impl From<PduType> for ProtocolFamily {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SimulationAddress {
     pub site_id : u16,
     pub application_id : u16,
@@ -616,7 +665,7 @@ impl Default for SimulationAddress {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct EntityId {
     pub simulation_address : SimulationAddress,
     pub entity_id : u16
@@ -681,7 +730,7 @@ impl Default for EventId {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct VectorF32 {
     pub first_vector_component : f32,
     pub second_vector_component : f32,
@@ -713,7 +762,7 @@ impl VectorF32 {
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct Location {
     pub x_coordinate : f64,
     pub y_coordinate : f64,
@@ -745,7 +794,7 @@ impl Location {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Orientation {
     pub psi : f32,
     pub theta : f32,
@@ -863,7 +912,7 @@ impl Default for DescriptorRecord {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct MunitionDescriptor {
     pub warhead : MunitionDescriptorWarhead,
     pub fuse : MunitionDescriptorFuse,
@@ -899,7 +948,7 @@ impl MunitionDescriptor {
 ///
 /// The standard defines the value to be a number of DIS time units since the top of the hour.
 /// There are 2^31 - 1 time units in an hour.
-/// This results in each time unit representing exactly 3600/(2^31) seconds (approximately 1.67638063 μs).
+/// This results in each time unit representing exactly 3600/(2^31 - 1) seconds (approximately 1.67638063 μs).
 ///
 /// This raw timestamp could also be interpreted as a Unix timestamp, or something else
 /// like a monotonically increasing timestamp. This is left up to the client applications of the protocol _by this library_.
@@ -912,6 +961,31 @@ impl TimeStamp {
     pub fn new(raw_timestamp: u32) -> Self {
         Self { raw_timestamp }
     }
+
+    /// Interprets the raw timestamp as a `DisTimeStamp`.
+    pub fn to_dis_time_stamp(&self) -> DisTimeStamp {
+        DisTimeStamp::from(self.raw_timestamp)
+    }
+
+    /// The time elapsed from `earlier` to this timestamp, interpreted as DIS timestamps. See `DisTimeStamp::duration_since`.
+    pub fn duration_since(&self, earlier: &TimeStamp) -> Duration {
+        self.to_dis_time_stamp().duration_since(&earlier.to_dis_time_stamp())
+    }
+
+    /// Orders two timestamps, interpreted as DIS timestamps. See `DisTimeStamp::wrapping_cmp`.
+    pub fn wrapping_cmp(&self, other: &TimeStamp) -> Ordering {
+        self.to_dis_time_stamp().wrapping_cmp(&other.to_dis_time_stamp())
+    }
+
+    /// Estimates the one-way latency of a PDU with this timestamp that is received at `now`. See `DisTimeStamp::latency_at`.
+    pub fn latency_at(&self, now: &TimeStamp) -> Option<Duration> {
+        self.to_dis_time_stamp().latency_at(&now.to_dis_time_stamp())
+    }
+
+    /// Estimates the one-way latency of a PDU with this timestamp that is received now, based on the system clock.
+    pub fn latency(&self) -> Option<Duration> {
+        self.to_dis_time_stamp().latency()
+    }
 }
 
 impl From<u32> for TimeStamp {
@@ -950,46 +1024,143 @@ impl From<DisTimeStamp> for TimeStamp {
 /// beginning of the current hour in the selected time reference.
 /// The `DisTimeStamp` stores both the units past the hour, as well as a conversion to
 /// nanoseconds past the hour.
+///
+/// Absolute timestamps are counted from the top of the hour in UTC, and can be compared between hosts with synchronised clocks.
+/// Relative timestamps are counted from a host-local epoch, and can only be compared with timestamps of the same host.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DisTimeStamp {
-    Absolute { units_past_the_hour: u32, nanoseconds_past_the_hour: u32 },
-    Relative { units_past_the_hour: u32, nanoseconds_past_the_hour: u32 },
+    Absolute { units_past_the_hour: u32, nanoseconds_past_the_hour: u64 },
+    Relative { units_past_the_hour: u32, nanoseconds_past_the_hour: u64 },
 }
 
 impl DisTimeStamp {
     pub fn new_absolute_from_secs(seconds_past_the_hour: u32) -> Self {
-        let nanoseconds_past_the_hour = seconds_to_nanoseconds(seconds_past_the_hour);
-        let units_past_the_hour = nanoseconds_to_dis_time_units(nanoseconds_past_the_hour);
+        Self::new_absolute_from_duration(Duration::from_secs(seconds_past_the_hour as u64))
+    }
+
+    pub fn new_relative_from_secs(seconds_past_the_hour: u32) -> Self {
+        Self::new_relative_from_duration(Duration::from_secs(seconds_past_the_hour as u64))
+    }
+
+    /// Creates an absolute timestamp from the time past the hour. Whole hours in `duration` are discarded,
+    /// so a duration since the Unix epoch results in the time past the current UTC hour.
+    pub fn new_absolute_from_duration(duration: Duration) -> Self {
+        let nanoseconds_past_the_hour = nanoseconds_past_the_hour(duration);
         Self::Absolute {
-            units_past_the_hour,
-            nanoseconds_past_the_hour
+            units_past_the_hour: nanoseconds_to_dis_time_units(nanoseconds_past_the_hour),
+            nanoseconds_past_the_hour,
         }
     }
 
-    pub fn new_relative_from_secs(seconds_past_the_hour: u32) -> Self {
-        let nanoseconds_past_the_hour = seconds_to_nanoseconds(seconds_past_the_hour);
-        let units_past_the_hour = nanoseconds_to_dis_time_units(nanoseconds_past_the_hour);
+    /// Creates a relative timestamp from the time past the hour. Whole hours in `duration` are discarded.
+    pub fn new_relative_from_duration(duration: Duration) -> Self {
+        let nanoseconds_past_the_hour = nanoseconds_past_the_hour(duration);
         Self::Relative {
-            units_past_the_hour,
-            nanoseconds_past_the_hour
+            units_past_the_hour: nanoseconds_to_dis_time_units(nanoseconds_past_the_hour),
+            nanoseconds_past_the_hour,
         }
     }
+
+    /// The current time as an absolute timestamp, based on the system clock.
+    pub fn now_absolute() -> Self {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self::new_absolute_from_duration(since_epoch)
+    }
+
+    /// The current time as a relative timestamp, measured on the monotonic clock of the host since `epoch`
+    /// (e.g., the moment the simulation application started).
+    pub fn now_relative(epoch: Instant) -> Self {
+        Self::new_relative_from_duration(epoch.elapsed())
+    }
+
+    pub fn is_absolute(&self) -> bool {
+        matches!(self, DisTimeStamp::Absolute { .. })
+    }
+
+    pub fn units_past_the_hour(&self) -> u32 {
+        match self {
+            DisTimeStamp::Absolute { units_past_the_hour, .. } => { *units_past_the_hour }
+            DisTimeStamp::Relative { units_past_the_hour, .. } => { *units_past_the_hour }
+        }
+    }
+
+    pub fn nanoseconds_past_the_hour(&self) -> u64 {
+        match self {
+            DisTimeStamp::Absolute { nanoseconds_past_the_hour, .. } => { *nanoseconds_past_the_hour }
+            DisTimeStamp::Relative { nanoseconds_past_the_hour, .. } => { *nanoseconds_past_the_hour }
+        }
+    }
+
+    /// The time past the hour as a `Duration`.
+    pub fn duration_past_the_hour(&self) -> Duration {
+        Duration::from_nanos(self.nanoseconds_past_the_hour())
+    }
+
+    /// The time elapsed from `earlier` to this timestamp, taking the rollover at the top of the hour into account.
+    /// Assumes that less than an hour passed between both timestamps.
+    pub fn duration_since(&self, earlier: &DisTimeStamp) -> Duration {
+        Duration::from_nanos(dis_time_units_to_nanoseconds(wrapping_units_difference(self, earlier)))
+    }
+
+    /// Orders two timestamps, taking the rollover at the top of the hour into account:
+    /// a timestamp is later than another when it is less than half an hour ahead of it.
+    pub fn wrapping_cmp(&self, other: &DisTimeStamp) -> Ordering {
+        let difference = wrapping_units_difference(self, other);
+        if difference == 0 {
+            Ordering::Equal
+        } else if difference < TIME_UNITS_PER_HOUR / 2 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+
+    /// Estimates the one-way latency of a PDU with this timestamp that is received at `now`.
+    /// Only absolute timestamps can be compared between hosts (assuming synchronised clocks), so relative timestamps result in `None`.
+    /// A timestamp that lies ahead of `now`, due to clock differences, results in a latency of zero.
+    pub fn latency_at(&self, now: &DisTimeStamp) -> Option<Duration> {
+        if !self.is_absolute() || !now.is_absolute() {
+            return None;
+        }
+        if self.wrapping_cmp(now) == Ordering::Greater {
+            Some(Duration::ZERO)
+        } else {
+            Some(now.duration_since(self))
+        }
+    }
+
+    /// Estimates the one-way latency of a PDU with this timestamp that is received now, based on the system clock.
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency_at(&Self::now_absolute())
+    }
+}
+
+/// Helper function to take the time past the hour from a duration, in nanoseconds
+fn nanoseconds_past_the_hour(duration: Duration) -> u64 {
+    (duration.as_nanos() % NANOSECONDS_PER_HOUR as u128) as u64
+}
+
+/// Helper function to convert nanoseconds past the hour to DIS Time Units past the hour.
+fn nanoseconds_to_dis_time_units(nanoseconds_past_the_hour: u64) -> u32 {
+    (nanoseconds_past_the_hour as u128 * TIME_UNITS_PER_HOUR as u128 / NANOSECONDS_PER_HOUR as u128) as u32
 }
 
-/// Helper function to convert seconds to nanoseconds
-fn seconds_to_nanoseconds(seconds: u32) -> u32 {
-    seconds * 1_000_000
+/// Helper function to convert DIS Time Units past the hour to nanoseconds past the hour.
+fn dis_time_units_to_nanoseconds(units_past_the_hour: u32) -> u64 {
+    (units_past_the_hour as u128 * NANOSECONDS_PER_HOUR as u128 / TIME_UNITS_PER_HOUR as u128) as u64
 }
 
-/// Helper function to convert nanoseconds pas the hour to DIS Time Units past the hour.
-fn nanoseconds_to_dis_time_units(nanoseconds_past_the_hour: u32) -> u32 {
-    (nanoseconds_past_the_hour as f32 / NANOSECONDS_PER_TIME_UNIT) as u32
+/// Helper function for the number of DIS Time Units from `earlier` to `later`, modulo one hour
+fn wrapping_units_difference(later: &DisTimeStamp, earlier: &DisTimeStamp) -> u32 {
+    (later.units_past_the_hour() as i64 - earlier.units_past_the_hour() as i64)
+        .rem_euclid(TIME_UNITS_PER_HOUR as i64) as u32
 }
 
 impl From<u32> for DisTimeStamp {
     fn from(value: u32) -> Self {
         let absolute_bit = (value & LEAST_SIGNIFICANT_BIT) == LEAST_SIGNIFICANT_BIT;
         let units_past_the_hour = value >> 1;
-        let nanoseconds_past_the_hour = (units_past_the_hour as f32 * NANOSECONDS_PER_TIME_UNIT) as u32;
+        let nanoseconds_past_the_hour = dis_time_units_to_nanoseconds(units_past_the_hour);
 
         if absolute_bit {
             Self::Absolute { units_past_the_hour, nanoseconds_past_the_hour }
@@ -1006,7 +1177,7 @@ impl From<TimeStamp> for DisTimeStamp {
 }
 
 /// 6.2.14 Clock Time record
-#[derive(Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct ClockTime {
     pub hour: i32,
     pub time_past_hour: u32,
@@ -1019,8 +1190,29 @@ impl ClockTime {
             time_past_hour,
         }
     }
+
+    /// Creates a `ClockTime` from a number of seconds, for real-world time counted from 0000 hours January 1, 1970 UTC.
+    /// The time past the hour is encoded as a relative timestamp.
+    pub fn from_seconds(seconds: f64) -> Self {
+        let hour = (seconds / SECONDS_PER_HOUR).floor();
+        let seconds_past_hour = seconds - (hour * SECONDS_PER_HOUR);
+        let units_past_hour = ((seconds_past_hour / SECONDS_PER_HOUR) * TIME_UNITS_PER_HOUR as f64)
+            .min((TIME_UNITS_PER_HOUR - 1) as f64) as u32;
+        Self {
+            hour: hour as i32,
+            time_past_hour: units_past_hour << 1,
+        }
+    }
+
+    /// The number of seconds represented by this `ClockTime`. The Absolute/Relative bit of the time past the hour is ignored.
+    pub fn to_seconds(&self) -> f64 {
+        let units_past_hour = (self.time_past_hour >> 1) as f64;
+        (self.hour as f64 * SECONDS_PER_HOUR) + (units_past_hour / TIME_UNITS_PER_HOUR as f64 * SECONDS_PER_HOUR)
+    }
 }
 
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
 pub struct DatumSpecification {
     pub fixed_datum_records: Vec<FixedDatum>,
     pub variable_datum_records: Vec<VariableDatum>,
@@ -1104,7 +1296,7 @@ pub fn length_padded_to_num_bytes(data_length_bytes: usize, pad_to_num_bytes: us
     PaddedRecordLengths::new(data_length_bytes, padding_bytes, padded_data_bytes)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum VariableParameter {
     Articulated(ArticulatedPart),
     Attached(AttachedPart),
@@ -1348,3 +1540,735 @@ impl BeamData {
         self
     }
 }
+
+pub const SUPPLY_QUANTITY_RECORD_LENGTH: u16 = 12;
+
+/// 6.2.85 Supply Quantity record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SupplyQuantity {
+    pub supply_type: EntityType,
+    pub quantity: f32,
+}
+
+impl SupplyQuantity {
+    pub fn new(supply_type: EntityType, quantity: f32) -> Self {
+        Self {
+            supply_type,
+            quantity,
+        }
+    }
+
+    pub fn with_supply_type(mut self, supply_type: EntityType) -> Self {
+        self.supply_type = supply_type;
+        self
+    }
+
+    pub fn with_quantity(mut self, quantity: f32) -> Self {
+        self.quantity = quantity;
+        self
+    }
+}
+
+pub const BASE_RECORD_SET_LENGTH: u16 = 16;
+
+/// 6.2.73 Record Specification record
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordSpecification {
+    pub record_sets: Vec<RecordSet>,
+}
+
+impl RecordSpecification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_record_set(mut self, record_set: RecordSet) -> Self {
+        self.record_sets.push(record_set);
+        self
+    }
+
+    pub fn with_record_sets(mut self, record_sets: Vec<RecordSet>) -> Self {
+        self.record_sets = record_sets;
+        self
+    }
+
+    /// The length of all record sets, excluding the Number of Record Sets field.
+    pub fn record_sets_length(&self) -> u16 {
+        self.record_sets.iter()
+            .map(|record_set| record_set.record_set_length())
+            .sum::<u16>()
+    }
+}
+
+/// A Record Set of the Record Specification record (6.2.73).
+///
+/// All records in a set have the same length, which is modeled in whole octets.
+/// When serialized, records shorter than `record_length_bytes` are padded with zeroes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordSet {
+    pub record_id: VariableRecordType,
+    pub record_serial_number: u32,
+    pub record_length_bytes: u16,
+    pub records: Vec<Vec<u8>>,
+}
+
+impl RecordSet {
+    pub fn new(record_id: VariableRecordType, record_serial_number: u32) -> Self {
+        Self {
+            record_id,
+            record_serial_number,
+            record_length_bytes: 0,
+            records: vec![],
+        }
+    }
+
+    pub fn with_record_length_bytes(mut self, record_length_bytes: u16) -> Self {
+        self.record_length_bytes = record_length_bytes;
+        self
+    }
+
+    /// Adds a record to the set, extending the record length of the set when the record is longer.
+    /// All records of a set must have the record length of the set to be serialized with `TrySerialize`.
+    pub fn with_record(mut self, record: Vec<u8>) -> Self {
+        self.record_length_bytes = self.record_length_bytes.max(record.len() as u16);
+        self.records.push(record);
+        self
+    }
+
+    /// The length of the record values, without the padding to a 64-bit boundary.
+    pub fn record_values_length(&self) -> usize {
+        self.record_length_bytes as usize * self.records.len()
+    }
+
+    /// The number of octets needed to pad the record values to a 64-bit boundary.
+    pub fn padding_length(&self) -> usize {
+        (EIGHT_OCTETS - self.record_values_length() % EIGHT_OCTETS) % EIGHT_OCTETS
+    }
+
+    pub fn record_set_length(&self) -> u16 {
+        BASE_RECORD_SET_LENGTH + (self.record_values_length() + self.padding_length()) as u16
+    }
+}
+
+pub const PERIMETER_POINT_RECORD_LENGTH: u16 = 8;
+
+/// Perimeter Point record of the Minefield PDUs, also used for the vertices of trip and detonation wires.
+/// The coordinates are offsets in meters from the location of the minefield or the mine.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PerimeterPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl PerimeterPoint {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+}
+
+/// 6.2.16 Data Filter record
+///
+/// Each flag states whether the corresponding per-mine field is included in a Minefield Data PDU.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DataFilter {
+    pub ground_burial_depth_offset: bool,
+    pub water_burial_depth_offset: bool,
+    pub snow_burial_depth_offset: bool,
+    pub mine_orientation: bool,
+    pub thermal_contrast: bool,
+    pub reflectance: bool,
+    pub mine_emplacement_time: bool,
+    pub trip_detonation_wire: bool,
+    pub fusing: bool,
+    pub scalar_detection_coefficient: bool,
+    pub paint_scheme: bool,
+}
+
+impl DataFilter {
+    pub fn with_ground_burial_depth_offset(mut self, ground_burial_depth_offset: bool) -> Self {
+        self.ground_burial_depth_offset = ground_burial_depth_offset;
+        self
+    }
+
+    pub fn with_water_burial_depth_offset(mut self, water_burial_depth_offset: bool) -> Self {
+        self.water_burial_depth_offset = water_burial_depth_offset;
+        self
+    }
+
+    pub fn with_snow_burial_depth_offset(mut self, snow_burial_depth_offset: bool) -> Self {
+        self.snow_burial_depth_offset = snow_burial_depth_offset;
+        self
+    }
+
+    pub fn with_mine_orientation(mut self, mine_orientation: bool) -> Self {
+        self.mine_orientation = mine_orientation;
+        self
+    }
+
+    pub fn with_thermal_contrast(mut self, thermal_contrast: bool) -> Self {
+        self.thermal_contrast = thermal_contrast;
+        self
+    }
+
+    pub fn with_reflectance(mut self, reflectance: bool) -> Self {
+        self.reflectance = reflectance;
+        self
+    }
+
+    pub fn with_mine_emplacement_time(mut self, mine_emplacement_time: bool) -> Self {
+        self.mine_emplacement_time = mine_emplacement_time;
+        self
+    }
+
+    pub fn with_trip_detonation_wire(mut self, trip_detonation_wire: bool) -> Self {
+        self.trip_detonation_wire = trip_detonation_wire;
+        self
+    }
+
+    pub fn with_fusing(mut self, fusing: bool) -> Self {
+        self.fusing = fusing;
+        self
+    }
+
+    pub fn with_scalar_detection_coefficient(mut self, scalar_detection_coefficient: bool) -> Self {
+        self.scalar_detection_coefficient = scalar_detection_coefficient;
+        self
+    }
+
+    pub fn with_paint_scheme(mut self, paint_scheme: bool) -> Self {
+        self.paint_scheme = paint_scheme;
+        self
+    }
+}
+
+pub const OBJECT_TYPE_RECORD_LENGTH: u16 = 4;
+
+/// 6.2.63 Object Type record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ObjectType {
+    pub domain: PlatformDomain,
+    pub kind: ObjectKind,
+    pub category: u8,
+    pub subcategory: u8,
+}
+
+impl ObjectType {
+    pub fn new(domain: PlatformDomain, kind: ObjectKind, category: u8, subcategory: u8) -> Self {
+        Self {
+            domain,
+            kind,
+            category,
+            subcategory,
+        }
+    }
+
+    pub fn with_domain(mut self, domain: PlatformDomain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: ObjectKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_category(mut self, category: u8) -> Self {
+        self.category = category;
+        self
+    }
+
+    pub fn with_subcategory(mut self, subcategory: u8) -> Self {
+        self.subcategory = subcategory;
+        self
+    }
+}
+
+/// General Object Appearance record of the Object State PDUs (6.2.14.2.1).
+///
+/// The specific appearance depends on the object type and is kept as a raw `u32` in the PDUs.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GeneralObjectAppearance {
+    pub percent_complete: u8,
+    pub damage: u8,
+    pub predistributed: bool,
+    pub deactivated: bool,
+    pub smoking: bool,
+    pub flaming: bool,
+    pub ied_present: u8,
+}
+
+impl GeneralObjectAppearance {
+    pub fn with_percent_complete(mut self, percent_complete: u8) -> Self {
+        self.percent_complete = percent_complete;
+        self
+    }
+
+    pub fn with_damage(mut self, damage: u8) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    pub fn with_predistributed(mut self, predistributed: bool) -> Self {
+        self.predistributed = predistributed;
+        self
+    }
+
+    pub fn with_deactivated(mut self, deactivated: bool) -> Self {
+        self.deactivated = deactivated;
+        self
+    }
+
+    pub fn with_smoking(mut self, smoking: bool) -> Self {
+        self.smoking = smoking;
+        self
+    }
+
+    pub fn with_flaming(mut self, flaming: bool) -> Self {
+        self.flaming = flaming;
+        self
+    }
+
+    pub fn with_ied_present(mut self, ied_present: u8) -> Self {
+        self.ied_present = ied_present;
+        self
+    }
+}
+
+/// Modifications field of the Object State PDUs, stating which attributes changed since the previous update.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ObjectStateModification {
+    pub location: bool,
+    pub orientation: bool,
+}
+
+impl ObjectStateModification {
+    pub fn with_location(mut self, location: bool) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: bool) -> Self {
+        self.orientation = orientation;
+        self
+    }
+}
+
+/// Resolution of Live Entity linear velocities, in decimeters per second.
+pub const LIVE_ENTITY_VELOCITY_SCALE: f32 = 10.0;
+/// Resolution of Live Entity linear accelerations, in decimeters per second squared.
+pub const LIVE_ENTITY_ACCELERATION_SCALE: f32 = 10.0;
+/// Resolution of Live Entity angular velocities, in milliradians per second.
+pub const LIVE_ENTITY_ANGULAR_VELOCITY_SCALE: f32 = 1000.0;
+/// Resolution of Live Entity locations in entity coordinates, in decimeters.
+pub const LIVE_ENTITY_LOCATION_SCALE: f32 = 10.0;
+
+/// Live Entity Identifier record
+///
+/// The compact counterpart of `EntityId`, with 8-bit site and application numbers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LiveEntityId {
+    pub site_number: u8,
+    pub application_number: u8,
+    pub entity_number: u16,
+}
+
+impl LiveEntityId {
+    pub fn new(site_number: u8, application_number: u8, entity_number: u16) -> Self {
+        Self {
+            site_number,
+            application_number,
+            entity_number,
+        }
+    }
+}
+
+impl From<LiveEntityId> for EntityId {
+    fn from(value: LiveEntityId) -> Self {
+        EntityId::new(value.site_number as u16, value.application_number as u16, value.entity_number)
+    }
+}
+
+/// Converts an `EntityId` into a `LiveEntityId`. Site and application numbers larger than 255 are truncated.
+impl From<&EntityId> for LiveEntityId {
+    fn from(value: &EntityId) -> Self {
+        LiveEntityId::new(
+            value.simulation_address.site_id as u8,
+            value.simulation_address.application_id as u8,
+            value.entity_id)
+    }
+}
+
+/// Live Event Identifier, the compact counterpart of `EventId`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LiveEventId {
+    pub site_number: u8,
+    pub application_number: u8,
+    pub event_number: u16,
+}
+
+impl LiveEventId {
+    pub fn new(site_number: u8, application_number: u8, event_number: u16) -> Self {
+        Self {
+            site_number,
+            application_number,
+            event_number,
+        }
+    }
+}
+
+impl From<LiveEventId> for EventId {
+    fn from(value: LiveEventId) -> Self {
+        EventId::new(SimulationAddress::new(value.site_number as u16, value.application_number as u16), value.event_number)
+    }
+}
+
+/// Converts an `EventId` into a `LiveEventId`. Site and application numbers larger than 255 are truncated.
+impl From<&EventId> for LiveEventId {
+    fn from(value: &EventId) -> Self {
+        LiveEventId::new(
+            value.simulation_address.site_id as u8,
+            value.simulation_address.application_id as u8,
+            value.event_id)
+    }
+}
+
+/// Live Entity Relative World Coordinates record
+///
+/// A location as offsets in meters from a reference point, which is identified by number and agreed upon by the
+/// participants of the exercise.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveEntityRelativeWorldCoordinates {
+    pub reference_point: u16,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+}
+
+impl LiveEntityRelativeWorldCoordinates {
+    pub fn new(reference_point: u16, delta_x: i16, delta_y: i16, delta_z: i16) -> Self {
+        Self {
+            reference_point,
+            delta_x,
+            delta_y,
+            delta_z,
+        }
+    }
+
+    /// Converts the relative coordinates to world coordinates, given the location of the reference point.
+    pub fn to_location(&self, reference_point: &Location) -> Location {
+        Location::new(
+            reference_point.x_coordinate + self.delta_x as f64,
+            reference_point.y_coordinate + self.delta_y as f64,
+            reference_point.z_coordinate + self.delta_z as f64)
+    }
+
+    /// Expresses `location` relative to the reference point with number `reference_point_id`, located at `reference_point`.
+    /// Offsets are rounded to whole meters and saturate at the bounds of an `i16`.
+    pub fn from_location(location: &Location, reference_point_id: u16, reference_point: &Location) -> Self {
+        Self::new(
+            reference_point_id,
+            (location.x_coordinate - reference_point.x_coordinate).round() as i16,
+            (location.y_coordinate - reference_point.y_coordinate).round() as i16,
+            (location.z_coordinate - reference_point.z_coordinate).round() as i16)
+    }
+}
+
+/// A vector of three 16-bit components, used by the Live Entity PDUs for scaled velocities, accelerations and locations.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveEntityVector {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+}
+
+impl LiveEntityVector {
+    pub fn new(x: i16, y: i16, z: i16) -> Self {
+        Self {
+            x,
+            y,
+            z,
+        }
+    }
+
+    /// Converts the vector to SI units, where `scale` is the number of units of this vector per SI unit.
+    pub fn to_vector_f32(&self, scale: f32) -> VectorF32 {
+        VectorF32::new(self.x as f32 / scale, self.y as f32 / scale, self.z as f32 / scale)
+    }
+
+    /// Converts a vector in SI units, where `scale` is the number of units of this vector per SI unit.
+    /// Values saturate at the bounds of an `i16`.
+    pub fn from_vector_f32(vector: &VectorF32, scale: f32) -> Self {
+        Self::new(
+            (vector.first_vector_component * scale).round() as i16,
+            (vector.second_vector_component * scale).round() as i16,
+            (vector.third_vector_component * scale).round() as i16)
+    }
+}
+
+/// Live Entity Orientation record, with each angle as an 8-bit binary angle of π/128 radians.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveEntityOrientation {
+    pub psi: i8,
+    pub theta: i8,
+    pub phi: i8,
+}
+
+impl LiveEntityOrientation {
+    pub fn new(psi: i8, theta: i8, phi: i8) -> Self {
+        Self {
+            psi,
+            theta,
+            phi,
+        }
+    }
+
+    pub fn to_orientation(&self) -> Orientation {
+        let to_radians = |angle: i8| angle as f32 * std::f32::consts::PI / 128.0;
+        Orientation::new(to_radians(self.psi), to_radians(self.theta), to_radians(self.phi))
+    }
+
+    /// Converts an `Orientation` to binary angles. Angles outside the range [-π, π) wrap around.
+    pub fn from_orientation(orientation: &Orientation) -> Self {
+        // the cast to i8 wraps the binary angle, where a direct float to i8 cast would saturate
+        let to_binary_angle = |angle: f32| (angle * 128.0 / std::f32::consts::PI).round() as i32 as i8;
+        Self::new(to_binary_angle(orientation.psi), to_binary_angle(orientation.theta), to_binary_angle(orientation.phi))
+    }
+}
+
+/// Live Entity Position Error record, in meters.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveEntityPositionError {
+    pub horizontal_error: u16,
+    pub vertical_error: u16,
+}
+
+impl LiveEntityPositionError {
+    pub fn new(horizontal_error: u16, vertical_error: u16) -> Self {
+        Self {
+            horizontal_error,
+            vertical_error,
+        }
+    }
+}
+
+/// Live Entity Orientation Error record, in milliradians.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveEntityOrientationError {
+    pub azimuth_error: u16,
+    pub elevation_error: u16,
+    pub rotation_error: u16,
+}
+
+impl LiveEntityOrientationError {
+    pub fn new(azimuth_error: u16, elevation_error: u16, rotation_error: u16) -> Self {
+        Self {
+            azimuth_error,
+            elevation_error,
+            rotation_error,
+        }
+    }
+}
+
+/// Live Dead Reckoning Parameters record
+///
+/// The linear acceleration is scaled by `LIVE_ENTITY_ACCELERATION_SCALE`,
+/// the angular velocity by `LIVE_ENTITY_ANGULAR_VELOCITY_SCALE`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LiveDeadReckoningParameters {
+    pub algorithm: DeadReckoningAlgorithm,
+    pub linear_acceleration: LiveEntityVector,
+    pub angular_velocity: LiveEntityVector,
+}
+
+impl LiveDeadReckoningParameters {
+    pub fn new(algorithm: DeadReckoningAlgorithm, linear_acceleration: LiveEntityVector, angular_velocity: LiveEntityVector) -> Self {
+        Self {
+            algorithm,
+            linear_acceleration,
+            angular_velocity,
+        }
+    }
+}
+
+pub(crate) const IO_RECORD_HEADER_LENGTH: u16 = 6;
+pub(crate) const IO_COMMUNICATIONS_NODE_RECORD_LENGTH: u16 = 16;
+pub(crate) const IO_EFFECT_RECORD_LENGTH: u16 = 16;
+pub(crate) const IO_COMMUNICATIONS_NODE_RECORD_TYPE: u32 = 5001;
+pub(crate) const IO_EFFECT_RECORD_TYPE: u32 = 5500;
+
+/// Standard Variable records of the Information Operations Action and Report PDUs (6.2.83).
+/// Records of other types than IO Communications Node and IO Effect are kept as the record type and the raw record data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IoRecord {
+    CommunicationsNode(IoCommunicationsNode),
+    Effect(IoEffect),
+    Unspecified(u32, Vec<u8>),
+}
+
+impl IoRecord {
+    pub fn record_type(&self) -> u32 {
+        match self {
+            IoRecord::CommunicationsNode(_) => { IO_COMMUNICATIONS_NODE_RECORD_TYPE }
+            IoRecord::Effect(_) => { IO_EFFECT_RECORD_TYPE }
+            IoRecord::Unspecified(record_type, _) => { *record_type }
+        }
+    }
+
+    /// The length of the record in octets, including the record type and length fields.
+    pub fn record_length(&self) -> u16 {
+        match self {
+            IoRecord::CommunicationsNode(_) => { IO_COMMUNICATIONS_NODE_RECORD_LENGTH }
+            IoRecord::Effect(_) => { IO_EFFECT_RECORD_LENGTH }
+            IoRecord::Unspecified(_, data) => { IO_RECORD_HEADER_LENGTH + data.len() as u16 }
+        }
+    }
+}
+
+/// 6.2.48 IO Communications Node record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct IoCommunicationsNode {
+    pub communications_node_type: IOCommsNodeRecordCommsNodeType,
+    pub communications_node_id: EntityId,
+    pub element_id: u16,
+}
+
+impl IoCommunicationsNode {
+    pub fn new(communications_node_type: IOCommsNodeRecordCommsNodeType, communications_node_id: EntityId, element_id: u16) -> Self {
+        Self {
+            communications_node_type,
+            communications_node_id,
+            element_id,
+        }
+    }
+}
+
+/// 6.2.49 IO Effect record
+///
+/// The duty cycle is a percentage (0 - 100), the duration is in seconds.
+/// The standard has no separate attack type: the kind of attack follows from the `IOActionIOActionType`
+/// of the IO Action PDU, its outcome from the `effect` of this record.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct IoEffect {
+    pub status: IOEffectsRecordIOStatus,
+    pub link_type: IOEffectsRecordIOLinkType,
+    pub effect: IOEffectsRecordIOEffect,
+    pub effect_duty_cycle: u8,
+    pub effect_duration: u16,
+    pub process: IOEffectsRecordIOProcess,
+}
+
+impl IoEffect {
+    pub fn with_status(mut self, status: IOEffectsRecordIOStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_link_type(mut self, link_type: IOEffectsRecordIOLinkType) -> Self {
+        self.link_type = link_type;
+        self
+    }
+
+    pub fn with_effect(mut self, effect: IOEffectsRecordIOEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    pub fn with_effect_duty_cycle(mut self, effect_duty_cycle: u8) -> Self {
+        self.effect_duty_cycle = effect_duty_cycle;
+        self
+    }
+
+    pub fn with_effect_duration(mut self, effect_duration: u16) -> Self {
+        self.effect_duration = effect_duration;
+        self
+    }
+
+    pub fn with_process(mut self, process: IOEffectsRecordIOProcess) -> Self {
+        self.process = process;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::time::Duration;
+    use crate::common::model::{DisTimeStamp, LiveEntityOrientation, Orientation, TimeStamp};
+    use crate::constants::TIME_UNITS_PER_HOUR;
+
+    #[test]
+    fn timestamp_from_secs() {
+        let time_stamp = DisTimeStamp::new_absolute_from_secs(1800);
+
+        assert_eq!(time_stamp.units_past_the_hour(), TIME_UNITS_PER_HOUR / 2);
+        assert_eq!(time_stamp.nanoseconds_past_the_hour(), 1_800_000_000_000);
+        assert!(time_stamp.is_absolute());
+    }
+
+    #[test]
+    fn timestamp_from_duration_discards_hours() {
+        let time_stamp = DisTimeStamp::new_relative_from_duration(Duration::from_secs(7200 + 90));
+
+        assert_eq!(time_stamp.duration_past_the_hour(), Duration::from_secs(90));
+        assert!(!time_stamp.is_absolute());
+    }
+
+    #[test]
+    fn timestamp_raw_roundtrip() {
+        let time_stamp = DisTimeStamp::new_absolute_from_duration(Duration::from_millis(2_345_678));
+        let raw: u32 = TimeStamp::from(time_stamp).into();
+
+        assert_eq!(raw & 1, 1);
+        let parsed = DisTimeStamp::from(raw);
+        assert_eq!(parsed.units_past_the_hour(), time_stamp.units_past_the_hour());
+        let error = parsed.duration_past_the_hour().abs_diff(time_stamp.duration_past_the_hour());
+        assert!(error < Duration::from_micros(2));
+    }
+
+    #[test]
+    fn timestamp_rollover() {
+        let before = DisTimeStamp::new_absolute_from_secs(3599);
+        let after = DisTimeStamp::new_absolute_from_secs(1);
+
+        assert_eq!(after.wrapping_cmp(&before), Ordering::Greater);
+        assert_eq!(before.wrapping_cmp(&after), Ordering::Less);
+        assert_eq!(before.wrapping_cmp(&before), Ordering::Equal);
+        let elapsed = after.duration_since(&before);
+        assert!(elapsed.abs_diff(Duration::from_secs(2)) < Duration::from_micros(2));
+    }
+
+    #[test]
+    fn raw_timestamp_helpers() {
+        let before = TimeStamp::from(DisTimeStamp::new_absolute_from_secs(3599));
+        let after = TimeStamp::from(DisTimeStamp::new_absolute_from_secs(1));
+
+        assert_eq!(after.wrapping_cmp(&before), Ordering::Greater);
+        assert!(after.duration_since(&before).abs_diff(Duration::from_secs(2)) < Duration::from_micros(2));
+        assert!(before.latency_at(&after).unwrap().abs_diff(Duration::from_secs(2)) < Duration::from_micros(2));
+        assert_eq!(TimeStamp::from(DisTimeStamp::new_relative_from_secs(1)).latency_at(&after), None);
+    }
+
+    #[test]
+    fn timestamp_latency() {
+        let sent = DisTimeStamp::new_absolute_from_secs(10);
+        let received = DisTimeStamp::new_absolute_from_duration(Duration::from_millis(10_250));
+
+        let latency = sent.latency_at(&received).unwrap();
+        assert!(latency.abs_diff(Duration::from_millis(250)) < Duration::from_micros(2));
+        assert_eq!(received.latency_at(&sent), Some(Duration::ZERO));
+        assert_eq!(DisTimeStamp::new_relative_from_secs(10).latency_at(&received), None);
+    }
+
+    #[test]
+    fn live_entity_orientation_wraps_angles() {
+        use std::f32::consts::PI;
+        let orientation = LiveEntityOrientation::from_orientation(&Orientation::new(PI, 1.5 * PI, -1.5 * PI));
+        assert_eq!(orientation, LiveEntityOrientation::new(-128, -64, 64));
+
+        let orientation = LiveEntityOrientation::from_orientation(&Orientation::new(2.0 * PI + 0.25 * PI, -PI, 0.5 * PI));
+        assert_eq!(orientation, LiveEntityOrientation::new(32, -128, 64));
+    }
+}
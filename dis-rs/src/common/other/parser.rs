@@ -1,6 +1,6 @@
 use nom::bytes::complete::take;
 use nom::combinator::peek;
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::sequence::tuple;
 use crate::common::parser::entity_id;
 use crate::common::model::{EntityId, PduBody, PduHeader};
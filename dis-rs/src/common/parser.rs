@@ -1,16 +1,17 @@
 use nom::combinator::peek;
+use nom::error::context;
 use nom::Err;
-use nom::IResult;
-use nom::number::complete::{be_f32, be_f64, be_i32, be_u16, be_u32, be_u64, be_u8};
-use nom::bytes::complete::take;
+use nom::number::complete::{be_f32, be_f64, be_i16, be_i32, be_i8, be_u16, be_u32, be_u64, be_u8};
+use nom::bytes::complete::{take, take_while_m_n};
 use nom::error::ErrorKind::Eof;
 use nom::multi::{count, many1};
-use nom::sequence::tuple;
+use nom::sequence::{terminated, tuple};
 use crate::common::entity_state::parser::entity_state_body;
-use crate::constants::{EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES};
-use crate::common::errors::DisError;
+use crate::constants::{EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, LEAST_SIGNIFICANT_BIT, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES};
+use crate::common::errors::{DisError, FieldParseError, PduParseError};
+use crate::common::{PduParseResult, SupportedVersion};
 use crate::common::other::parser::other_body;
-use crate::common::model::{BeamData, ClockTime, DatumSpecification, DescriptorRecord, EntityId, EntityType, EventId, FixedDatum, Location, MunitionDescriptor, Orientation, Pdu, PduBody, PduHeader, SimulationAddress, VariableDatum, VectorF32, EntityTypeParameter, length_padded_to_num_bytes, SeparationParameter, EntityAssociationParameter, VariableParameter, ArticulatedPart, AttachedPart};
+use crate::common::model::{BeamData, ClockTime, DatumSpecification, DescriptorRecord, EntityId, EntityType, EventId, FixedDatum, Location, MunitionDescriptor, Orientation, Pdu, PduBody, PduHeader, SimulationAddress, VariableDatum, VectorF32, EntityTypeParameter, length_padded_to_num_bytes, SeparationParameter, EntityAssociationParameter, VariableParameter, ArticulatedPart, AttachedPart, RecordSet, RecordSpecification, SupplyQuantity, PerimeterPoint, DataFilter, ObjectType, GeneralObjectAppearance, ObjectStateModification, LiveEntityId, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEntityOrientation, LiveEntityPositionError, LiveEntityOrientationError, LiveDeadReckoningParameters, IoRecord, IoCommunicationsNode, IoEffect, IO_COMMUNICATIONS_NODE_RECORD_TYPE, IO_EFFECT_RECORD_TYPE, IO_RECORD_HEADER_LENGTH};
 use crate::common::acknowledge::parser::acknowledge_body;
 use crate::common::action_request::parser::action_request_body;
 use crate::common::action_response::parser::action_response_body;
@@ -29,24 +30,77 @@ use crate::common::event_report::parser::event_report_body;
 use crate::common::fire::parser::fire_body;
 use crate::common::receiver::parser::receiver_body;
 use crate::common::remove_entity::parser::remove_entity_body;
+use crate::common::repair_complete::parser::repair_complete_body;
+use crate::common::repair_response::parser::repair_response_body;
+use crate::common::resupply_cancel::parser::resupply_cancel_body;
+use crate::common::resupply_offer::parser::resupply_offer_body;
+use crate::common::resupply_received::parser::resupply_received_body;
+use crate::common::service_request::parser::service_request_body;
 use crate::common::set_data::parser::set_data_body;
 use crate::common::signal::parser::signal_body;
 use crate::common::start_resume::parser::start_resume_body;
 use crate::common::stop_freeze::parser::stop_freeze_body;
 use crate::common::transmitter::parser::transmitter_body;
 use crate::v7::parser::parse_pdu_status;
-use crate::enumerations::{Country, DetonationTypeIndicator, EntityKind, ExplosiveMaterialCategories, FireTypeIndicator, MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion, VariableRecordType};
+use crate::enumerations::{Country, DetonationTypeIndicator, EntityKind, ExplosiveMaterialCategories, FireTypeIndicator, MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion, VariableRecordType, ObjectKind, DeadReckoningAlgorithm, IOCommsNodeRecordCommsNodeType, IOEffectsRecordIOEffect, IOEffectsRecordIOLinkType, IOEffectsRecordIOProcess, IOEffectsRecordIOStatus};
 use crate::enumerations::{ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator, AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus, EntityAssociationGroupMemberType, EntityAssociationPhysicalAssociationType, EntityAssociationPhysicalConnectionType, SeparationPreEntityIndicator, SeparationReasonForSeparation, StationName, VariableParameterRecordType};
 use crate::common::iff::parser::iff_body;
+use crate::common::intercom_control::parser::intercom_control_body;
+use crate::common::intercom_signal::parser::intercom_signal_body;
+use crate::common::aggregate_state::parser::aggregate_state_body;
+use crate::common::is_group_of::parser::is_group_of_body;
+use crate::common::transfer_ownership::parser::transfer_ownership_body;
+use crate::common::is_part_of::parser::is_part_of_body;
+use crate::common::minefield_state::parser::minefield_state_body;
+use crate::common::minefield_query::parser::minefield_query_body;
+use crate::common::minefield_data::parser::minefield_data_body;
+use crate::common::minefield_response_nack::parser::minefield_response_nack_body;
+use crate::common::environmental_process::parser::environmental_process_body;
+use crate::common::gridded_data::parser::gridded_data_body;
+use crate::common::point_object_state::parser::point_object_state_body;
+use crate::common::linear_object_state::parser::linear_object_state_body;
+use crate::common::areal_object_state::parser::areal_object_state_body;
+use crate::common::live_entity::tspi::parser::tspi_body;
+use crate::common::live_entity::appearance::parser::appearance_body;
+use crate::common::live_entity::articulated_parts::parser::articulated_parts_body;
+use crate::common::live_entity::le_fire::parser::le_fire_body;
+use crate::common::live_entity::le_detonation::parser::le_detonation_body;
+use crate::common::directed_energy_fire::parser::directed_energy_fire_body;
+use crate::common::entity_damage_status::parser::entity_damage_status_body;
+use crate::common::information_operations_action::parser::information_operations_action_body;
+use crate::common::information_operations_report::parser::information_operations_report_body;
+use crate::common::underwater_acoustic::parser::underwater_acoustic_body;
+use crate::common::supplemental_emission_entity_state::parser::sees_body;
+use crate::common::simulation_management_with_reliability::create_entity_r::parser::create_entity_r_body;
+use crate::common::simulation_management_with_reliability::remove_entity_r::parser::remove_entity_r_body;
+use crate::common::simulation_management_with_reliability::start_resume_r::parser::start_resume_r_body;
+use crate::common::simulation_management_with_reliability::stop_freeze_r::parser::stop_freeze_r_body;
+use crate::common::simulation_management_with_reliability::acknowledge_r::parser::acknowledge_r_body;
+use crate::common::simulation_management_with_reliability::action_request_r::parser::action_request_r_body;
+use crate::common::simulation_management_with_reliability::action_response_r::parser::action_response_r_body;
+use crate::common::simulation_management_with_reliability::data_query_r::parser::data_query_r_body;
+use crate::common::simulation_management_with_reliability::set_data_r::parser::set_data_r_body;
+use crate::common::simulation_management_with_reliability::data_r::parser::data_r_body;
+use crate::common::simulation_management_with_reliability::event_report_r::parser::event_report_r_body;
+use crate::common::simulation_management_with_reliability::comment_r::parser::comment_r_body;
+use crate::common::simulation_management_with_reliability::record_r::parser::record_r_body;
+use crate::common::simulation_management_with_reliability::set_record_r::parser::set_record_r_body;
+use crate::common::simulation_management_with_reliability::record_query_r::parser::record_query_r_body;
+
+/// The result of the parsers of this crate, which report the field or record of the PDU that failed to parse.
+pub(crate) type IResult<I, O> = nom::IResult<I, O, FieldParseError<I>>;
+
+const PDU_TYPE_OFFSET: usize = 2;
+/// PDUs in a bundle start at an 8-octet boundary, so at most seven octets of padding follow a PDU
+const MAX_BUNDLE_PADDING_OCTETS: usize = 7;
 
 pub fn parse_multiple_pdu(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    match many1(pdu)(input) {
+    match many1(terminated(pdu, bundle_padding))(input) {
         Ok((_, pdus)) => { Ok(pdus) }
         Err(err) => { Err(DisError::ParseError(err.to_string())) } // TODO not very descriptive / error means we can not match any PDUs
     }
 }
 
-#[allow(dead_code)]
 pub fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
     match pdu(input) {
         Ok((_, pdu)) => { Ok(pdu) }
@@ -54,9 +108,102 @@ pub fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
     }
 }
 
+/// Parses all PDUs in the input, producing a result for each PDU.
+/// After a PDU that cannot be parsed, parsing continues with the next PDU based on the PDU Length field of the header.
+/// When the PDU Length field cannot be trusted, the rest of the input is reported as a single failed PDU.
+pub fn parse_multiple_pdu_results(input: &[u8]) -> Vec<PduParseResult> {
+    let mut results = vec![];
+    let mut offset = 0;
+    while offset < input.len() {
+        let remaining = &input[offset..];
+        let (result, consumed) = pdu_result(remaining);
+        results.push(PduParseResult {
+            offset,
+            pdu_type: remaining.get(PDU_TYPE_OFFSET).map(|pdu_type| PduType::from(*pdu_type)),
+            result,
+        });
+        match consumed {
+            Some(consumed) => {
+                offset += consumed;
+                if let Ok((_, padding)) = bundle_padding(&input[offset..]) {
+                    offset += padding.len();
+                }
+            }
+            None => { break }
+        }
+    }
+    results
+}
+
+/// Skips the zero octets that align the next PDU in a bundle to an 8-octet boundary (IEEE 1278.1-2012, 5.3.3.3).
+/// A PDU never starts with a zero octet, as that is not a valid Protocol Version.
+pub(crate) fn bundle_padding(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    take_while_m_n(0, MAX_BUNDLE_PADDING_OCTETS, |octet| octet == 0)(input)
+}
+
+/// Parses a single PDU at the start of the input, returning the result and the number of octets the PDU occupies,
+/// if that can be determined.
+fn pdu_result(input: &[u8]) -> (Result<Pdu, PduParseError>, Option<usize>) {
+    let header = match pdu_header(input) {
+        Ok((_, header)) => { header }
+        Err(err) => { return (Err(parse_error_reason(input, err)), None) }
+    };
+
+    let pdu_length = header.pdu_length as usize;
+    if pdu_length < PDU_HEADER_LEN_BYTES as usize || pdu_length > input.len() {
+        return (Err(PduParseError::LengthMismatch { pdu_length: header.pdu_length, available: input.len() }), None);
+    }
+    if let SupportedVersion::Unsupported = SupportedVersion::from(header.protocol_version) {
+        return (Err(PduParseError::UnsupportedProtocolVersion(header.protocol_version)), Some(pdu_length));
+    }
+    if let PduType::Unspecified(value) = header.pdu_type {
+        return (Err(PduParseError::UnknownEnumeration { field: header_field_name(PDU_TYPE_OFFSET), value: value as u32 }), Some(pdu_length));
+    }
+
+    let pdu_bytes = &input[..pdu_length];
+    match pdu(pdu_bytes) {
+        Ok((_, pdu)) => { (Ok(pdu), Some(pdu_length)) }
+        Err(err) => { (Err(parse_error_reason(pdu_bytes, err)), Some(pdu_length)) }
+    }
+}
+
+/// Converts a nom error into a `PduParseError`, locating the field at which parsing failed.
+/// Body parsers name the failing field through a nom `context`; without one the field is named by its offset.
+fn parse_error_reason(pdu_bytes: &[u8], err: Err<FieldParseError<&[u8]>>) -> PduParseError {
+    match err {
+        Err::Error(error) | Err::Failure(error) => {
+            let field_offset = pdu_bytes.len().saturating_sub(error.input.len());
+            let field = error.field.unwrap_or_else(|| header_field_name(field_offset));
+            if error.code == Eof {
+                PduParseError::TruncatedRecord { field, field_offset }
+            } else {
+                PduParseError::InvalidRecord { field, field_offset }
+            }
+        }
+        Err::Incomplete(_) => {
+            PduParseError::TruncatedRecord { field: header_field_name(pdu_bytes.len()), field_offset: pdu_bytes.len() }
+        }
+    }
+}
+
+/// Names the header field at the given octet offset; offsets beyond the header are in the PDU body,
+/// for body fields that are not named by their parser.
+fn header_field_name(offset: usize) -> &'static str {
+    match offset {
+        0 => { "Protocol Version" }
+        1 => { "Exercise ID" }
+        2 => { "PDU Type" }
+        3 => { "Protocol Family" }
+        4..=7 => { "Timestamp" }
+        8..=9 => { "PDU Length" }
+        10..=11 => { "PDU Status / Padding" }
+        _ => { "PDU Body" }
+    }
+}
+
 #[allow(dead_code)]
 pub fn parse_multiple_header(input: &[u8]) -> Result<Vec<PduHeader>, DisError> {
-    match many1(pdu_header_skip_body)(input) {
+    match many1(terminated(pdu_header_skip_body, bundle_padding))(input) {
         Ok((_, headers)) => { Ok(headers) }
         Err(parse_error) => {
             if let Err::Error(ref error) = parse_error {
@@ -171,12 +318,12 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::Fire => { fire_body(header)(input)? }
             PduType::Detonation => { detonation_body(header)(input)? }
             PduType::Collision => { collision_body(input)? }
-            // PduType::ServiceRequest => {}
-            // PduType::ResupplyOffer => {}
-            // PduType::ResupplyReceived => {}
-            // PduType::ResupplyCancel => {}
-            // PduType::RepairComplete => {}
-            // PduType::RepairResponse => {}
+            PduType::ServiceRequest => { service_request_body(input)? }
+            PduType::ResupplyOffer => { resupply_offer_body(input)? }
+            PduType::ResupplyReceived => { resupply_received_body(input)? }
+            PduType::ResupplyCancel => { resupply_cancel_body(input)? }
+            PduType::RepairComplete => { repair_complete_body(input)? }
+            PduType::RepairResponse => { repair_response_body(input)? }
             PduType::CreateEntity => { create_entity_body(input)? }
             PduType::RemoveEntity => { remove_entity_body(input)? }
             PduType::StartResume => { start_resume_body(input)? }
@@ -195,52 +342,51 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::Signal => { signal_body(input)? }
             PduType::Receiver => { receiver_body(input)? }
             PduType::IFF => { iff_body(input)? }
-            // PduType::UnderwaterAcoustic => {}
-            // PduType::SupplementalEmissionEntityState => {}
-            // PduType::IntercomSignal => {}
-            // PduType::IntercomControl => {}
-            // PduType::AggregateState => {}
-            // PduType::IsGroupOf => {}
-            // PduType::TransferOwnership => {}
-            // PduType::IsPartOf => {}
-            // PduType::MinefieldState => {}
-            // PduType::MinefieldQuery => {}
-            // PduType::MinefieldData => {}
-            // PduType::MinefieldResponseNACK => {}
-            // PduType::EnvironmentalProcess => {}
-            // PduType::GriddedData => {}
-            // PduType::PointObjectState => {}
-            // PduType::LinearObjectState => {}
-            // PduType::ArealObjectState => {}
-            // PduType::TSPI => {}
-            // PduType::Appearance => {}
-            // PduType::ArticulatedParts => {}
-            // PduType::LEFire => {}
-            // PduType::LEDetonation => {}
-            // PduType::CreateEntityR => {}
-            // PduType::RemoveEntityR => {}
-            // PduType::StartResumeR => {}
-            // PduType::StopFreezeR => {}
-            // PduType::AcknowledgeR => {}
-            // PduType::ActionRequestR => {}
-            // PduType::ActionResponseR => {}
-            // PduType::DataQueryR => {}
-            // PduType::SetDataR => {}
-            // PduType::DataR => {}
-            // PduType::EventReportR => {}
-            // PduType::CommentR => {}
-            // PduType::RecordR => {}
-            // PduType::SetRecordR => {}
-            // PduType::RecordQueryR => {}
+            PduType::UnderwaterAcoustic => { underwater_acoustic_body(input)? }
+            PduType::SupplementalEmissionEntityState => { sees_body(input)? }
+            PduType::IntercomSignal => { intercom_signal_body(input)? }
+            PduType::IntercomControl => { intercom_control_body(input)? }
+            PduType::AggregateState => { aggregate_state_body(input)? }
+            PduType::IsGroupOf => { is_group_of_body(header)(input)? }
+            PduType::TransferOwnership => { transfer_ownership_body(input)? }
+            PduType::IsPartOf => { is_part_of_body(input)? }
+            PduType::MinefieldState => { minefield_state_body(input)? }
+            PduType::MinefieldQuery => { minefield_query_body(input)? }
+            PduType::MinefieldData => { minefield_data_body(input)? }
+            PduType::MinefieldResponseNACK => { minefield_response_nack_body(input)? }
+            PduType::EnvironmentalProcess => { environmental_process_body(input)? }
+            PduType::GriddedData => { gridded_data_body(input)? }
+            PduType::PointObjectState => { point_object_state_body(input)? }
+            PduType::LinearObjectState => { linear_object_state_body(input)? }
+            PduType::ArealObjectState => { areal_object_state_body(input)? }
+            PduType::TSPI => { tspi_body(input)? }
+            PduType::Appearance => { appearance_body(input)? }
+            PduType::ArticulatedParts => { articulated_parts_body(input)? }
+            PduType::LEFire => { le_fire_body(input)? }
+            PduType::LEDetonation => { le_detonation_body(input)? }
+            PduType::CreateEntityR => { create_entity_r_body(input)? }
+            PduType::RemoveEntityR => { remove_entity_r_body(input)? }
+            PduType::StartResumeR => { start_resume_r_body(input)? }
+            PduType::StopFreezeR => { stop_freeze_r_body(input)? }
+            PduType::AcknowledgeR => { acknowledge_r_body(input)? }
+            PduType::ActionRequestR => { action_request_r_body(input)? }
+            PduType::ActionResponseR => { action_response_r_body(input)? }
+            PduType::DataQueryR => { data_query_r_body(input)? }
+            PduType::SetDataR => { set_data_r_body(input)? }
+            PduType::DataR => { data_r_body(input)? }
+            PduType::EventReportR => { event_report_r_body(input)? }
+            PduType::CommentR => { comment_r_body(input)? }
+            PduType::RecordR => { record_r_body(input)? }
+            PduType::SetRecordR => { set_record_r_body(input)? }
+            PduType::RecordQueryR => { record_query_r_body(input)? }
             PduType::CollisionElastic => { collision_elastic_body(input)? }
             PduType::EntityStateUpdate => { entity_state_update_body(input)? }
-            // PduType::DirectedEnergyFire => {}
-            // PduType::EntityDamageStatus => {}
-            // PduType::InformationOperationsAction => {}
-            // PduType::InformationOperationsReport => {}
+            PduType::DirectedEnergyFire => { directed_energy_fire_body(input)? }
+            PduType::EntityDamageStatus => { entity_damage_status_body(input)? }
+            PduType::InformationOperationsAction => { information_operations_action_body(input)? }
+            PduType::InformationOperationsReport => { information_operations_report_body(input)? }
             PduType::Attribute => { attribute_body(input)? }
             PduType::Unspecified(_type_number) => { other_body(header)(input)? } // TODO Log unspecified type number?
-            _ => { other_body(header)(input)? }
         };
         Ok((input, body))
     }
@@ -469,13 +615,13 @@ pub fn munition_descriptor(input: &[u8]) -> IResult<&[u8], MunitionDescriptor> {
     }))
 }
 
-fn warhead(input: &[u8]) -> IResult<&[u8], MunitionDescriptorWarhead> {
+pub(crate) fn warhead(input: &[u8]) -> IResult<&[u8], MunitionDescriptorWarhead> {
     let (input, warhead) = be_u16(input)?;
     let warhead = MunitionDescriptorWarhead::from(warhead);
     Ok((input, warhead))
 }
 
-fn fuse(input: &[u8]) -> IResult<&[u8], MunitionDescriptorFuse> {
+pub(crate) fn fuse(input: &[u8]) -> IResult<&[u8], MunitionDescriptorFuse> {
     let (input, fuse) = be_u16(input)?;
     let fuse = MunitionDescriptorFuse::from(fuse);
     Ok((input, fuse))
@@ -488,18 +634,210 @@ pub fn clock_time(input: &[u8]) -> IResult<&[u8], ClockTime> {
     Ok((input, time))
 }
 
+pub fn supply_quantity(input: &[u8]) -> IResult<&[u8], SupplyQuantity> {
+    let (input, supply_type) = entity_type(input)?;
+    let (input, quantity) = be_f32(input)?;
+    Ok((input, SupplyQuantity::new(supply_type, quantity)))
+}
+
 pub fn datum_specification(input: &[u8]) -> IResult<&[u8], DatumSpecification> {
-    let (input, num_fixed_datums) = be_u32(input)?;
-    let (input, num_variable_datums) = be_u32(input)?;
+    let (input, num_fixed_datums) = context("Number of Fixed Datum Records", be_u32)(input)?;
+    let (input, num_variable_datums) = context("Number of Variable Datum Records", be_u32)(input)?;
 
-    let (input, fixed_datums) = count(fixed_datum, num_fixed_datums as usize)(input)?;
-    let (input, variable_datums) = count(variable_datum, num_variable_datums as usize)(input)?;
+    let (input, fixed_datums) = context("Fixed Datum Records", count(fixed_datum, num_fixed_datums as usize))(input)?;
+    let (input, variable_datums) = context("Variable Datum Records", count(variable_datum, num_variable_datums as usize))(input)?;
 
     let datums = DatumSpecification::new(fixed_datums, variable_datums);
 
     Ok((input, datums))
 }
 
+pub fn record_specification(input: &[u8]) -> IResult<&[u8], RecordSpecification> {
+    let (input, number_of_record_sets) = context("Number of Record Sets", be_u32)(input)?;
+    let (input, record_sets) = context("Record Sets", count(record_set, number_of_record_sets as usize))(input)?;
+
+    Ok((input, RecordSpecification::new().with_record_sets(record_sets)))
+}
+
+pub fn record_set(input: &[u8]) -> IResult<&[u8], RecordSet> {
+    let (input, record_id) = be_u32(input)?;
+    let (input, record_serial_number) = be_u32(input)?;
+    let (input, _padding) = be_u32(input)?;
+    let (input, record_length_bits) = be_u16(input)?;
+    let (input, record_count) = be_u16(input)?;
+
+    let record_length_bytes = (record_length_bits as usize).div_ceil(ONE_BYTE_IN_BITS);
+    let (input, records) = count(take(record_length_bytes), record_count as usize)(input)?;
+
+    let record_set = RecordSet {
+        record_id: VariableRecordType::from(record_id),
+        record_serial_number,
+        record_length_bytes: record_length_bytes as u16,
+        records: records.iter().map(|record| record.to_vec()).collect(),
+    };
+    let (input, _padding) = take(record_set.padding_length())(input)?;
+
+    Ok((input, record_set))
+}
+
+pub fn perimeter_point(input: &[u8]) -> IResult<&[u8], PerimeterPoint> {
+    let (input, x) = be_f32(input)?;
+    let (input, y) = be_f32(input)?;
+    Ok((input, PerimeterPoint::new(x, y)))
+}
+
+pub fn data_filter(input: &[u8]) -> IResult<&[u8], DataFilter> {
+    let (input, filter) = be_u32(input)?;
+    Ok((input, DataFilter {
+        ground_burial_depth_offset: filter & LEAST_SIGNIFICANT_BIT != 0,
+        water_burial_depth_offset: (filter >> 1) & LEAST_SIGNIFICANT_BIT != 0,
+        snow_burial_depth_offset: (filter >> 2) & LEAST_SIGNIFICANT_BIT != 0,
+        mine_orientation: (filter >> 3) & LEAST_SIGNIFICANT_BIT != 0,
+        thermal_contrast: (filter >> 4) & LEAST_SIGNIFICANT_BIT != 0,
+        reflectance: (filter >> 5) & LEAST_SIGNIFICANT_BIT != 0,
+        mine_emplacement_time: (filter >> 6) & LEAST_SIGNIFICANT_BIT != 0,
+        trip_detonation_wire: (filter >> 7) & LEAST_SIGNIFICANT_BIT != 0,
+        fusing: (filter >> 8) & LEAST_SIGNIFICANT_BIT != 0,
+        scalar_detection_coefficient: (filter >> 9) & LEAST_SIGNIFICANT_BIT != 0,
+        paint_scheme: (filter >> 10) & LEAST_SIGNIFICANT_BIT != 0,
+    }))
+}
+
+pub fn object_type(input: &[u8]) -> IResult<&[u8], ObjectType> {
+    let (input, domain) = be_u8(input)?;
+    let (input, kind) = be_u8(input)?;
+    let (input, category) = be_u8(input)?;
+    let (input, subcategory) = be_u8(input)?;
+    Ok((input, ObjectType::new(
+        PlatformDomain::from(domain),
+        ObjectKind::from(kind),
+        category,
+        subcategory)))
+}
+
+pub fn general_object_appearance(input: &[u8]) -> IResult<&[u8], GeneralObjectAppearance> {
+    let (input, appearance) = be_u16(input)?;
+    Ok((input, GeneralObjectAppearance {
+        percent_complete: (appearance & 0x00FF) as u8,
+        damage: ((appearance >> 8) & 0x0003) as u8,
+        predistributed: (appearance >> 10) & LEAST_SIGNIFICANT_BIT as u16 != 0,
+        deactivated: (appearance >> 11) & LEAST_SIGNIFICANT_BIT as u16 != 0,
+        smoking: (appearance >> 12) & LEAST_SIGNIFICANT_BIT as u16 != 0,
+        flaming: (appearance >> 13) & LEAST_SIGNIFICANT_BIT as u16 != 0,
+        ied_present: ((appearance >> 14) & 0x0003) as u8,
+    }))
+}
+
+pub fn object_state_modification(input: &[u8]) -> IResult<&[u8], ObjectStateModification> {
+    let (input, modifications) = be_u8(input)?;
+    Ok((input, ObjectStateModification {
+        location: modifications & LEAST_SIGNIFICANT_BIT as u8 != 0,
+        orientation: (modifications >> 1) & LEAST_SIGNIFICANT_BIT as u8 != 0,
+    }))
+}
+
+pub fn live_entity_id(input: &[u8]) -> IResult<&[u8], LiveEntityId> {
+    let (input, site_number) = be_u8(input)?;
+    let (input, application_number) = be_u8(input)?;
+    let (input, entity_number) = be_u16(input)?;
+    Ok((input, LiveEntityId::new(site_number, application_number, entity_number)))
+}
+
+pub fn live_entity_relative_world_coordinates(input: &[u8]) -> IResult<&[u8], LiveEntityRelativeWorldCoordinates> {
+    let (input, reference_point) = be_u16(input)?;
+    let (input, delta_x) = be_i16(input)?;
+    let (input, delta_y) = be_i16(input)?;
+    let (input, delta_z) = be_i16(input)?;
+    Ok((input, LiveEntityRelativeWorldCoordinates::new(reference_point, delta_x, delta_y, delta_z)))
+}
+
+pub fn live_entity_vector(input: &[u8]) -> IResult<&[u8], LiveEntityVector> {
+    let (input, x) = be_i16(input)?;
+    let (input, y) = be_i16(input)?;
+    let (input, z) = be_i16(input)?;
+    Ok((input, LiveEntityVector::new(x, y, z)))
+}
+
+pub fn live_entity_orientation(input: &[u8]) -> IResult<&[u8], LiveEntityOrientation> {
+    let (input, psi) = be_i8(input)?;
+    let (input, theta) = be_i8(input)?;
+    let (input, phi) = be_i8(input)?;
+    Ok((input, LiveEntityOrientation::new(psi, theta, phi)))
+}
+
+pub fn live_entity_position_error(input: &[u8]) -> IResult<&[u8], LiveEntityPositionError> {
+    let (input, horizontal_error) = be_u16(input)?;
+    let (input, vertical_error) = be_u16(input)?;
+    Ok((input, LiveEntityPositionError::new(horizontal_error, vertical_error)))
+}
+
+pub fn live_entity_orientation_error(input: &[u8]) -> IResult<&[u8], LiveEntityOrientationError> {
+    let (input, azimuth_error) = be_u16(input)?;
+    let (input, elevation_error) = be_u16(input)?;
+    let (input, rotation_error) = be_u16(input)?;
+    Ok((input, LiveEntityOrientationError::new(azimuth_error, elevation_error, rotation_error)))
+}
+
+pub fn live_dead_reckoning_parameters(input: &[u8]) -> IResult<&[u8], LiveDeadReckoningParameters> {
+    let (input, algorithm) = be_u8(input)?;
+    let (input, linear_acceleration) = live_entity_vector(input)?;
+    let (input, angular_velocity) = live_entity_vector(input)?;
+    Ok((input, LiveDeadReckoningParameters::new(
+        DeadReckoningAlgorithm::from(algorithm),
+        linear_acceleration,
+        angular_velocity)))
+}
+
+pub fn io_record(input: &[u8]) -> IResult<&[u8], IoRecord> {
+    let (input, record_type) = be_u32(input)?;
+    let (input, record_length) = be_u16(input)?;
+    let (input, record_data) = take(record_length.saturating_sub(IO_RECORD_HEADER_LENGTH))(input)?;
+
+    let record = match record_type {
+        IO_COMMUNICATIONS_NODE_RECORD_TYPE => {
+            let (_, record) = io_communications_node(record_data)?;
+            IoRecord::CommunicationsNode(record)
+        }
+        IO_EFFECT_RECORD_TYPE => {
+            let (_, record) = io_effect(record_data)?;
+            IoRecord::Effect(record)
+        }
+        _ => { IoRecord::Unspecified(record_type, record_data.to_vec()) }
+    };
+
+    Ok((input, record))
+}
+
+fn io_communications_node(input: &[u8]) -> IResult<&[u8], IoCommunicationsNode> {
+    let (input, communications_node_type) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+    let (input, communications_node_id) = entity_id(input)?;
+    let (input, element_id) = be_u16(input)?;
+
+    Ok((input, IoCommunicationsNode::new(
+        IOCommsNodeRecordCommsNodeType::from(communications_node_type),
+        communications_node_id,
+        element_id)))
+}
+
+fn io_effect(input: &[u8]) -> IResult<&[u8], IoEffect> {
+    let (input, status) = be_u8(input)?;
+    let (input, link_type) = be_u8(input)?;
+    let (input, effect) = be_u8(input)?;
+    let (input, effect_duty_cycle) = be_u8(input)?;
+    let (input, effect_duration) = be_u16(input)?;
+    let (input, process) = be_u16(input)?;
+    let (input, _padding) = be_u16(input)?;
+
+    Ok((input, IoEffect::default()
+        .with_status(IOEffectsRecordIOStatus::from(status))
+        .with_link_type(IOEffectsRecordIOLinkType::from(link_type))
+        .with_effect(IOEffectsRecordIOEffect::from(effect))
+        .with_effect_duty_cycle(effect_duty_cycle)
+        .with_effect_duration(effect_duration)
+        .with_process(IOEffectsRecordIOProcess::from(process))))
+}
+
 pub fn fixed_datum(input: &[u8]) -> IResult<&[u8], FixedDatum> {
     let (input, datum_id) = be_u32(input)?;
     let (input, datum_value) = be_u32(input)?;
@@ -515,12 +853,12 @@ pub fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
     let datum_id = VariableRecordType::from(datum_id);
     let (input, datum_length_bits) = be_u32(input)?;
 
-    let datum_length_bytes = datum_length_bits as usize % ONE_BYTE_IN_BITS;
+    let datum_length_bytes = (datum_length_bits as usize).div_ceil(ONE_BYTE_IN_BITS);
     let padded_record = length_padded_to_num_bytes(
         EIGHT_OCTETS + datum_length_bytes,
         EIGHT_OCTETS);
 
-    let (input, datum_value) = take(padded_record.data_length_bytes)(input)?;
+    let (input, datum_value) = take(datum_length_bytes)(input)?;
     let (input, _datum_padding) = take(padded_record.padding_length_bytes)(input)?;
 
     let variable_datum = VariableDatum::new(datum_id, datum_value.to_vec());
@@ -530,10 +868,14 @@ pub fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
 
 #[cfg(test)]
 mod tests {
-    use crate::common::errors::DisError;
-    use crate::common::parser::parse_multiple_header;
+    use bytes::BytesMut;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::errors::{DisError, PduParseError};
+    use crate::common::model::{EntityId, EntityType, Pdu, PduHeader};
+    use crate::common::parser::{parse_multiple_header, parse_multiple_pdu_results};
+    use crate::common::Serialize;
     use crate::constants::PDU_HEADER_LEN_BYTES;
-    use crate::enumerations::{PduType, ProtocolFamily, ProtocolVersion};
+    use crate::enumerations::{EntityKind, ForceId, PduType, ProtocolFamily, ProtocolVersion};
 
     #[test]
     fn parse_header() {
@@ -622,6 +964,92 @@ mod tests {
         let error = headers.expect_err("Should be Err");
         assert_eq!(error, DisError::InsufficientHeaderLength(11));
     }
+
+    fn entity_state_bytes() -> Vec<u8> {
+        let body = EntityState::new(EntityId::new(1, 1, 1), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityState), body, 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    #[test]
+    fn parse_results_good_bad_good() {
+        let good = entity_state_bytes();
+        // EntityState header stating a total length of 14 bytes, i.e., a 2 byte body
+        let bad: [u8; 14] = [0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0xf4];
+        let mut bytes = good.clone();
+        bytes.extend_from_slice(&bad);
+        bytes.extend_from_slice(&good);
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].result.is_ok());
+        assert_eq!(results[1].offset, good.len());
+        assert_eq!(results[1].pdu_type, Some(PduType::EntityState));
+        assert_eq!(results[1].result, Err(PduParseError::TruncatedRecord { field: "Entity ID", field_offset: 14 }));
+        assert_eq!(results[2].offset, good.len() + bad.len());
+        assert!(results[2].result.is_ok());
+    }
+
+    #[test]
+    fn parse_results_names_failing_body_field() {
+        // CreateEntity PDU of 26 octets, ending halfway the Request ID field
+        let bytes: [u8; 26] = [0x06, 0x01, 0x0b, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x01,
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x02,
+            0x00, 0x00];
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].pdu_type, Some(PduType::CreateEntity));
+        assert_eq!(results[0].result, Err(PduParseError::TruncatedRecord { field: "Request ID", field_offset: 24 }));
+    }
+
+    #[test]
+    fn parse_results_names_failing_logistics_body_field() {
+        // ResupplyCancel PDU of 21 octets, ending halfway the Application number of the Supplying ID field
+        let bytes: [u8; 21] = [0x06, 0x01, 0x08, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x01,
+            0x00, 0x01, 0x00];
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].pdu_type, Some(PduType::ResupplyCancel));
+        assert_eq!(results[0].result, Err(PduParseError::TruncatedRecord { field: "Supplying ID", field_offset: 20 }));
+    }
+
+    #[test]
+    fn parse_results_length_mismatch() {
+        let good = entity_state_bytes();
+        let mut bytes = good.clone();
+        bytes.extend_from_slice(&good[..20]);
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].result.is_ok());
+        assert_eq!(results[1].result, Err(PduParseError::LengthMismatch { pdu_length: good.len() as u16, available: 20 }));
+    }
+
+    #[test]
+    fn parse_results_truncated_header() {
+        let bytes: [u8; 6] = [0x06, 0x01, 0x01, 0x01, 0x4e, 0xea];
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].result, Err(PduParseError::TruncatedRecord { field: "Timestamp", field_offset: 4 }));
+    }
+
+    #[test]
+    fn parse_results_unsupported_version() {
+        let bytes: [u8; 12] = [0x05, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0c, 0x00, 0x00];
+
+        let results = parse_multiple_pdu_results(&bytes);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].result, Err(PduParseError::UnsupportedProtocolVersion(ProtocolVersion::from(5))));
+    }
 }
 
 pub fn variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParameter> {
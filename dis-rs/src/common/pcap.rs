@@ -0,0 +1,952 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{ErrorKind, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::{parse, TrySerialize};
+
+const PCAP_MAGIC_MICROSECONDS: u32 = 0xA1B2_C3D4;
+const PCAP_MAGIC_NANOSECONDS: u32 = 0xA1B2_3C4D;
+const PCAPNG_SECTION_HEADER_BLOCK: u32 = 0x0A0D_0D0A;
+const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+const PCAPNG_INTERFACE_DESCRIPTION_BLOCK: u32 = 0x0000_0001;
+const PCAPNG_SIMPLE_PACKET_BLOCK: u32 = 0x0000_0003;
+const PCAPNG_ENHANCED_PACKET_BLOCK: u32 = 0x0000_0006;
+const PCAPNG_OPTION_END: u16 = 0;
+const PCAPNG_OPTION_IF_TSRESOL: u16 = 9;
+const PCAP_HEADER_LENGTH: usize = 24;
+const PCAP_RECORD_HEADER_LENGTH: usize = 16;
+/// Snapshot length written by `PcapWriter`, equal to the libpcap default; frames are never truncated
+const PCAP_SNAPSHOT_LENGTH: u32 = 262_144;
+/// Sanity limit for the length of records and blocks, to reject corrupt files before allocating
+const MAX_RECORD_LENGTH: usize = 1 << 24;
+const MICROSECONDS_PER_SECOND: u64 = 1_000_000;
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+
+const ETHERNET_HEADER_LENGTH: usize = 14;
+const LINUX_SLL_HEADER_LENGTH: usize = 16;
+const NULL_HEADER_LENGTH: usize = 4;
+const VLAN_TAG_LENGTH: usize = 4;
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86DD;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88A8;
+
+const IPV4_HEADER_LENGTH: usize = 20;
+const IPV6_HEADER_LENGTH: usize = 40;
+const UDP_HEADER_LENGTH: usize = 8;
+const IP_PROTOCOL_UDP: u8 = 17;
+const IPV4_MORE_FRAGMENTS: u16 = 0x2000;
+const IPV4_DONT_FRAGMENT: u16 = 0x4000;
+const IPV4_FRAGMENT_OFFSET_MASK: u16 = 0x1FFF;
+const IPV6_HOP_BY_HOP: u8 = 0;
+const IPV6_ROUTING: u8 = 43;
+const IPV6_FRAGMENT: u8 = 44;
+const IPV6_AUTHENTICATION: u8 = 51;
+const IPV6_DESTINATION_OPTIONS: u8 = 60;
+const DEFAULT_TTL: u8 = 64;
+/// Maximum payload of a single UDP datagram over IPv4
+const MAX_UDP_PAYLOAD_OCTETS: usize = 65_507;
+/// Capture time after which an incomplete fragmented packet is discarded, as the Linux default (ipfrag_time)
+const FRAGMENT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Maximum number of fragmented packets that are reassembled at the same time
+const MAX_FRAGMENTED_PACKETS: usize = 64;
+
+/// A UDP datagram read from, or to be written to, a capture file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedDatagram {
+    /// Capture time, as the duration since the UNIX epoch
+    pub time: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+impl CapturedDatagram {
+    pub fn new(time: Duration, source: SocketAddr, destination: SocketAddr, payload: Vec<u8>) -> Self {
+        Self {
+            time,
+            source,
+            destination,
+            payload,
+        }
+    }
+
+    /// Parses the PDUs in the payload of the datagram.
+    pub fn pdus(&self) -> Result<Vec<Pdu>, DisError> {
+        parse(&self.payload)
+    }
+}
+
+/// A PDU read from a capture file, with the capture time and addresses of the datagram that contained it.
+#[derive(Debug, PartialEq)]
+pub struct CapturedPdu {
+    /// Capture time, as the duration since the UNIX epoch
+    pub time: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub pdu: Pdu,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ByteOrder {
+    Little,
+    Big,
+}
+
+impl ByteOrder {
+    fn u16(self, bytes: &[u8]) -> u16 {
+        let bytes = [bytes[0], bytes[1]];
+        match self {
+            ByteOrder::Little => { u16::from_le_bytes(bytes) }
+            ByteOrder::Big => { u16::from_be_bytes(bytes) }
+        }
+    }
+
+    fn u32(self, bytes: &[u8]) -> u32 {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        match self {
+            ByteOrder::Little => { u32::from_le_bytes(bytes) }
+            ByteOrder::Big => { u32::from_be_bytes(bytes) }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Interface {
+    link_type: u32,
+    units_per_second: u64,
+}
+
+enum Format {
+    Pcap { byte_order: ByteOrder, interface: Interface },
+    PcapNg { byte_order: ByteOrder, interfaces: Vec<Interface> },
+}
+
+/// A captured link-layer frame
+struct Frame {
+    time: Duration,
+    link_type: u32,
+    data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct FragmentKey {
+    source: IpAddr,
+    destination: IpAddr,
+    identification: u32,
+}
+
+/// The fragments of an IP packet that is being reassembled
+struct FragmentBuffer {
+    first_seen: Duration,
+    fragments: Vec<(usize, Vec<u8>)>,
+    total_length: Option<usize>,
+}
+
+impl FragmentBuffer {
+    fn new(first_seen: Duration) -> Self {
+        Self {
+            first_seen,
+            fragments: vec![],
+            total_length: None,
+        }
+    }
+
+    /// Adds a fragment, returning the reassembled payload when all fragments are present.
+    fn add(&mut self, offset: usize, data: &[u8], last: bool) -> Option<Vec<u8>> {
+        if last {
+            self.total_length = Some(offset + data.len());
+        }
+        self.fragments.push((offset, data.to_vec()));
+        self.fragments.sort_by_key(|(offset, _)| *offset);
+
+        let total_length = self.total_length?;
+        let mut covered = 0;
+        for (offset, data) in &self.fragments {
+            if *offset > covered {
+                return None;
+            }
+            covered = covered.max(offset + data.len());
+        }
+        if covered < total_length {
+            return None;
+        }
+
+        let mut payload = vec![0u8; total_length];
+        for (offset, data) in &self.fragments {
+            let end = (offset + data.len()).min(total_length);
+            if *offset < end {
+                payload[*offset..end].copy_from_slice(&data[..end - offset]);
+            }
+        }
+        Some(payload)
+    }
+}
+
+/// Reads UDP datagrams from pcap and pcapng capture files, such as those recorded by Wireshark or tcpdump.
+///
+/// Ethernet (including VLAN tags), raw IP, Linux cooked (SLL) and BSD loopback captures are supported.
+/// Fragmented IPv4 and IPv6 packets are reassembled. Frames that do not contain a UDP datagram, or that were truncated
+/// during capture, are skipped. Incomplete fragmented packets are discarded after 30 seconds of capture time,
+/// and at most 64 packets are reassembled at the same time; when full, the oldest packet is discarded.
+///
+/// The reader is an iterator over the captured datagrams. Use `into_pdus` to iterate over the PDUs in the datagrams instead.
+pub struct PcapReader<R: Read> {
+    reader: R,
+    format: Format,
+    fragments: HashMap<FragmentKey, FragmentBuffer>,
+    done: bool,
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Creates a reader, reading the file header to determine the format of the capture.
+    pub fn new(mut reader: R) -> Result<Self, DisError> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+
+        let format = if u32::from_be_bytes(magic) == PCAPNG_SECTION_HEADER_BLOCK {
+            let byte_order = read_section_header(&mut reader)?;
+            Format::PcapNg { byte_order, interfaces: vec![] }
+        } else {
+            let (byte_order, units_per_second) = match (u32::from_le_bytes(magic), u32::from_be_bytes(magic)) {
+                (PCAP_MAGIC_MICROSECONDS, _) => { (ByteOrder::Little, MICROSECONDS_PER_SECOND) }
+                (PCAP_MAGIC_NANOSECONDS, _) => { (ByteOrder::Little, NANOSECONDS_PER_SECOND) }
+                (_, PCAP_MAGIC_MICROSECONDS) => { (ByteOrder::Big, MICROSECONDS_PER_SECOND) }
+                (_, PCAP_MAGIC_NANOSECONDS) => { (ByteOrder::Big, NANOSECONDS_PER_SECOND) }
+                _ => { return Err(DisError::CaptureError("Not a pcap or pcapng file".to_string())) }
+            };
+            let mut header = [0u8; PCAP_HEADER_LENGTH - 4];
+            reader.read_exact(&mut header)?;
+            let link_type = byte_order.u32(&header[16..20]) & 0xFFFF;
+            Format::Pcap { byte_order, interface: Interface { link_type, units_per_second } }
+        };
+
+        Ok(Self {
+            reader,
+            format,
+            fragments: HashMap::new(),
+            done: false,
+        })
+    }
+
+    /// Reads the next UDP datagram from the capture, or returns `None` at the end of the capture.
+    pub fn next_datagram(&mut self) -> Result<Option<CapturedDatagram>, DisError> {
+        while let Some(frame) = self.next_frame()? {
+            if let Some(datagram) = self.decode_frame(&frame) {
+                return Ok(Some(datagram));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Turns the reader into an iterator over the PDUs in the captured datagrams.
+    pub fn into_pdus(self) -> CapturedPdus<R> {
+        CapturedPdus {
+            reader: self,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn next_frame(&mut self) -> Result<Option<Frame>, DisError> {
+        match &mut self.format {
+            Format::Pcap { byte_order, interface } => {
+                loop {
+                    let mut header = [0u8; PCAP_RECORD_HEADER_LENGTH];
+                    if !read_exact_or_eof(&mut self.reader, &mut header)? {
+                        return Ok(None);
+                    }
+                    let seconds = byte_order.u32(&header[0..4]) as u64;
+                    let fraction = byte_order.u32(&header[4..8]) as u64;
+                    let captured_length = byte_order.u32(&header[8..12]) as usize;
+                    let original_length = byte_order.u32(&header[12..16]) as usize;
+                    let data = read_record(&mut self.reader, captured_length)?;
+                    if captured_length < original_length {
+                        continue;
+                    }
+
+                    let ticks = seconds * interface.units_per_second + fraction;
+                    return Ok(Some(Frame { time: ticks_to_duration(ticks, interface.units_per_second), link_type: interface.link_type, data }));
+                }
+            }
+            Format::PcapNg { byte_order, interfaces } => {
+                loop {
+                    let mut block_type = [0u8; 4];
+                    if !read_exact_or_eof(&mut self.reader, &mut block_type)? {
+                        return Ok(None);
+                    }
+                    if u32::from_be_bytes(block_type) == PCAPNG_SECTION_HEADER_BLOCK {
+                        *byte_order = read_section_header(&mut self.reader)?;
+                        interfaces.clear();
+                        continue;
+                    }
+
+                    let block_type = byte_order.u32(&block_type);
+                    let mut block_length = [0u8; 4];
+                    self.reader.read_exact(&mut block_length)?;
+                    let block_length = byte_order.u32(&block_length) as usize;
+                    if block_length < 12 || block_length % 4 != 0 {
+                        return Err(DisError::CaptureError(format!("Invalid pcapng block length {}", block_length)));
+                    }
+                    // the body excludes the block type and the leading and trailing block length fields
+                    let block = read_record(&mut self.reader, block_length - 8)?;
+                    let body = &block[..block.len() - 4];
+
+                    match block_type {
+                        PCAPNG_INTERFACE_DESCRIPTION_BLOCK if body.len() >= 8 => {
+                            interfaces.push(Interface {
+                                link_type: byte_order.u16(&body[0..2]) as u32,
+                                units_per_second: interface_resolution(*byte_order, &body[8..]),
+                            });
+                        }
+                        PCAPNG_ENHANCED_PACKET_BLOCK if body.len() >= 20 => {
+                            let interface = match interfaces.get(byte_order.u32(&body[0..4]) as usize) {
+                                Some(interface) => { *interface }
+                                None => { return Err(DisError::CaptureError("Packet refers to an undefined interface".to_string())) }
+                            };
+                            let ticks = ((byte_order.u32(&body[4..8]) as u64) << 32) | byte_order.u32(&body[8..12]) as u64;
+                            let captured_length = byte_order.u32(&body[12..16]) as usize;
+                            let original_length = byte_order.u32(&body[16..20]) as usize;
+                            if captured_length < original_length || 20 + captured_length > body.len() {
+                                continue;
+                            }
+                            return Ok(Some(Frame {
+                                time: ticks_to_duration(ticks, interface.units_per_second),
+                                link_type: interface.link_type,
+                                data: body[20..20 + captured_length].to_vec(),
+                            }));
+                        }
+                        PCAPNG_SIMPLE_PACKET_BLOCK if body.len() >= 4 => {
+                            let interface = match interfaces.first() {
+                                Some(interface) => { *interface }
+                                None => { return Err(DisError::CaptureError("Packet refers to an undefined interface".to_string())) }
+                            };
+                            let original_length = byte_order.u32(&body[0..4]) as usize;
+                            if 4 + original_length > body.len() {
+                                continue;
+                            }
+                            return Ok(Some(Frame {
+                                time: Duration::ZERO,
+                                link_type: interface.link_type,
+                                data: body[4..4 + original_length].to_vec(),
+                            }));
+                        }
+                        _ => { }
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_frame(&mut self, frame: &Frame) -> Option<CapturedDatagram> {
+        let packet = match frame.link_type {
+            LINKTYPE_NULL => { frame.data.get(NULL_HEADER_LENGTH..)? }
+            LINKTYPE_ETHERNET => { ethernet_payload(&frame.data)? }
+            LINKTYPE_RAW => { &frame.data[..] }
+            LINKTYPE_LINUX_SLL => {
+                let ether_type = u16::from_be_bytes([*frame.data.get(14)?, *frame.data.get(15)?]);
+                if ether_type != ETHERTYPE_IPV4 && ether_type != ETHERTYPE_IPV6 {
+                    return None;
+                }
+                frame.data.get(LINUX_SLL_HEADER_LENGTH..)?
+            }
+            _ => { return None; }
+        };
+
+        let (source, destination, segment) = match packet.first()? >> 4 {
+            4 => { self.ipv4_payload(packet, frame.time)? }
+            6 => { self.ipv6_payload(packet, frame.time)? }
+            _ => { return None; }
+        };
+        udp_datagram(frame.time, source, destination, &segment)
+    }
+
+    /// Returns the addresses and the UDP segment of an IPv4 packet, reassembling fragmented packets.
+    fn ipv4_payload(&mut self, packet: &[u8], time: Duration) -> Option<(IpAddr, IpAddr, Vec<u8>)> {
+        let header_length = ((packet.first()? & 0x0F) as usize) * 4;
+        let total_length = u16::from_be_bytes([*packet.get(2)?, *packet.get(3)?]) as usize;
+        if header_length < IPV4_HEADER_LENGTH || total_length < header_length || total_length > packet.len() {
+            return None;
+        }
+        if packet[9] != IP_PROTOCOL_UDP {
+            return None;
+        }
+        let source = IpAddr::from(<[u8; 4]>::try_from(&packet[12..16]).ok()?);
+        let destination = IpAddr::from(<[u8; 4]>::try_from(&packet[16..20]).ok()?);
+        let payload = &packet[header_length..total_length];
+
+        let flags_and_offset = u16::from_be_bytes([packet[6], packet[7]]);
+        let more_fragments = flags_and_offset & IPV4_MORE_FRAGMENTS != 0;
+        let offset = ((flags_and_offset & IPV4_FRAGMENT_OFFSET_MASK) as usize) * 8;
+        if !more_fragments && offset == 0 {
+            return Some((source, destination, payload.to_vec()));
+        }
+
+        let identification = u16::from_be_bytes([packet[4], packet[5]]) as u32;
+        let payload = self.reassemble(FragmentKey { source, destination, identification }, time, offset, payload, !more_fragments)?;
+        Some((source, destination, payload))
+    }
+
+    /// Returns the addresses and the UDP segment of an IPv6 packet, skipping extension headers and reassembling fragmented packets.
+    fn ipv6_payload(&mut self, packet: &[u8], time: Duration) -> Option<(IpAddr, IpAddr, Vec<u8>)> {
+        let payload_length = u16::from_be_bytes([*packet.get(4)?, *packet.get(5)?]) as usize;
+        if packet.len() < IPV6_HEADER_LENGTH + payload_length {
+            return None;
+        }
+        let source = IpAddr::from(<[u8; 16]>::try_from(&packet[8..24]).ok()?);
+        let destination = IpAddr::from(<[u8; 16]>::try_from(&packet[24..40]).ok()?);
+
+        let mut next_header = packet[6];
+        let mut payload = packet[IPV6_HEADER_LENGTH..IPV6_HEADER_LENGTH + payload_length].to_vec();
+        loop {
+            match next_header {
+                IP_PROTOCOL_UDP => { return Some((source, destination, payload)); }
+                IPV6_HOP_BY_HOP | IPV6_ROUTING | IPV6_DESTINATION_OPTIONS => {
+                    let length = (*payload.get(1)? as usize + 1) * 8;
+                    next_header = *payload.first()?;
+                    payload = payload.get(length..)?.to_vec();
+                }
+                IPV6_AUTHENTICATION => {
+                    let length = (*payload.get(1)? as usize + 2) * 4;
+                    next_header = *payload.first()?;
+                    payload = payload.get(length..)?.to_vec();
+                }
+                IPV6_FRAGMENT => {
+                    let fragment_header = payload.get(..8)?;
+                    let offset_and_flag = u16::from_be_bytes([fragment_header[2], fragment_header[3]]);
+                    let identification = u32::from_be_bytes([fragment_header[4], fragment_header[5], fragment_header[6], fragment_header[7]]);
+                    next_header = fragment_header[0];
+                    let offset = (offset_and_flag & 0xFFF8) as usize;
+                    let last = offset_and_flag & 0x0001 == 0;
+                    payload = self.reassemble(FragmentKey { source, destination, identification }, time, offset, &payload[8..], last)?;
+                }
+                _ => { return None; }
+            }
+        }
+    }
+
+    /// Adds a fragment received at `time`, returning the reassembled payload when the packet is complete.
+    /// Packets that timed out are discarded first, and the oldest packet when the maximum number of packets is reached.
+    fn reassemble(&mut self, key: FragmentKey, time: Duration, offset: usize, data: &[u8], last: bool) -> Option<Vec<u8>> {
+        self.fragments.retain(|_, buffer| time.saturating_sub(buffer.first_seen) <= FRAGMENT_TIMEOUT);
+        if !self.fragments.contains_key(&key) && self.fragments.len() >= MAX_FRAGMENTED_PACKETS {
+            let oldest = self.fragments.iter()
+                .min_by_key(|(_, buffer)| buffer.first_seen)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.fragments.remove(&oldest);
+            }
+        }
+
+        let payload = self.fragments.entry(key.clone())
+            .or_insert_with(|| FragmentBuffer::new(time))
+            .add(offset, data, last)?;
+        self.fragments.remove(&key);
+        Some(payload)
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = Result<CapturedDatagram, DisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_datagram() {
+            Ok(Some(datagram)) => { Some(Ok(datagram)) }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Iterator over the PDUs in a capture, created using `PcapReader::into_pdus`.
+/// A datagram that cannot be parsed, such as non-DIS UDP traffic, results in a single error; reading continues with the next datagram.
+pub struct CapturedPdus<R: Read> {
+    reader: PcapReader<R>,
+    pending: VecDeque<CapturedPdu>,
+}
+
+impl<R: Read> Iterator for CapturedPdus<R> {
+    type Item = Result<CapturedPdu, DisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pdu) = self.pending.pop_front() {
+                return Some(Ok(pdu));
+            }
+            let datagram = match self.reader.next()? {
+                Ok(datagram) => { datagram }
+                Err(err) => { return Some(Err(err)); }
+            };
+            match datagram.pdus() {
+                Ok(pdus) => {
+                    self.pending.extend(pdus.into_iter().map(|pdu| CapturedPdu {
+                        time: datagram.time,
+                        source: datagram.source,
+                        destination: datagram.destination,
+                        pdu,
+                    }));
+                }
+                Err(err) => { return Some(Err(err)); }
+            }
+        }
+    }
+}
+
+/// Writes UDP datagrams to a pcap capture file (microsecond resolution, Ethernet link type), which can be opened with Wireshark.
+///
+/// Each datagram is written as a single, unfragmented IPv4 or IPv6 packet, with zeroed MAC addresses.
+pub struct PcapWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> PcapWriter<W> {
+    /// Creates a writer, writing the file header of the capture.
+    pub fn new(mut writer: W) -> Result<Self, DisError> {
+        let mut header = Vec::with_capacity(PCAP_HEADER_LENGTH);
+        header.extend_from_slice(&PCAP_MAGIC_MICROSECONDS.to_le_bytes());
+        header.extend_from_slice(&2u16.to_le_bytes()); // version major
+        header.extend_from_slice(&4u16.to_le_bytes()); // version minor
+        header.extend_from_slice(&0i32.to_le_bytes()); // time zone offset
+        header.extend_from_slice(&0u32.to_le_bytes()); // timestamp accuracy
+        header.extend_from_slice(&PCAP_SNAPSHOT_LENGTH.to_le_bytes());
+        header.extend_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+        writer.write_all(&header)?;
+
+        Ok(Self { writer })
+    }
+
+    /// Writes a single datagram. The source and destination must both be IPv4 or both be IPv6 addresses.
+    pub fn write_datagram(&mut self, datagram: &CapturedDatagram) -> Result<(), DisError> {
+        if datagram.payload.len() > MAX_UDP_PAYLOAD_OCTETS {
+            return Err(DisError::ExceedsMtu(datagram.payload.len(), MAX_UDP_PAYLOAD_OCTETS));
+        }
+        let frame = ethernet_frame(datagram.source, datagram.destination, &datagram.payload)?;
+
+        let mut record = Vec::with_capacity(PCAP_RECORD_HEADER_LENGTH + frame.len());
+        record.extend_from_slice(&(datagram.time.as_secs() as u32).to_le_bytes());
+        record.extend_from_slice(&datagram.time.subsec_micros().to_le_bytes());
+        record.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        record.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        record.extend_from_slice(&frame);
+        self.writer.write_all(&record)?;
+        Ok(())
+    }
+
+    /// Serializes a PDU and writes it as a single datagram.
+    /// The PDU must be complete, including the PDU Length in the header (see `TrySerialize`).
+    pub fn write_pdu(&mut self, time: Duration, source: SocketAddr, destination: SocketAddr, pdu: &Pdu) -> Result<(), DisError> {
+        let mut payload = vec![0u8; pdu.header.pdu_length as usize];
+        pdu.try_serialize_to_slice(&mut payload)?;
+        self.write_datagram(&CapturedDatagram::new(time, source, destination, payload))
+    }
+
+    pub fn flush(&mut self) -> Result<(), DisError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads the remainder of a pcapng Section Header Block, after the block type, returning the byte order of the section.
+fn read_section_header<R: Read>(reader: &mut R) -> Result<ByteOrder, DisError> {
+    let mut fields = [0u8; 8];
+    reader.read_exact(&mut fields)?;
+    let byte_order = if u32::from_be_bytes([fields[4], fields[5], fields[6], fields[7]]) == PCAPNG_BYTE_ORDER_MAGIC {
+        ByteOrder::Big
+    } else if u32::from_le_bytes([fields[4], fields[5], fields[6], fields[7]]) == PCAPNG_BYTE_ORDER_MAGIC {
+        ByteOrder::Little
+    } else {
+        return Err(DisError::CaptureError("Invalid pcapng byte-order magic".to_string()));
+    };
+
+    let block_length = byte_order.u32(&fields[0..4]) as usize;
+    if block_length < 28 || block_length % 4 != 0 {
+        return Err(DisError::CaptureError(format!("Invalid pcapng block length {}", block_length)));
+    }
+    read_record(reader, block_length - 12)?;
+    Ok(byte_order)
+}
+
+/// Determines the timestamp resolution of a pcapng interface from the options of its Interface Description Block.
+fn interface_resolution(byte_order: ByteOrder, mut options: &[u8]) -> u64 {
+    while options.len() >= 4 {
+        let code = byte_order.u16(&options[0..2]);
+        let length = byte_order.u16(&options[2..4]) as usize;
+        if code == PCAPNG_OPTION_END {
+            break;
+        }
+        if code == PCAPNG_OPTION_IF_TSRESOL && length >= 1 && options.len() > 4 {
+            let resolution = options[4];
+            let exponent = (resolution & 0x7F) as u32;
+            return if resolution & 0x80 == 0 {
+                10u64.checked_pow(exponent).unwrap_or(MICROSECONDS_PER_SECOND)
+            } else {
+                2u64.checked_pow(exponent).unwrap_or(MICROSECONDS_PER_SECOND)
+            };
+        }
+        let padded_length = 4 + length.next_multiple_of(4);
+        options = options.get(padded_length..).unwrap_or(&[]);
+    }
+    MICROSECONDS_PER_SECOND
+}
+
+fn ticks_to_duration(ticks: u64, units_per_second: u64) -> Duration {
+    let seconds = ticks / units_per_second;
+    let nanoseconds = ((ticks % units_per_second) as u128 * NANOSECONDS_PER_SECOND as u128) / units_per_second as u128;
+    Duration::new(seconds, nanoseconds as u32)
+}
+
+/// Fills the buffer, returning `false` when the reader is at the end of the input before reading anything.
+fn read_exact_or_eof<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<bool, DisError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => { return Ok(false); }
+            Ok(0) => { return Err(DisError::CaptureError("Capture file ends in the middle of a record".to_string())); }
+            Ok(read) => { filled += read; }
+            Err(err) if err.kind() == ErrorKind::Interrupted => { }
+            Err(err) => { return Err(err.into()); }
+        }
+    }
+    Ok(true)
+}
+
+fn read_record<R: Read>(reader: &mut R, length: usize) -> Result<Vec<u8>, DisError> {
+    if length > MAX_RECORD_LENGTH {
+        return Err(DisError::CaptureError(format!("Record length {} is too large", length)));
+    }
+    let mut record = vec![0u8; length];
+    if length > 0 && !read_exact_or_eof(reader, &mut record)? {
+        return Err(DisError::CaptureError("Capture file ends in the middle of a record".to_string()));
+    }
+    Ok(record)
+}
+
+/// Returns the IP packet in an Ethernet frame, skipping VLAN tags.
+fn ethernet_payload(frame: &[u8]) -> Option<&[u8]> {
+    let mut offset = ETHERNET_HEADER_LENGTH - 2;
+    loop {
+        let ether_type = u16::from_be_bytes([*frame.get(offset)?, *frame.get(offset + 1)?]);
+        match ether_type {
+            ETHERTYPE_VLAN | ETHERTYPE_QINQ => { offset += VLAN_TAG_LENGTH; }
+            ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => { return frame.get(offset + 2..); }
+            _ => { return None; }
+        }
+    }
+}
+
+fn udp_datagram(time: Duration, source: IpAddr, destination: IpAddr, segment: &[u8]) -> Option<CapturedDatagram> {
+    let header = segment.get(..UDP_HEADER_LENGTH)?;
+    let source_port = u16::from_be_bytes([header[0], header[1]]);
+    let destination_port = u16::from_be_bytes([header[2], header[3]]);
+    let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+    if length < UDP_HEADER_LENGTH || length > segment.len() {
+        return None;
+    }
+
+    Some(CapturedDatagram::new(
+        time,
+        SocketAddr::new(source, source_port),
+        SocketAddr::new(destination, destination_port),
+        segment[UDP_HEADER_LENGTH..length].to_vec()))
+}
+
+fn ethernet_frame(source: SocketAddr, destination: SocketAddr, payload: &[u8]) -> Result<Vec<u8>, DisError> {
+    let segment = udp_segment(source, destination, payload);
+    let mut frame = vec![0u8; ETHERNET_HEADER_LENGTH - 2];
+    match (source.ip(), destination.ip()) {
+        (IpAddr::V4(source_ip), IpAddr::V4(destination_ip)) => {
+            frame.extend_from_slice(&ETHERTYPE_IPV4.to_be_bytes());
+            frame.extend_from_slice(&ipv4_header(source_ip, destination_ip, 0, IPV4_DONT_FRAGMENT, segment.len()));
+        }
+        (IpAddr::V6(source_ip), IpAddr::V6(destination_ip)) => {
+            frame.extend_from_slice(&ETHERTYPE_IPV6.to_be_bytes());
+            frame.extend_from_slice(&ipv6_header(source_ip, destination_ip, IP_PROTOCOL_UDP, segment.len()));
+        }
+        _ => { return Err(DisError::CaptureError("Source and destination address are of different IP versions".to_string())); }
+    }
+    frame.extend_from_slice(&segment);
+    Ok(frame)
+}
+
+fn ipv4_header(source: Ipv4Addr, destination: Ipv4Addr, identification: u16, flags_and_offset: u16, payload_length: usize) -> [u8; IPV4_HEADER_LENGTH] {
+    let mut header = [0u8; IPV4_HEADER_LENGTH];
+    header[0] = 0x45; // version 4, header length of five 32-bit words
+    header[2..4].copy_from_slice(&((IPV4_HEADER_LENGTH + payload_length) as u16).to_be_bytes());
+    header[4..6].copy_from_slice(&identification.to_be_bytes());
+    header[6..8].copy_from_slice(&flags_and_offset.to_be_bytes());
+    header[8] = DEFAULT_TTL;
+    header[9] = IP_PROTOCOL_UDP;
+    header[12..16].copy_from_slice(&source.octets());
+    header[16..20].copy_from_slice(&destination.octets());
+    let checksum = !ones_complement_sum(0, &header);
+    header[10..12].copy_from_slice(&checksum.to_be_bytes());
+    header
+}
+
+fn ipv6_header(source: Ipv6Addr, destination: Ipv6Addr, next_header: u8, payload_length: usize) -> [u8; IPV6_HEADER_LENGTH] {
+    let mut header = [0u8; IPV6_HEADER_LENGTH];
+    header[0] = 0x60; // version 6
+    header[4..6].copy_from_slice(&(payload_length as u16).to_be_bytes());
+    header[6] = next_header;
+    header[7] = DEFAULT_TTL;
+    header[8..24].copy_from_slice(&source.octets());
+    header[24..40].copy_from_slice(&destination.octets());
+    header
+}
+
+/// Builds a UDP header and payload, including the checksum over the IPv4 or IPv6 pseudo-header.
+fn udp_segment(source: SocketAddr, destination: SocketAddr, payload: &[u8]) -> Vec<u8> {
+    let length = (UDP_HEADER_LENGTH + payload.len()) as u16;
+    let mut segment = Vec::with_capacity(length as usize);
+    segment.extend_from_slice(&source.port().to_be_bytes());
+    segment.extend_from_slice(&destination.port().to_be_bytes());
+    segment.extend_from_slice(&length.to_be_bytes());
+    segment.extend_from_slice(&[0, 0]);
+    segment.extend_from_slice(payload);
+
+    let address_sum = match (source.ip(), destination.ip()) {
+        (IpAddr::V4(source), IpAddr::V4(destination)) => {
+            ones_complement_sum(ones_complement_sum(0, &source.octets()), &destination.octets())
+        }
+        (source, destination) => {
+            let source = ip_octets(source);
+            let destination = ip_octets(destination);
+            ones_complement_sum(ones_complement_sum(0, &source), &destination)
+        }
+    };
+    let pseudo_header_sum = ones_complement_sum(address_sum, &[0, IP_PROTOCOL_UDP, (length >> 8) as u8, length as u8]);
+    let checksum = match !ones_complement_sum(pseudo_header_sum, &segment) {
+        0 => { 0xFFFF }
+        checksum => { checksum }
+    };
+    segment[6..8].copy_from_slice(&checksum.to_be_bytes());
+    segment
+}
+
+fn ip_octets(address: IpAddr) -> [u8; 16] {
+    match address {
+        IpAddr::V4(address) => { address.to_ipv6_mapped().octets() }
+        IpAddr::V6(address) => { address.octets() }
+    }
+}
+
+/// Adds the data as big-endian 16-bit words to the one's complement sum, as used in IP and UDP checksums.
+fn ones_complement_sum(initial: u16, data: &[u8]) -> u16 {
+    let mut sum = initial as u32;
+    for word in data.chunks(2) {
+        let high = word[0] as u32;
+        let low = word.get(1).copied().unwrap_or(0) as u32;
+        sum += (high << 8) | low;
+    }
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    sum as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::time::Duration;
+    use crate::common::TrySerialize;
+    use crate::common::bundler::Bundler;
+    use crate::common::create_entity::model::CreateEntity;
+    use crate::common::errors::DisError;
+    use crate::common::model::{EntityId, Pdu, PduHeader};
+    use crate::common::pcap::{CapturedDatagram, ipv4_header, ipv6_header, LINKTYPE_RAW, PcapReader, PcapWriter, udp_segment};
+    use crate::enumerations::PduType;
+
+    fn create_entity(request_id: u32) -> Pdu {
+        let body = CreateEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_request_id(request_id)
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::CreateEntity), body, 0)
+    }
+
+    fn address(address: &str) -> SocketAddr {
+        address.parse().unwrap()
+    }
+
+    /// Builds a little-endian pcapng file with a single interface, containing the given packets as Enhanced Packet Blocks.
+    fn pcapng(link_type: u16, if_tsresol: u8, packets: &[(u64, Vec<u8>)]) -> Vec<u8> {
+        let mut file = vec![];
+        let section_header: Vec<u8> = [0x0A0D_0D0Au32.to_le_bytes(), 28u32.to_le_bytes(), 0x1A2B_3C4Du32.to_le_bytes()].concat();
+        file.extend_from_slice(&section_header);
+        file.extend_from_slice(&[1, 0, 0, 0]); // version
+        file.extend_from_slice(&u64::MAX.to_le_bytes()); // section length unspecified
+        file.extend_from_slice(&28u32.to_le_bytes());
+
+        file.extend_from_slice(&1u32.to_le_bytes());
+        file.extend_from_slice(&32u32.to_le_bytes());
+        file.extend_from_slice(&link_type.to_le_bytes());
+        file.extend_from_slice(&[0, 0]);
+        file.extend_from_slice(&0u32.to_le_bytes());
+        file.extend_from_slice(&9u16.to_le_bytes());
+        file.extend_from_slice(&1u16.to_le_bytes());
+        file.extend_from_slice(&[if_tsresol, 0, 0, 0]);
+        file.extend_from_slice(&[0, 0, 0, 0]); // end of options
+        file.extend_from_slice(&32u32.to_le_bytes());
+
+        for (ticks, data) in packets {
+            let padded_length = data.len().next_multiple_of(4);
+            let block_length = (32 + padded_length) as u32;
+            file.extend_from_slice(&6u32.to_le_bytes());
+            file.extend_from_slice(&block_length.to_le_bytes());
+            file.extend_from_slice(&0u32.to_le_bytes());
+            file.extend_from_slice(&((ticks >> 32) as u32).to_le_bytes());
+            file.extend_from_slice(&(*ticks as u32).to_le_bytes());
+            file.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            file.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            file.extend_from_slice(data);
+            file.resize(file.len() + padded_length - data.len(), 0);
+            file.extend_from_slice(&block_length.to_le_bytes());
+        }
+        file
+    }
+
+    #[test]
+    fn write_and_read_pcap() {
+        let source = address("10.0.0.1:3000");
+        let destination = address("10.0.0.255:3000");
+        let mut writer = PcapWriter::new(Vec::new()).unwrap();
+        writer.write_pdu(Duration::new(1_700_000_000, 250_000_000), source, destination, &create_entity(1)).unwrap();
+        writer.write_pdu(Duration::new(1_700_000_001, 0), source, destination, &create_entity(2)).unwrap();
+        let file = writer.into_inner();
+        assert_eq!(&file[16..20], &262_144u32.to_le_bytes());
+
+        let pdus: Vec<_> = PcapReader::new(&file[..]).unwrap().into_pdus().map(Result::unwrap).collect();
+        assert_eq!(pdus.len(), 2);
+        assert_eq!(pdus[0].time, Duration::new(1_700_000_000, 250_000_000));
+        assert_eq!(pdus[0].source, source);
+        assert_eq!(pdus[0].destination, destination);
+        assert_eq!(pdus[0].pdu, create_entity(1));
+        assert_eq!(pdus[1].pdu, create_entity(2));
+    }
+
+    #[test]
+    fn write_and_read_ipv6_bundle() {
+        let source = address("[fe80::1]:3000");
+        let destination = address("[ff02::1]:3001");
+        let mut bundler = Bundler::default();
+        bundler.add(&create_entity(1), 0.0).unwrap();
+        bundler.add(&create_entity(2), 0.0).unwrap();
+        let datagram = CapturedDatagram::new(Duration::from_secs(10), source, destination, bundler.flush().unwrap());
+
+        let mut writer = PcapWriter::new(Vec::new()).unwrap();
+        writer.write_datagram(&datagram).unwrap();
+        let file = writer.into_inner();
+
+        let mut reader = PcapReader::new(&file[..]).unwrap();
+        assert_eq!(reader.next_datagram().unwrap(), Some(datagram));
+        assert_eq!(reader.next_datagram().unwrap(), None);
+
+        let pdus: Vec<_> = PcapReader::new(&file[..]).unwrap().into_pdus().map(|pdu| pdu.unwrap().pdu).collect();
+        assert_eq!(pdus, vec![create_entity(1), create_entity(2)]);
+    }
+
+    #[test]
+    fn mixed_ip_versions_cannot_be_written() {
+        let mut writer = PcapWriter::new(Vec::new()).unwrap();
+        let datagram = CapturedDatagram::new(Duration::ZERO, address("10.0.0.1:3000"), address("[ff02::1]:3000"), vec![]);
+        assert!(matches!(writer.write_datagram(&datagram), Err(DisError::CaptureError(_))));
+    }
+
+    #[test]
+    fn read_pcapng_with_fragmented_ipv4() {
+        let source = Ipv4Addr::new(192, 168, 1, 10);
+        let destination = Ipv4Addr::new(192, 168, 1, 20);
+        let mut payload = vec![0u8; 28];
+        create_entity(7).try_serialize_to_slice(&mut payload).unwrap();
+        let segment = udp_segment(SocketAddr::new(source.into(), 3000), SocketAddr::new(destination.into(), 3000), &payload);
+
+        // fragments of 24 and 12 octets, received out of order
+        let first = [&ipv4_header(source, destination, 42, 0x2000, 24)[..], &segment[..24]].concat();
+        let second = [&ipv4_header(source, destination, 42, 3, 12)[..], &segment[24..]].concat();
+        let file = pcapng(LINKTYPE_RAW as u16, 9, &[(5_000_000_000, second), (5_500_000_000, first)]);
+
+        let pdus: Vec<_> = PcapReader::new(&file[..]).unwrap().into_pdus().map(Result::unwrap).collect();
+        assert_eq!(pdus.len(), 1);
+        assert_eq!(pdus[0].time, Duration::from_millis(5_500));
+        assert_eq!(pdus[0].source, SocketAddr::new(source.into(), 3000));
+        assert_eq!(pdus[0].pdu, create_entity(7));
+    }
+
+    #[test]
+    fn discard_incomplete_fragmented_packets() {
+        let source = Ipv4Addr::new(192, 168, 1, 10);
+        let destination = Ipv4Addr::new(192, 168, 1, 20);
+        let mut payload = vec![0u8; 28];
+        create_entity(7).try_serialize_to_slice(&mut payload).unwrap();
+        let segment = udp_segment(SocketAddr::new(source.into(), 3000), SocketAddr::new(destination.into(), 3000), &payload);
+        let first = |id: u16| [&ipv4_header(source, destination, id, 0x2000, 24)[..], &segment[..24]].concat();
+        let second = |id: u16| [&ipv4_header(source, destination, id, 3, 12)[..], &segment[24..]].concat();
+        let millis = |millis: u64| millis * 1_000_000;
+
+        // packet 1 times out, and packet 2 is the oldest when packet 66 exceeds the maximum of 64 packets
+        let mut packets = vec![(0, first(1))];
+        packets.extend((2..=66).map(|id| (millis(31_000 + id as u64), first(id))));
+        packets.push((millis(31_100), second(66)));
+        let file = pcapng(LINKTYPE_RAW as u16, 9, &packets);
+
+        let mut reader = PcapReader::new(&file[..]).unwrap();
+        let datagram = reader.next_datagram().unwrap().unwrap();
+        assert_eq!(datagram.payload, payload);
+        assert_eq!(reader.next_datagram().unwrap(), None);
+
+        let identifications: Vec<u32> = reader.fragments.keys().map(|key| key.identification).collect();
+        assert_eq!(identifications.len(), 63);
+        assert!(!identifications.contains(&1));
+        assert!(!identifications.contains(&2));
+    }
+
+    #[test]
+    fn read_pcapng_with_fragmented_ipv6() {
+        let source: Ipv6Addr = "fe80::1".parse().unwrap();
+        let destination: Ipv6Addr = "fe80::2".parse().unwrap();
+        let payload = vec![1u8; 40];
+        let segment = udp_segment(SocketAddr::new(source.into(), 4000), SocketAddr::new(destination.into(), 4001), &payload);
+
+        let fragment = |offset: usize, more: bool, data: &[u8]| {
+            let fragment_header = [&[17u8, 0][..], &((offset as u16) | more as u16).to_be_bytes(), &7u32.to_be_bytes()].concat();
+            let mut frame = vec![0u8; 12];
+            frame.extend_from_slice(&0x86DDu16.to_be_bytes());
+            frame.extend_from_slice(&ipv6_header(source, destination, 44, 8 + data.len()));
+            frame.extend_from_slice(&fragment_header);
+            frame.extend_from_slice(data);
+            frame
+        };
+        let file = pcapng(1, 6, &[(1, fragment(0, true, &segment[..32])), (2, fragment(32, false, &segment[32..]))]);
+
+        let datagrams: Vec<_> = PcapReader::new(&file[..]).unwrap().map(Result::unwrap).collect();
+        assert_eq!(datagrams, vec![CapturedDatagram::new(
+            Duration::from_micros(2), SocketAddr::new(source.into(), 4000), SocketAddr::new(destination.into(), 4001), payload)]);
+    }
+
+    #[test]
+    fn not_a_capture_file() {
+        assert!(matches!(PcapReader::new(&[0u8; 24][..]), Err(DisError::CaptureError(_))));
+    }
+}
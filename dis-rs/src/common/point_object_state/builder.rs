@@ -0,0 +1,79 @@
+use crate::common::point_object_state::model::PointObjectState;
+use crate::common::model::{EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation, SimulationAddress};
+use crate::enumerations::ForceId;
+
+pub struct PointObjectStateBuilder(PointObjectState);
+
+impl PointObjectStateBuilder {
+    pub fn new() -> Self {
+        PointObjectStateBuilder(PointObjectState::default())
+    }
+
+    pub fn new_from_body(body: PointObjectState) -> Self {
+        PointObjectStateBuilder(body)
+    }
+
+    pub fn build(self) -> PointObjectState {
+        self.0
+    }
+
+    pub fn with_object_id(mut self, object_id: EntityId) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    pub fn with_referenced_object_id(mut self, referenced_object_id: EntityId) -> Self {
+        self.0.referenced_object_id = referenced_object_id;
+        self
+    }
+
+    pub fn with_update_number(mut self, update_number: u16) -> Self {
+        self.0.update_number = update_number;
+        self
+    }
+
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    pub fn with_modifications(mut self, modifications: ObjectStateModification) -> Self {
+        self.0.modifications = modifications;
+        self
+    }
+
+    pub fn with_object_type(mut self, object_type: ObjectType) -> Self {
+        self.0.object_type = object_type;
+        self
+    }
+
+    pub fn with_object_location(mut self, object_location: Location) -> Self {
+        self.0.object_location = object_location;
+        self
+    }
+
+    pub fn with_object_orientation(mut self, object_orientation: Orientation) -> Self {
+        self.0.object_orientation = object_orientation;
+        self
+    }
+
+    pub fn with_specific_object_appearance(mut self, specific_object_appearance: u32) -> Self {
+        self.0.specific_object_appearance = specific_object_appearance;
+        self
+    }
+
+    pub fn with_general_object_appearance(mut self, general_object_appearance: GeneralObjectAppearance) -> Self {
+        self.0.general_object_appearance = general_object_appearance;
+        self
+    }
+
+    pub fn with_requester_id(mut self, requester_id: SimulationAddress) -> Self {
+        self.0.requester_id = requester_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: SimulationAddress) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+}
@@ -0,0 +1,58 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::point_object_state::model::PointObjectState;
+    use crate::common::model::{DisTimeStamp, EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation, Pdu, PduHeader, SimulationAddress};
+    use crate::enumerations::{ForceId, ObjectKind, PduType, PlatformDomain};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn point_object_state_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::PointObjectState);
+
+        let body = PointObjectState::builder()
+            .with_object_id(EntityId::new(1, 1, 100))
+            .with_referenced_object_id(EntityId::new(1, 1, 101))
+            .with_update_number(7)
+            .with_force_id(ForceId::Friendly)
+            .with_modifications(ObjectStateModification::default()
+                .with_location(true))
+            .with_object_type(ObjectType::new(PlatformDomain::Land, ObjectKind::from(1), 2, 3))
+            .with_object_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_object_orientation(Orientation::new(0.0, 0.5, 1.0))
+            .with_specific_object_appearance(0x0102_0304)
+            .with_general_object_appearance(GeneralObjectAppearance::default()
+                .with_percent_complete(80)
+                .with_damage(1)
+                .with_smoking(true)
+                .with_ied_present(2))
+            .with_requester_id(SimulationAddress::new(1, 1))
+            .with_receiving_id(SimulationAddress::new(2, 2))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation, PduBody, SimulationAddress};
+use crate::common::point_object_state::builder::PointObjectStateBuilder;
+use crate::enumerations::{ForceId, PduType};
+
+const POINT_OBJECT_STATE_BODY_LENGTH: u16 = 76;
+
+/// 5.11.3.2 Point Object State PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct PointObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    pub object_location: Location,
+    pub object_orientation: Orientation,
+    pub specific_object_appearance: u32,
+    pub general_object_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+}
+
+impl PointObjectState {
+    pub fn builder() -> PointObjectStateBuilder {
+        PointObjectStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> PointObjectStateBuilder {
+        PointObjectStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::PointObjectState(self)
+    }
+}
+
+impl BodyInfo for PointObjectState {
+    fn body_length(&self) -> u16 {
+        POINT_OBJECT_STATE_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::PointObjectState
+    }
+}
+
+impl Interaction for PointObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u32};
+use crate::common::entity_state::parser::force_id;
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, general_object_appearance, location, object_state_modification, object_type, orientation, simulation_address};
+use crate::common::point_object_state::model::PointObjectState;
+
+pub fn point_object_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, object_id) = context("Object ID", entity_id)(input)?;
+    let (input, referenced_object_id) = context("Referenced Object ID", entity_id)(input)?;
+    let (input, update_number) = context("Update Number", be_u16)(input)?;
+    let (input, force_id) = context("Force ID", force_id)(input)?;
+    let (input, modifications) = context("Modifications", object_state_modification)(input)?;
+    let (input, object_type) = context("Object Type", object_type)(input)?;
+    let (input, object_location) = context("Object Location", location)(input)?;
+    let (input, object_orientation) = context("Object Orientation", orientation)(input)?;
+    let (input, specific_object_appearance) = context("Specific Object Appearance", be_u32)(input)?;
+    let (input, general_object_appearance) = context("General Object Appearance", general_object_appearance)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, requester_id) = context("Requester ID", simulation_address)(input)?;
+    let (input, receiving_id) = context("Receiving ID", simulation_address)(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+
+    let body = PointObjectState::builder()
+        .with_object_id(object_id)
+        .with_referenced_object_id(referenced_object_id)
+        .with_update_number(update_number)
+        .with_force_id(force_id)
+        .with_modifications(modifications)
+        .with_object_type(object_type)
+        .with_object_location(object_location)
+        .with_object_orientation(object_orientation)
+        .with_specific_object_appearance(specific_object_appearance)
+        .with_general_object_appearance(general_object_appearance)
+        .with_requester_id(requester_id)
+        .with_receiving_id(receiving_id)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
@@ -0,0 +1,26 @@
+use bytes::BufMut;
+use crate::common::point_object_state::model::PointObjectState;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for PointObjectState {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let object_id_bytes = self.object_id.serialize(buf);
+        let referenced_object_id_bytes = self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        buf.put_u8(self.force_id.into());
+        let modifications_bytes = self.modifications.serialize(buf);
+        let object_type_bytes = self.object_type.serialize(buf);
+        let location_bytes = self.object_location.serialize(buf);
+        let orientation_bytes = self.object_orientation.serialize(buf);
+        buf.put_u32(self.specific_object_appearance);
+        let general_appearance_bytes = self.general_object_appearance.serialize(buf);
+        buf.put_u16(0u16);
+        let requester_id_bytes = self.requester_id.serialize(buf);
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        buf.put_u32(0u32);
+
+        object_id_bytes + referenced_object_id_bytes + 3 + modifications_bytes + object_type_bytes
+            + location_bytes + orientation_bytes + 4 + general_appearance_bytes + 2
+            + requester_id_bytes + receiving_id_bytes + 4
+    }
+}
@@ -0,0 +1,60 @@
+/// The true state of a local object and the state that was last issued for it, shared by the PDU publishers
+/// (`EntityStatePublisher`, `DirectedEnergyFirePublisher` and `EntityDamageStatusPublisher`).
+///
+/// The publishers own the issuance rules (thresholds and heartbeat intervals) of their PDU;
+/// this type keeps the bookkeeping of what was issued, and when.
+pub(crate) struct PublishedState<T> {
+    current: T,
+    last_issued: Option<Issued<T>>,
+}
+
+/// A state that was issued, together with the time (in seconds) it was issued.
+pub(crate) struct Issued<T> {
+    pub(crate) state: T,
+    pub(crate) time: f64,
+}
+
+impl<T: Clone> PublishedState<T> {
+    pub(crate) fn new(current: T) -> Self {
+        Self {
+            current,
+            last_issued: None,
+        }
+    }
+
+    pub(crate) fn current(&self) -> &T {
+        &self.current
+    }
+
+    pub(crate) fn current_mut(&mut self) -> &mut T {
+        &mut self.current
+    }
+
+    pub(crate) fn update(&mut self, current: T) {
+        self.current = current;
+    }
+
+    pub(crate) fn issued(&self) -> Option<&Issued<T>> {
+        self.last_issued.as_ref()
+    }
+
+    pub(crate) fn last_issued(&self) -> Option<&T> {
+        self.last_issued.as_ref().map(|issued| &issued.state)
+    }
+
+    /// Records that the current state is issued at `time`, and returns it.
+    pub(crate) fn issue(&mut self, time: f64) -> T {
+        self.last_issued = Some(Issued {
+            state: self.current.clone(),
+            time,
+        });
+        self.current.clone()
+    }
+}
+
+impl<T> Issued<T> {
+    /// Checks whether the heartbeat interval, in seconds, elapsed at `time` since this state was issued.
+    pub(crate) fn heartbeat_elapsed(&self, time: f64, heartbeat: f64) -> bool {
+        time - self.time >= heartbeat
+    }
+}
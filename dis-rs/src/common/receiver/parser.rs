@@ -1,4 +1,4 @@
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::number::complete::{be_f32, be_u16};
 use crate::common::parser::entity_id;
 use crate::common::model::PduBody;
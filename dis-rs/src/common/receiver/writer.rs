@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::receiver::model::Receiver;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 
 impl SerializePdu for Receiver {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let rx_ref_id_bytes = self.radio_reference_id.serialize(buf);
         buf.put_u16(self.radio_number);
         buf.put_u16(self.receiver_state.into());
@@ -0,0 +1,22 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::be_u16;
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::repair_complete::model::RepairComplete;
+use crate::enumerations::RepairCompleteRepair;
+
+pub fn repair_complete_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, repairing_id) = context("Repairing ID", entity_id)(input)?;
+    let (input, repair) = context("Repair", be_u16)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+
+    let body = RepairComplete::builder()
+        .with_receiving_id(receiving_id)
+        .with_repairing_id(repairing_id)
+        .with_repair(RepairCompleteRepair::from(repair))
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use bytes::BufMut;
+use crate::common::repair_complete::model::RepairComplete;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for RepairComplete {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        let repairing_id_bytes = self.repairing_id.serialize(buf);
+        buf.put_u16(self.repair.into());
+        buf.put_u16(0u16);
+
+        receiving_id_bytes + repairing_id_bytes + 4
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::repair_response::model::RepairResponse;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::enumerations::{PduType, RepairResponseRepairResult};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn repair_response_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::RepairResponse);
+
+        let body = RepairResponse::builder()
+            .with_receiving_id(EntityId::new(1, 1, 1))
+            .with_repairing_id(EntityId::new(1, 1, 2))
+            .with_repair_result(RepairResponseRepairResult::RepairEnded)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::repair_response::builder::RepairResponseBuilder;
+use crate::enumerations::{PduType, RepairResponseRepairResult};
+
+const REPAIR_RESPONSE_BODY_LENGTH: u16 = 16;
+
+/// 5.5.10 Repair Response PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct RepairResponse {
+    pub receiving_id: EntityId,
+    pub repairing_id: EntityId,
+    pub repair_result: RepairResponseRepairResult,
+}
+
+impl RepairResponse {
+    pub fn builder() -> RepairResponseBuilder {
+        RepairResponseBuilder::new()
+    }
+
+    pub fn into_builder(self) -> RepairResponseBuilder {
+        RepairResponseBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::RepairResponse(self)
+    }
+}
+
+impl BodyInfo for RepairResponse {
+    fn body_length(&self) -> u16 {
+        REPAIR_RESPONSE_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::RepairResponse
+    }
+}
+
+impl Interaction for RepairResponse {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.repairing_id)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::repair_response::model::RepairResponse;
+use crate::enumerations::RepairResponseRepairResult;
+
+pub fn repair_response_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, repairing_id) = context("Repairing ID", entity_id)(input)?;
+    let (input, repair_result) = context("Repair Result", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+
+    let body = RepairResponse::builder()
+        .with_receiving_id(receiving_id)
+        .with_repairing_id(repairing_id)
+        .with_repair_result(RepairResponseRepairResult::from(repair_result))
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::model::EntityId;
+
+pub struct ResupplyCancelBuilder(ResupplyCancel);
+
+impl ResupplyCancelBuilder {
+    pub fn new() -> Self {
+        ResupplyCancelBuilder(ResupplyCancel::default())
+    }
+
+    pub fn new_from_body(body: ResupplyCancel) -> Self {
+        ResupplyCancelBuilder(body)
+    }
+
+    pub fn build(self) -> ResupplyCancel {
+        self.0
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_supplying_id(mut self, supplying_id: EntityId) -> Self {
+        self.0.supplying_id = supplying_id;
+        self
+    }
+}
\ No newline at end of file
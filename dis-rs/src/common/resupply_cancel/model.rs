@@ -0,0 +1,50 @@
+use crate::common::model::{EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::resupply_cancel::builder::ResupplyCancelBuilder;
+use crate::enumerations::PduType;
+
+const RESUPPLY_CANCEL_BODY_LENGTH: u16 = 12;
+
+/// 5.5.8 Resupply Cancel PDU
+///
+/// The PDU can be sent by either the receiving or the supplying entity;
+/// `Interaction` reports the receiving entity as the originator.
+#[derive(Debug, Default, PartialEq)]
+pub struct ResupplyCancel {
+    pub receiving_id: EntityId,
+    pub supplying_id: EntityId,
+}
+
+impl ResupplyCancel {
+    pub fn builder() -> ResupplyCancelBuilder {
+        ResupplyCancelBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ResupplyCancelBuilder {
+        ResupplyCancelBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ResupplyCancel(self)
+    }
+}
+
+impl BodyInfo for ResupplyCancel {
+    fn body_length(&self) -> u16 {
+        RESUPPLY_CANCEL_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ResupplyCancel
+    }
+}
+
+impl Interaction for ResupplyCancel {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.supplying_id)
+    }
+}
\ No newline at end of file
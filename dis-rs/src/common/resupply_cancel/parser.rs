@@ -0,0 +1,17 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+
+pub fn resupply_cancel_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, supplying_id) = context("Supplying ID", entity_id)(input)?;
+
+    let body = ResupplyCancel::builder()
+        .with_receiving_id(receiving_id)
+        .with_supplying_id(supplying_id)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use bytes::BufMut;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ResupplyCancel {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        let supplying_id_bytes = self.supplying_id.serialize(buf);
+
+        receiving_id_bytes + supplying_id_bytes
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::common::resupply_offer::model::ResupplyOffer;
+use crate::common::model::{EntityId, SupplyQuantity};
+
+pub struct ResupplyOfferBuilder(ResupplyOffer);
+
+impl ResupplyOfferBuilder {
+    pub fn new() -> Self {
+        ResupplyOfferBuilder(ResupplyOffer::default())
+    }
+
+    pub fn new_from_body(body: ResupplyOffer) -> Self {
+        ResupplyOfferBuilder(body)
+    }
+
+    pub fn build(self) -> ResupplyOffer {
+        self.0
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_supplying_id(mut self, supplying_id: EntityId) -> Self {
+        self.0.supplying_id = supplying_id;
+        self
+    }
+
+    pub fn with_supplies(mut self, supplies: Vec<SupplyQuantity>) -> Self {
+        self.0.supplies = supplies;
+        self
+    }
+
+    pub fn with_supply(mut self, supply: SupplyQuantity) -> Self {
+        self.0.supplies.push(supply);
+        self
+    }
+}
\ No newline at end of file
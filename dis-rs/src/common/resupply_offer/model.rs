@@ -0,0 +1,48 @@
+use crate::common::model::{EntityId, PduBody, SupplyQuantity, SUPPLY_QUANTITY_RECORD_LENGTH};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::resupply_offer::builder::ResupplyOfferBuilder;
+use crate::enumerations::PduType;
+
+pub const BASE_RESUPPLY_OFFER_BODY_LENGTH: u16 = 16;
+
+/// 5.5.6 Resupply Offer PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct ResupplyOffer {
+    pub receiving_id: EntityId,
+    pub supplying_id: EntityId,
+    pub supplies: Vec<SupplyQuantity>,
+}
+
+impl ResupplyOffer {
+    pub fn builder() -> ResupplyOfferBuilder {
+        ResupplyOfferBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ResupplyOfferBuilder {
+        ResupplyOfferBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ResupplyOffer(self)
+    }
+}
+
+impl BodyInfo for ResupplyOffer {
+    fn body_length(&self) -> u16 {
+        BASE_RESUPPLY_OFFER_BODY_LENGTH + (SUPPLY_QUANTITY_RECORD_LENGTH * self.supplies.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ResupplyOffer
+    }
+}
+
+impl Interaction for ResupplyOffer {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.supplying_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+}
\ No newline at end of file
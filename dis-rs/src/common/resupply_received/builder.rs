@@ -0,0 +1,38 @@
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::model::{EntityId, SupplyQuantity};
+
+pub struct ResupplyReceivedBuilder(ResupplyReceived);
+
+impl ResupplyReceivedBuilder {
+    pub fn new() -> Self {
+        ResupplyReceivedBuilder(ResupplyReceived::default())
+    }
+
+    pub fn new_from_body(body: ResupplyReceived) -> Self {
+        ResupplyReceivedBuilder(body)
+    }
+
+    pub fn build(self) -> ResupplyReceived {
+        self.0
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_supplying_id(mut self, supplying_id: EntityId) -> Self {
+        self.0.supplying_id = supplying_id;
+        self
+    }
+
+    pub fn with_supplies(mut self, supplies: Vec<SupplyQuantity>) -> Self {
+        self.0.supplies = supplies;
+        self
+    }
+
+    pub fn with_supply(mut self, supply: SupplyQuantity) -> Self {
+        self.0.supplies.push(supply);
+        self
+    }
+}
\ No newline at end of file
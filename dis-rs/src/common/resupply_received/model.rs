@@ -0,0 +1,48 @@
+use crate::common::model::{EntityId, PduBody, SupplyQuantity, SUPPLY_QUANTITY_RECORD_LENGTH};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::resupply_received::builder::ResupplyReceivedBuilder;
+use crate::enumerations::PduType;
+
+pub const BASE_RESUPPLY_RECEIVED_BODY_LENGTH: u16 = 16;
+
+/// 5.5.7 Resupply Received PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct ResupplyReceived {
+    pub receiving_id: EntityId,
+    pub supplying_id: EntityId,
+    pub supplies: Vec<SupplyQuantity>,
+}
+
+impl ResupplyReceived {
+    pub fn builder() -> ResupplyReceivedBuilder {
+        ResupplyReceivedBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ResupplyReceivedBuilder {
+        ResupplyReceivedBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ResupplyReceived(self)
+    }
+}
+
+impl BodyInfo for ResupplyReceived {
+    fn body_length(&self) -> u16 {
+        BASE_RESUPPLY_RECEIVED_BODY_LENGTH + (SUPPLY_QUANTITY_RECORD_LENGTH * self.supplies.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ResupplyReceived
+    }
+}
+
+impl Interaction for ResupplyReceived {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.supplying_id)
+    }
+}
\ No newline at end of file
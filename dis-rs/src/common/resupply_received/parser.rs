@@ -0,0 +1,24 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, supply_quantity};
+use crate::common::resupply_received::model::ResupplyReceived;
+
+pub fn resupply_received_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, supplying_id) = context("Supplying ID", entity_id)(input)?;
+    let (input, number_of_supply_types) = context("Number of Supply Types", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, supplies) = context("Supplies", count(supply_quantity, number_of_supply_types as usize))(input)?;
+
+    let body = ResupplyReceived::builder()
+        .with_receiving_id(receiving_id)
+        .with_supplying_id(supplying_id)
+        .with_supplies(supplies)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
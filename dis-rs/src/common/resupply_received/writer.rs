@@ -0,0 +1,18 @@
+use bytes::BufMut;
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ResupplyReceived {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let receiving_id_bytes = self.receiving_id.serialize(buf);
+        let supplying_id_bytes = self.supplying_id.serialize(buf);
+        buf.put_u8(self.supplies.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        let supplies_bytes = self.supplies.iter()
+            .map(|supply| supply.serialize(buf))
+            .sum::<u16>();
+
+        receiving_id_bytes + supplying_id_bytes + 4 + supplies_bytes
+    }
+}
\ No newline at end of file
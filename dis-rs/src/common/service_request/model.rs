@@ -0,0 +1,49 @@
+use crate::common::model::{EntityId, PduBody, SupplyQuantity, SUPPLY_QUANTITY_RECORD_LENGTH};
+use crate::common::{BodyInfo, Interaction};
+use crate::common::service_request::builder::ServiceRequestBuilder;
+use crate::enumerations::{PduType, ServiceRequestServiceTypeRequested};
+
+pub const BASE_SERVICE_REQUEST_BODY_LENGTH: u16 = 16;
+
+/// 5.5.5 Service Request PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct ServiceRequest {
+    pub requesting_id: EntityId,
+    pub servicing_id: EntityId,
+    pub service_type_requested: ServiceRequestServiceTypeRequested,
+    pub supplies: Vec<SupplyQuantity>,
+}
+
+impl ServiceRequest {
+    pub fn builder() -> ServiceRequestBuilder {
+        ServiceRequestBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ServiceRequestBuilder {
+        ServiceRequestBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ServiceRequest(self)
+    }
+}
+
+impl BodyInfo for ServiceRequest {
+    fn body_length(&self) -> u16 {
+        BASE_SERVICE_REQUEST_BODY_LENGTH + (SUPPLY_QUANTITY_RECORD_LENGTH * self.supplies.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ServiceRequest
+    }
+}
+
+impl Interaction for ServiceRequest {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.servicing_id)
+    }
+}
\ No newline at end of file
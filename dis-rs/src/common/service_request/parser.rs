@@ -0,0 +1,26 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u8};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, supply_quantity};
+use crate::common::service_request::model::ServiceRequest;
+use crate::enumerations::ServiceRequestServiceTypeRequested;
+
+pub fn service_request_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, requesting_id) = context("Requesting ID", entity_id)(input)?;
+    let (input, servicing_id) = context("Servicing ID", entity_id)(input)?;
+    let (input, service_type_requested) = context("Service Type Requested", be_u8)(input)?;
+    let (input, number_of_supply_types) = context("Number of Supply Types", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, supplies) = context("Supplies", count(supply_quantity, number_of_supply_types as usize))(input)?;
+
+    let body = ServiceRequest::builder()
+        .with_requesting_id(requesting_id)
+        .with_servicing_id(servicing_id)
+        .with_service_type_requested(ServiceRequestServiceTypeRequested::from(service_type_requested))
+        .with_supplies(supplies)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use bytes::BufMut;
+use crate::common::service_request::model::ServiceRequest;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ServiceRequest {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let requesting_id_bytes = self.requesting_id.serialize(buf);
+        let servicing_id_bytes = self.servicing_id.serialize(buf);
+        buf.put_u8(self.service_type_requested.into());
+        buf.put_u8(self.supplies.len() as u8);
+        buf.put_u16(0u16);
+        let supplies_bytes = self.supplies.iter()
+            .map(|supply| supply.serialize(buf))
+            .sum::<u16>();
+
+        requesting_id_bytes + servicing_id_bytes + 4 + supplies_bytes
+    }
+}
\ No newline at end of file
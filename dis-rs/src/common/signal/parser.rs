@@ -1,4 +1,4 @@
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::number::complete::{be_u16, be_u32};
 use crate::common::model::PduBody;
 use crate::common::parser::entity_id;
@@ -32,7 +32,7 @@ pub fn signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     Ok((input, body.into_pdu_body()))
 }
 
-fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
+pub(crate) fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
     let encoding_class = (encoding_scheme_bytes & 0xC000) >> 14;
     let low_bits = encoding_scheme_bytes & 0x3FFF;
     let encoding_class = SignalEncodingClass::from(encoding_class);
@@ -0,0 +1,109 @@
+use crate::common::errors::DisError;
+use crate::common::model::EntityId;
+use crate::common::parser::entity_id;
+use crate::common::signal::model::{BASE_SIGNAL_BODY_LENGTH, EncodingScheme};
+use crate::common::signal::parser::parse_encoding_scheme;
+use crate::common::view::{check_body_length, decode, read_u16, read_u32};
+use crate::constants::ONE_BYTE_IN_BITS;
+use crate::enumerations::SignalTdlType;
+
+const RADIO_REFERENCE_ID_OFFSET: usize = 0;
+const RADIO_NUMBER_OFFSET: usize = 6;
+const ENCODING_SCHEME_OFFSET: usize = 8;
+const TDL_TYPE_OFFSET: usize = 10;
+const SAMPLE_RATE_OFFSET: usize = 12;
+const DATA_LENGTH_OFFSET: usize = 16;
+const SAMPLES_OFFSET: usize = 18;
+const DATA_OFFSET: usize = 20;
+
+/// A borrowed view over the body of a Signal PDU (5.8.4), decoding fields when they are accessed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SignalView<'a> {
+    body: &'a [u8],
+}
+
+impl<'a> SignalView<'a> {
+    /// Creates a view over a Signal body, checking that the body holds the fixed fields and the signal data.
+    pub fn new(body: &'a [u8]) -> Result<Self, DisError> {
+        check_body_length(body, BASE_SIGNAL_BODY_LENGTH as usize)?;
+        let data_length_bytes = read_u16(body, DATA_LENGTH_OFFSET) as usize / ONE_BYTE_IN_BITS;
+        check_body_length(body, DATA_OFFSET + data_length_bytes)?;
+
+        Ok(Self { body })
+    }
+
+    pub fn radio_reference_id(&self) -> EntityId {
+        decode(self.body, RADIO_REFERENCE_ID_OFFSET, entity_id)
+    }
+
+    pub fn radio_number(&self) -> u16 {
+        read_u16(self.body, RADIO_NUMBER_OFFSET)
+    }
+
+    pub fn encoding_scheme(&self) -> EncodingScheme {
+        parse_encoding_scheme(read_u16(self.body, ENCODING_SCHEME_OFFSET), self.data())
+    }
+
+    pub fn tdl_type(&self) -> SignalTdlType {
+        SignalTdlType::from(read_u16(self.body, TDL_TYPE_OFFSET))
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        read_u32(self.body, SAMPLE_RATE_OFFSET)
+    }
+
+    /// The length of the signal data in bits.
+    pub fn data_length(&self) -> u16 {
+        read_u16(self.body, DATA_LENGTH_OFFSET)
+    }
+
+    pub fn samples(&self) -> u16 {
+        read_u16(self.body, SAMPLES_OFFSET)
+    }
+
+    /// The signal data, without padding.
+    pub fn data(&self) -> &'a [u8] {
+        let data_length_bytes = self.data_length() as usize / ONE_BYTE_IN_BITS;
+        &self.body[DATA_OFFSET..DATA_OFFSET + data_length_bytes]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::model::{EntityId, Pdu, PduBody, PduHeader};
+    use crate::common::Serialize;
+    use crate::common::signal::model::{EncodingScheme, Signal};
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{PduType, SignalEncodingClass, SignalEncodingType};
+
+    #[test]
+    fn signal_view_matches_parsed() {
+        let body = Signal::new()
+            .with_radio_reference_id(EntityId::new(1, 2, 3))
+            .with_radio_number(4)
+            .with_encoding_scheme(EncodingScheme::EncodedAudio {
+                encoding_class: SignalEncodingClass::Encodedaudio,
+                encoding_type: SignalEncodingType::_8bitmulaw_ITUTG_711_1 })
+            .with_sample_rate(8000)
+            .with_samples(5)
+            .with_data(vec![1, 2, 3, 4, 5])
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::Signal), body, 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+
+        let pdu_view = PduView::new(&buf).unwrap();
+        let view = if let Ok(BodyView::Signal(view)) = pdu_view.body_view() { view } else { panic!("Expected a Signal view") };
+        let signal = if let PduBody::Signal(signal) = pdu_view.to_pdu().unwrap().body { signal } else { panic!() };
+
+        assert_eq!(view.radio_reference_id(), signal.radio_reference_id);
+        assert_eq!(view.radio_number(), signal.radio_number);
+        assert_eq!(view.encoding_scheme(), signal.encoding_scheme);
+        assert_eq!(view.tdl_type(), signal.tdl_type);
+        assert_eq!(view.sample_rate(), signal.sample_rate);
+        assert_eq!(view.samples(), signal.samples);
+        assert_eq!(view.data_length(), 40);
+        assert_eq!(view.data(), &signal.data[..]);
+    }
+}
@@ -1,11 +1,11 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::common::signal::model::{EncodingScheme, Signal};
 use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
 use crate::common::model::length_padded_to_num_bytes;
 
 impl SerializePdu for Signal {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         let radio_ref_id_bytes = self.radio_reference_id.serialize(buf);
         buf.put_u16(self.radio_number);
         let encoding_scheme_bytes = self.encoding_scheme.serialize(buf);
@@ -24,17 +24,17 @@ impl SerializePdu for Signal {
 }
 
 impl Serialize for EncodingScheme {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         match self {
             EncodingScheme::EncodedAudio { encoding_class, encoding_type } => {
                 let class_bits = u16::from(*encoding_class) << 14;
                 let type_bits = u16::from(*encoding_type);
-                buf.put_u16(class_bits & type_bits);
+                buf.put_u16(class_bits | type_bits);
                 2
             }
             EncodingScheme::RawBinaryData { encoding_class, nr_of_messages } => {
                 let class_bits = u16::from(*encoding_class) << 14;
-                buf.put_u16(class_bits & *nr_of_messages);
+                buf.put_u16(class_bits | *nr_of_messages);
                 2
             }
             EncodingScheme::ApplicationSpecificData { encoding_class, .. } => {
@@ -49,4 +49,34 @@ impl Serialize for EncodingScheme {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::Serialize;
+    use crate::common::signal::model::EncodingScheme;
+    use crate::common::signal::parser::parse_encoding_scheme;
+    use crate::enumerations::{SignalEncodingClass, SignalEncodingType};
+
+    #[test]
+    fn encoding_scheme_raw_binary_data() {
+        let encoding_scheme = EncodingScheme::RawBinaryData { encoding_class: SignalEncodingClass::RawBinaryData, nr_of_messages: 3 };
+        let mut buf = BytesMut::new();
+
+        assert_eq!(encoding_scheme.serialize(&mut buf), 2);
+        assert_eq!(buf.as_ref(), &[0x40, 0x03]);
+        assert_eq!(parse_encoding_scheme(u16::from_be_bytes([buf[0], buf[1]]), &[]), encoding_scheme);
+    }
+
+    #[test]
+    fn encoding_scheme_encoded_audio() {
+        let encoding_scheme = EncodingScheme::EncodedAudio {
+            encoding_class: SignalEncodingClass::Encodedaudio,
+            encoding_type: SignalEncodingType::_8bitmulaw_ITUTG_711_1 };
+        let mut buf = BytesMut::new();
+
+        encoding_scheme.serialize(&mut buf);
+        assert_eq!(parse_encoding_scheme(u16::from_be_bytes([buf[0], buf[1]]), &[]), encoding_scheme);
+    }
+}
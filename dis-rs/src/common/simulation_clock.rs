@@ -0,0 +1,302 @@
+use crate::common::entity_table::matches_entity_id;
+use crate::common::model::{ClockTime, EntityId, Pdu, PduBody};
+use crate::enumerations::{StopFreezeFrozenBehavior, StopFreezeReason};
+
+/// The state of a simulation in an exercise, as controlled by StartResume and StopFreeze PDUs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SimulationState {
+    /// The simulation is not started yet, or is stopped because the exercise terminated
+    Stopped,
+    /// The simulation is running
+    Running,
+    /// The simulation is frozen, and can be resumed
+    Frozen,
+}
+
+/// Events emitted by the `SimulationClock` when the state of the simulation changes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SimulationEvent {
+    /// The simulation started or resumed, with the simulation clock at the given simulation time (in seconds)
+    Started { simulation_time: f64 },
+    /// The simulation is frozen for the given reason
+    Frozen { reason: StopFreezeReason },
+    /// The simulation stopped, because the exercise terminated
+    Stopped { reason: StopFreezeReason },
+}
+
+/// A start or stop that takes effect at a real-world time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Transition {
+    Start { simulation_time: f64 },
+    Stop { reason: StopFreezeReason, frozen_behavior: StopFreezeFrozenBehavior },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct ScheduledTransition {
+    time: f64,
+    transition: Transition,
+}
+
+/// Tracks the state and simulation time of a simulation, by applying received StartResume and StopFreeze PDUs
+/// (and their reliable -R variants) that are addressed to the simulation.
+///
+/// Each PDU takes effect at the real-world time it states; PDUs with a real-world time in the past take effect immediately.
+/// A StartResume sets the simulation clock to the stated simulation time and runs the simulation.
+/// A StopFreeze with reason Termination stops the simulation; any other reason freezes it.
+/// While frozen, the frozen behavior of the StopFreeze PDU determines whether the simulation clock keeps running,
+/// and whether the simulation transmits and processes updates.
+///
+/// All times are in seconds. Real-world time is counted from 0000 hours January 1, 1970 UTC,
+/// as used by the real-world time `ClockTime` of the PDUs.
+pub struct SimulationClock {
+    simulation_id: EntityId,
+    state: SimulationState,
+    frozen_behavior: StopFreezeFrozenBehavior,
+    reference_time: f64,
+    reference_simulation_time: f64,
+    scheduled: Vec<ScheduledTransition>,
+}
+
+impl SimulationClock {
+    /// Creates a clock for the simulation with the given id, in the Stopped state with the simulation time at zero.
+    pub fn new(simulation_id: EntityId) -> Self {
+        Self {
+            simulation_id,
+            state: SimulationState::Stopped,
+            frozen_behavior: StopFreezeFrozenBehavior::from(0u8),
+            reference_time: 0.0,
+            reference_simulation_time: 0.0,
+            scheduled: vec![],
+        }
+    }
+
+    /// Processes a received PDU at real-world `time`, returning the resulting events.
+    /// StartResume and StopFreeze PDUs that are not addressed to the simulation, and other PDUs, are ignored.
+    pub fn process(&mut self, pdu: &Pdu, time: f64) -> Vec<SimulationEvent> {
+        let (receiving_id, scheduled) = match &pdu.body {
+            PduBody::StartResume(body) => { (body.receiving_id, start(&body.real_world_time, &body.simulation_time)) }
+            PduBody::StartResumeR(body) => { (body.start_resume.receiving_id, start(&body.start_resume.real_world_time, &body.start_resume.simulation_time)) }
+            PduBody::StopFreeze(body) => { (body.receiving_id, stop(&body.real_world_time, body.reason, body.frozen_behavior)) }
+            PduBody::StopFreezeR(body) => { (body.stop_freeze.receiving_id, stop(&body.stop_freeze.real_world_time, body.stop_freeze.reason, body.stop_freeze.frozen_behavior)) }
+            _ => { return vec![] }
+        };
+
+        if !matches_entity_id(&receiving_id, &self.simulation_id) {
+            return vec![];
+        }
+
+        // keep the schedule ordered by time; transitions for the same time are applied in order of arrival
+        let index = self.scheduled.partition_point(|existing| existing.time <= scheduled.time);
+        self.scheduled.insert(index, scheduled);
+        self.update(time)
+    }
+
+    /// Applies the scheduled transitions that are due at real-world `time`, returning the resulting events.
+    pub fn update(&mut self, time: f64) -> Vec<SimulationEvent> {
+        let due = self.scheduled.partition_point(|scheduled| scheduled.time <= time);
+        let due: Vec<ScheduledTransition> = self.scheduled.drain(..due).collect();
+
+        due.into_iter()
+            .map(|scheduled| self.apply(scheduled))
+            .collect()
+    }
+
+    /// The simulation time at real-world `time`.
+    /// Only valid for times at or after the last applied transition; call `update` first to apply due transitions.
+    pub fn simulation_time(&self, time: f64) -> f64 {
+        if self.is_clock_running() {
+            self.reference_simulation_time + (time - self.reference_time)
+        } else {
+            self.reference_simulation_time
+        }
+    }
+
+    pub fn state(&self) -> SimulationState {
+        self.state
+    }
+
+    /// Indicates whether the simulation clock advances; always when running, and while frozen when the frozen behavior says so.
+    pub fn is_clock_running(&self) -> bool {
+        match self.state {
+            SimulationState::Running => { true }
+            SimulationState::Frozen => { self.frozen_behavior.run_simulation_clock }
+            SimulationState::Stopped => { false }
+        }
+    }
+
+    /// Indicates whether the simulation should transmit updates (e.g., EntityState PDUs).
+    pub fn transmit_updates(&self) -> bool {
+        match self.state {
+            SimulationState::Running => { true }
+            SimulationState::Frozen => { self.frozen_behavior.transmit_updates }
+            SimulationState::Stopped => { false }
+        }
+    }
+
+    /// Indicates whether the simulation should process received updates.
+    pub fn process_updates(&self) -> bool {
+        match self.state {
+            SimulationState::Running => { true }
+            SimulationState::Frozen => { self.frozen_behavior.process_updates }
+            SimulationState::Stopped => { false }
+        }
+    }
+
+    /// The real-world times of the transitions that are scheduled but not yet applied.
+    pub fn scheduled_times(&self) -> impl Iterator<Item = f64> + '_ {
+        self.scheduled.iter().map(|scheduled| scheduled.time)
+    }
+
+    fn apply(&mut self, scheduled: ScheduledTransition) -> SimulationEvent {
+        match scheduled.transition {
+            Transition::Start { simulation_time } => {
+                self.state = SimulationState::Running;
+                self.reference_time = scheduled.time;
+                self.reference_simulation_time = simulation_time;
+                SimulationEvent::Started { simulation_time }
+            }
+            Transition::Stop { reason, frozen_behavior } => {
+                self.reference_simulation_time = self.simulation_time(scheduled.time);
+                self.reference_time = scheduled.time;
+                self.frozen_behavior = frozen_behavior;
+                if reason == StopFreezeReason::Termination {
+                    self.state = SimulationState::Stopped;
+                    SimulationEvent::Stopped { reason }
+                } else {
+                    self.state = SimulationState::Frozen;
+                    SimulationEvent::Frozen { reason }
+                }
+            }
+        }
+    }
+}
+
+fn start(real_world_time: &ClockTime, simulation_time: &ClockTime) -> ScheduledTransition {
+    ScheduledTransition {
+        time: real_world_time.to_seconds(),
+        transition: Transition::Start { simulation_time: simulation_time.to_seconds() },
+    }
+}
+
+fn stop(real_world_time: &ClockTime, reason: StopFreezeReason, frozen_behavior: StopFreezeFrozenBehavior) -> ScheduledTransition {
+    ScheduledTransition {
+        time: real_world_time.to_seconds(),
+        transition: Transition::Stop { reason, frozen_behavior },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::{ClockTime, EntityId, Pdu, PduHeader};
+    use crate::common::simulation_clock::{SimulationClock, SimulationEvent, SimulationState};
+    use crate::common::start_resume::model::StartResume;
+    use crate::common::stop_freeze::model::StopFreeze;
+    use crate::enumerations::{PduType, StopFreezeFrozenBehavior, StopFreezeReason};
+
+    const START_TIME: f64 = 1_700_000_000.0;
+
+    fn start_resume(receiving_id: EntityId, real_world_time: f64, simulation_time: f64) -> Pdu {
+        let body = StartResume::new()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(receiving_id)
+            .with_real_world_time(ClockTime::from_seconds(real_world_time))
+            .with_simulation_time(ClockTime::from_seconds(simulation_time))
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::StartResume), body, 0)
+    }
+
+    fn stop_freeze(real_world_time: f64, reason: StopFreezeReason, frozen_behavior: StopFreezeFrozenBehavior) -> Pdu {
+        let body = StopFreeze::new()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_real_world_time(ClockTime::from_seconds(real_world_time))
+            .with_reason(reason)
+            .with_frozen_behavior(frozen_behavior)
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::StopFreeze), body, 0)
+    }
+
+    fn frozen_behavior(run_simulation_clock: bool, transmit_updates: bool, process_updates: bool) -> StopFreezeFrozenBehavior {
+        StopFreezeFrozenBehavior { run_simulation_clock, transmit_updates, process_updates }
+    }
+
+    #[test]
+    fn clock_time_seconds() {
+        let clock_time = ClockTime::from_seconds(START_TIME + 0.5);
+
+        assert_eq!(clock_time.hour, (START_TIME / 3600.0) as i32);
+        assert!((clock_time.to_seconds() - (START_TIME + 0.5)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn start_immediately() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+        assert_eq!(clock.state(), SimulationState::Stopped);
+
+        let events = clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME, 60.0), START_TIME + 1.0);
+        assert!(matches!(events.as_slice(), [SimulationEvent::Started { simulation_time }] if (simulation_time - 60.0).abs() < 1e-3));
+        assert_eq!(clock.state(), SimulationState::Running);
+        assert!(clock.transmit_updates());
+        assert!((clock.simulation_time(START_TIME + 10.0) - 70.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scheduled_start() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+
+        assert!(clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME + 5.0, 0.0), START_TIME).is_empty());
+        assert_eq!(clock.scheduled_times().count(), 1);
+        assert!(clock.update(START_TIME + 4.0).is_empty());
+        assert_eq!(clock.update(START_TIME + 5.0), vec![SimulationEvent::Started { simulation_time: 0.0 }]);
+        assert_eq!(clock.state(), SimulationState::Running);
+    }
+
+    #[test]
+    fn not_addressed() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+
+        assert!(clock.process(&start_resume(EntityId::new(1, 3, 0), START_TIME, 0.0), START_TIME).is_empty());
+        assert_eq!(clock.process(&start_resume(EntityId::new(0xFFFF, 0xFFFF, 0), START_TIME, 0.0), START_TIME).len(), 1);
+    }
+
+    #[test]
+    fn freeze_and_resume() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+        clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME, 0.0), START_TIME);
+
+        let events = clock.process(&stop_freeze(START_TIME + 10.0, StopFreezeReason::Recess, frozen_behavior(false, false, true)), START_TIME + 10.0);
+        assert_eq!(events, vec![SimulationEvent::Frozen { reason: StopFreezeReason::Recess }]);
+        assert_eq!(clock.state(), SimulationState::Frozen);
+        assert!(!clock.is_clock_running());
+        assert!(!clock.transmit_updates());
+        assert!(clock.process_updates());
+        assert!((clock.simulation_time(START_TIME + 100.0) - 10.0).abs() < 1e-3);
+
+        clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME + 100.0, 10.0), START_TIME + 100.0);
+        assert!((clock.simulation_time(START_TIME + 110.0) - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn frozen_clock_keeps_running() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+        clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME, 0.0), START_TIME);
+        clock.process(&stop_freeze(START_TIME + 10.0, StopFreezeReason::Recess, frozen_behavior(true, true, false)), START_TIME + 10.0);
+
+        assert!(clock.is_clock_running());
+        assert!(clock.transmit_updates());
+        assert!(!clock.process_updates());
+        assert!((clock.simulation_time(START_TIME + 20.0) - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn terminate() {
+        let mut clock = SimulationClock::new(EntityId::new(1, 2, 0));
+        clock.process(&start_resume(EntityId::new(1, 2, 0), START_TIME, 0.0), START_TIME);
+
+        let events = clock.process(&stop_freeze(START_TIME + 10.0, StopFreezeReason::Termination, frozen_behavior(true, true, true)), START_TIME + 10.0);
+        assert_eq!(events, vec![SimulationEvent::Stopped { reason: StopFreezeReason::Termination }]);
+        assert_eq!(clock.state(), SimulationState::Stopped);
+        assert!(!clock.is_clock_running());
+        assert!(!clock.transmit_updates());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::acknowledge_r::builder::AcknowledgeRBuilder;
+use crate::enumerations::PduType;
+
+/// 5.12.4.6 Acknowledge-R PDU
+///
+/// Has the same fields as the Acknowledge PDU.
+#[derive(Debug, Default, PartialEq)]
+pub struct AcknowledgeR {
+    pub acknowledge: Acknowledge,
+}
+
+impl AcknowledgeR {
+    pub fn builder() -> AcknowledgeRBuilder {
+        AcknowledgeRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> AcknowledgeRBuilder {
+        AcknowledgeRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::AcknowledgeR(self)
+    }
+}
+
+impl BodyInfo for AcknowledgeR {
+    fn body_length(&self) -> u16 {
+        self.acknowledge.body_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::AcknowledgeR
+    }
+}
+
+impl Interaction for AcknowledgeR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.acknowledge.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.acknowledge.receiver()
+    }
+}
\ No newline at end of file
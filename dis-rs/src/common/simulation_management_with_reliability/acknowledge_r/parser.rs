@@ -0,0 +1,25 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u32};
+use crate::common::parser::entity_id;
+use crate::common::simulation_management_with_reliability::acknowledge_r::model::AcknowledgeR;
+use crate::common::model::PduBody;
+use crate::enumerations::{AcknowledgeFlag, ResponseFlag};
+
+pub fn acknowledge_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, acknowledge_flag) = context("Acknowledge Flag", be_u16)(input)?;
+    let (input, response_flag) = context("Response Flag", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+
+    let body = AcknowledgeR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_acknowledge_flag(AcknowledgeFlag::from(acknowledge_flag))
+        .with_response_flag(ResponseFlag::from(response_flag))
+        .with_request_id(request_id)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::acknowledge_r::model::AcknowledgeR;
+use crate::common::{SerializePdu, SupportedVersion};
+
+impl SerializePdu for AcknowledgeR {
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16 {
+        // same layout as the Acknowledge PDU
+        self.acknowledge.serialize_pdu(version, buf)
+    }
+}
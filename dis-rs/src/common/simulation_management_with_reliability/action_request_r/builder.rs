@@ -0,0 +1,60 @@
+use crate::common::simulation_management_with_reliability::action_request_r::model::ActionRequestR;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::model::{EntityId, FixedDatum, VariableDatum};
+use crate::enumerations::{ActionId, RequiredReliabilityService};
+
+pub struct ActionRequestRBuilder(ActionRequestR);
+
+impl ActionRequestRBuilder {
+    pub fn new() -> Self {
+        ActionRequestRBuilder(ActionRequestR::default())
+    }
+
+    pub fn new_from_body(body: ActionRequestR) -> Self {
+        ActionRequestRBuilder(body)
+    }
+
+    pub fn build(self) -> ActionRequestR {
+        self.0
+    }
+
+    pub fn with_action_request(mut self, action_request: ActionRequest) -> Self {
+        self.0.action_request = action_request;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.action_request.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.action_request.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_required_reliability_service(mut self, required_reliability_service: RequiredReliabilityService) -> Self {
+        self.0.required_reliability_service = required_reliability_service;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.action_request.request_id = request_id;
+        self
+    }
+
+    pub fn with_action_id(mut self, action_id: ActionId) -> Self {
+        self.0.action_request.action_id = action_id;
+        self
+    }
+
+    pub fn with_fixed_datums(mut self, fixed_datum_records: Vec<FixedDatum>) -> Self {
+        self.0.action_request.fixed_datum_records = fixed_datum_records;
+        self
+    }
+
+    pub fn with_variable_datums(mut self, variable_datum_records: Vec<VariableDatum>) -> Self {
+        self.0.action_request.variable_datum_records = variable_datum_records;
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::action_request::model::ActionRequest;
+use crate::common::model::{EntityId, PduBody, FIXED_DATUM_LENGTH, BASE_VARIABLE_DATUM_LENGTH, length_padded_to_num_bytes};
+use crate::common::simulation_management_with_reliability::action_request_r::builder::ActionRequestRBuilder;
+use crate::constants::{EIGHT_OCTETS};
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+pub const BASE_ACTION_REQUEST_R_BODY_LENGTH: u16 = 32;
+
+/// 5.12.4.7 Action Request-R PDU
+///
+/// Composes the Action Request PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct ActionRequestR {
+    pub action_request: ActionRequest,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl ActionRequestR {
+    pub fn builder() -> ActionRequestRBuilder {
+        ActionRequestRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ActionRequestRBuilder {
+        ActionRequestRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ActionRequestR(self)
+    }
+}
+
+impl BodyInfo for ActionRequestR {
+    fn body_length(&self) -> u16 {
+        BASE_ACTION_REQUEST_R_BODY_LENGTH +
+            (FIXED_DATUM_LENGTH * self.action_request.fixed_datum_records.len() as u16) +
+            (self.action_request.variable_datum_records.iter().map(|datum| {
+                let padded_record = length_padded_to_num_bytes(
+                    BASE_VARIABLE_DATUM_LENGTH as usize + datum.datum_value.len(),
+                    EIGHT_OCTETS);
+                padded_record.record_length_bytes as u16
+            } ).sum::<u16>())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ActionRequestR
+    }
+}
+
+impl Interaction for ActionRequestR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.action_request.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.action_request.receiver()
+    }
+}
\ No newline at end of file
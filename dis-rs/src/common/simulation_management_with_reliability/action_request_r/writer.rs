@@ -0,0 +1,23 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::action_request_r::model::ActionRequestR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for ActionRequestR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.action_request.originating_id.serialize(buf);
+        let receiving_bytes = self.action_request.receiving_id.serialize(buf);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        buf.put_u32(self.action_request.request_id);
+        buf.put_u32(self.action_request.action_id.into());
+        buf.put_u32(self.action_request.fixed_datum_records.len() as u32);
+        buf.put_u32(self.action_request.variable_datum_records.len() as u32);
+        let fixed_datum_bytes = self.action_request.fixed_datum_records.iter()
+            .map(|datum| datum.serialize(buf)).sum::<u16>();
+        let variable_datum_bytes = self.action_request.variable_datum_records.iter()
+            .map(|datum| datum.serialize(buf)).sum::<u16>();
+
+        originating_bytes + receiving_bytes + 20 + fixed_datum_bytes + variable_datum_bytes
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::common::simulation_management_with_reliability::action_response_r::model::ActionResponseR;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::model::{EntityId, FixedDatum, VariableDatum};
+use crate::enumerations::RequestStatus;
+
+pub struct ActionResponseRBuilder(ActionResponseR);
+
+impl ActionResponseRBuilder {
+    pub fn new() -> Self {
+        ActionResponseRBuilder(ActionResponseR::default())
+    }
+
+    pub fn new_from_body(body: ActionResponseR) -> Self {
+        ActionResponseRBuilder(body)
+    }
+
+    pub fn build(self) -> ActionResponseR {
+        self.0
+    }
+
+    pub fn with_action_response(mut self, action_response: ActionResponse) -> Self {
+        self.0.action_response = action_response;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.action_response.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.action_response.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.action_response.request_id = request_id;
+        self
+    }
+
+    pub fn with_request_status(mut self, request_status: RequestStatus) -> Self {
+        self.0.action_response.request_status = request_status;
+        self
+    }
+
+    pub fn with_fixed_datums(mut self, fixed_datum_records: Vec<FixedDatum>) -> Self {
+        self.0.action_response.fixed_datum_records = fixed_datum_records;
+        self
+    }
+
+    pub fn with_variable_datums(mut self, variable_datum_records: Vec<VariableDatum>) -> Self {
+        self.0.action_response.variable_datum_records = variable_datum_records;
+        self
+    }
+}
\ No newline at end of file
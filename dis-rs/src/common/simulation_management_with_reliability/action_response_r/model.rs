@@ -0,0 +1,47 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::action_response::model::ActionResponse;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::action_response_r::builder::ActionResponseRBuilder;
+use crate::enumerations::PduType;
+
+/// 5.12.4.8 Action Response-R PDU
+///
+/// Has the same fields as the Action Response PDU.
+#[derive(Debug, Default, PartialEq)]
+pub struct ActionResponseR {
+    pub action_response: ActionResponse,
+}
+
+impl ActionResponseR {
+    pub fn builder() -> ActionResponseRBuilder {
+        ActionResponseRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> ActionResponseRBuilder {
+        ActionResponseRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ActionResponseR(self)
+    }
+}
+
+impl BodyInfo for ActionResponseR {
+    fn body_length(&self) -> u16 {
+        self.action_response.body_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ActionResponseR
+    }
+}
+
+impl Interaction for ActionResponseR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.action_response.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.action_response.receiver()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::be_u32;
+use crate::common::parser::{entity_id, datum_specification};
+use crate::common::simulation_management_with_reliability::action_response_r::model::ActionResponseR;
+use crate::common::model::PduBody;
+use crate::enumerations::RequestStatus;
+
+pub fn action_response_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, request_status) = context("Request Status", be_u32)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
+
+    let body = ActionResponseR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_request_id(request_id)
+        .with_request_status(RequestStatus::from(request_status))
+        .with_fixed_datums(datums.fixed_datum_records)
+        .with_variable_datums(datums.variable_datum_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
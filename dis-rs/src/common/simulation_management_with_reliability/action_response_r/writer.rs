@@ -0,0 +1,10 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::action_response_r::model::ActionResponseR;
+use crate::common::{SerializePdu, SupportedVersion};
+
+impl SerializePdu for ActionResponseR {
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16 {
+        // same layout as the ActionResponse PDU
+        self.action_response.serialize_pdu(version, buf)
+    }
+}
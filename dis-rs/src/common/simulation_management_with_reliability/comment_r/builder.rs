@@ -0,0 +1,39 @@
+use crate::common::simulation_management_with_reliability::comment_r::model::CommentR;
+use crate::common::comment::model::Comment;
+use crate::common::model::{EntityId, VariableDatum};
+
+pub struct CommentRBuilder(CommentR);
+
+impl CommentRBuilder {
+    pub fn new() -> Self {
+        CommentRBuilder(CommentR::default())
+    }
+
+    pub fn new_from_body(body: CommentR) -> Self {
+        CommentRBuilder(body)
+    }
+
+    pub fn build(self) -> CommentR {
+        self.0
+    }
+
+    pub fn with_comment(mut self, comment: Comment) -> Self {
+        self.0.comment = comment;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.comment.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.comment.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_variable_datums(mut self, variable_datum_records: Vec<VariableDatum>) -> Self {
+        self.0.comment.variable_datum_records = variable_datum_records;
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::simulation_management_with_reliability::comment_r::model::CommentR;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader, VariableDatum};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{PduType, VariableRecordType};
+
+    #[test]
+    fn comment_r_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::CommentR);
+
+        let body = CommentR::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_variable_datums(vec![VariableDatum::new(VariableRecordType::Azimuth_52340, vec![1, 2, 3, 4, 5, 6, 7])])
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
\ No newline at end of file
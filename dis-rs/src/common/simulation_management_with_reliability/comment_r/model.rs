@@ -0,0 +1,47 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::comment::model::Comment;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::comment_r::builder::CommentRBuilder;
+use crate::enumerations::PduType;
+
+/// 5.12.4.13 Comment-R PDU
+///
+/// Has the same fields as the Comment PDU.
+#[derive(Debug, Default, PartialEq)]
+pub struct CommentR {
+    pub comment: Comment,
+}
+
+impl CommentR {
+    pub fn builder() -> CommentRBuilder {
+        CommentRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> CommentRBuilder {
+        CommentRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::CommentR(self)
+    }
+}
+
+impl BodyInfo for CommentR {
+    fn body_length(&self) -> u16 {
+        self.comment.body_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::CommentR
+    }
+}
+
+impl Interaction for CommentR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.comment.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.comment.receiver()
+    }
+}
\ No newline at end of file
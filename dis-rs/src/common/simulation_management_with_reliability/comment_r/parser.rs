@@ -0,0 +1,19 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use crate::common::parser::{entity_id, datum_specification};
+use crate::common::simulation_management_with_reliability::comment_r::model::CommentR;
+use crate::common::model::PduBody;
+
+pub fn comment_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
+
+    let body = CommentR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_variable_datums(datums.variable_datum_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
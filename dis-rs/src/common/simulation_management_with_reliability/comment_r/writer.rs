@@ -0,0 +1,10 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::comment_r::model::CommentR;
+use crate::common::{SerializePdu, SupportedVersion};
+
+impl SerializePdu for CommentR {
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16 {
+        // same layout as the Comment PDU
+        self.comment.serialize_pdu(version, buf)
+    }
+}
@@ -0,0 +1,66 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::create_entity::model::CreateEntity;
+    use crate::common::simulation_management_with_reliability::create_entity_r::model::CreateEntityR;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{PduType, RequiredReliabilityService};
+
+    #[test]
+    fn create_entity_r_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::CreateEntityR);
+
+        let body = CreateEntityR::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_request_id(42)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn create_entity_r_composes_create_entity() {
+        let create_entity = CreateEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_request_id(42)
+            .build();
+        let body = CreateEntityR::builder()
+            .with_create_entity(create_entity)
+            .with_required_reliability_service(RequiredReliabilityService::Unacknowledged)
+            .build();
+
+        assert_eq!(body.create_entity.request_id, 42);
+        assert_eq!(body, CreateEntityR::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_request_id(42)
+            .with_required_reliability_service(RequiredReliabilityService::Unacknowledged)
+            .build());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::create_entity_r::builder::CreateEntityRBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+const CREATE_ENTITY_R_BODY_LENGTH: u16 = 20;
+
+/// 5.12.4.2 Create Entity-R PDU
+///
+/// Composes the Create Entity PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct CreateEntityR {
+    pub create_entity: CreateEntity,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl CreateEntityR {
+    pub fn builder() -> CreateEntityRBuilder {
+        CreateEntityRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> CreateEntityRBuilder {
+        CreateEntityRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::CreateEntityR(self)
+    }
+}
+
+impl BodyInfo for CreateEntityR {
+    fn body_length(&self) -> u16 {
+        CREATE_ENTITY_R_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::CreateEntityR
+    }
+}
+
+impl Interaction for CreateEntityR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.create_entity.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.create_entity.receiver()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::create_entity_r::model::CreateEntityR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for CreateEntityR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.create_entity.originating_id.serialize(buf);
+        let receiving_bytes = self.create_entity.receiving_id.serialize(buf);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        buf.put_u32(self.create_entity.request_id);
+
+        originating_bytes + receiving_bytes + 8
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::data_query::model::DataQuery;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::data_query_r::builder::DataQueryRBuilder;
+use crate::constants::{FOUR_OCTETS};
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+pub const BASE_DATA_QUERY_R_BODY_LENGTH: u16 = 32;
+
+/// 5.12.4.9 Data Query-R PDU
+///
+/// Composes the Data Query PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct DataQueryR {
+    pub data_query: DataQuery,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl DataQueryR {
+    pub fn builder() -> DataQueryRBuilder {
+        DataQueryRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> DataQueryRBuilder {
+        DataQueryRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::DataQueryR(self)
+    }
+}
+
+impl BodyInfo for DataQueryR {
+    fn body_length(&self) -> u16 {
+        BASE_DATA_QUERY_R_BODY_LENGTH +
+            (FOUR_OCTETS * self.data_query.fixed_datum_records.len()) as u16 +
+            (FOUR_OCTETS * self.data_query.variable_datum_records.len()) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::DataQueryR
+    }
+}
+
+impl Interaction for DataQueryR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.data_query.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.data_query.receiver()
+    }
+}
\ No newline at end of file
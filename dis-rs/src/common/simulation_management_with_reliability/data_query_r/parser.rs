@@ -0,0 +1,36 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::parser::entity_id;
+use crate::common::simulation_management_with_reliability::data_query_r::model::DataQueryR;
+use crate::common::model::PduBody;
+use crate::enumerations::{RequiredReliabilityService, VariableRecordType};
+
+pub fn data_query_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, required_reliability_service) = context("Required Reliability Service", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, time_interval) = context("Time Interval", be_u32)(input)?;
+    let (input, number_of_fixed_datums) = context("Number of Fixed Datum Records", be_u32)(input)?;
+    let (input, number_of_variable_datums) = context("Number of Variable Datum Records", be_u32)(input)?;
+    let (input, fixed_datum_ids) = context("Fixed Datum IDs", count(be_u32, number_of_fixed_datums as usize))(input)?;
+    let fixed_datum_ids = fixed_datum_ids.iter().map(|id| VariableRecordType::from(*id)).collect();
+    let (input, variable_datum_ids) = context("Variable Datum IDs", count(be_u32, number_of_variable_datums as usize))(input)?;
+    let variable_datum_ids = variable_datum_ids.iter().map(|id| VariableRecordType::from(*id)).collect();
+
+    let body = DataQueryR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_required_reliability_service(RequiredReliabilityService::from(required_reliability_service))
+        .with_request_id(request_id)
+        .with_time_interval(time_interval)
+        .with_fixed_datums(fixed_datum_ids)
+        .with_variable_datums(variable_datum_ids)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::data_query_r::model::DataQueryR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for DataQueryR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.data_query.originating_id.serialize(buf);
+        let receiving_bytes = self.data_query.receiving_id.serialize(buf);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        buf.put_u32(self.data_query.request_id);
+        buf.put_u32(self.data_query.time_interval);
+        buf.put_u32(self.data_query.fixed_datum_records.len() as u32);
+        buf.put_u32(self.data_query.variable_datum_records.len() as u32);
+        let fixed_datum_bytes = self.data_query.fixed_datum_records.iter()
+            .map(|datum_id| { buf.put_u32((*datum_id).into()); 4 }).sum::<u16>();
+        let variable_datum_bytes = self.data_query.variable_datum_records.iter()
+            .map(|datum_id| { buf.put_u32((*datum_id).into()); 4 }).sum::<u16>();
+
+        originating_bytes + receiving_bytes + 20 + fixed_datum_bytes + variable_datum_bytes
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::common::simulation_management_with_reliability::data_r::model::DataR;
+use crate::common::data::model::Data;
+use crate::common::model::{EntityId, FixedDatum, VariableDatum};
+use crate::enumerations::RequiredReliabilityService;
+
+pub struct DataRBuilder(DataR);
+
+impl DataRBuilder {
+    pub fn new() -> Self {
+        DataRBuilder(DataR::default())
+    }
+
+    pub fn new_from_body(body: DataR) -> Self {
+        DataRBuilder(body)
+    }
+
+    pub fn build(self) -> DataR {
+        self.0
+    }
+
+    pub fn with_data(mut self, data: Data) -> Self {
+        self.0.data = data;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.data.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.data.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.data.request_id = request_id;
+        self
+    }
+
+    pub fn with_required_reliability_service(mut self, required_reliability_service: RequiredReliabilityService) -> Self {
+        self.0.required_reliability_service = required_reliability_service;
+        self
+    }
+
+    pub fn with_fixed_datums(mut self, fixed_datum_records: Vec<FixedDatum>) -> Self {
+        self.0.data.fixed_datum_records = fixed_datum_records;
+        self
+    }
+
+    pub fn with_variable_datums(mut self, variable_datum_records: Vec<VariableDatum>) -> Self {
+        self.0.data.variable_datum_records = variable_datum_records;
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::data::model::Data;
+use crate::common::model::{EntityId, PduBody, FIXED_DATUM_LENGTH, BASE_VARIABLE_DATUM_LENGTH, length_padded_to_num_bytes};
+use crate::common::simulation_management_with_reliability::data_r::builder::DataRBuilder;
+use crate::constants::{EIGHT_OCTETS};
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+pub const BASE_DATA_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.11 Data-R PDU
+///
+/// Composes the Data PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct DataR {
+    pub data: Data,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl DataR {
+    pub fn builder() -> DataRBuilder {
+        DataRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> DataRBuilder {
+        DataRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::DataR(self)
+    }
+}
+
+impl BodyInfo for DataR {
+    fn body_length(&self) -> u16 {
+        BASE_DATA_R_BODY_LENGTH +
+            (FIXED_DATUM_LENGTH * self.data.fixed_datum_records.len() as u16) +
+            (self.data.variable_datum_records.iter().map(|datum| {
+                let padded_record = length_padded_to_num_bytes(
+                    BASE_VARIABLE_DATUM_LENGTH as usize + datum.datum_value.len(),
+                    EIGHT_OCTETS);
+                padded_record.record_length_bytes as u16
+            } ).sum::<u16>())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::DataR
+    }
+}
+
+impl Interaction for DataR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.data.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.data.receiver()
+    }
+}
\ No newline at end of file
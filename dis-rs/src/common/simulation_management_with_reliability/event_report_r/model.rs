@@ -0,0 +1,47 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::event_report::model::EventReport;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::event_report_r::builder::EventReportRBuilder;
+use crate::enumerations::PduType;
+
+/// 5.12.4.12 Event Report-R PDU
+///
+/// Has the same fields as the Event Report PDU.
+#[derive(Debug, Default, PartialEq)]
+pub struct EventReportR {
+    pub event_report: EventReport,
+}
+
+impl EventReportR {
+    pub fn builder() -> EventReportRBuilder {
+        EventReportRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> EventReportRBuilder {
+        EventReportRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::EventReportR(self)
+    }
+}
+
+impl BodyInfo for EventReportR {
+    fn body_length(&self) -> u16 {
+        self.event_report.body_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EventReportR
+    }
+}
+
+impl Interaction for EventReportR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.event_report.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.event_report.receiver()
+    }
+}
\ No newline at end of file
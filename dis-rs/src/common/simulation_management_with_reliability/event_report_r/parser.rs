@@ -0,0 +1,25 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::be_u32;
+use crate::common::parser::{entity_id, datum_specification};
+use crate::common::simulation_management_with_reliability::event_report_r::model::EventReportR;
+use crate::common::model::PduBody;
+use crate::enumerations::EventType;
+
+pub fn event_report_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, event_type) = context("Event Type", be_u32)(input)?;
+    let (input, _padding) = context("Padding", be_u32)(input)?;
+    let (input, datums) = context("Datum Specification", datum_specification)(input)?;
+
+    let body = EventReportR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_event_type(EventType::from(event_type))
+        .with_fixed_datums(datums.fixed_datum_records)
+        .with_variable_datums(datums.variable_datum_records)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::event_report_r::model::EventReportR;
+use crate::common::{SerializePdu, SupportedVersion};
+
+impl SerializePdu for EventReportR {
+    fn serialize_pdu<B: BufMut>(&self, version: SupportedVersion, buf: &mut B) -> u16 {
+        // same layout as the EventReport PDU
+        self.event_report.serialize_pdu(version, buf)
+    }
+}
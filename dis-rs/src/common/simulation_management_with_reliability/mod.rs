@@ -0,0 +1,15 @@
+pub mod create_entity_r;
+pub mod remove_entity_r;
+pub mod start_resume_r;
+pub mod stop_freeze_r;
+pub mod acknowledge_r;
+pub mod action_request_r;
+pub mod action_response_r;
+pub mod data_query_r;
+pub mod set_data_r;
+pub mod data_r;
+pub mod event_report_r;
+pub mod comment_r;
+pub mod record_r;
+pub mod set_record_r;
+pub mod record_query_r;
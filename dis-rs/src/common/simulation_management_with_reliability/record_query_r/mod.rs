@@ -0,0 +1,47 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::simulation_management_with_reliability::record_query_r::model::RecordQueryR;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{PduType, RecordQueryREventType, RequiredReliabilityService, VariableRecordType};
+
+    #[test]
+    fn record_query_r_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::RecordQueryR);
+
+        let body = RecordQueryR::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_request_id(42)
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_event_type(RecordQueryREventType::from(0))
+            .with_time(3600)
+            .with_record_id(VariableRecordType::Azimuth_52340)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
\ No newline at end of file
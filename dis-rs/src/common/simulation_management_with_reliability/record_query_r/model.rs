@@ -0,0 +1,54 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::record_query_r::builder::RecordQueryRBuilder;
+use crate::constants::{FOUR_OCTETS};
+use crate::enumerations::{PduType, RecordQueryREventType, RequiredReliabilityService, VariableRecordType};
+
+pub const BASE_RECORD_QUERY_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.16 Record Query-R PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct RecordQueryR {
+    pub originating_id: EntityId,
+    pub receiving_id: EntityId,
+    pub request_id: u32,
+    pub required_reliability_service: RequiredReliabilityService,
+    pub event_type: RecordQueryREventType,
+    pub time: u32,
+    pub record_ids: Vec<VariableRecordType>,
+}
+
+impl RecordQueryR {
+    pub fn builder() -> RecordQueryRBuilder {
+        RecordQueryRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> RecordQueryRBuilder {
+        RecordQueryRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::RecordQueryR(self)
+    }
+}
+
+impl BodyInfo for RecordQueryR {
+    fn body_length(&self) -> u16 {
+        BASE_RECORD_QUERY_R_BODY_LENGTH +
+            (FOUR_OCTETS * self.record_ids.len()) as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::RecordQueryR
+    }
+}
+
+impl Interaction for RecordQueryR {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::parser::entity_id;
+use crate::common::simulation_management_with_reliability::record_query_r::model::RecordQueryR;
+use crate::common::model::PduBody;
+use crate::enumerations::{RecordQueryREventType, RequiredReliabilityService, VariableRecordType};
+
+pub fn record_query_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, required_reliability_service) = context("Required Reliability Service", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, event_type) = context("Event Type", be_u16)(input)?;
+    let (input, time) = context("Time", be_u32)(input)?;
+    let (input, number_of_records) = context("Number of Records", be_u32)(input)?;
+    let (input, record_ids) = context("Record IDs", count(be_u32, number_of_records as usize))(input)?;
+    let record_ids = record_ids.iter().map(|id| VariableRecordType::from(*id)).collect();
+
+    let body = RecordQueryR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_request_id(request_id)
+        .with_required_reliability_service(RequiredReliabilityService::from(required_reliability_service))
+        .with_event_type(RecordQueryREventType::from(event_type))
+        .with_time(time)
+        .with_record_ids(record_ids)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
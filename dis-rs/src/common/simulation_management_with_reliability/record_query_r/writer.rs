@@ -0,0 +1,20 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::record_query_r::model::RecordQueryR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for RecordQueryR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_id.serialize(buf);
+        let receiving_bytes = self.receiving_id.serialize(buf);
+        buf.put_u32(self.request_id);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(self.event_type.into());
+        buf.put_u32(self.time);
+        buf.put_u32(self.record_ids.len() as u32);
+        let record_ids_bytes = self.record_ids.iter()
+            .map(|record_id| { buf.put_u32((*record_id).into()); 4 }).sum::<u16>();
+
+        originating_bytes + receiving_bytes + 16 + record_ids_bytes
+    }
+}
\ No newline at end of file
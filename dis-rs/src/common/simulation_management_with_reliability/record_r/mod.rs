@@ -0,0 +1,49 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::simulation_management_with_reliability::record_r::model::RecordR;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader, RecordSet};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{PduType, RecordREventType, RequiredReliabilityService, VariableRecordType};
+
+    #[test]
+    fn record_r_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::RecordR);
+
+        let body = RecordR::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 1, 2))
+            .with_request_id(42)
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_event_type(RecordREventType::from(0))
+            .with_response_serial_number(7)
+            .with_record_set(RecordSet::new(VariableRecordType::Azimuth_52340, 1)
+                .with_record(vec![1, 2, 3])
+                .with_record(vec![4, 5, 6]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
\ No newline at end of file
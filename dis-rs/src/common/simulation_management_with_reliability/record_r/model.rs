@@ -0,0 +1,53 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, PduBody, RecordSpecification};
+use crate::common::simulation_management_with_reliability::record_r::builder::RecordRBuilder;
+use crate::enumerations::{PduType, RecordREventType, RequiredReliabilityService};
+
+pub const BASE_RECORD_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.14 Record-R PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct RecordR {
+    pub originating_id: EntityId,
+    pub receiving_id: EntityId,
+    pub request_id: u32,
+    pub required_reliability_service: RequiredReliabilityService,
+    pub event_type: RecordREventType,
+    pub response_serial_number: u32,
+    pub record_specification: RecordSpecification,
+}
+
+impl RecordR {
+    pub fn builder() -> RecordRBuilder {
+        RecordRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> RecordRBuilder {
+        RecordRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::RecordR(self)
+    }
+}
+
+impl BodyInfo for RecordR {
+    fn body_length(&self) -> u16 {
+        BASE_RECORD_R_BODY_LENGTH +
+            self.record_specification.record_sets_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::RecordR
+    }
+}
+
+impl Interaction for RecordR {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::record_r::model::RecordR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for RecordR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_id.serialize(buf);
+        let receiving_bytes = self.receiving_id.serialize(buf);
+        buf.put_u32(self.request_id);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(self.event_type.into());
+        buf.put_u32(self.response_serial_number);
+        let record_specification_bytes = self.record_specification.serialize(buf);
+
+        originating_bytes + receiving_bytes + 12 + record_specification_bytes
+    }
+}
\ No newline at end of file
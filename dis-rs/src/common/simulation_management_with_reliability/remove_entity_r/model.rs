@@ -0,0 +1,50 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::remove_entity_r::builder::RemoveEntityRBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+const REMOVE_ENTITY_R_BODY_LENGTH: u16 = 20;
+
+/// 5.12.4.3 Remove Entity-R PDU
+///
+/// Composes the Remove Entity PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct RemoveEntityR {
+    pub remove_entity: RemoveEntity,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl RemoveEntityR {
+    pub fn builder() -> RemoveEntityRBuilder {
+        RemoveEntityRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> RemoveEntityRBuilder {
+        RemoveEntityRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::RemoveEntityR(self)
+    }
+}
+
+impl BodyInfo for RemoveEntityR {
+    fn body_length(&self) -> u16 {
+        REMOVE_ENTITY_R_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::RemoveEntityR
+    }
+}
+
+impl Interaction for RemoveEntityR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.remove_entity.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.remove_entity.receiver()
+    }
+}
\ No newline at end of file
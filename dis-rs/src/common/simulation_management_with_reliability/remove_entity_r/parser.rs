@@ -0,0 +1,25 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+use crate::common::parser::entity_id;
+use crate::common::simulation_management_with_reliability::remove_entity_r::model::RemoveEntityR;
+use crate::common::model::PduBody;
+use crate::enumerations::RequiredReliabilityService;
+
+pub fn remove_entity_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, required_reliability_service) = context("Required Reliability Service", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+
+    let body = RemoveEntityR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_required_reliability_service(RequiredReliabilityService::from(required_reliability_service))
+        .with_request_id(request_id)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
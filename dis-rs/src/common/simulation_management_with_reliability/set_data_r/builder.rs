@@ -0,0 +1,55 @@
+use crate::common::simulation_management_with_reliability::set_data_r::model::SetDataR;
+use crate::common::set_data::model::SetData;
+use crate::common::model::{EntityId, FixedDatum, VariableDatum};
+use crate::enumerations::RequiredReliabilityService;
+
+pub struct SetDataRBuilder(SetDataR);
+
+impl SetDataRBuilder {
+    pub fn new() -> Self {
+        SetDataRBuilder(SetDataR::default())
+    }
+
+    pub fn new_from_body(body: SetDataR) -> Self {
+        SetDataRBuilder(body)
+    }
+
+    pub fn build(self) -> SetDataR {
+        self.0
+    }
+
+    pub fn with_set_data(mut self, set_data: SetData) -> Self {
+        self.0.set_data = set_data;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.set_data.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.set_data.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_required_reliability_service(mut self, required_reliability_service: RequiredReliabilityService) -> Self {
+        self.0.required_reliability_service = required_reliability_service;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.set_data.request_id = request_id;
+        self
+    }
+
+    pub fn with_fixed_datums(mut self, fixed_datum_records: Vec<FixedDatum>) -> Self {
+        self.0.set_data.fixed_datum_records = fixed_datum_records;
+        self
+    }
+
+    pub fn with_variable_datums(mut self, variable_datum_records: Vec<VariableDatum>) -> Self {
+        self.0.set_data.variable_datum_records = variable_datum_records;
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::set_data::model::SetData;
+use crate::common::model::{EntityId, PduBody, FIXED_DATUM_LENGTH, BASE_VARIABLE_DATUM_LENGTH, length_padded_to_num_bytes};
+use crate::common::simulation_management_with_reliability::set_data_r::builder::SetDataRBuilder;
+use crate::constants::{EIGHT_OCTETS};
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+pub const BASE_SET_DATA_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.10 Set Data-R PDU
+///
+/// Composes the Set Data PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct SetDataR {
+    pub set_data: SetData,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl SetDataR {
+    pub fn builder() -> SetDataRBuilder {
+        SetDataRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> SetDataRBuilder {
+        SetDataRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::SetDataR(self)
+    }
+}
+
+impl BodyInfo for SetDataR {
+    fn body_length(&self) -> u16 {
+        BASE_SET_DATA_R_BODY_LENGTH +
+            (FIXED_DATUM_LENGTH * self.set_data.fixed_datum_records.len() as u16) +
+            (self.set_data.variable_datum_records.iter().map(|datum| {
+                let padded_record = length_padded_to_num_bytes(
+                    BASE_VARIABLE_DATUM_LENGTH as usize + datum.datum_value.len(),
+                    EIGHT_OCTETS);
+                padded_record.record_length_bytes as u16
+            } ).sum::<u16>())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::SetDataR
+    }
+}
+
+impl Interaction for SetDataR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.set_data.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.set_data.receiver()
+    }
+}
\ No newline at end of file
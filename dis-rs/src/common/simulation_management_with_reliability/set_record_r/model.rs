@@ -0,0 +1,51 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, PduBody, RecordSpecification};
+use crate::common::simulation_management_with_reliability::set_record_r::builder::SetRecordRBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+pub const BASE_SET_RECORD_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.15 Set Record-R PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct SetRecordR {
+    pub originating_id: EntityId,
+    pub receiving_id: EntityId,
+    pub request_id: u32,
+    pub required_reliability_service: RequiredReliabilityService,
+    pub record_specification: RecordSpecification,
+}
+
+impl SetRecordR {
+    pub fn builder() -> SetRecordRBuilder {
+        SetRecordRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> SetRecordRBuilder {
+        SetRecordRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::SetRecordR(self)
+    }
+}
+
+impl BodyInfo for SetRecordR {
+    fn body_length(&self) -> u16 {
+        BASE_SET_RECORD_R_BODY_LENGTH +
+            self.record_specification.record_sets_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::SetRecordR
+    }
+}
+
+impl Interaction for SetRecordR {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+}
\ No newline at end of file
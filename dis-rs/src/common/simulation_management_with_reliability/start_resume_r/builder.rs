@@ -0,0 +1,55 @@
+use crate::common::simulation_management_with_reliability::start_resume_r::model::StartResumeR;
+use crate::common::start_resume::model::StartResume;
+use crate::common::model::{EntityId, ClockTime};
+use crate::enumerations::RequiredReliabilityService;
+
+pub struct StartResumeRBuilder(StartResumeR);
+
+impl StartResumeRBuilder {
+    pub fn new() -> Self {
+        StartResumeRBuilder(StartResumeR::default())
+    }
+
+    pub fn new_from_body(body: StartResumeR) -> Self {
+        StartResumeRBuilder(body)
+    }
+
+    pub fn build(self) -> StartResumeR {
+        self.0
+    }
+
+    pub fn with_start_resume(mut self, start_resume: StartResume) -> Self {
+        self.0.start_resume = start_resume;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.start_resume.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.start_resume.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_real_world_time(mut self, real_world_time: ClockTime) -> Self {
+        self.0.start_resume.real_world_time = real_world_time;
+        self
+    }
+
+    pub fn with_simulation_time(mut self, simulation_time: ClockTime) -> Self {
+        self.0.start_resume.simulation_time = simulation_time;
+        self
+    }
+
+    pub fn with_required_reliability_service(mut self, required_reliability_service: RequiredReliabilityService) -> Self {
+        self.0.required_reliability_service = required_reliability_service;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.start_resume.request_id = request_id;
+        self
+    }
+}
\ No newline at end of file
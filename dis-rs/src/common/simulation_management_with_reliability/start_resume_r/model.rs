@@ -0,0 +1,50 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::start_resume::model::StartResume;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::start_resume_r::builder::StartResumeRBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+const START_RESUME_R_BODY_LENGTH: u16 = 36;
+
+/// 5.12.4.4 Start/Resume-R PDU
+///
+/// Composes the Start/Resume PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct StartResumeR {
+    pub start_resume: StartResume,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl StartResumeR {
+    pub fn builder() -> StartResumeRBuilder {
+        StartResumeRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> StartResumeRBuilder {
+        StartResumeRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::StartResumeR(self)
+    }
+}
+
+impl BodyInfo for StartResumeR {
+    fn body_length(&self) -> u16 {
+        START_RESUME_R_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::StartResumeR
+    }
+}
+
+impl Interaction for StartResumeR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.start_resume.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.start_resume.receiver()
+    }
+}
\ No newline at end of file
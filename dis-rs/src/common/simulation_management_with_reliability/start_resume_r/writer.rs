@@ -0,0 +1,18 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::start_resume_r::model::StartResumeR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for StartResumeR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.start_resume.originating_id.serialize(buf);
+        let receiving_bytes = self.start_resume.receiving_id.serialize(buf);
+        let real_world_time_bytes = self.start_resume.real_world_time.serialize(buf);
+        let simulation_time_bytes = self.start_resume.simulation_time.serialize(buf);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+        buf.put_u32(self.start_resume.request_id);
+
+        originating_bytes + receiving_bytes + real_world_time_bytes + simulation_time_bytes + 8
+    }
+}
\ No newline at end of file
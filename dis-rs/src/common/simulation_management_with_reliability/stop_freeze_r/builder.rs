@@ -0,0 +1,60 @@
+use crate::common::simulation_management_with_reliability::stop_freeze_r::model::StopFreezeR;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::model::{EntityId, ClockTime};
+use crate::enumerations::{RequiredReliabilityService, StopFreezeFrozenBehavior, StopFreezeReason};
+
+pub struct StopFreezeRBuilder(StopFreezeR);
+
+impl StopFreezeRBuilder {
+    pub fn new() -> Self {
+        StopFreezeRBuilder(StopFreezeR::default())
+    }
+
+    pub fn new_from_body(body: StopFreezeR) -> Self {
+        StopFreezeRBuilder(body)
+    }
+
+    pub fn build(self) -> StopFreezeR {
+        self.0
+    }
+
+    pub fn with_stop_freeze(mut self, stop_freeze: StopFreeze) -> Self {
+        self.0.stop_freeze = stop_freeze;
+        self
+    }
+
+    pub fn with_origination_id(mut self, originating_id: EntityId) -> Self {
+        self.0.stop_freeze.originating_id = originating_id;
+        self
+    }
+
+    pub fn with_receiving_id(mut self, receiving_id: EntityId) -> Self {
+        self.0.stop_freeze.receiving_id = receiving_id;
+        self
+    }
+
+    pub fn with_real_world_time(mut self, real_world_time: ClockTime) -> Self {
+        self.0.stop_freeze.real_world_time = real_world_time;
+        self
+    }
+
+    pub fn with_reason(mut self, reason: StopFreezeReason) -> Self {
+        self.0.stop_freeze.reason = reason;
+        self
+    }
+
+    pub fn with_frozen_behavior(mut self, frozen_behavior: StopFreezeFrozenBehavior) -> Self {
+        self.0.stop_freeze.frozen_behavior = frozen_behavior;
+        self
+    }
+
+    pub fn with_required_reliability_service(mut self, required_reliability_service: RequiredReliabilityService) -> Self {
+        self.0.required_reliability_service = required_reliability_service;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.stop_freeze.request_id = request_id;
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::simulation_management_with_reliability::stop_freeze_r::builder::StopFreezeRBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService};
+
+const STOP_FREEZE_R_BODY_LENGTH: u16 = 28;
+
+/// 5.12.4.5 Stop/Freeze-R PDU
+///
+/// Composes the Stop/Freeze PDU with the Required Reliability Service.
+#[derive(Debug, Default, PartialEq)]
+pub struct StopFreezeR {
+    pub stop_freeze: StopFreeze,
+    pub required_reliability_service: RequiredReliabilityService,
+}
+
+impl StopFreezeR {
+    pub fn builder() -> StopFreezeRBuilder {
+        StopFreezeRBuilder::new()
+    }
+
+    pub fn into_builder(self) -> StopFreezeRBuilder {
+        StopFreezeRBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::StopFreezeR(self)
+    }
+}
+
+impl BodyInfo for StopFreezeR {
+    fn body_length(&self) -> u16 {
+        STOP_FREEZE_R_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::StopFreezeR
+    }
+}
+
+impl Interaction for StopFreezeR {
+    fn originator(&self) -> Option<&EntityId> {
+        self.stop_freeze.originator()
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        self.stop_freeze.receiver()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u32, be_u8};
+use crate::common::parser::{entity_id, clock_time};
+use crate::common::simulation_management_with_reliability::stop_freeze_r::model::StopFreezeR;
+use crate::common::model::PduBody;
+use crate::enumerations::{RequiredReliabilityService, StopFreezeFrozenBehavior, StopFreezeReason};
+
+pub fn stop_freeze_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, real_world_time) = context("Real-World Time", clock_time)(input)?;
+    let (input, reason) = context("Reason", be_u8)(input)?;
+    let (input, frozen_behavior) = context("Frozen Behavior", be_u8)(input)?;
+    let (input, required_reliability_service) = context("Required Reliability Service", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+
+    let body = StopFreezeR::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_real_world_time(real_world_time)
+        .with_reason(StopFreezeReason::from(reason))
+        .with_frozen_behavior(StopFreezeFrozenBehavior::from(frozen_behavior))
+        .with_required_reliability_service(RequiredReliabilityService::from(required_reliability_service))
+        .with_request_id(request_id)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use bytes::BufMut;
+use crate::common::simulation_management_with_reliability::stop_freeze_r::model::StopFreezeR;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for StopFreezeR {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.stop_freeze.originating_id.serialize(buf);
+        let receiving_bytes = self.stop_freeze.receiving_id.serialize(buf);
+        let real_world_time_bytes = self.stop_freeze.real_world_time.serialize(buf);
+        buf.put_u8(self.stop_freeze.reason.into());
+        buf.put_u8(self.stop_freeze.frozen_behavior.into());
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(0u8);
+        buf.put_u32(self.stop_freeze.request_id);
+
+        originating_bytes + receiving_bytes + real_world_time_bytes + 8
+    }
+}
\ No newline at end of file
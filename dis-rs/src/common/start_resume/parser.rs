@@ -1,15 +1,16 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::be_u32;
 use crate::common::parser::{clock_time, entity_id};
 use crate::common::start_resume::model::StartResume;
 use crate::common::model::PduBody;
 
 pub fn start_resume_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, real_world_time) = clock_time(input)?;
-    let (input, simulation_time) = clock_time(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, real_world_time) = context("Real-World Time", clock_time)(input)?;
+    let (input, simulation_time) = context("Simulation Time", clock_time)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
 
     let body = StartResume::new()
         .with_origination_id(originating_id)
@@ -1,4 +1,5 @@
-use nom::IResult;
+use crate::common::parser::IResult;
+use nom::error::context;
 use nom::number::complete::{be_u16, be_u32, be_u8};
 use crate::common::parser::{clock_time, entity_id};
 use crate::common::stop_freeze::model::StopFreeze;
@@ -6,13 +7,13 @@ use crate::common::model::PduBody;
 use crate::enumerations::{StopFreezeFrozenBehavior, StopFreezeReason};
 
 pub fn stop_freeze_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, real_world_time) = clock_time(input)?;
-    let (input, reason) = be_u8(input)?;
-    let (input, behavior) = be_u8(input)?;
-    let (input, _padding) = be_u16(input)?;
-    let (input, request_id) = be_u32(input)?;
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, real_world_time) = context("Real-World Time", clock_time)(input)?;
+    let (input, reason) = context("Reason", be_u8)(input)?;
+    let (input, behavior) = context("Frozen Behavior", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u16)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
 
     let reason = StopFreezeReason::from(reason);
     let behavior = StopFreezeFrozenBehavior::from(behavior);
@@ -0,0 +1,48 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::supplemental_emission_entity_state::model::{PropulsionSystemData, SupplementalEmissionEntityState, VectoringNozzleSystemData};
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader};
+    use crate::enumerations::PduType;
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn sees_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::SupplementalEmissionEntityState);
+
+        let body = SupplementalEmissionEntityState::builder()
+            .with_originating_entity_id(EntityId::new(1, 1, 1))
+            .with_infrared_signature_representation_index(1)
+            .with_acoustic_signature_representation_index(2)
+            .with_radar_cross_section_signature_representation_index(3)
+            .with_propulsion_system(PropulsionSystemData::new(80.0, 2400.0))
+            .with_propulsion_system(PropulsionSystemData::new(75.5, 2300.0))
+            .with_vectoring_nozzle_system(VectoringNozzleSystemData::new(5.0, -10.0))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
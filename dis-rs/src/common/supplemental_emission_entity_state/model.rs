@@ -0,0 +1,89 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, PduBody};
+use crate::common::supplemental_emission_entity_state::builder::SupplementalEmissionEntityStateBuilder;
+use crate::enumerations::PduType;
+
+const BASE_SEES_BODY_LENGTH: u16 = 16;
+const PROPULSION_SYSTEM_DATA_LENGTH: u16 = 8;
+const VECTORING_NOZZLE_SYSTEM_DATA_LENGTH: u16 = 8;
+
+/// 5.7.6 Supplemental Emission / Entity State (SEES) PDU
+///
+/// The signature representation indices are kept as raw values, as they are defined per exercise.
+#[derive(Debug, Default, PartialEq)]
+pub struct SupplementalEmissionEntityState {
+    pub originating_entity_id: EntityId,
+    pub infrared_signature_representation_index: u16,
+    pub acoustic_signature_representation_index: u16,
+    pub radar_cross_section_signature_representation_index: u16,
+    pub propulsion_systems: Vec<PropulsionSystemData>,
+    pub vectoring_nozzle_systems: Vec<VectoringNozzleSystemData>,
+}
+
+impl SupplementalEmissionEntityState {
+    pub fn builder() -> SupplementalEmissionEntityStateBuilder {
+        SupplementalEmissionEntityStateBuilder::new()
+    }
+
+    pub fn into_builder(self) -> SupplementalEmissionEntityStateBuilder {
+        SupplementalEmissionEntityStateBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::SupplementalEmissionEntityState(self)
+    }
+}
+
+impl BodyInfo for SupplementalEmissionEntityState {
+    fn body_length(&self) -> u16 {
+        BASE_SEES_BODY_LENGTH
+            + (PROPULSION_SYSTEM_DATA_LENGTH * self.propulsion_systems.len() as u16)
+            + (VECTORING_NOZZLE_SYSTEM_DATA_LENGTH * self.vectoring_nozzle_systems.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::SupplementalEmissionEntityState
+    }
+}
+
+impl Interaction for SupplementalEmissionEntityState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.74 Propulsion System Data record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PropulsionSystemData {
+    pub power_setting: f32,
+    pub engine_rpm: f32,
+}
+
+impl PropulsionSystemData {
+    pub fn new(power_setting: f32, engine_rpm: f32) -> Self {
+        Self {
+            power_setting,
+            engine_rpm,
+        }
+    }
+}
+
+/// 6.2.97 Vectoring Nozzle System Data record, deflection angles in degrees.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct VectoringNozzleSystemData {
+    pub horizontal_deflection_angle: f32,
+    pub vertical_deflection_angle: f32,
+}
+
+impl VectoringNozzleSystemData {
+    pub fn new(horizontal_deflection_angle: f32, vertical_deflection_angle: f32) -> Self {
+        Self {
+            horizontal_deflection_angle,
+            vertical_deflection_angle,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16};
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::supplemental_emission_entity_state::model::{PropulsionSystemData, SupplementalEmissionEntityState, VectoringNozzleSystemData};
+
+pub fn sees_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_entity_id) = context("Originating Entity ID", entity_id)(input)?;
+    let (input, infrared_index) = context("Infrared Index", be_u16)(input)?;
+    let (input, acoustic_index) = context("Acoustic Index", be_u16)(input)?;
+    let (input, radar_cross_section_index) = context("Radar Cross Section Index", be_u16)(input)?;
+    let (input, number_of_propulsion_systems) = context("Number of Propulsion Systems", be_u16)(input)?;
+    let (input, number_of_vectoring_nozzle_systems) = context("Number of Vectoring Nozzle Systems", be_u16)(input)?;
+    let (input, propulsion_systems) = context("Propulsion System Data", count(propulsion_system_data, number_of_propulsion_systems as usize))(input)?;
+    let (input, vectoring_nozzle_systems) = context("Vectoring Nozzle System Data", count(vectoring_nozzle_system_data, number_of_vectoring_nozzle_systems as usize))(input)?;
+
+    let body = SupplementalEmissionEntityState::builder()
+        .with_originating_entity_id(originating_entity_id)
+        .with_infrared_signature_representation_index(infrared_index)
+        .with_acoustic_signature_representation_index(acoustic_index)
+        .with_radar_cross_section_signature_representation_index(radar_cross_section_index)
+        .with_propulsion_systems(propulsion_systems)
+        .with_vectoring_nozzle_systems(vectoring_nozzle_systems)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+pub(crate) fn propulsion_system_data(input: &[u8]) -> IResult<&[u8], PropulsionSystemData> {
+    let (input, power_setting) = be_f32(input)?;
+    let (input, engine_rpm) = be_f32(input)?;
+
+    Ok((input, PropulsionSystemData::new(power_setting, engine_rpm)))
+}
+
+pub(crate) fn vectoring_nozzle_system_data(input: &[u8]) -> IResult<&[u8], VectoringNozzleSystemData> {
+    let (input, horizontal_deflection_angle) = be_f32(input)?;
+    let (input, vertical_deflection_angle) = be_f32(input)?;
+
+    Ok((input, VectoringNozzleSystemData::new(horizontal_deflection_angle, vertical_deflection_angle)))
+}
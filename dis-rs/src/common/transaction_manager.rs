@@ -0,0 +1,484 @@
+use std::collections::BTreeMap;
+use crate::common::model::{EntityId, Pdu, PduBody};
+use crate::common::errors::DisError;
+use crate::enumerations::{AcknowledgeFlag, RequestStatus, RequiredReliabilityService, ResponseFlag};
+use crate::VariableParameters;
+
+/// The final result of a simulation management transaction.
+/// Each outcome returns the request PDU, so the user can tell which request it concerns.
+#[derive(Debug, PartialEq)]
+pub enum TransactionOutcome {
+    /// The receiver acknowledged the request with response flag 'Able to comply',
+    /// or answered it with a Data (-R) PDU
+    Acknowledged { request_id: u32, request: Pdu },
+    /// The receiver acknowledged the request with a response flag other than 'Able to comply'
+    Rejected { request_id: u32, response_flag: ResponseFlag, request: Pdu },
+    /// The receiver answered the Action Request (-R) with an Action Response (-R) PDU,
+    /// with the request status telling whether the action is complete, refused or failed
+    Responded { request_id: u32, request_status: RequestStatus, request: Pdu },
+    /// No response was received after all retransmissions of the request
+    TimedOut { request_id: u32, request: Pdu },
+}
+
+/// Events emitted by the `TransactionManager` while polling the outstanding requests.
+#[allow(clippy::large_enum_variant)] // events are consumed right after polling, boxing the outcome gains nothing
+#[derive(Debug, PartialEq)]
+pub enum TransactionEvent {
+    /// The request with the given request id must be sent again; it can be obtained using `TransactionManager::pending_mut`
+    Retransmit(u32),
+    /// The transaction finished, because all retransmissions went unanswered
+    Completed(TransactionOutcome),
+}
+
+/// A request registered with `TransactionManager::request`, to be sent by the user.
+#[allow(clippy::large_enum_variant)] // requests are sent right away, boxing the PDU gains nothing
+#[derive(Debug, PartialEq)]
+pub enum Request<'a> {
+    /// The request awaits a response, and remains available through `TransactionManager::pending_mut`
+    Pending(&'a mut Pdu),
+    /// The request asks for the unacknowledged reliability service, so no response is expected and it is not tracked
+    Unacknowledged(Pdu),
+}
+
+impl Request<'_> {
+    pub fn pdu(&self) -> &Pdu {
+        match self {
+            Request::Pending(pdu) => { pdu }
+            Request::Unacknowledged(pdu) => { pdu }
+        }
+    }
+
+    pub fn pdu_mut(&mut self) -> &mut Pdu {
+        match self {
+            Request::Pending(pdu) => { pdu }
+            Request::Unacknowledged(pdu) => { pdu }
+        }
+    }
+}
+
+/// The type of PDU that completes a transaction.
+/// Acknowledgements also carry the type of request they acknowledge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ResponseKind {
+    Acknowledge(AcknowledgeFlag),
+    ActionResponse,
+    Data,
+    AcknowledgeR(AcknowledgeFlag),
+    ActionResponseR,
+    DataR,
+}
+
+/// The status reported by a response PDU, if any.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ResponseStatus {
+    Flag(ResponseFlag),
+    Request(RequestStatus),
+    None,
+}
+
+struct Transaction {
+    request: Pdu,
+    originating_id: EntityId,
+    response: ResponseKind,
+    last_sent: f64,
+    retries: u32,
+}
+
+/// Correlates simulation management requests with their responses.
+///
+/// The manager allocates the request ids for outgoing requests, keeps the requests that are not yet answered,
+/// and matches received Acknowledge, Action Response and Data PDUs (and their reliable counterparts) on request id.
+/// Requests that are not answered within the retry delay (SM_REL_RETRY_DELAY) are retransmitted,
+/// at most the retry count (SM_REL_RETRY_CNT) times, after which the transaction times out.
+/// Requests of the reliable (-R) family that ask for the unacknowledged reliability service only get a request id;
+/// no response is expected for these, so they are not tracked.
+///
+/// Supported requests and their expected responses are:
+/// - CreateEntity, RemoveEntity, StartResume and StopFreeze: Acknowledge, with the matching acknowledge flag
+/// - ActionRequest: ActionResponse
+/// - DataQuery and SetData: Data
+/// - the -R variants of the above: AcknowledgeR, ActionResponseR and DataR, respectively
+///
+/// All times are in seconds, on a time line chosen by the user (e.g., simulation time or time since start).
+pub struct TransactionManager {
+    next_request_id: u32,
+    retry_delay: f64,
+    retry_count: u32,
+    transactions: BTreeMap<u32, Transaction>,
+}
+
+impl Default for TransactionManager {
+    fn default() -> Self {
+        Self::new(&VariableParameters::default())
+    }
+}
+
+impl TransactionManager {
+    /// Creates a new manager, taking the retry delay and retry count from the provided `VariableParameters`.
+    pub fn new(parameters: &VariableParameters) -> Self {
+        Self {
+            next_request_id: 1,
+            retry_delay: parameters.SM_REL_RETRY_DELAY as f64,
+            retry_count: parameters.SM_REL_RETRY_CNT as u32,
+            transactions: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the time, in seconds, to wait for a response before retransmitting a request.
+    pub fn with_retry_delay(mut self, retry_delay: f64) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets the maximum number of retransmissions of a request.
+    pub fn with_retry_count(mut self, retry_count: u32) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
+    /// Registers `request` as sent at `time`. The request id of the body is replaced by a newly allocated id.
+    /// Returns the request, to be sent by the user.
+    /// Requests asking for the unacknowledged reliability service are returned as `Request::Unacknowledged`
+    /// and are not tracked by the manager.
+    ///
+    /// Returns `DisError::NotARequest` when the PDU is not a simulation management request that expects a response.
+    pub fn request(&mut self, mut request: Pdu, time: f64) -> Result<Request<'_>, DisError> {
+        let (request_id, originating_id, response, acknowledged) = if let Some(fields) = request_fields(&mut request.body) {
+            // only requests use up a request id
+            let request_id = self.allocate_request_id();
+            *fields.request_id = request_id;
+            (request_id, fields.originating_id, fields.response, fields.acknowledged)
+        } else {
+            return Err(DisError::NotARequest(request.header.pdu_type));
+        };
+
+        if !acknowledged {
+            return Ok(Request::Unacknowledged(request));
+        }
+
+        let transaction = self.transactions.entry(request_id).or_insert(Transaction {
+            request,
+            originating_id,
+            response,
+            last_sent: time,
+            retries: 0,
+        });
+        Ok(Request::Pending(&mut transaction.request))
+    }
+
+    /// Processes a received PDU, returning the outcome of the transaction it completes.
+    /// A response completes a transaction when it has the request id of an outstanding request,
+    /// is of the type expected for that request (for acknowledgements including the acknowledge flag),
+    /// and is addressed to the originator of the request.
+    /// Other PDUs are ignored.
+    pub fn process(&mut self, pdu: &Pdu) -> Option<TransactionOutcome> {
+        let (response, request_id, receiving_id, status) = response_fields(&pdu.body)?;
+        let transaction = self.transactions.get(&request_id)?;
+        if transaction.response != response || transaction.originating_id != receiving_id {
+            return None;
+        }
+
+        let transaction = self.transactions.remove(&request_id)?;
+        match status {
+            ResponseStatus::Flag(response_flag) if response_flag != ResponseFlag::AbleToComply => {
+                Some(TransactionOutcome::Rejected { request_id, response_flag, request: transaction.request })
+            }
+            ResponseStatus::Request(request_status) => {
+                Some(TransactionOutcome::Responded { request_id, request_status, request: transaction.request })
+            }
+            _ => { Some(TransactionOutcome::Acknowledged { request_id, request: transaction.request }) }
+        }
+    }
+
+    /// Checks the outstanding requests at `time`. Requests for which the retry delay elapsed are either marked
+    /// for retransmission, or time out when the retry count is exhausted.
+    /// The user must send the requests for which a `Retransmit` event is returned.
+    pub fn poll(&mut self, time: f64) -> Vec<TransactionEvent> {
+        let expired: Vec<u32> = self.transactions.iter()
+            .filter(|(_, transaction)| time - transaction.last_sent >= self.retry_delay)
+            .map(|(request_id, _)| *request_id)
+            .collect();
+
+        expired.into_iter()
+            .filter_map(|request_id| {
+                let transaction = self.transactions.get_mut(&request_id)?;
+                if transaction.retries < self.retry_count {
+                    transaction.retries += 1;
+                    transaction.last_sent = time;
+                    Some(TransactionEvent::Retransmit(request_id))
+                } else {
+                    let transaction = self.transactions.remove(&request_id)?;
+                    Some(TransactionEvent::Completed(
+                        TransactionOutcome::TimedOut { request_id, request: transaction.request }))
+                }
+            }).collect()
+    }
+
+    /// Stops tracking the request with the given request id, returning the request.
+    pub fn cancel(&mut self, request_id: u32) -> Option<Pdu> {
+        self.transactions.remove(&request_id).map(|transaction| transaction.request)
+    }
+
+    /// The outstanding request with the given request id, if any.
+    pub fn pending(&self, request_id: u32) -> Option<&Pdu> {
+        self.transactions.get(&request_id).map(|transaction| &transaction.request)
+    }
+
+    /// Mutable access to the outstanding request with the given request id, e.g., to retransmit it.
+    pub fn pending_mut(&mut self, request_id: u32) -> Option<&mut Pdu> {
+        self.transactions.get_mut(&request_id).map(|transaction| &mut transaction.request)
+    }
+
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Allocates the next request id, skipping zero and the ids of outstanding requests.
+    fn allocate_request_id(&mut self) -> u32 {
+        while self.next_request_id == 0 || self.transactions.contains_key(&self.next_request_id) {
+            self.next_request_id = self.next_request_id.wrapping_add(1);
+        }
+        let request_id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        request_id
+    }
+}
+
+struct RequestFields<'a> {
+    request_id: &'a mut u32,
+    originating_id: EntityId,
+    response: ResponseKind,
+    acknowledged: bool,
+}
+
+fn fields(request_id: &mut u32, originating_id: EntityId, response: ResponseKind, acknowledged: bool) -> Option<RequestFields<'_>> {
+    Some(RequestFields { request_id, originating_id, response, acknowledged })
+}
+
+fn acknowledged(service: RequiredReliabilityService) -> bool {
+    service == RequiredReliabilityService::Acknowledged
+}
+
+fn request_fields(body: &mut PduBody) -> Option<RequestFields<'_>> {
+    match body {
+        PduBody::CreateEntity(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Acknowledge(AcknowledgeFlag::CreateEntity), true) }
+        PduBody::RemoveEntity(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Acknowledge(AcknowledgeFlag::RemoveEntity), true) }
+        PduBody::StartResume(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Acknowledge(AcknowledgeFlag::StartResume), true) }
+        PduBody::StopFreeze(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Acknowledge(AcknowledgeFlag::StopFreeze), true) }
+        PduBody::ActionRequest(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::ActionResponse, true) }
+        PduBody::DataQuery(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Data, true) }
+        PduBody::SetData(body) => { fields(&mut body.request_id, body.originating_id, ResponseKind::Data, true) }
+        PduBody::CreateEntityR(body) => { fields(&mut body.create_entity.request_id, body.create_entity.originating_id, ResponseKind::AcknowledgeR(AcknowledgeFlag::CreateEntity), acknowledged(body.required_reliability_service)) }
+        PduBody::RemoveEntityR(body) => { fields(&mut body.remove_entity.request_id, body.remove_entity.originating_id, ResponseKind::AcknowledgeR(AcknowledgeFlag::RemoveEntity), acknowledged(body.required_reliability_service)) }
+        PduBody::StartResumeR(body) => { fields(&mut body.start_resume.request_id, body.start_resume.originating_id, ResponseKind::AcknowledgeR(AcknowledgeFlag::StartResume), acknowledged(body.required_reliability_service)) }
+        PduBody::StopFreezeR(body) => { fields(&mut body.stop_freeze.request_id, body.stop_freeze.originating_id, ResponseKind::AcknowledgeR(AcknowledgeFlag::StopFreeze), acknowledged(body.required_reliability_service)) }
+        PduBody::ActionRequestR(body) => { fields(&mut body.action_request.request_id, body.action_request.originating_id, ResponseKind::ActionResponseR, acknowledged(body.required_reliability_service)) }
+        PduBody::DataQueryR(body) => { fields(&mut body.data_query.request_id, body.data_query.originating_id, ResponseKind::DataR, acknowledged(body.required_reliability_service)) }
+        PduBody::SetDataR(body) => { fields(&mut body.set_data.request_id, body.set_data.originating_id, ResponseKind::DataR, acknowledged(body.required_reliability_service)) }
+        _ => { None }
+    }
+}
+
+/// Returns the kind of response, the request id, the receiving id and the status
+/// (the response flag for acknowledgements, the request status for action responses) of a response PDU.
+fn response_fields(body: &PduBody) -> Option<(ResponseKind, u32, EntityId, ResponseStatus)> {
+    match body {
+        PduBody::Acknowledge(body) => { Some((ResponseKind::Acknowledge(body.acknowledge_flag), body.request_id, body.receiving_id, ResponseStatus::Flag(body.response_flag))) }
+        PduBody::ActionResponse(body) => { Some((ResponseKind::ActionResponse, body.request_id, body.receiving_id, ResponseStatus::Request(body.request_status))) }
+        PduBody::Data(body) => { Some((ResponseKind::Data, body.request_id, body.receiving_id, ResponseStatus::None)) }
+        PduBody::AcknowledgeR(body) => { Some((ResponseKind::AcknowledgeR(body.acknowledge.acknowledge_flag), body.acknowledge.request_id, body.acknowledge.receiving_id, ResponseStatus::Flag(body.acknowledge.response_flag))) }
+        PduBody::ActionResponseR(body) => { Some((ResponseKind::ActionResponseR, body.action_response.request_id, body.action_response.receiving_id, ResponseStatus::Request(body.action_response.request_status))) }
+        PduBody::DataR(body) => { Some((ResponseKind::DataR, body.data.request_id, body.data.receiving_id, ResponseStatus::None)) }
+        _ => { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::acknowledge::model::Acknowledge;
+    use crate::common::action_request::model::ActionRequest;
+    use crate::common::action_response::model::ActionResponse;
+    use crate::common::create_entity::model::CreateEntity;
+    use crate::common::simulation_management_with_reliability::create_entity_r::model::CreateEntityR;
+    use crate::common::errors::DisError;
+    use crate::common::model::{EntityId, Pdu, PduBody, PduHeader};
+    use crate::common::transaction_manager::{Request, TransactionEvent, TransactionManager, TransactionOutcome};
+    use crate::enumerations::{AcknowledgeFlag, ActionId, PduType, RequestStatus, RequiredReliabilityService, ResponseFlag};
+
+    fn create_entity() -> Pdu {
+        let body = CreateEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::CreateEntity), body, 0)
+    }
+
+    fn acknowledge(request_id: u32, response_flag: ResponseFlag) -> Pdu {
+        acknowledge_with_flag(request_id, AcknowledgeFlag::CreateEntity, response_flag)
+    }
+
+    fn acknowledge_with_flag(request_id: u32, acknowledge_flag: AcknowledgeFlag, response_flag: ResponseFlag) -> Pdu {
+        let body = Acknowledge::builder()
+            .with_origination_id(EntityId::new(1, 2, 0))
+            .with_receiving_id(EntityId::new(1, 1, 0))
+            .with_acknowledge_flag(acknowledge_flag)
+            .with_response_flag(response_flag)
+            .with_request_id(request_id)
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::Acknowledge), body, 0)
+    }
+
+    fn request_id(pdu: &Pdu) -> u32 {
+        match &pdu.body {
+            PduBody::CreateEntity(body) => { body.request_id }
+            PduBody::ActionRequest(body) => { body.request_id }
+            PduBody::CreateEntityR(body) => { body.create_entity.request_id }
+            _ => { 0 }
+        }
+    }
+
+    #[test]
+    fn allocate_request_ids() {
+        let mut manager = TransactionManager::default();
+
+        assert_eq!(request_id(manager.request(create_entity(), 0.0).unwrap().pdu()), 1);
+        assert_eq!(request_id(manager.request(create_entity(), 0.0).unwrap().pdu()), 2);
+        assert_eq!(manager.len(), 2);
+    }
+
+    #[test]
+    fn acknowledged() {
+        let mut manager = TransactionManager::default();
+        manager.request(create_entity(), 0.0).unwrap();
+
+        // a response for an unknown request is ignored
+        assert_eq!(manager.process(&acknowledge(2, ResponseFlag::AbleToComply)), None);
+
+        let outcome = manager.process(&acknowledge(1, ResponseFlag::AbleToComply));
+        assert!(matches!(outcome, Some(TransactionOutcome::Acknowledged { request_id: 1, .. })));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn acknowledge_flag_must_match_request() {
+        let mut manager = TransactionManager::default();
+        manager.request(create_entity(), 0.0).unwrap();
+
+        assert_eq!(manager.process(&acknowledge_with_flag(1, AcknowledgeFlag::RemoveEntity, ResponseFlag::AbleToComply)), None);
+        assert_eq!(manager.len(), 1);
+
+        let outcome = manager.process(&acknowledge_with_flag(1, AcknowledgeFlag::CreateEntity, ResponseFlag::AbleToComply));
+        assert!(matches!(outcome, Some(TransactionOutcome::Acknowledged { request_id: 1, .. })));
+    }
+
+    #[test]
+    fn unacknowledged_requests_are_not_tracked() {
+        let mut manager = TransactionManager::default();
+        let body = CreateEntityR::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_required_reliability_service(RequiredReliabilityService::Unacknowledged)
+            .build()
+            .into_pdu_body();
+        let request = manager.request(Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::CreateEntityR), body, 0), 0.0).unwrap();
+
+        assert!(matches!(request, Request::Unacknowledged(_)));
+        assert_eq!(request_id(request.pdu()), 1);
+        assert!(manager.is_empty());
+        assert!(manager.poll(10.0).is_empty());
+
+        // the request id is not reused
+        assert_eq!(request_id(manager.request(create_entity(), 10.0).unwrap().pdu()), 2);
+    }
+
+    #[test]
+    fn rejected() {
+        let mut manager = TransactionManager::default();
+        manager.request(create_entity(), 0.0).unwrap();
+
+        let outcome = manager.process(&acknowledge(1, ResponseFlag::UnableToComply));
+        assert!(matches!(outcome, Some(TransactionOutcome::Rejected { request_id: 1, response_flag: ResponseFlag::UnableToComply, .. })));
+    }
+
+    #[test]
+    fn action_response() {
+        let mut manager = TransactionManager::default();
+        let request = ActionRequest::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_action_id(ActionId::Dismount)
+            .build()
+            .into_pdu_body();
+        manager.request(Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::ActionRequest), request, 0), 0.0).unwrap();
+
+        // an Acknowledge does not complete an ActionRequest
+        assert_eq!(manager.process(&acknowledge(1, ResponseFlag::AbleToComply)), None);
+
+        let response = ActionResponse::builder()
+            .with_origination_id(EntityId::new(1, 2, 0))
+            .with_receiving_id(EntityId::new(1, 1, 0))
+            .with_request_id(1)
+            .with_request_status(RequestStatus::Complete)
+            .build()
+            .into_pdu_body();
+        let outcome = manager.process(&Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::ActionResponse), response, 0));
+        assert!(matches!(outcome, Some(TransactionOutcome::Responded { request_id: 1, request_status: RequestStatus::Complete, .. })));
+    }
+
+    #[test]
+    fn action_response_reports_request_status() {
+        let mut manager = TransactionManager::default();
+        let request = ActionRequest::builder()
+            .with_origination_id(EntityId::new(1, 1, 0))
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_action_id(ActionId::Dismount)
+            .build()
+            .into_pdu_body();
+        manager.request(Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::ActionRequest), request, 0), 0.0).unwrap();
+
+        // request status 5 is 'Request Rejected'
+        let response = ActionResponse::builder()
+            .with_origination_id(EntityId::new(1, 2, 0))
+            .with_receiving_id(EntityId::new(1, 1, 0))
+            .with_request_id(1)
+            .with_request_status(RequestStatus::from(5))
+            .build()
+            .into_pdu_body();
+        let outcome = manager.process(&Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::ActionResponse), response, 0));
+        assert!(matches!(outcome, Some(TransactionOutcome::Responded { request_id: 1, request_status, .. }) if request_status == RequestStatus::from(5)));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn retransmit_and_time_out() {
+        let mut manager = TransactionManager::default();
+        manager.request(create_entity(), 0.0).unwrap();
+
+        // default retry delay of 2 s, retry count of 3
+        assert!(manager.poll(1.9).is_empty());
+        assert_eq!(manager.poll(2.0), vec![TransactionEvent::Retransmit(1)]);
+        assert!(manager.pending_mut(1).is_some());
+        assert_eq!(manager.poll(4.0), vec![TransactionEvent::Retransmit(1)]);
+        assert_eq!(manager.poll(6.0), vec![TransactionEvent::Retransmit(1)]);
+
+        let events = manager.poll(8.0);
+        assert!(matches!(events.as_slice(), [TransactionEvent::Completed(TransactionOutcome::TimedOut { request_id: 1, .. })]));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn not_a_request() {
+        let mut manager = TransactionManager::default();
+
+        assert_eq!(manager.request(acknowledge(1, ResponseFlag::AbleToComply), 0.0).err(), Some(DisError::NotARequest(PduType::Acknowledge)));
+
+        // a PDU that is not a request does not use up a request id
+        assert_eq!(request_id(manager.request(create_entity(), 0.0).unwrap().pdu()), 1);
+    }
+}
@@ -0,0 +1,48 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::transfer_ownership::model::TransferOwnership;
+    use crate::common::model::{DisTimeStamp, EntityId, Pdu, PduHeader, RecordSet};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+    use crate::enumerations::{PduType, RequiredReliabilityService, TransferControlTransferType, VariableRecordType};
+
+    #[test]
+    fn transfer_ownership_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::TransferOwnership);
+
+        let body = TransferOwnership::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(1, 2, 1))
+            .with_request_id(15)
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_transfer_type(TransferControlTransferType::from(1))
+            .with_transfer_entity_id(EntityId::new(1, 1, 20))
+            .with_record_set(RecordSet::new(VariableRecordType::Azimuth_52340, 1)
+                .with_record(vec![1, 2, 3, 4]))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
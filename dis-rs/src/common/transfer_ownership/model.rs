@@ -0,0 +1,53 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, PduBody, RecordSpecification};
+use crate::common::transfer_ownership::builder::TransferOwnershipBuilder;
+use crate::enumerations::{PduType, RequiredReliabilityService, TransferControlTransferType};
+
+pub const BASE_TRANSFER_OWNERSHIP_BODY_LENGTH: u16 = 28;
+
+/// 5.9.4 Transfer Ownership PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct TransferOwnership {
+    pub originating_id: EntityId,
+    pub receiving_id: EntityId,
+    pub request_id: u32,
+    pub required_reliability_service: RequiredReliabilityService,
+    pub transfer_type: TransferControlTransferType,
+    pub transfer_entity_id: EntityId,
+    pub record_specification: RecordSpecification,
+}
+
+impl TransferOwnership {
+    pub fn builder() -> TransferOwnershipBuilder {
+        TransferOwnershipBuilder::new()
+    }
+
+    pub fn into_builder(self) -> TransferOwnershipBuilder {
+        TransferOwnershipBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::TransferOwnership(self)
+    }
+}
+
+impl BodyInfo for TransferOwnership {
+    fn body_length(&self) -> u16 {
+        BASE_TRANSFER_OWNERSHIP_BODY_LENGTH +
+            self.record_specification.record_sets_length()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::TransferOwnership
+    }
+}
+
+impl Interaction for TransferOwnership {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_id)
+    }
+}
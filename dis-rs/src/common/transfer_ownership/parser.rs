@@ -0,0 +1,29 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::number::complete::{be_u32, be_u8};
+use crate::common::parser::{entity_id, record_specification};
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::common::model::PduBody;
+use crate::enumerations::{RequiredReliabilityService, TransferControlTransferType};
+
+pub fn transfer_ownership_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, originating_id) = context("Originating ID", entity_id)(input)?;
+    let (input, receiving_id) = context("Receiving ID", entity_id)(input)?;
+    let (input, request_id) = context("Request ID", be_u32)(input)?;
+    let (input, required_reliability_service) = context("Required Reliability Service", be_u8)(input)?;
+    let (input, transfer_type) = context("Transfer Type", be_u8)(input)?;
+    let (input, transfer_entity_id) = context("Transfer Entity ID", entity_id)(input)?;
+    let (input, record_specification) = context("Record Specification", record_specification)(input)?;
+
+    let body = TransferOwnership::builder()
+        .with_origination_id(originating_id)
+        .with_receiving_id(receiving_id)
+        .with_request_id(request_id)
+        .with_required_reliability_service(RequiredReliabilityService::from(required_reliability_service))
+        .with_transfer_type(TransferControlTransferType::from(transfer_type))
+        .with_transfer_entity_id(transfer_entity_id)
+        .with_record_specification(record_specification)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
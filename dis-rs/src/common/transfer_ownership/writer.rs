@@ -0,0 +1,17 @@
+use bytes::BufMut;
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for TransferOwnership {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let originating_bytes = self.originating_id.serialize(buf);
+        let receiving_bytes = self.receiving_id.serialize(buf);
+        buf.put_u32(self.request_id);
+        buf.put_u8(self.required_reliability_service.into());
+        buf.put_u8(self.transfer_type.into());
+        let transfer_entity_bytes = self.transfer_entity_id.serialize(buf);
+        let record_specification_bytes = self.record_specification.serialize(buf);
+
+        originating_bytes + receiving_bytes + 6 + transfer_entity_bytes + record_specification_bytes
+    }
+}
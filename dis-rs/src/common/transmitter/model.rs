@@ -3,7 +3,7 @@ use crate::enumerations::{PduType, VariableRecordType, TransmitterTransmitState,
 use crate::common::{BodyInfo, Interaction};
 use crate::constants::{EIGHT_OCTETS, ZERO_OCTETS};
 
-const BASE_TRANSMITTER_BODY_LENGTH: u16 = 92;
+pub(crate) const BASE_TRANSMITTER_BODY_LENGTH: u16 = 92;
 pub const BEAM_ANTENNA_PATTERN_OCTETS: u16 = 40;
 pub const BASE_VTP_RECORD_LENGTH: u16 = 6;
 
@@ -297,7 +297,7 @@ impl From<bool> for CryptoMode {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct BeamAntennaPattern {
     pub beam_direction: Orientation,
     pub azimuth_beamwidth: f32,
@@ -1,5 +1,5 @@
 use nom::bytes::complete::take;
-use nom::IResult;
+use crate::common::parser::IResult;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u16, be_u32, be_u64, be_u8};
 use crate::common::model::{PduBody, PduHeader};
@@ -76,7 +76,7 @@ pub fn transmitter_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8],
     }
 }
 
-fn modulation_type(input: &[u8]) -> IResult<&[u8], ModulationType> {
+pub(crate) fn modulation_type(input: &[u8]) -> IResult<&[u8], ModulationType> {
     let (input, spread_spectrum) = spread_spectrum(input)?;
     let (input, major_modulation) = be_u16(input)?;
     let major_modulation = TransmitterMajorModulation::from(major_modulation);
@@ -121,7 +121,7 @@ fn spread_spectrum(input: &[u8]) -> IResult<&[u8], SpreadSpectrum> {
     Ok((input, SpreadSpectrum::new_with_values(frequency_hopping, pseudo_noise, time_hopping)))
 }
 
-fn crypto_key_id(input: &[u8]) -> IResult<&[u8], CryptoKeyId> {
+pub(crate) fn crypto_key_id(input: &[u8]) -> IResult<&[u8], CryptoKeyId> {
     let (input, value) = be_u16(input)?;
     let pseudo_crypto_key = value >> 1;
     let crypto_mode = (value & 0x0001) != 0;
@@ -133,7 +133,7 @@ fn crypto_key_id(input: &[u8]) -> IResult<&[u8], CryptoKeyId> {
     }))
 }
 
-fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
+pub(crate) fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
     let (input, beam_direction) = orientation(input)?;
     let (input, azimuth_beamwidth) = be_f32(input)?;
     let (input, elevation_beamwidth) = be_f32(input)?;
@@ -0,0 +1,167 @@
+use nom::number::complete::be_u64;
+use crate::common::errors::DisError;
+use crate::common::model::{EntityId, EntityType, Location, VectorF32};
+use crate::common::parser::{entity_id, entity_type, location, vec3_f32};
+use crate::common::transmitter::model::{BASE_TRANSMITTER_BODY_LENGTH, CryptoKeyId, ModulationType};
+use crate::common::transmitter::parser::{crypto_key_id, modulation_type};
+use crate::common::view::{check_body_length, decode, read_f32, read_u16};
+use crate::enumerations::{ProtocolVersion, TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterInputSource, TransmitterTransmitState};
+
+const RADIO_REFERENCE_ID_OFFSET: usize = 0;
+const RADIO_NUMBER_OFFSET: usize = 6;
+const RADIO_TYPE_OFFSET: usize = 8;
+const TRANSMIT_STATE_OFFSET: usize = 16;
+const INPUT_SOURCE_OFFSET: usize = 17;
+const NUMBER_OF_VTP_OFFSET: usize = 18;
+const ANTENNA_LOCATION_OFFSET: usize = 20;
+const RELATIVE_ANTENNA_LOCATION_OFFSET: usize = 44;
+const ANTENNA_PATTERN_TYPE_OFFSET: usize = 56;
+const ANTENNA_PATTERN_LENGTH_OFFSET: usize = 58;
+const FREQUENCY_OFFSET: usize = 60;
+const TRANSMIT_FREQUENCY_BANDWIDTH_OFFSET: usize = 68;
+const POWER_OFFSET: usize = 72;
+const MODULATION_TYPE_OFFSET: usize = 76;
+const CRYPTO_SYSTEM_OFFSET: usize = 84;
+const CRYPTO_KEY_ID_OFFSET: usize = 86;
+const LENGTH_OF_MODULATION_PARAMETERS_OFFSET: usize = 88;
+const MODULATION_PARAMETERS_OFFSET: usize = 92;
+
+/// A borrowed view over the body of a Transmitter PDU (5.8.3), decoding fields when they are accessed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransmitterView<'a> {
+    body: &'a [u8],
+    version: ProtocolVersion,
+}
+
+impl<'a> TransmitterView<'a> {
+    /// Creates a view over a Transmitter body, checking that the body holds the fixed fields and the modulation parameters.
+    pub fn new(body: &'a [u8], version: ProtocolVersion) -> Result<Self, DisError> {
+        check_body_length(body, BASE_TRANSMITTER_BODY_LENGTH as usize)?;
+        let length_of_modulation_parameters = body[LENGTH_OF_MODULATION_PARAMETERS_OFFSET] as usize;
+        check_body_length(body, MODULATION_PARAMETERS_OFFSET + length_of_modulation_parameters)?;
+
+        Ok(Self { body, version })
+    }
+
+    pub fn radio_reference_id(&self) -> EntityId {
+        decode(self.body, RADIO_REFERENCE_ID_OFFSET, entity_id)
+    }
+
+    pub fn radio_number(&self) -> u16 {
+        read_u16(self.body, RADIO_NUMBER_OFFSET)
+    }
+
+    pub fn radio_type(&self) -> EntityType {
+        decode(self.body, RADIO_TYPE_OFFSET, entity_type)
+    }
+
+    pub fn transmit_state(&self) -> TransmitterTransmitState {
+        TransmitterTransmitState::from(self.body[TRANSMIT_STATE_OFFSET])
+    }
+
+    pub fn input_source(&self) -> TransmitterInputSource {
+        TransmitterInputSource::from(self.body[INPUT_SOURCE_OFFSET])
+    }
+
+    /// The number of variable transmitter parameter records. The field is only defined in DIS v7, so this is 0 for other versions.
+    #[allow(clippy::wildcard_in_or_patterns)]
+    pub fn number_of_variable_transmitter_parameters(&self) -> u16 {
+        match self.version {
+            ProtocolVersion::IEEE1278_12012 => { read_u16(self.body, NUMBER_OF_VTP_OFFSET) }
+            ProtocolVersion::IEEE1278_1A1998 | _ => { 0 }
+        }
+    }
+
+    pub fn antenna_location(&self) -> Location {
+        decode(self.body, ANTENNA_LOCATION_OFFSET, location)
+    }
+
+    pub fn relative_antenna_location(&self) -> VectorF32 {
+        decode(self.body, RELATIVE_ANTENNA_LOCATION_OFFSET, vec3_f32)
+    }
+
+    pub fn antenna_pattern_type(&self) -> TransmitterAntennaPatternType {
+        TransmitterAntennaPatternType::from(read_u16(self.body, ANTENNA_PATTERN_TYPE_OFFSET))
+    }
+
+    pub fn antenna_pattern_length(&self) -> u16 {
+        read_u16(self.body, ANTENNA_PATTERN_LENGTH_OFFSET)
+    }
+
+    pub fn frequency(&self) -> u64 {
+        decode(self.body, FREQUENCY_OFFSET, be_u64)
+    }
+
+    pub fn transmit_frequency_bandwidth(&self) -> f32 {
+        read_f32(self.body, TRANSMIT_FREQUENCY_BANDWIDTH_OFFSET)
+    }
+
+    pub fn power(&self) -> f32 {
+        read_f32(self.body, POWER_OFFSET)
+    }
+
+    pub fn modulation_type(&self) -> ModulationType {
+        decode(self.body, MODULATION_TYPE_OFFSET, modulation_type)
+    }
+
+    pub fn crypto_system(&self) -> TransmitterCryptoSystem {
+        TransmitterCryptoSystem::from(read_u16(self.body, CRYPTO_SYSTEM_OFFSET))
+    }
+
+    pub fn crypto_key_id(&self) -> CryptoKeyId {
+        decode(self.body, CRYPTO_KEY_ID_OFFSET, crypto_key_id)
+    }
+
+    /// The raw modulation parameters, if present.
+    pub fn modulation_parameters(&self) -> Option<&'a [u8]> {
+        let length = self.body[LENGTH_OF_MODULATION_PARAMETERS_OFFSET] as usize;
+        if length > 0 {
+            Some(&self.body[MODULATION_PARAMETERS_OFFSET..MODULATION_PARAMETERS_OFFSET + length])
+        } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::model::{EntityId, EntityType, Location, Pdu, PduBody, PduHeader, VectorF32};
+    use crate::common::Serialize;
+    use crate::common::transmitter::model::Transmitter;
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{EntityKind, PduType};
+
+    #[test]
+    fn transmitter_view_matches_parsed() {
+        let body = Transmitter::new()
+            .with_radio_reference_id(EntityId::new(1, 2, 3))
+            .with_radio_number(1)
+            .with_radio_type(EntityType::default().with_kind(EntityKind::Radio))
+            .with_antenna_location(Location::new(1.0, 2.0, 3.0))
+            .with_relative_antenna_location(VectorF32::new(0.5, 0.0, 1.5))
+            .with_frequency(121_500_000)
+            .with_transmit_frequency_bandwidth(25_000.0)
+            .with_power(10.0)
+            .with_modulation_parameters(vec![1, 2, 3, 4, 5, 6, 7, 8])
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::Transmitter), body, 0);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+
+        let pdu_view = PduView::new(&buf).unwrap();
+        let view = if let Ok(BodyView::Transmitter(view)) = pdu_view.body_view() { view } else { panic!("Expected a Transmitter view") };
+        let transmitter = if let PduBody::Transmitter(transmitter) = pdu_view.to_pdu().unwrap().body { transmitter } else { panic!() };
+
+        assert_eq!(view.radio_reference_id(), transmitter.radio_reference_id);
+        assert_eq!(view.radio_number(), transmitter.radio_number);
+        assert_eq!(view.radio_type(), transmitter.radio_type);
+        assert_eq!(view.antenna_location(), transmitter.antenna_location);
+        assert_eq!(view.relative_antenna_location(), transmitter.relative_antenna_location);
+        assert_eq!(view.frequency(), transmitter.frequency);
+        assert_eq!(view.transmit_frequency_bandwidth(), transmitter.transmit_frequency_bandwidth);
+        assert_eq!(view.power(), transmitter.power);
+        assert_eq!(view.modulation_type(), transmitter.modulation_type);
+        assert_eq!(view.crypto_key_id(), transmitter.crypto_key_id);
+        assert_eq!(view.number_of_variable_transmitter_parameters(), 0);
+        assert_eq!(view.modulation_parameters().map(|params| params.to_vec()), transmitter.modulation_parameters);
+    }
+}
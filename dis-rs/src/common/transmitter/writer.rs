@@ -1,4 +1,4 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::common::transmitter::model::{BASE_VTP_RECORD_LENGTH, BEAM_ANTENNA_PATTERN_OCTETS, BeamAntennaPattern, CryptoKeyId, CryptoMode, ModulationType, SpreadSpectrum, Transmitter, VariableTransmitterParameter};
 use crate::common::model::length_padded_to_num_bytes;
@@ -6,7 +6,7 @@ use crate::enumerations::TransmitterMajorModulation;
 use crate::constants::{EIGHT_OCTETS, ZERO_OCTETS};
 
 impl SerializePdu for Transmitter {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
         self.radio_reference_id.serialize(buf);
         buf.put_u16(self.radio_number);
         self.radio_type.serialize(buf);
@@ -55,7 +55,7 @@ impl SerializePdu for Transmitter {
 }
 
 impl Serialize for ModulationType {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let spread_spectrum_bytes = self.spread_spectrum.serialize(buf);
         buf.put_u16(self.major_modulation.into());
         match self.major_modulation {
@@ -97,7 +97,7 @@ impl Serialize for ModulationType {
 }
 
 impl Serialize for SpreadSpectrum {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         const BIT_0: u16 = 0x8000;
         const BIT_1: u16 = 0x4000;
         const BIT_2: u16 = 0x2000;
@@ -118,7 +118,7 @@ impl Serialize for SpreadSpectrum {
 }
 
 impl Serialize for CryptoKeyId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let field = self.pseudo_crypto_key << 1;
         let field = match self.crypto_mode {
             CryptoMode::Baseband => { field }
@@ -130,7 +130,7 @@ impl Serialize for CryptoKeyId {
 }
 
 impl Serialize for BeamAntennaPattern {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         self.beam_direction.serialize(buf);
         buf.put_f32(self.azimuth_beamwidth);
         buf.put_f32(self.elevation_beamwidth);
@@ -147,7 +147,7 @@ impl Serialize for BeamAntennaPattern {
 }
 
 impl Serialize for VariableTransmitterParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let record_padded_lengths = length_padded_to_num_bytes(
             BASE_VTP_RECORD_LENGTH as usize + self.fields.len(),
             EIGHT_OCTETS);
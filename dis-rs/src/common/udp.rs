@@ -0,0 +1,320 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::time::Duration;
+use socket2::{Domain, Protocol, Socket, Type};
+use crate::common::model::{DisTimeStamp, Pdu, TimeStamp};
+use crate::common::{BodyInfo, parse, TrySerialize};
+use crate::enumerations::ProtocolVersion;
+
+/// Maximum payload of a single UDP datagram. Datagrams can contain multiple (bundled) PDUs.
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+const DEFAULT_MULTICAST_TTL: u32 = 1;
+
+/// The way an endpoint addresses other participants of the exercise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UdpMode {
+    /// Send to a single remote address
+    Unicast,
+    /// Send to a broadcast address; enables SO_BROADCAST on the socket
+    Broadcast,
+    /// Join the multicast group on the given local interface, and send to the group
+    Multicast { group: Ipv4Addr, interface: Ipv4Addr },
+}
+
+/// Settings for a `UdpEndpoint`.
+///
+/// The local address is the address the socket is bound to, the remote address is where sent PDUs go to.
+/// For broadcast that is the broadcast address, for multicast the group address and port.
+/// Received PDUs can be filtered on exercise id and protocol version, which is disabled by default.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UdpSettings {
+    pub mode: UdpMode,
+    pub local_address: SocketAddr,
+    pub remote_address: SocketAddr,
+    pub exercise_id: Option<u8>,
+    pub protocol_version: Option<ProtocolVersion>,
+    pub multicast_ttl: u32,
+    pub multicast_loop: bool,
+    pub read_timeout: Option<Duration>,
+}
+
+impl UdpSettings {
+    pub fn new(mode: UdpMode, local_address: SocketAddr, remote_address: SocketAddr) -> Self {
+        Self {
+            mode,
+            local_address,
+            remote_address,
+            exercise_id: None,
+            protocol_version: None,
+            multicast_ttl: DEFAULT_MULTICAST_TTL,
+            multicast_loop: true,
+            read_timeout: None,
+        }
+    }
+
+    pub fn new_unicast(local_address: SocketAddr, remote_address: SocketAddr) -> Self {
+        Self::new(UdpMode::Unicast, local_address, remote_address)
+    }
+
+    pub fn new_broadcast(local_address: SocketAddr, broadcast_address: SocketAddr) -> Self {
+        Self::new(UdpMode::Broadcast, local_address, broadcast_address)
+    }
+
+    /// Multicast settings, for the group and port in `group_address`, joined on the given local interface.
+    /// The socket is bound to the port of the group on all interfaces.
+    /// Only IPv4 groups are supported; an IPv6 group address results in an error of kind `InvalidInput`.
+    pub fn new_multicast(group_address: SocketAddr, interface: Ipv4Addr) -> io::Result<Self> {
+        let group = match group_address.ip() {
+            IpAddr::V4(group) => { group }
+            IpAddr::V6(group) => {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                          format!("IPv6 multicast group {group} is not supported")));
+            }
+        };
+        let local_address = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), group_address.port());
+        Ok(Self::new(UdpMode::Multicast { group, interface }, local_address, group_address))
+    }
+
+    /// Only accept received PDUs for the given exercise
+    pub fn with_exercise_id(mut self, exercise_id: u8) -> Self {
+        self.exercise_id = Some(exercise_id);
+        self
+    }
+
+    /// Only accept received PDUs of the given protocol version
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = Some(protocol_version);
+        self
+    }
+
+    pub fn with_multicast_ttl(mut self, multicast_ttl: u32) -> Self {
+        self.multicast_ttl = multicast_ttl;
+        self
+    }
+
+    /// Whether multicast datagrams sent by this host are looped back to the local sockets (default: true)
+    pub fn with_multicast_loop(mut self, multicast_loop: bool) -> Self {
+        self.multicast_loop = multicast_loop;
+        self
+    }
+
+    /// Timeout for `UdpEndpoint::recv`; by default `recv` blocks until a datagram arrives
+    pub fn with_read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Whether a received PDU passes the exercise id and protocol version filters
+    pub(crate) fn accepts(&self, pdu: &Pdu) -> bool {
+        self.exercise_id.map_or(true, |exercise_id| pdu.header.exercise_id == exercise_id)
+            && self.protocol_version.map_or(true, |version| pdu.header.protocol_version == version)
+    }
+}
+
+/// A blocking UDP socket that sends and receives DIS PDUs.
+///
+/// The socket is bound with SO_REUSEADDR, so multiple applications on the same host can receive
+/// broadcast or multicast traffic on the same port.
+pub struct UdpEndpoint {
+    socket: UdpSocket,
+    settings: UdpSettings,
+    buffer: Vec<u8>,
+}
+
+impl UdpEndpoint {
+    /// Creates and binds the socket, and joins the multicast group when configured.
+    pub fn new(settings: UdpSettings) -> io::Result<Self> {
+        let socket = bind_socket(&settings)?;
+        socket.set_read_timeout(settings.read_timeout)?;
+
+        Ok(Self {
+            socket,
+            settings,
+            buffer: vec![0; MAX_DATAGRAM_SIZE],
+        })
+    }
+
+    pub fn settings(&self) -> &UdpSettings {
+        &self.settings
+    }
+
+    /// The local address the socket is bound to, e.g. to find out the port when binding to port 0.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Sends a PDU to the remote address.
+    /// The PDU Length and the (absolute) timestamp in the header are filled in before the PDU is serialized.
+    /// A PDU that cannot be serialized results in an error of kind `InvalidInput`.
+    pub fn send(&self, pdu: &mut Pdu) -> io::Result<usize> {
+        stamp_header(pdu);
+
+        let mut buf = vec![0u8; pdu.header.pdu_length as usize];
+        pdu.try_serialize_to_slice(&mut buf)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))?;
+        self.socket.send_to(&buf, self.settings.remote_address)
+    }
+
+    /// Sends an already serialized datagram, such as a bundle of PDUs produced by a `Bundler`, to the remote address.
+    pub fn send_datagram(&self, datagram: &[u8]) -> io::Result<usize> {
+        self.socket.send_to(datagram, self.settings.remote_address)
+    }
+
+    /// Receives a single datagram and returns the PDUs it contains that pass the exercise id and protocol version filters.
+    /// Blocks until a datagram arrives, or the read timeout elapses (resulting in an error of kind `WouldBlock` or `TimedOut`).
+    /// A datagram that cannot be parsed results in an error of kind `InvalidData`.
+    pub fn recv(&mut self) -> io::Result<Vec<Pdu>> {
+        let (length, _source) = self.socket.recv_from(&mut self.buffer)?;
+        let pdus = parse(&self.buffer[..length])
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+        Ok(pdus.into_iter()
+            .filter(|pdu| self.settings.accepts(pdu))
+            .collect())
+    }
+}
+
+/// Creates a socket bound according to the settings, and joins the multicast group when configured.
+pub(crate) fn bind_socket(settings: &UdpSettings) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::for_address(settings.local_address), Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    socket.bind(&settings.local_address.into())?;
+
+    match settings.mode {
+        UdpMode::Unicast => { }
+        UdpMode::Broadcast => { socket.set_broadcast(true)?; }
+        UdpMode::Multicast { group, interface } => {
+            socket.join_multicast_v4(&group, &interface)?;
+            socket.set_multicast_if_v4(&interface)?;
+            socket.set_multicast_loop_v4(settings.multicast_loop)?;
+            socket.set_multicast_ttl_v4(settings.multicast_ttl)?;
+        }
+    }
+
+    Ok(socket.into())
+}
+
+/// Fills in the PDU Length and the current (absolute) timestamp in the header of a PDU that is about to be sent.
+pub(crate) fn stamp_header(pdu: &mut Pdu) {
+    pdu.header = pdu.header
+        .with_length(pdu.body.body_length())
+        .with_time_stamp(TimeStamp::from(DisTimeStamp::now_absolute()));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::time::Duration;
+    use crate::common::bundler::Bundler;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::model::{EntityId, EntityType, Pdu, PduBody, PduHeader};
+    use crate::common::udp::{UdpEndpoint, UdpSettings};
+    use crate::enumerations::{EntityKind, ForceId, PduType};
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    fn loopback() -> SocketAddr {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)
+    }
+
+    fn entity_state(header: PduHeader, entity_number: u16) -> Pdu {
+        let body = EntityState::new(EntityId::new(1, 1, entity_number), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .into_pdu_body();
+        Pdu::finalize_from_parts(header, body, 0)
+    }
+
+    fn entity_number(pdu: &Pdu) -> u16 {
+        if let PduBody::EntityState(body) = &pdu.body {
+            body.entity_id.entity_id
+        } else { panic!("Expected an EntityState PDU") }
+    }
+
+    #[test]
+    fn unicast_send_and_receive() {
+        let mut receiver = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), loopback())
+            .with_read_timeout(TIMEOUT)).unwrap();
+        let sender = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), receiver.local_addr().unwrap())).unwrap();
+
+        let mut pdu = entity_state(PduHeader::new_v7(1, PduType::EntityState), 1);
+        pdu.header.pdu_length = 0;
+        sender.send(&mut pdu).unwrap();
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].header.pdu_length, pdu.header.pdu_length);
+        assert_eq!(received[0].header.time_stamp, pdu.header.time_stamp);
+        assert_eq!(received[0].header.time_stamp & 1, 1);
+        assert_eq!(entity_number(&received[0]), 1);
+    }
+
+    #[test]
+    fn send_and_receive_bundle() {
+        let mut receiver = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), loopback())
+            .with_read_timeout(TIMEOUT)).unwrap();
+        let sender = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), receiver.local_addr().unwrap())).unwrap();
+
+        let mut bundler = Bundler::default();
+        for entity in 1..=3 {
+            assert!(bundler.add(&entity_state(PduHeader::new_v7(1, PduType::EntityState), entity), 0.0).unwrap().is_empty());
+        }
+        sender.send_datagram(&bundler.flush().unwrap()).unwrap();
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.iter().map(entity_number).collect::<Vec<u16>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn filter_exercise_id_and_protocol_version() {
+        let mut receiver = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), loopback())
+            .with_exercise_id(2)
+            .with_protocol_version(crate::enumerations::ProtocolVersion::IEEE1278_12012)
+            .with_read_timeout(TIMEOUT)).unwrap();
+        let sender = UdpEndpoint::new(UdpSettings::new_unicast(loopback(), receiver.local_addr().unwrap())).unwrap();
+
+        sender.send(&mut entity_state(PduHeader::new_v7(1, PduType::EntityState), 1)).unwrap();
+        sender.send(&mut entity_state(PduHeader::new_v6(2, PduType::EntityState), 2)).unwrap();
+        sender.send(&mut entity_state(PduHeader::new_v7(2, PduType::EntityState), 3)).unwrap();
+
+        assert!(receiver.recv().unwrap().is_empty());
+        assert!(receiver.recv().unwrap().is_empty());
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(entity_number(&received[0]), 3);
+    }
+
+    #[test]
+    fn broadcast_send_and_receive() {
+        let any = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0);
+        let mut receiver = UdpEndpoint::new(UdpSettings::new_broadcast(any, loopback())
+            .with_read_timeout(TIMEOUT)).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+        let sender = UdpEndpoint::new(UdpSettings::new_broadcast(
+            loopback(), SocketAddr::new(Ipv4Addr::new(127, 255, 255, 255).into(), port))).unwrap();
+
+        sender.send(&mut entity_state(PduHeader::new_v6(1, PduType::EntityState), 4)).unwrap();
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(entity_number(&received[0]), 4);
+    }
+
+    #[test]
+    fn multicast_send_and_receive() {
+        let group = SocketAddr::new(Ipv4Addr::new(239, 1, 2, 3).into(), 30_123);
+        let mut receiver = UdpEndpoint::new(UdpSettings::new_multicast(group, Ipv4Addr::LOCALHOST).unwrap()
+            .with_read_timeout(TIMEOUT)).unwrap();
+        let sender = UdpEndpoint::new(UdpSettings::new_multicast(group, Ipv4Addr::LOCALHOST).unwrap()).unwrap();
+
+        sender.send(&mut entity_state(PduHeader::new_v7(1, PduType::EntityState), 5)).unwrap();
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(entity_number(&received[0]), 5);
+    }
+
+    #[test]
+    fn multicast_rejects_ipv6_group() {
+        let group = SocketAddr::new(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1).into(), 30_123);
+        let error = UdpSettings::new_multicast(group, Ipv4Addr::LOCALHOST).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,63 @@
+pub mod parser;
+pub mod model;
+pub mod writer;
+pub mod builder;
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::underwater_acoustic::model::{AdditionalPassiveActivity, PropulsionPlantConfiguration, Shaft, UABeam, UAEmitterSystem, UAFundamentalParameterData, UnderwaterAcoustic};
+    use crate::common::model::{DisTimeStamp, EntityId, EventId, Pdu, PduHeader, SimulationAddress, VectorF32};
+    use crate::enumerations::{PduType, UAAcousticEmitterSystemFunction, UAAcousticSystemName, UAActiveEmissionParameterIndex, UAPassiveParameterIndex, UAScanPattern, UAStateChangeUpdateIndicator};
+    use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
+
+    #[test]
+    fn underwater_acoustic_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::UnderwaterAcoustic);
+
+        let body = UnderwaterAcoustic::builder()
+            .with_emitting_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 1), 1))
+            .with_state_change_update_indicator(UAStateChangeUpdateIndicator::from(1u8))
+            .with_passive_parameter_index(UAPassiveParameterIndex::from(1u16))
+            .with_propulsion_plant_configuration(PropulsionPlantConfiguration::new(2, true))
+            .with_shaft(Shaft::new(120, 150, 5))
+            .with_shaft(Shaft::new(-30, 0, -2))
+            .with_additional_passive_activity(AdditionalPassiveActivity::new(3, 12))
+            .with_emitter_system(UAEmitterSystem::new()
+                .with_acoustic_system_name(UAAcousticSystemName::from(1u16))
+                .with_acoustic_function(UAAcousticEmitterSystemFunction::from(1u8))
+                .with_acoustic_id_number(1)
+                .with_location(VectorF32::new(1.0, 2.0, 3.0))
+                .with_beam(UABeam::new()
+                    .with_beam_id_number(1)
+                    .with_fundamental_parameters(UAFundamentalParameterData::new()
+                        .with_active_emission_parameter_index(UAActiveEmissionParameterIndex::from(1u16))
+                        .with_scan_pattern(UAScanPattern::from(1u16))
+                        .with_beam_center_azimuth(0.5)
+                        .with_azimuthal_beamwidth(0.1)
+                        .with_beam_center_depression_elevation(-0.2)
+                        .with_depression_elevation_beamwidth(0.1))))
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        original_pdu.serialize(&mut buf);
+        assert_eq!(pdu_length as usize, buf.len());
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref _err) => {
+                println!("{_err}");
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::common::model::{EntityId, EventId, PduBody, VectorF32};
+use crate::common::underwater_acoustic::builder::UnderwaterAcousticBuilder;
+use crate::enumerations::{PduType, UAAcousticEmitterSystemFunction, UAAcousticSystemName, UAActiveEmissionParameterIndex, UAPassiveParameterIndex, UAScanPattern, UAStateChangeUpdateIndicator};
+
+const BASE_UNDERWATER_ACOUSTIC_BODY_LENGTH: u16 = 20;
+const SHAFT_LENGTH: u16 = 8;
+const APA_LENGTH: u16 = 4;
+const UA_EMITTER_SYSTEM_BASE_LENGTH: u16 = 20;
+const UA_BEAM_LENGTH: u16 = 24;
+
+const HULL_MOUNTED_MASKER_FLAG: u8 = 0x80;
+const PROPULSION_PLANT_CONFIGURATION_MASK: u8 = 0x7F;
+
+/// 5.7.5 Underwater Acoustic (UA) PDU
+#[derive(Debug, Default, PartialEq)]
+pub struct UnderwaterAcoustic {
+    pub emitting_entity_id: EntityId,
+    pub event_id: EventId,
+    pub state_change_update_indicator: UAStateChangeUpdateIndicator,
+    pub passive_parameter_index: UAPassiveParameterIndex,
+    pub propulsion_plant_configuration: PropulsionPlantConfiguration,
+    pub shafts: Vec<Shaft>,
+    pub additional_passive_activities: Vec<AdditionalPassiveActivity>,
+    pub emitter_systems: Vec<UAEmitterSystem>,
+}
+
+impl UnderwaterAcoustic {
+    pub fn builder() -> UnderwaterAcousticBuilder {
+        UnderwaterAcousticBuilder::new()
+    }
+
+    pub fn into_builder(self) -> UnderwaterAcousticBuilder {
+        UnderwaterAcousticBuilder::new_from_body(self)
+    }
+
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::UnderwaterAcoustic(self)
+    }
+}
+
+impl BodyInfo for UnderwaterAcoustic {
+    fn body_length(&self) -> u16 {
+        BASE_UNDERWATER_ACOUSTIC_BODY_LENGTH
+            + (SHAFT_LENGTH * self.shafts.len() as u16)
+            + (APA_LENGTH * self.additional_passive_activities.len() as u16)
+            + self.emitter_systems.iter()
+            .map(|system| system.system_data_length_bytes())
+            .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::UnderwaterAcoustic
+    }
+}
+
+impl Interaction for UnderwaterAcoustic {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.emitting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Propulsion Plant Configuration record (6.2.71)
+///
+/// The configuration is kept as the raw 7-bit value of the UA-Propulsion Plant Configuration enumeration (SISO-REF-010).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PropulsionPlantConfiguration {
+    pub configuration: u8,
+    pub hull_mounted_masker_on: bool,
+}
+
+impl PropulsionPlantConfiguration {
+    pub fn new(configuration: u8, hull_mounted_masker_on: bool) -> Self {
+        Self {
+            configuration,
+            hull_mounted_masker_on,
+        }
+    }
+}
+
+impl From<u8> for PropulsionPlantConfiguration {
+    fn from(value: u8) -> Self {
+        Self {
+            configuration: value & PROPULSION_PLANT_CONFIGURATION_MASK,
+            hull_mounted_masker_on: value & HULL_MOUNTED_MASKER_FLAG != 0,
+        }
+    }
+}
+
+impl From<&PropulsionPlantConfiguration> for u8 {
+    fn from(value: &PropulsionPlantConfiguration) -> Self {
+        let masker = if value.hull_mounted_masker_on { HULL_MOUNTED_MASKER_FLAG } else { 0 };
+        (value.configuration & PROPULSION_PLANT_CONFIGURATION_MASK) | masker
+    }
+}
+
+/// Shaft RPMs record (6.2.80), in revolutions per minute.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Shaft {
+    pub current_rpm: i16,
+    pub ordered_rpm: i16,
+    pub rpm_rate_of_change: i32,
+}
+
+impl Shaft {
+    pub fn new(current_rpm: i16, ordered_rpm: i16, rpm_rate_of_change: i32) -> Self {
+        Self {
+            current_rpm,
+            ordered_rpm,
+            rpm_rate_of_change,
+        }
+    }
+}
+
+/// Additional Passive Activity (APA) record (UA PDU).
+///
+/// The parameter index is kept as the raw 16-bit value, including its state bits.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AdditionalPassiveActivity {
+    pub parameter_index: u16,
+    pub value: i16,
+}
+
+impl AdditionalPassiveActivity {
+    pub fn new(parameter_index: u16, value: i16) -> Self {
+        Self {
+            parameter_index,
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct UAEmitterSystem {
+    pub acoustic_system_name: UAAcousticSystemName,
+    pub acoustic_function: UAAcousticEmitterSystemFunction,
+    pub acoustic_id_number: u8,
+    pub location: VectorF32,
+    pub beams: Vec<UABeam>,
+}
+
+impl UAEmitterSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_acoustic_system_name(mut self, acoustic_system_name: UAAcousticSystemName) -> Self {
+        self.acoustic_system_name = acoustic_system_name;
+        self
+    }
+
+    pub fn with_acoustic_function(mut self, acoustic_function: UAAcousticEmitterSystemFunction) -> Self {
+        self.acoustic_function = acoustic_function;
+        self
+    }
+
+    pub fn with_acoustic_id_number(mut self, acoustic_id_number: u8) -> Self {
+        self.acoustic_id_number = acoustic_id_number;
+        self
+    }
+
+    pub fn with_location(mut self, location: VectorF32) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn with_beams(mut self, beams: &mut Vec<UABeam>) -> Self {
+        self.beams.append(beams);
+        self
+    }
+
+    pub fn with_beam(mut self, beam: UABeam) -> Self {
+        self.beams.push(beam);
+        self
+    }
+
+    pub fn system_data_length_bytes(&self) -> u16 {
+        UA_EMITTER_SYSTEM_BASE_LENGTH + (UA_BEAM_LENGTH * self.beams.len() as u16)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct UABeam {
+    pub beam_id_number: u8,
+    pub fundamental_parameters: UAFundamentalParameterData,
+}
+
+impl UABeam {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_beam_id_number(mut self, beam_id_number: u8) -> Self {
+        self.beam_id_number = beam_id_number;
+        self
+    }
+
+    pub fn with_fundamental_parameters(mut self, fundamental_parameters: UAFundamentalParameterData) -> Self {
+        self.fundamental_parameters = fundamental_parameters;
+        self
+    }
+
+    pub fn beam_data_length_bytes(&self) -> u16 {
+        UA_BEAM_LENGTH
+    }
+}
+
+/// UA Fundamental Parameter Data record (6.2.91); angles are in radians.
+#[derive(Debug, Default, PartialEq)]
+pub struct UAFundamentalParameterData {
+    pub active_emission_parameter_index: UAActiveEmissionParameterIndex,
+    pub scan_pattern: UAScanPattern,
+    pub beam_center_azimuth: f32,
+    pub azimuthal_beamwidth: f32,
+    pub beam_center_depression_elevation: f32,
+    pub depression_elevation_beamwidth: f32,
+}
+
+impl UAFundamentalParameterData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_active_emission_parameter_index(mut self, active_emission_parameter_index: UAActiveEmissionParameterIndex) -> Self {
+        self.active_emission_parameter_index = active_emission_parameter_index;
+        self
+    }
+
+    pub fn with_scan_pattern(mut self, scan_pattern: UAScanPattern) -> Self {
+        self.scan_pattern = scan_pattern;
+        self
+    }
+
+    pub fn with_beam_center_azimuth(mut self, beam_center_azimuth: f32) -> Self {
+        self.beam_center_azimuth = beam_center_azimuth;
+        self
+    }
+
+    pub fn with_azimuthal_beamwidth(mut self, azimuthal_beamwidth: f32) -> Self {
+        self.azimuthal_beamwidth = azimuthal_beamwidth;
+        self
+    }
+
+    pub fn with_beam_center_depression_elevation(mut self, beam_center_depression_elevation: f32) -> Self {
+        self.beam_center_depression_elevation = beam_center_depression_elevation;
+        self
+    }
+
+    pub fn with_depression_elevation_beamwidth(mut self, depression_elevation_beamwidth: f32) -> Self {
+        self.depression_elevation_beamwidth = depression_elevation_beamwidth;
+        self
+    }
+}
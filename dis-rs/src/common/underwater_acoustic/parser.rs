@@ -0,0 +1,103 @@
+use crate::common::parser::IResult;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_i16, be_i32, be_u16, be_u8};
+use crate::common::model::PduBody;
+use crate::common::parser::{entity_id, event_id, vec3_f32};
+use crate::common::underwater_acoustic::model::{AdditionalPassiveActivity, PropulsionPlantConfiguration, Shaft, UABeam, UAEmitterSystem, UAFundamentalParameterData, UnderwaterAcoustic};
+use crate::enumerations::{UAAcousticEmitterSystemFunction, UAAcousticSystemName, UAActiveEmissionParameterIndex, UAPassiveParameterIndex, UAScanPattern, UAStateChangeUpdateIndicator};
+
+pub fn underwater_acoustic_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    let (input, emitting_entity_id) = context("Emitting Entity ID", entity_id)(input)?;
+    let (input, event_id) = context("Event ID", event_id)(input)?;
+    let (input, state_change_update_indicator) = context("State Change Update Indicator", be_u8)(input)?;
+    let (input, _padding) = context("Padding", be_u8)(input)?;
+    let (input, passive_parameter_index) = context("Passive Parameter Index", be_u16)(input)?;
+    let (input, propulsion_plant_configuration) = context("Propulsion Plant Configuration", be_u8)(input)?;
+    let (input, number_of_shafts) = context("Number of Shafts", be_u8)(input)?;
+    let (input, number_of_apas) = context("Number of APAs", be_u8)(input)?;
+    let (input, number_of_systems) = context("Number of Emitter Systems", be_u8)(input)?;
+    let (input, shafts) = context("Shaft Records", count(shaft, number_of_shafts as usize))(input)?;
+    let (input, apas) = context("APA Records", count(additional_passive_activity, number_of_apas as usize))(input)?;
+    let (input, systems) = context("Emitter Systems", count(ua_emitter_system, number_of_systems as usize))(input)?;
+
+    let body = UnderwaterAcoustic::builder()
+        .with_emitting_entity_id(emitting_entity_id)
+        .with_event_id(event_id)
+        .with_state_change_update_indicator(UAStateChangeUpdateIndicator::from(state_change_update_indicator))
+        .with_passive_parameter_index(UAPassiveParameterIndex::from(passive_parameter_index))
+        .with_propulsion_plant_configuration(PropulsionPlantConfiguration::from(propulsion_plant_configuration))
+        .with_shafts(shafts)
+        .with_additional_passive_activities(apas)
+        .with_emitter_systems(systems)
+        .build();
+
+    Ok((input, body.into_pdu_body()))
+}
+
+pub(crate) fn shaft(input: &[u8]) -> IResult<&[u8], Shaft> {
+    let (input, current_rpm) = be_i16(input)?;
+    let (input, ordered_rpm) = be_i16(input)?;
+    let (input, rpm_rate_of_change) = be_i32(input)?;
+
+    Ok((input, Shaft::new(current_rpm, ordered_rpm, rpm_rate_of_change)))
+}
+
+pub(crate) fn additional_passive_activity(input: &[u8]) -> IResult<&[u8], AdditionalPassiveActivity> {
+    let (input, parameter_index) = be_u16(input)?;
+    let (input, value) = be_i16(input)?;
+
+    Ok((input, AdditionalPassiveActivity::new(parameter_index, value)))
+}
+
+pub(crate) fn ua_emitter_system(input: &[u8]) -> IResult<&[u8], UAEmitterSystem> {
+    let (input, _system_data_length) = be_u8(input)?;
+    let (input, number_of_beams) = be_u8(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, acoustic_system_name) = be_u16(input)?;
+    let (input, acoustic_function) = be_u8(input)?;
+    let (input, acoustic_id_number) = be_u8(input)?;
+    let (input, location) = vec3_f32(input)?;
+    let (input, mut beams) = count(ua_beam, number_of_beams as usize)(input)?;
+
+    let system = UAEmitterSystem::new()
+        .with_acoustic_system_name(UAAcousticSystemName::from(acoustic_system_name))
+        .with_acoustic_function(UAAcousticEmitterSystemFunction::from(acoustic_function))
+        .with_acoustic_id_number(acoustic_id_number)
+        .with_location(location)
+        .with_beams(&mut beams);
+
+    Ok((input, system))
+}
+
+pub(crate) fn ua_beam(input: &[u8]) -> IResult<&[u8], UABeam> {
+    let (input, _beam_data_length) = be_u8(input)?;
+    let (input, beam_id_number) = be_u8(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, fundamental_parameters) = ua_fundamental_parameter_data(input)?;
+
+    let beam = UABeam::new()
+        .with_beam_id_number(beam_id_number)
+        .with_fundamental_parameters(fundamental_parameters);
+
+    Ok((input, beam))
+}
+
+fn ua_fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], UAFundamentalParameterData> {
+    let (input, active_emission_parameter_index) = be_u16(input)?;
+    let (input, scan_pattern) = be_u16(input)?;
+    let (input, beam_center_azimuth) = be_f32(input)?;
+    let (input, azimuthal_beamwidth) = be_f32(input)?;
+    let (input, beam_center_depression_elevation) = be_f32(input)?;
+    let (input, depression_elevation_beamwidth) = be_f32(input)?;
+
+    let parameters = UAFundamentalParameterData::new()
+        .with_active_emission_parameter_index(UAActiveEmissionParameterIndex::from(active_emission_parameter_index))
+        .with_scan_pattern(UAScanPattern::from(scan_pattern))
+        .with_beam_center_azimuth(beam_center_azimuth)
+        .with_azimuthal_beamwidth(azimuthal_beamwidth)
+        .with_beam_center_depression_elevation(beam_center_depression_elevation)
+        .with_depression_elevation_beamwidth(depression_elevation_beamwidth);
+
+    Ok((input, parameters))
+}
@@ -0,0 +1,92 @@
+use bytes::BufMut;
+use crate::common::underwater_acoustic::model::{AdditionalPassiveActivity, Shaft, UABeam, UAEmitterSystem, UAFundamentalParameterData, UnderwaterAcoustic};
+use crate::common::{Serialize, SerializePdu, SupportedVersion};
+
+impl SerializePdu for UnderwaterAcoustic {
+    fn serialize_pdu<B: BufMut>(&self, _version: SupportedVersion, buf: &mut B) -> u16 {
+        let entity_bytes = self.emitting_entity_id.serialize(buf);
+        let event_bytes = self.event_id.serialize(buf);
+        buf.put_u8(self.state_change_update_indicator.into());
+        buf.put_u8(0u8);
+        buf.put_u16(self.passive_parameter_index.into());
+        buf.put_u8((&self.propulsion_plant_configuration).into());
+        buf.put_u8(self.shafts.len() as u8);
+        buf.put_u8(self.additional_passive_activities.len() as u8);
+        buf.put_u8(self.emitter_systems.len() as u8);
+
+        let shafts_bytes = self.shafts.iter()
+            .map(|shaft| shaft.serialize(buf))
+            .sum::<u16>();
+        let apas_bytes = self.additional_passive_activities.iter()
+            .map(|apa| apa.serialize(buf))
+            .sum::<u16>();
+        let systems_bytes = self.emitter_systems.iter()
+            .map(|system| system.serialize(buf))
+            .sum::<u16>();
+
+        entity_bytes + event_bytes + 8 + shafts_bytes + apas_bytes + systems_bytes
+    }
+}
+
+impl Serialize for Shaft {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_i16(self.current_rpm);
+        buf.put_i16(self.ordered_rpm);
+        buf.put_i32(self.rpm_rate_of_change);
+
+        8
+    }
+}
+
+impl Serialize for AdditionalPassiveActivity {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.parameter_index);
+        buf.put_i16(self.value);
+
+        4
+    }
+}
+
+impl Serialize for UAEmitterSystem {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let system_length_in_words = self.system_data_length_bytes() / 4;
+        buf.put_u8(system_length_in_words as u8);
+        buf.put_u8(self.beams.len() as u8);
+        buf.put_u16(0u16);
+        buf.put_u16(self.acoustic_system_name.into());
+        buf.put_u8(self.acoustic_function.into());
+        buf.put_u8(self.acoustic_id_number);
+        let location_bytes = self.location.serialize(buf);
+
+        let beams_bytes = self.beams.iter()
+            .map(|beam| beam.serialize(buf))
+            .sum::<u16>();
+
+        8 + location_bytes + beams_bytes
+    }
+}
+
+impl Serialize for UABeam {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let beam_length_in_words = self.beam_data_length_bytes() / 4;
+        buf.put_u8(beam_length_in_words as u8);
+        buf.put_u8(self.beam_id_number);
+        buf.put_u16(0u16);
+        let parameter_bytes = self.fundamental_parameters.serialize(buf);
+
+        4 + parameter_bytes
+    }
+}
+
+impl Serialize for UAFundamentalParameterData {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.active_emission_parameter_index.into());
+        buf.put_u16(self.scan_pattern.into());
+        buf.put_f32(self.beam_center_azimuth);
+        buf.put_f32(self.azimuthal_beamwidth);
+        buf.put_f32(self.beam_center_depression_elevation);
+        buf.put_f32(self.depression_elevation_beamwidth);
+
+        20
+    }
+}
@@ -0,0 +1,281 @@
+use crate::common::parser::IResult;
+use crate::common::detonation::view::DetonationView;
+use crate::common::entity_state::view::EntityStateView;
+use crate::common::errors::DisError;
+use crate::common::fire::view::FireView;
+use crate::common::model::{Pdu, PduHeader};
+use crate::common::parser::{bundle_padding, parse_header, parse_pdu};
+use crate::common::signal::view::SignalView;
+use crate::common::transmitter::view::TransmitterView;
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{DetonationTypeIndicator, FireTypeIndicator, PduType, ProtocolFamily, ProtocolVersion};
+use crate::v7::model::PduStatus;
+use crate::v7::parser::parse_pdu_status_fields;
+
+const PROTOCOL_VERSION_OFFSET: usize = 0;
+const EXERCISE_ID_OFFSET: usize = 1;
+const PDU_TYPE_OFFSET: usize = 2;
+const PROTOCOL_FAMILY_OFFSET: usize = 3;
+const TIME_STAMP_OFFSET: usize = 4;
+const PDU_LENGTH_OFFSET: usize = 8;
+const PDU_STATUS_OFFSET: usize = 10;
+
+/// A borrowed view over the bytes of a single PDU.
+///
+/// The view only checks that the input holds a complete header and the number of bytes stated in the PDU Length field.
+/// Header fields are decoded when accessed, so PDUs can be filtered on for instance the exercise id or PDU type
+/// without parsing the body. The body can be inspected through a `BodyView`, or fully parsed into a `Pdu`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PduView<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PduView<'a> {
+    /// Creates a view over the PDU at the start of `input`. Any bytes after the PDU are not part of the view.
+    pub fn new(input: &'a [u8]) -> Result<Self, DisError> {
+        if input.len() < PDU_HEADER_LEN_BYTES as usize {
+            return Err(DisError::InsufficientHeaderLength(input.len() as u16));
+        }
+        let pdu_length = read_u16(input, PDU_LENGTH_OFFSET) as usize;
+        if pdu_length < PDU_HEADER_LEN_BYTES as usize || pdu_length > input.len() {
+            return Err(DisError::InsufficientPduLength(
+                (pdu_length as u16).saturating_sub(PDU_HEADER_LEN_BYTES),
+                (input.len() - PDU_HEADER_LEN_BYTES as usize) as u16));
+        }
+
+        Ok(Self { bytes: &input[..pdu_length] })
+    }
+
+    /// Iterates over views of all PDUs in `input`, for instance a datagram containing multiple PDUs.
+    /// The iterator stops after the first PDU that is incomplete.
+    pub fn iter(input: &'a [u8]) -> PduViewIter<'a> {
+        PduViewIter { input }
+    }
+
+    /// The bytes of the complete PDU.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        ProtocolVersion::from(self.bytes[PROTOCOL_VERSION_OFFSET])
+    }
+
+    pub fn exercise_id(&self) -> u8 {
+        self.bytes[EXERCISE_ID_OFFSET]
+    }
+
+    pub fn pdu_type(&self) -> PduType {
+        PduType::from(self.bytes[PDU_TYPE_OFFSET])
+    }
+
+    pub fn protocol_family(&self) -> ProtocolFamily {
+        ProtocolFamily::from(self.bytes[PROTOCOL_FAMILY_OFFSET])
+    }
+
+    pub fn time_stamp(&self) -> u32 {
+        read_u32(self.bytes, TIME_STAMP_OFFSET)
+    }
+
+    pub fn pdu_length(&self) -> u16 {
+        read_u16(self.bytes, PDU_LENGTH_OFFSET)
+    }
+
+    /// The PDU Status field, which is only present in DIS v7 PDUs.
+    pub fn pdu_status(&self) -> Option<PduStatus> {
+        if self.protocol_version() == ProtocolVersion::IEEE1278_12012 {
+            Some(parse_pdu_status_fields(self.bytes[PDU_TYPE_OFFSET], self.bytes[PDU_STATUS_OFFSET]))
+        } else { None }
+    }
+
+    /// Decodes the complete header.
+    pub fn header(&self) -> Result<PduHeader, DisError> {
+        parse_header(self.bytes)
+    }
+
+    /// The bytes of the PDU body.
+    pub fn body(&self) -> &'a [u8] {
+        &self.bytes[PDU_HEADER_LEN_BYTES as usize..]
+    }
+
+    /// Provides a typed view over the body for the supported PDU types,
+    /// or the raw body bytes for other PDU types.
+    pub fn body_view(&self) -> Result<BodyView<'a>, DisError> {
+        let version = self.protocol_version();
+        let view = match self.pdu_type() {
+            PduType::EntityState => { BodyView::EntityState(EntityStateView::new(self.body(), version)?) }
+            PduType::Fire => {
+                let fire_type_indicator = self.pdu_status()
+                    .and_then(|status| status.fire_type_indicator)
+                    .unwrap_or(FireTypeIndicator::Munition);
+                BodyView::Fire(FireView::new(self.body(), fire_type_indicator)?)
+            }
+            PduType::Detonation => {
+                let detonation_type_indicator = self.pdu_status()
+                    .and_then(|status| status.detonation_type_indicator)
+                    .unwrap_or(DetonationTypeIndicator::Munition);
+                BodyView::Detonation(DetonationView::new(self.body(), detonation_type_indicator)?)
+            }
+            PduType::Signal => { BodyView::Signal(SignalView::new(self.body())?) }
+            PduType::Transmitter => { BodyView::Transmitter(TransmitterView::new(self.body(), version)?) }
+            _ => { BodyView::Other(self.body()) }
+        };
+        Ok(view)
+    }
+
+    /// Fully parses the PDU into an owned `Pdu`.
+    pub fn to_pdu(&self) -> Result<Pdu, DisError> {
+        parse_pdu(self.bytes)
+    }
+}
+
+/// Typed views over the body of a PDU, for the PDU types that support views.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BodyView<'a> {
+    EntityState(EntityStateView<'a>),
+    Fire(FireView<'a>),
+    Detonation(DetonationView<'a>),
+    Signal(SignalView<'a>),
+    Transmitter(TransmitterView<'a>),
+    Other(&'a [u8]),
+}
+
+/// Iterator over views of consecutive PDUs in a buffer, created with `PduView::iter`.
+/// Up to seven zero octets padding a PDU in a bundle to an 8-octet boundary are skipped.
+pub struct PduViewIter<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for PduViewIter<'a> {
+    type Item = Result<PduView<'a>, DisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        match PduView::new(self.input) {
+            Ok(view) => {
+                self.input = &self.input[view.bytes.len()..];
+                if let Ok((input, _padding)) = bundle_padding(self.input) {
+                    self.input = input;
+                }
+                Some(Ok(view))
+            }
+            Err(err) => {
+                self.input = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Checks that a body holds at least `minimum_length` octets.
+pub(crate) fn check_body_length(body: &[u8], minimum_length: usize) -> Result<(), DisError> {
+    if body.len() < minimum_length {
+        Err(DisError::InsufficientPduLength(minimum_length as u16, body.len() as u16))
+    } else { Ok(()) }
+}
+
+/// Decodes a field at `offset` using one of the regular parsers.
+/// Views check the length of their input when they are created, so decoding fixed-size fields cannot run out of input.
+pub(crate) fn decode<'a, O>(bytes: &'a [u8], offset: usize, parser: impl Fn(&'a [u8]) -> IResult<&'a [u8], O>) -> O {
+    let (_, value) = parser(&bytes[offset..])
+        .expect("Length of the view is checked on creation");
+    value
+}
+
+pub(crate) fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+pub(crate) fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+pub(crate) fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_bits(read_u32(bytes, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use crate::common::Serialize;
+    use crate::common::bundler::Bundler;
+    use crate::common::create_entity::model::CreateEntity;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::model::{EntityId, EntityType, Location, Pdu, PduHeader};
+    use crate::common::view::{BodyView, PduView};
+    use crate::enumerations::{EntityKind, ForceId, PduType};
+
+    fn entity_state_bytes(exercise_id: u8, entity_number: u16) -> Vec<u8> {
+        let body = EntityState::new(EntityId::new(1, 2, entity_number), ForceId::Friendly,
+                                    EntityType::default().with_kind(EntityKind::Platform))
+            .with_location(Location::new(1.0, 2.0, 3.0))
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(exercise_id, PduType::EntityState), body, 10);
+        let mut buf = BytesMut::new();
+        pdu.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    #[test]
+    fn header_view() {
+        let bytes = entity_state_bytes(3, 1);
+        let view = PduView::new(&bytes).unwrap();
+
+        assert_eq!(view.exercise_id(), 3);
+        assert_eq!(view.pdu_type(), PduType::EntityState);
+        assert_eq!(view.pdu_length() as usize, bytes.len());
+        assert_eq!(view.header().unwrap(), view.to_pdu().unwrap().header);
+        assert!(view.pdu_status().is_some());
+    }
+
+    #[test]
+    fn view_too_short() {
+        let bytes = entity_state_bytes(3, 1);
+        assert!(PduView::new(&bytes[..10]).is_err());
+        assert!(PduView::new(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn iterate_and_filter() {
+        let mut bytes = entity_state_bytes(1, 1);
+        bytes.extend(entity_state_bytes(2, 2));
+        bytes.extend(entity_state_bytes(1, 3));
+
+        let entity_numbers: Vec<u16> = PduView::iter(&bytes)
+            .filter_map(|view| view.ok())
+            .filter(|view| view.exercise_id() == 1)
+            .filter_map(|view| match view.body_view() {
+                Ok(BodyView::EntityState(entity_state)) => { Some(entity_state.entity_id().entity_id) }
+                _ => { None }
+            })
+            .collect();
+        assert_eq!(entity_numbers, vec![1, 3]);
+    }
+
+    #[test]
+    fn iterate_over_bundle() {
+        let mut bundler = Bundler::default();
+        let pdus: Vec<Pdu> = (1..=3).map(|request_id| {
+            let body = CreateEntity::builder()
+                .with_origination_id(EntityId::new(1, 1, 0))
+                .with_receiving_id(EntityId::new(1, 2, 0))
+                .with_request_id(request_id)
+                .build()
+                .into_pdu_body();
+            Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::CreateEntity), body, 0)
+        }).collect();
+        for pdu in &pdus {
+            assert!(bundler.add(pdu, 0.0).unwrap().is_empty());
+        }
+        let datagram = bundler.flush().unwrap();
+        // 28 octet PDUs, each but the last padded to 32 octets
+        assert_eq!(datagram.len(), 92);
+
+        let parsed: Vec<Pdu> = PduView::iter(&datagram)
+            .map(|view| view.unwrap().to_pdu().unwrap())
+            .collect();
+        assert_eq!(parsed, pdus);
+    }
+}
@@ -1,12 +1,18 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::model::{Pdu, PduBody, PduHeader};
-use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use crate::constants::{EIGHT_OCTETS, PDU_HEADER_LEN_BYTES};
-use crate::common::model::{ClockTime, DescriptorRecord, EntityId, EventId, FixedDatum, Location, MunitionDescriptor, Orientation, SimulationAddress, VariableDatum, VectorF32, ArticulatedPart, AttachedPart, BeamData, EntityAssociationParameter, EntityTypeParameter, length_padded_to_num_bytes, SeparationParameter, VariableParameter};
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion, TrySerialize};
+use crate::common::errors::DisError;
+use crate::common::iff::model::ModeSBasicData;
+use crate::common::aggregate_state::model::AGGREGATE_MARKING_STRING_LENGTH;
+use crate::common::directed_energy_fire::model::DirectedEnergyRecord;
+use crate::common::is_group_of::model::IsGroupOf;
+use crate::constants::{EIGHT_OCTETS, ELEVEN_OCTETS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES};
+use crate::fixed_parameters::MAX_PDU_SIZE_OCTETS;
+use crate::common::model::{ClockTime, DescriptorRecord, EntityId, EventId, FixedDatum, Location, MunitionDescriptor, Orientation, SimulationAddress, VariableDatum, VectorF32, ArticulatedPart, AttachedPart, BeamData, EntityAssociationParameter, EntityTypeParameter, length_padded_to_num_bytes, RecordSet, RecordSpecification, SeparationParameter, SupplyQuantity, VariableParameter, PerimeterPoint, DataFilter, ObjectType, GeneralObjectAppearance, ObjectStateModification, LiveEntityId, LiveEventId, LiveEntityRelativeWorldCoordinates, LiveEntityVector, LiveEntityOrientation, LiveEntityPositionError, LiveEntityOrientationError, LiveDeadReckoningParameters, IoRecord, IoCommunicationsNode, IoEffect, IO_RECORD_HEADER_LENGTH};
 use crate::enumerations::{ProtocolVersion, VariableParameterRecordType};
 
 impl Serialize for PduHeader {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.protocol_version.into());
         buf.put_u8(self.exercise_id);
         buf.put_u8(self.pdu_type.into());
@@ -28,21 +34,21 @@ impl Serialize for PduHeader {
 }
 
 impl Serialize for Pdu {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let header_size = self.header.serialize(buf);
         let version: SupportedVersion = self.header.protocol_version.into();
         let body_size = match &self.body {
-            PduBody::Other(body) => { body.serialize_pdu(version, buf) } // TODO check if buffer capacity is enough for the body of an 'Other' PDU; perhaps make Serialize trait fallible
+            PduBody::Other(body) => { body.serialize_pdu(version, buf) } // Use TrySerialize to check the size of an 'Other' PDU before writing
             PduBody::EntityState(body) => { body.serialize_pdu(version, buf) }
             PduBody::Fire(body) => { body.serialize_pdu(version, buf) }
             PduBody::Detonation(body) => { body.serialize_pdu(version, buf) }
             PduBody::Collision(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ServiceRequest(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ResupplyOffer(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ResupplyReceived(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ResupplyCancel(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::RepairComplete(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::RepairResponse(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ServiceRequest(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ResupplyOffer(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ResupplyReceived(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ResupplyCancel(body) => { body.serialize_pdu(version, buf) }
+            PduBody::RepairComplete(body) => { body.serialize_pdu(version, buf) }
+            PduBody::RepairResponse(body) => { body.serialize_pdu(version, buf) }
             PduBody::CreateEntity(body) => { body.serialize_pdu(version, buf) }
             PduBody::RemoveEntity(body) => { body.serialize_pdu(version, buf) }
             PduBody::StartResume(body) => { body.serialize_pdu(version, buf) }
@@ -61,58 +67,297 @@ impl Serialize for Pdu {
             PduBody::Signal(body) => { body.serialize_pdu(version, buf) }
             PduBody::Receiver(body) => { body.serialize_pdu(version, buf) }
             PduBody::IFF(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::UnderwaterAcoustic(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::SupplementalEmissionEntityState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IntercomSignal(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IntercomControl(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::AggregateState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IsGroupOf(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::TransferOwnership(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IsPartOf(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldQuery(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldResponseNACK(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EnvironmentalProcess(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::GriddedData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::PointObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LinearObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArealObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::TSPI(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::Appearance(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArticulatedParts(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEDetonation(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::CreateEntityR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::RemoveEntityR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::StartResumeR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::StopFreezeR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::AcknowledgeR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ActionRequestR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ActionResponseR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::DataQueryR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::SetDataR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::DataR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EventReportR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::CommentR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::RecordR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::SetRecordR(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::RecordQueryR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::UnderwaterAcoustic(body) => { body.serialize_pdu(version, buf) }
+            PduBody::SupplementalEmissionEntityState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IntercomSignal(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IntercomControl(body) => { body.serialize_pdu(version, buf) }
+            PduBody::AggregateState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IsGroupOf(body) => { body.serialize_pdu(version, buf) }
+            PduBody::TransferOwnership(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IsPartOf(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldQuery(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldData(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldResponseNACK(body) => { body.serialize_pdu(version, buf) }
+            PduBody::EnvironmentalProcess(body) => { body.serialize_pdu(version, buf) }
+            PduBody::GriddedData(body) => { body.serialize_pdu(version, buf) }
+            PduBody::PointObjectState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::LinearObjectState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ArealObjectState(body) => { body.serialize_pdu(version, buf) }
+            PduBody::TSPI(body) => { body.serialize_pdu(version, buf) }
+            PduBody::Appearance(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ArticulatedParts(body) => { body.serialize_pdu(version, buf) }
+            PduBody::LEFire(body) => { body.serialize_pdu(version, buf) }
+            PduBody::LEDetonation(body) => { body.serialize_pdu(version, buf) }
+            PduBody::CreateEntityR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::RemoveEntityR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::StartResumeR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::StopFreezeR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::AcknowledgeR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ActionRequestR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::ActionResponseR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::DataQueryR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::SetDataR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::DataR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::EventReportR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::CommentR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::RecordR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::SetRecordR(body) => { body.serialize_pdu(version, buf) }
+            PduBody::RecordQueryR(body) => { body.serialize_pdu(version, buf) }
             PduBody::CollisionElastic(body) => { body.serialize_pdu(version, buf) }
             PduBody::EntityStateUpdate(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::DirectedEnergyFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EntityDamageStatus(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsAction(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsReport(body) => { body.serialize_pdu(version, buf) }
+            PduBody::DirectedEnergyFire(body) => { body.serialize_pdu(version, buf) }
+            PduBody::EntityDamageStatus(body) => { body.serialize_pdu(version, buf) }
+            PduBody::InformationOperationsAction(body) => { body.serialize_pdu(version, buf) }
+            PduBody::InformationOperationsReport(body) => { body.serialize_pdu(version, buf) }
             PduBody::Attribute(body) => { body.serialize_pdu(version, buf) }
-            _ => { 0 }
         };
         header_size + body_size
     }
 }
 
+impl TrySerialize for Pdu {
+    fn try_serialize<B: BufMut>(&self, buf: &mut B) -> Result<usize, DisError> {
+        validate_body(&self.body)?;
+
+        let pdu_length = PDU_HEADER_LEN_BYTES as usize + self.body.body_length() as usize;
+        if pdu_length > MAX_PDU_SIZE_OCTETS as usize {
+            return Err(DisError::PduTooLarge(pdu_length));
+        }
+        if self.header.pdu_length as usize != pdu_length {
+            return Err(DisError::PduLengthMismatch(pdu_length as u16, self.header.pdu_length));
+        }
+        if buf.remaining_mut() < pdu_length {
+            return Err(DisError::InsufficientBufferSize(pdu_length, buf.remaining_mut()));
+        }
+
+        let remaining_before = buf.remaining_mut();
+        self.serialize(buf);
+        let written = remaining_before - buf.remaining_mut();
+        if written != pdu_length {
+            return Err(DisError::PduLengthMismatch(pdu_length as u16, written as u16));
+        }
+        Ok(written)
+    }
+}
+
+/// Checks the parts of a PduBody that cannot be represented on the wire: strings that are not ASCII or too long,
+/// and lists of records that do not fit their count field.
+fn validate_body(body: &PduBody) -> Result<(), DisError> {
+    match body {
+        PduBody::Other(body) if body.body.len() > MAX_PDU_SIZE_OCTETS as usize => {
+            return Err(DisError::PduTooLarge(PDU_HEADER_LEN_BYTES as usize + body.body.len()));
+        }
+        PduBody::EntityState(body) => {
+            check_record_count(body.variable_parameters.len(), u8::MAX as usize)?;
+            check_ascii_string(&body.entity_marking.marking_string, ELEVEN_OCTETS)?;
+        }
+        PduBody::EntityStateUpdate(body) => { check_record_count(body.variable_parameters.len(), u8::MAX as usize)?; }
+        PduBody::Detonation(body) => { check_record_count(body.variable_parameters.len(), u8::MAX as usize)?; }
+        PduBody::ServiceRequest(body) => { check_record_count(body.supplies.len(), u8::MAX as usize)?; }
+        PduBody::ResupplyOffer(body) => { check_record_count(body.supplies.len(), u8::MAX as usize)?; }
+        PduBody::ResupplyReceived(body) => { check_record_count(body.supplies.len(), u8::MAX as usize)?; }
+        PduBody::ActionRequest(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::ActionResponse(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::DataQuery(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::SetData(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::Data(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::EventReport(body) => { check_datum_counts(&body.fixed_datum_records, &body.variable_datum_records)?; }
+        PduBody::Comment(body) => { check_record_count(body.variable_datum_records.len(), u32::MAX as usize)?; }
+        PduBody::ActionRequestR(body) => { check_datum_counts(&body.action_request.fixed_datum_records, &body.action_request.variable_datum_records)?; }
+        PduBody::ActionResponseR(body) => { check_datum_counts(&body.action_response.fixed_datum_records, &body.action_response.variable_datum_records)?; }
+        PduBody::DataQueryR(body) => { check_datum_counts(&body.data_query.fixed_datum_records, &body.data_query.variable_datum_records)?; }
+        PduBody::SetDataR(body) => { check_datum_counts(&body.set_data.fixed_datum_records, &body.set_data.variable_datum_records)?; }
+        PduBody::DataR(body) => { check_datum_counts(&body.data.fixed_datum_records, &body.data.variable_datum_records)?; }
+        PduBody::EventReportR(body) => { check_datum_counts(&body.event_report.fixed_datum_records, &body.event_report.variable_datum_records)?; }
+        PduBody::CommentR(body) => { check_record_count(body.comment.variable_datum_records.len(), u32::MAX as usize)?; }
+        PduBody::RecordR(body) => { check_record_specification(&body.record_specification)?; }
+        PduBody::SetRecordR(body) => { check_record_specification(&body.record_specification)?; }
+        PduBody::RecordQueryR(body) => { check_record_count(body.record_ids.len(), u32::MAX as usize)?; }
+        PduBody::ElectromagneticEmission(body) => {
+            check_record_count(body.emitter_systems.len(), u8::MAX as usize)?;
+            for system in &body.emitter_systems {
+                check_record_count(system.beams.len(), u8::MAX as usize)?;
+                for beam in &system.beams {
+                    check_record_count(beam.track_jam_data.len(), u8::MAX as usize)?;
+                }
+            }
+        }
+        PduBody::Transmitter(body) => {
+            check_record_count(body.variable_transmitter_parameters.len(), u16::MAX as usize)?;
+            if let Some(modulation_parameters) = &body.modulation_parameters {
+                check_record_count(modulation_parameters.len(), u8::MAX as usize)?;
+            }
+        }
+        PduBody::Signal(body) => {
+            // the data length field states the number of bits
+            check_record_count(body.data.len() * ONE_BYTE_IN_BITS, u16::MAX as usize)?;
+        }
+        PduBody::IntercomSignal(body) => {
+            // the data length field states the number of bits
+            check_record_count(body.data.len() * ONE_BYTE_IN_BITS, u16::MAX as usize)?;
+        }
+        PduBody::IntercomControl(body) => {
+            check_record_count(body.intercom_parameters.len(), u32::MAX as usize)?;
+            for parameters in &body.intercom_parameters {
+                check_record_count(parameters.record_specific_field.len(), u16::MAX as usize)?;
+            }
+        }
+        PduBody::AggregateState(body) => {
+            check_ascii_string(&body.aggregate_marking.marking_string, AGGREGATE_MARKING_STRING_LENGTH)?;
+            check_record_count(body.aggregates.len(), u16::MAX as usize)?;
+            check_record_count(body.entities.len(), u16::MAX as usize)?;
+            check_record_count(body.silent_aggregate_systems.len(), u16::MAX as usize)?;
+            check_record_count(body.silent_entity_systems.len(), u16::MAX as usize)?;
+            for system in &body.silent_entity_systems {
+                check_record_count(system.appearances.len(), u16::MAX as usize)?;
+            }
+            check_record_count(body.variable_datums.len(), u32::MAX as usize)?;
+        }
+        PduBody::IsGroupOf(body) => {
+            check_record_count(body.descriptions.len(), u8::MAX as usize)?;
+            check_group_entity_descriptions(body)?;
+        }
+        PduBody::TransferOwnership(body) => { check_record_specification(&body.record_specification)?; }
+        PduBody::MinefieldState(body) => {
+            check_record_count(body.perimeter_points.len(), u8::MAX as usize)?;
+            check_record_count(body.mine_types.len(), u16::MAX as usize)?;
+        }
+        PduBody::MinefieldQuery(body) => {
+            check_record_count(body.requested_perimeter_points.len(), u8::MAX as usize)?;
+            check_record_count(body.sensor_types.len(), u8::MAX as usize)?;
+        }
+        PduBody::MinefieldData(body) => {
+            check_record_count(body.sensor_types.len(), u8::MAX as usize)?;
+            check_record_count(body.mines.len(), u8::MAX as usize)?;
+            for mine in &body.mines {
+                if let Some(wires) = &mine.trip_detonation_wires {
+                    check_record_count(wires.len(), u8::MAX as usize)?;
+                    for wire in wires {
+                        check_record_count(wire.vertices.len(), u8::MAX as usize)?;
+                    }
+                }
+            }
+        }
+        PduBody::MinefieldResponseNACK(body) => { check_record_count(body.missing_pdu_sequence_numbers.len(), u8::MAX as usize)?; }
+        PduBody::EnvironmentalProcess(body) => { check_record_count(body.environment_records.len(), u16::MAX as usize)?; }
+        PduBody::GriddedData(body) => {
+            check_record_count(body.grid_axis_descriptors.len(), u8::MAX as usize)?;
+            check_record_count(body.grid_data.len(), u8::MAX as usize)?;
+        }
+        PduBody::LinearObjectState(body) => { check_record_count(body.linear_segment_parameters.len(), u8::MAX as usize)?; }
+        PduBody::ArealObjectState(body) => { check_record_count(body.object_locations.len(), u16::MAX as usize)?; }
+        PduBody::TSPI(body) => {
+            if let Some(data) = &body.system_specific_data {
+                check_record_count(data.len(), u8::MAX as usize)?;
+            }
+        }
+        PduBody::Appearance(body) => {
+            if let Some(marking) = &body.entity_marking {
+                check_ascii_string(&marking.marking_string, ELEVEN_OCTETS)?;
+            }
+        }
+        PduBody::ArticulatedParts(body) => { check_record_count(body.variable_parameters.len(), u8::MAX as usize)?; }
+        PduBody::DirectedEnergyFire(body) => {
+            check_record_count(body.de_records.len(), u16::MAX as usize)?;
+            for record in &body.de_records {
+                if let DirectedEnergyRecord::AreaAiming(record) = record {
+                    check_record_count(record.beam_antenna_patterns.len(), u16::MAX as usize)?;
+                    check_record_count(record.target_energy_depositions.len(), u16::MAX as usize)?;
+                }
+            }
+        }
+        PduBody::EntityDamageStatus(body) => { check_record_count(body.damage_descriptions.len(), u16::MAX as usize)?; }
+        PduBody::InformationOperationsAction(body) => { check_record_count(body.io_records.len(), u16::MAX as usize)?; }
+        PduBody::InformationOperationsReport(body) => { check_record_count(body.io_records.len(), u16::MAX as usize)?; }
+        PduBody::UnderwaterAcoustic(body) => {
+            check_record_count(body.shafts.len(), u8::MAX as usize)?;
+            check_record_count(body.additional_passive_activities.len(), u8::MAX as usize)?;
+            check_record_count(body.emitter_systems.len(), u8::MAX as usize)?;
+            for system in &body.emitter_systems {
+                check_record_count(system.beams.len(), u8::MAX as usize)?;
+            }
+        }
+        PduBody::SupplementalEmissionEntityState(body) => {
+            check_record_count(body.propulsion_systems.len(), u16::MAX as usize)?;
+            check_record_count(body.vectoring_nozzle_systems.len(), u16::MAX as usize)?;
+        }
+        PduBody::IFF(body) => {
+            if let Some(layer_2) = &body.layer_2 {
+                check_record_count(layer_2.iff_fundamental_parameters.len(), u16::MAX as usize)?;
+            }
+            if let Some(layer_3) = &body.layer_3 {
+                check_record_count(layer_3.data_records.iff_data_records.len(), u16::MAX as usize)?;
+            }
+            if let Some(layer_4) = &body.layer_4 {
+                check_record_count(layer_4.data_records.iff_data_records.len(), u16::MAX as usize)?;
+                if let ModeSBasicData::Transponder(basic_data) = &layer_4.mode_s_basic_data {
+                    check_ascii_string(&basic_data.aircraft_identification, EIGHT_OCTETS)?;
+                }
+            }
+            if let Some(layer_5) = &body.layer_5 {
+                check_record_count(layer_5.data_records.iff_data_records.len(), u16::MAX as usize)?;
+            }
+        }
+        PduBody::Attribute(body) => {
+            check_record_count(body.attribute_record_sets.len(), u16::MAX as usize)?;
+            for record_set in &body.attribute_record_sets {
+                check_record_count(record_set.attribute_records.len(), u16::MAX as usize)?;
+            }
+        }
+        _ => { }
+    }
+    Ok(())
+}
+
+fn check_datum_counts<F, V>(fixed_datum_records: &[F], variable_datum_records: &[V]) -> Result<(), DisError> {
+    check_record_count(fixed_datum_records.len(), u32::MAX as usize)?;
+    check_record_count(variable_datum_records.len(), u32::MAX as usize)
+}
+
+fn check_record_specification(record_specification: &RecordSpecification) -> Result<(), DisError> {
+    check_record_count(record_specification.record_sets.len(), u32::MAX as usize)?;
+    for record_set in &record_specification.record_sets {
+        check_record_count(record_set.records.len(), u16::MAX as usize)?;
+        // the record length field states the number of bits
+        check_record_count(record_set.record_length_bytes as usize * ONE_BYTE_IN_BITS, u16::MAX as usize)?;
+        if let Some(record) = record_set.records.iter().find(|record| record.len() != record_set.record_length_bytes as usize) {
+            return Err(DisError::RecordLengthMismatch(record.len(), record_set.record_length_bytes));
+        }
+    }
+    Ok(())
+}
+
+/// The receiver determines the type of all GED records from the Grouped Entity Category,
+/// and divides the remainder of the body evenly over records of undefined or unknown categories.
+fn check_group_entity_descriptions(body: &IsGroupOf) -> Result<(), DisError> {
+    let category: u8 = body.grouped_entity_category.into();
+    let expected_category = if (1..=9).contains(&category) { Some(category) } else { None };
+    let record_length = body.descriptions.first().map(|description| description.record_length());
+    if body.descriptions.iter().any(|description|
+        description.grouped_entity_category() != expected_category || Some(description.record_length()) != record_length) {
+        return Err(DisError::GroupEntityDescriptionMismatch(body.grouped_entity_category));
+    }
+    Ok(())
+}
+
+fn check_record_count(count: usize, maximum: usize) -> Result<(), DisError> {
+    if count > maximum {
+        Err(DisError::RecordCountOverflow(count, maximum))
+    } else { Ok(()) }
+}
+
+/// Checks that a String value can be written as an ASCII string in a field of `length` octets.
+pub(crate) fn check_ascii_string(value: &str, length: usize) -> Result<(), DisError> {
+    if value.len() > length {
+        Err(DisError::StringTooLongError)
+    } else if !value.is_ascii() {
+        Err(DisError::StringNotAsciiError)
+    } else { Ok(()) }
+}
+
 impl Serialize for EntityId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let num_bytes = self.simulation_address.serialize(buf);
         buf.put_u16(self.entity_id);
         num_bytes + 2
@@ -120,7 +365,7 @@ impl Serialize for EntityId {
 }
 
 impl Serialize for SimulationAddress {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u16(self.site_id);
         buf.put_u16(self.application_id);
         4
@@ -128,7 +373,7 @@ impl Serialize for SimulationAddress {
 }
 
 impl Serialize for EventId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let num_bytes = self.simulation_address.serialize(buf);
         buf.put_u16(self.event_id);
         num_bytes + 2
@@ -136,7 +381,7 @@ impl Serialize for EventId {
 }
 
 impl Serialize for VectorF32 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f32(self.first_vector_component);
         buf.put_f32(self.second_vector_component);
         buf.put_f32(self.third_vector_component);
@@ -145,7 +390,7 @@ impl Serialize for VectorF32 {
 }
 
 impl Serialize for Location {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f64(self.x_coordinate);
         buf.put_f64(self.y_coordinate);
         buf.put_f64(self.z_coordinate);
@@ -154,7 +399,7 @@ impl Serialize for Location {
 }
 
 impl Serialize for Orientation {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f32(self.psi);
         buf.put_f32(self.theta);
         buf.put_f32(self.phi);
@@ -163,7 +408,7 @@ impl Serialize for Orientation {
 }
 
 impl Serialize for DescriptorRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         match self {
             DescriptorRecord::Munition { entity_type, munition } => {
                 let entity_bytes = entity_type.serialize(buf);
@@ -187,7 +432,7 @@ impl Serialize for DescriptorRecord {
 }
 
 impl Serialize for MunitionDescriptor {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u16(self.warhead.into());
         buf.put_u16(self.fuse.into());
         buf.put_u16(self.quantity);
@@ -197,15 +442,229 @@ impl Serialize for MunitionDescriptor {
 }
 
 impl Serialize for ClockTime {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_i32(self.hour);
         buf.put_u32(self.time_past_hour);
         8
     }
 }
 
+impl Serialize for SupplyQuantity {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let supply_type_bytes = self.supply_type.serialize(buf);
+        buf.put_f32(self.quantity);
+
+        supply_type_bytes + 4
+    }
+}
+
+impl Serialize for RecordSpecification {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(self.record_sets.len() as u32);
+        let record_sets_bytes = self.record_sets.iter()
+            .map(|record_set| record_set.serialize(buf))
+            .sum::<u16>();
+
+        4 + record_sets_bytes
+    }
+}
+
+impl Serialize for RecordSet {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(self.record_id.into());
+        buf.put_u32(self.record_serial_number);
+        buf.put_u32(0u32);
+        // the record length field states the number of bits; TrySerialize rejects record lengths that do not fit
+        buf.put_u16(self.record_length_bytes.saturating_mul(ONE_BYTE_IN_BITS as u16));
+        buf.put_u16(self.records.len() as u16);
+        let record_length = self.record_length_bytes as usize;
+        for record in &self.records {
+            // TrySerialize rejects records that differ in length from the record length of the set
+            let record = &record[..record.len().min(record_length)];
+            buf.put_slice(record);
+            buf.put_bytes(0u8, record_length - record.len());
+        }
+        buf.put_bytes(0u8, self.padding_length());
+
+        self.record_set_length()
+    }
+}
+
+impl Serialize for PerimeterPoint {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_f32(self.x);
+        buf.put_f32(self.y);
+        8
+    }
+}
+
+impl Serialize for DataFilter {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let filter = u32::from(self.ground_burial_depth_offset) |
+            (u32::from(self.water_burial_depth_offset) << 1) |
+            (u32::from(self.snow_burial_depth_offset) << 2) |
+            (u32::from(self.mine_orientation) << 3) |
+            (u32::from(self.thermal_contrast) << 4) |
+            (u32::from(self.reflectance) << 5) |
+            (u32::from(self.mine_emplacement_time) << 6) |
+            (u32::from(self.trip_detonation_wire) << 7) |
+            (u32::from(self.fusing) << 8) |
+            (u32::from(self.scalar_detection_coefficient) << 9) |
+            (u32::from(self.paint_scheme) << 10);
+        buf.put_u32(filter);
+        4
+    }
+}
+
+impl Serialize for ObjectType {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.domain.into());
+        buf.put_u8(self.kind.into());
+        buf.put_u8(self.category);
+        buf.put_u8(self.subcategory);
+        4
+    }
+}
+
+impl Serialize for GeneralObjectAppearance {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let appearance = u16::from(self.percent_complete) |
+            ((u16::from(self.damage) & 0x0003) << 8) |
+            (u16::from(self.predistributed) << 10) |
+            (u16::from(self.deactivated) << 11) |
+            (u16::from(self.smoking) << 12) |
+            (u16::from(self.flaming) << 13) |
+            ((u16::from(self.ied_present) & 0x0003) << 14);
+        buf.put_u16(appearance);
+        2
+    }
+}
+
+impl Serialize for ObjectStateModification {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        let modifications = u8::from(self.location) |
+            (u8::from(self.orientation) << 1);
+        buf.put_u8(modifications);
+        1
+    }
+}
+
+impl Serialize for LiveEntityId {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.site_number);
+        buf.put_u8(self.application_number);
+        buf.put_u16(self.entity_number);
+        4
+    }
+}
+
+impl Serialize for LiveEventId {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.site_number);
+        buf.put_u8(self.application_number);
+        buf.put_u16(self.event_number);
+        4
+    }
+}
+
+impl Serialize for LiveEntityRelativeWorldCoordinates {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.reference_point);
+        buf.put_i16(self.delta_x);
+        buf.put_i16(self.delta_y);
+        buf.put_i16(self.delta_z);
+        8
+    }
+}
+
+impl Serialize for LiveEntityVector {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_i16(self.x);
+        buf.put_i16(self.y);
+        buf.put_i16(self.z);
+        6
+    }
+}
+
+impl Serialize for LiveEntityOrientation {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_i8(self.psi);
+        buf.put_i8(self.theta);
+        buf.put_i8(self.phi);
+        3
+    }
+}
+
+impl Serialize for LiveEntityPositionError {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.horizontal_error);
+        buf.put_u16(self.vertical_error);
+        4
+    }
+}
+
+impl Serialize for LiveEntityOrientationError {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u16(self.azimuth_error);
+        buf.put_u16(self.elevation_error);
+        buf.put_u16(self.rotation_error);
+        6
+    }
+}
+
+impl Serialize for LiveDeadReckoningParameters {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.algorithm.into());
+        let acceleration_bytes = self.linear_acceleration.serialize(buf);
+        let angular_velocity_bytes = self.angular_velocity.serialize(buf);
+        1 + acceleration_bytes + angular_velocity_bytes
+    }
+}
+
+impl Serialize for IoRecord {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u32(self.record_type());
+        buf.put_u16(self.record_length());
+        let record_bytes = match self {
+            IoRecord::CommunicationsNode(record) => { record.serialize(buf) }
+            IoRecord::Effect(record) => { record.serialize(buf) }
+            IoRecord::Unspecified(_, data) => {
+                buf.put_slice(data);
+                data.len() as u16
+            }
+        };
+
+        IO_RECORD_HEADER_LENGTH + record_bytes
+    }
+}
+
+impl Serialize for IoCommunicationsNode {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.communications_node_type.into());
+        buf.put_u8(0u8);
+        let node_id_bytes = self.communications_node_id.serialize(buf);
+        buf.put_u16(self.element_id);
+
+        2 + node_id_bytes + 2
+    }
+}
+
+impl Serialize for IoEffect {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
+        buf.put_u8(self.status.into());
+        buf.put_u8(self.link_type.into());
+        buf.put_u8(self.effect.into());
+        buf.put_u8(self.effect_duty_cycle);
+        buf.put_u16(self.effect_duration);
+        buf.put_u16(self.process.into());
+        buf.put_u16(0u16);
+
+        10
+    }
+}
+
 impl Serialize for FixedDatum {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u32(self.datum_id.into());
         buf.put_u32(self.datum_value);
 
@@ -214,22 +673,22 @@ impl Serialize for FixedDatum {
 }
 
 impl Serialize for VariableDatum {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u32(self.datum_id.into());
         let padded_record = length_padded_to_num_bytes(
             EIGHT_OCTETS + self.datum_value.len(),
             EIGHT_OCTETS);
-        let data_length_with_padding = padded_record.record_length_bytes as u16;
-        buf.put_u32(data_length_with_padding.into());
+        let datum_length_bits = (self.datum_value.len() * ONE_BYTE_IN_BITS) as u32;
+        buf.put_u32(datum_length_bits);
         buf.put_slice(self.datum_value.as_slice());
         buf.put_bytes(0, padded_record.padding_length_bytes);
 
-        8 + data_length_with_padding
+        padded_record.record_length_bytes as u16
     }
 }
 
 impl Serialize for VariableParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         match self {
             VariableParameter::Articulated(inner) => {
                 buf.put_u8(VariableParameterRecordType::ArticulatedPart.into());
@@ -261,7 +720,7 @@ impl Serialize for VariableParameter {
 }
 
 impl Serialize for ArticulatedPart {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.change_indicator.into());
         buf.put_u16(self.attachment_id.into());
         let type_class: u32 = self.type_class.into();
@@ -275,7 +734,7 @@ impl Serialize for ArticulatedPart {
 }
 
 impl Serialize for AttachedPart {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.detached_indicator.into());
         buf.put_u16(self.attachment_id);
         buf.put_u32(self.parameter_type.into());
@@ -286,7 +745,7 @@ impl Serialize for AttachedPart {
 }
 
 impl Serialize for SeparationParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.reason.into());
         buf.put_u8(self.pre_entity_indicator.into());
         buf.put_u8(0u8);
@@ -300,7 +759,7 @@ impl Serialize for SeparationParameter {
 }
 
 impl Serialize for EntityTypeParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.change_indicator.into());
         self.entity_type.serialize(buf);
         buf.put_u16(0u16);
@@ -311,7 +770,7 @@ impl Serialize for EntityTypeParameter {
 }
 
 impl Serialize for EntityAssociationParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_u8(self.change_indicator.into());
         buf.put_u8(self.association_status.into());
         buf.put_u8(self.association_type.into());
@@ -326,7 +785,7 @@ impl Serialize for EntityAssociationParameter {
 }
 
 impl Serialize for BeamData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         buf.put_f32(self.azimuth_center);
         buf.put_f32(self.azimuth_sweep);
         buf.put_f32(self.elevation_center);
@@ -340,10 +799,15 @@ impl Serialize for BeamData {
 #[cfg(test)]
 mod tests {
     use bytes::BytesMut;
-    use crate::common::Serialize;
+    use crate::common::{Serialize, TrySerialize};
+    use crate::common::entity_state::model::{EntityMarking, EntityState};
+    use crate::common::errors::DisError;
+    use crate::common::other::model::Other;
+    use crate::common::transfer_ownership::model::TransferOwnership;
     use crate::constants::PDU_HEADER_LEN_BYTES;
-    use crate::enumerations::{PduType, LvcIndicator};
-    use crate::common::model::PduHeader;
+    use crate::enumerations::{EntityKind, EntityMarkingCharacterSet, ForceId, PduType, LvcIndicator, VariableRecordType};
+    use crate::common::model::{EntityId, EntityType, Pdu, PduHeader, RecordSet, VariableDatum, VariableParameter};
+    use crate::common::parser::variable_datum;
     use crate::v7::model::PduStatus;
 
     #[test]
@@ -385,4 +849,116 @@ mod tests {
         let expected : [u8;12] = [0x07, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x0c, 0x02, 0x00];
         assert_eq!(buf.as_ref(), expected.as_ref());
     }
+
+    fn entity_state_pdu(entity_state: EntityState) -> Pdu {
+        Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityState), entity_state.into_pdu_body(), 0)
+    }
+
+    fn entity_state() -> EntityState {
+        EntityState::new(EntityId::new(1, 1, 1), ForceId::Friendly, EntityType::default().with_kind(EntityKind::Platform))
+    }
+
+    #[test]
+    fn try_serialize_matches_serialize() {
+        let pdu = entity_state_pdu(entity_state());
+        let mut expected = BytesMut::new();
+        pdu.serialize(&mut expected);
+
+        let mut buf = BytesMut::new();
+        assert_eq!(pdu.try_serialize(&mut buf), Ok(expected.len()));
+        assert_eq!(buf, expected);
+
+        let mut slice = [0u8; 256];
+        assert_eq!(pdu.try_serialize_to_slice(&mut slice), Ok(expected.len()));
+        assert_eq!(&slice[..expected.len()], expected.as_ref());
+    }
+
+    #[test]
+    fn try_serialize_insufficient_slice() {
+        let pdu = entity_state_pdu(entity_state());
+        let mut slice = [0u8; 100];
+
+        assert_eq!(pdu.try_serialize_to_slice(&mut slice), Err(DisError::InsufficientBufferSize(144, 100)));
+    }
+
+    #[test]
+    fn try_serialize_invalid_marking() {
+        let pdu = entity_state_pdu(entity_state()
+            .with_marking(EntityMarking::new("MARKING_TOO_LONG".to_string(), EntityMarkingCharacterSet::ASCII)));
+        assert_eq!(pdu.try_serialize(&mut BytesMut::new()), Err(DisError::StringTooLongError));
+
+        let pdu = entity_state_pdu(entity_state()
+            .with_marking(EntityMarking::new("Ünicode".to_string(), EntityMarkingCharacterSet::ASCII)));
+        assert_eq!(pdu.try_serialize(&mut BytesMut::new()), Err(DisError::StringNotAsciiError));
+    }
+
+    #[test]
+    fn try_serialize_record_set_lengths() {
+        let pdu = |record_set: RecordSet| {
+            let body = TransferOwnership::builder()
+                .with_record_set(record_set)
+                .build()
+                .into_pdu_body();
+            Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::TransferOwnership), body, 0)
+        };
+
+        let mut longer_record = RecordSet::new(VariableRecordType::Azimuth_52340, 1)
+            .with_record(vec![1, 2, 3, 4]);
+        longer_record.record_length_bytes = 2;
+        assert_eq!(pdu(longer_record).try_serialize(&mut BytesMut::new()), Err(DisError::RecordLengthMismatch(4, 2)));
+
+        let shorter_record = RecordSet::new(VariableRecordType::Azimuth_52340, 1)
+            .with_record(vec![1, 2, 3, 4])
+            .with_record(vec![5, 6]);
+        assert_eq!(pdu(shorter_record).try_serialize(&mut BytesMut::new()), Err(DisError::RecordLengthMismatch(2, 4)));
+
+        // the record length field states the number of bits, which must fit in 16 bits
+        let oversized = RecordSet::new(VariableRecordType::Azimuth_52340, 1)
+            .with_record(vec![0; 8192]);
+        assert_eq!(pdu(oversized).try_serialize(&mut BytesMut::new()), Err(DisError::RecordCountOverflow(65536, 65535)));
+    }
+
+    #[test]
+    fn try_serialize_record_count_overflow() {
+        let mut body = entity_state();
+        body.variable_parameters = (0..256).map(|_| VariableParameter::Unspecified(0, [0; 15])).collect();
+        let pdu = Pdu {
+            header: PduHeader::new_v7(1, PduType::EntityState),
+            body: body.into_pdu_body(),
+        };
+
+        assert_eq!(pdu.try_serialize(&mut BytesMut::new()), Err(DisError::RecordCountOverflow(256, 255)));
+    }
+
+    #[test]
+    fn try_serialize_too_large() {
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::Other),
+                                           Other::new(vec![0; 8200]).into_pdu_body(), 0);
+
+        assert_eq!(pdu.try_serialize(&mut BytesMut::new()), Err(DisError::PduTooLarge(8212)));
+    }
+
+    #[test]
+    fn try_serialize_length_mismatch() {
+        let pdu = Pdu {
+            header: PduHeader::new_v7(1, PduType::EntityState),
+            body: entity_state().into_pdu_body(),
+        };
+
+        assert_eq!(pdu.try_serialize(&mut BytesMut::new()), Err(DisError::PduLengthMismatch(144, 0)));
+    }
+
+    #[test]
+    fn variable_datum_length_in_bits() {
+        let datum = VariableDatum::new(VariableRecordType::Azimuth_52340, vec![1, 2, 3, 4, 5, 6, 7]);
+        let mut buf = BytesMut::new();
+
+        assert_eq!(datum.serialize(&mut buf), 16);
+        assert_eq!(&buf[4..8], &[0x00, 0x00, 0x00, 0x38]);
+        assert_eq!(&buf[8..], &[1, 2, 3, 4, 5, 6, 7, 0]);
+
+        let (remainder, parsed) = variable_datum(&buf).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(parsed, datum);
+    }
 }
@@ -11,11 +11,11 @@ pub const FOUR_OCTETS: usize = 4;
 #[allow(dead_code)]
 pub const SIX_OCTETS: usize = 6;
 pub const EIGHT_OCTETS: usize = 8;
+pub const ELEVEN_OCTETS: usize = 11;
 pub const LEAST_SIGNIFICANT_BIT : u32 = 0x001;
 pub const FIVE_LEAST_SIGNIFICANT_BITS : u32 = 0x1f;
-pub const NANOSECONDS_PER_HOUR: u32 = 3600 * 1e6 as u32;
-pub const TIME_UNITS_PER_HOUR: u32 = 2^31 - 1;
-pub const NANOSECONDS_PER_TIME_UNIT: f32 = NANOSECONDS_PER_HOUR as f32 / TIME_UNITS_PER_HOUR as f32;
+pub const NANOSECONDS_PER_HOUR: u64 = 3_600_000_000_000;
+pub const TIME_UNITS_PER_HOUR: u32 = (1 << 31) - 1;
 
 pub const BIT_0_IN_BYTE: u8 = 0x80;
 pub const BIT_1_IN_BYTE: u8 = 0x40;
@@ -23,7 +23,6 @@ pub use common::errors::*;
 /*
 TODO:
 - Common function for calculating body length based on header data (pdu_length - header_length); now at several places
-- Dead-reckoning algorithms
 
 TESTS:
 - Build Other PDU
@@ -1,8 +1,9 @@
-use nom::{bits, IResult};
-use nom::error::Error;
+use nom::bits;
 use nom::sequence::tuple;
 use nom::complete::take as take_bits;
 use nom::bytes::complete::take as take_bytes;
+use crate::common::errors::FieldParseError;
+use crate::common::parser::IResult;
 use crate::v6::entity_state::model::EntityCapabilities;
 
 pub fn entity_capabilities(input: &[u8]) -> IResult<&[u8], EntityCapabilities> {
@@ -11,7 +12,7 @@ pub fn entity_capabilities(input: &[u8]) -> IResult<&[u8], EntityCapabilities> {
             fuel_supply,
             recovery,
             repair,
-            _pad_out)) : (&[u8], (u8,u8,u8,u8,u8)) = bits::<_,_,Error<(&[u8], usize)>,_,_>(tuple(
+            _pad_out)) : (&[u8], (u8,u8,u8,u8,u8)) = bits::<_,_,FieldParseError<(&[u8], usize)>,_,_>(tuple(
         (take_bits(1usize),
          take_bits(1usize),
          take_bits(1usize),
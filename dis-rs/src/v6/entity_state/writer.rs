@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::Serialize;
 use crate::v6::entity_state::model::EntityCapabilities;
 
 impl Serialize for EntityCapabilities {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let ammunition_supply = u32::from(self.ammunition_supply) << 31;
         let fuel_supply = u32::from(self.fuel_supply) << 30;
         let recovery = u32::from(self.recovery) << 29;
@@ -1,9 +1,9 @@
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use crate::common::Serialize;
 use crate::v7::model::PduStatus;
 
 impl Serialize for PduStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize<B: BufMut>(&self, buf: &mut B) -> u16 {
         let tei : u8 = if let Some(tei) = self.transferred_entity_indicator {
             u8::from(tei)
         } else {0u8};
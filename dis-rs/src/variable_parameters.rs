@@ -1,7 +1,12 @@
 use crate::{NO_APPLIC, NO_ENTITY, NO_SITE};
+use crate::common::entity_state::model::EntityState;
 use crate::common::model::EntityId;
+use crate::enumerations::{DeadReckoningAlgorithm, EntityKind, PlatformDomain};
+
+const SECONDS_PER_MINUTE: f32 = 60.0;
 
 #[allow(non_snake_case)]
+#[derive(Clone)]
 pub struct VariableParameters {
     pub AGG_RESPONSE_DFLT: f32, //Default: 10 s
     pub COLLISION_ELASTIC_TIMEOUT: f32, //Default: 5 s
@@ -110,6 +115,33 @@ impl VariableParameters {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the heartbeat interval in seconds for EntityState PDUs of the given entity,
+    /// based on the kind and domain of the entity type.
+    /// Entities that use the Static dead reckoning algorithm use the HBT_STATIONARY interval.
+    pub fn entity_state_heartbeat(&self, entity_state: &EntityState) -> f32 {
+        if entity_state.dead_reckoning_parameters.algorithm == DeadReckoningAlgorithm::StaticNonmovingEntity {
+            return self.HBT_STATIONARY * SECONDS_PER_MINUTE;
+        }
+
+        match (entity_state.entity_type.kind, entity_state.entity_type.domain) {
+            (EntityKind::Platform, PlatformDomain::Air) => { self.HBT_ESPDU_PLATFORM_AIR }
+            (EntityKind::Platform, PlatformDomain::Surface) => { self.HBT_ESPDU_PLATFORM_SURFACE }
+            (EntityKind::Platform, PlatformDomain::Subsurface) => { self.HBT_ESPDU_PLATFORM_SUBSURFACE }
+            (EntityKind::Platform, PlatformDomain::Space) => { self.HBT_ESPDU_PLATFORM_SPACE }
+            (EntityKind::Platform, _) => { self.HBT_ESPDU_PLATFORM_LAND }
+            (EntityKind::Munition, _) => { self.HBT_ESPDU_KIND_MUNITION }
+            (EntityKind::Lifeform, _) => { self.HBT_ESPDU_KIND_LIFE_FORM }
+            (EntityKind::Environmental, _) => { self.HBT_ESPDU_KIND_ENVIRONMENTAL }
+            (EntityKind::Culturalfeature, _) => { self.HBT_ESPDU_KIND_CULTURAL_FEATURE }
+            (EntityKind::Supply, _) => { self.HBT_ESPDU_KIND_SUPPLY }
+            (EntityKind::Radio, _) => { self.HBT_ESPDU_KIND_RADIO }
+            (EntityKind::Expendable, _) => { self.HBT_ESPDU_KIND_EXPENDABLE }
+            (EntityKind::SensorEmitter, _) => { self.HBT_ESPDU_KIND_SENSOR }
+            // Other and unspecified kinds use the platform (land) heartbeat, which equals all other defaults
+            (_, _) => { self.HBT_ESPDU_PLATFORM_LAND }
+        }
+    }
 }
 
 impl Default for VariableParameters {